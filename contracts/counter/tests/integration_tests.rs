@@ -65,6 +65,9 @@ fn count() -> anyhow::Result<()> {
         exec_res.unwrap_err().downcast::<ContractError>()?,
         expected_err
     );
+    // Or compare the error with the contract error directly
+    let exec_res = contract.call_as(&user).reset(0);
+    assert!(exec_res.unwrap_err().is_contract_error(&expected_err));
 
     Ok(())
 }
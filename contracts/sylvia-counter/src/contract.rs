@@ -0,0 +1,65 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Response, StdResult};
+use cw_storage_plus::Item;
+use sylvia::types::{ExecCtx, InstantiateCtx, MigrateCtx, QueryCtx};
+use sylvia::{contract, entry_points};
+
+#[cw_serde]
+pub struct CountResponse {
+    pub count: u32,
+}
+
+pub struct CounterContract {
+    pub(crate) count: Item<'static, u32>,
+}
+
+impl Default for CounterContract {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// ANCHOR: contract
+#[entry_points]
+#[contract]
+#[sv::msg_attr(exec, derive(cw_orch::ExecuteFns))]
+#[sv::msg_attr(query, derive(cw_orch::QueryFns))]
+impl CounterContract {
+    // ANCHOR_END: contract
+    pub const fn new() -> Self {
+        Self {
+            count: Item::new("count"),
+        }
+    }
+
+    #[sv::msg(instantiate)]
+    pub fn instantiate(&self, ctx: InstantiateCtx, count: u32) -> StdResult<Response> {
+        self.count.save(ctx.deps.storage, &count)?;
+        Ok(Response::new())
+    }
+
+    #[sv::msg(exec)]
+    pub fn increment(&self, ctx: ExecCtx) -> StdResult<Response> {
+        self.count
+            .update(ctx.deps.storage, |count| -> StdResult<_> { Ok(count + 1) })?;
+        Ok(Response::new().add_attribute("action", "increment"))
+    }
+
+    #[sv::msg(exec)]
+    pub fn set_count(&self, ctx: ExecCtx, count: u32) -> StdResult<Response> {
+        self.count.save(ctx.deps.storage, &count)?;
+        Ok(Response::new().add_attribute("action", "set_count"))
+    }
+
+    #[sv::msg(query)]
+    pub fn count(&self, ctx: QueryCtx) -> StdResult<CountResponse> {
+        let count = self.count.load(ctx.deps.storage)?;
+        Ok(CountResponse { count })
+    }
+
+    #[sv::msg(migrate)]
+    pub fn migrate(&self, ctx: MigrateCtx) -> StdResult<Response> {
+        self.count.save(ctx.deps.storage, &0)?;
+        Ok(Response::new())
+    }
+}
@@ -0,0 +1,15 @@
+// ANCHOR: interface
+use cw_orch::interface;
+
+use crate::contract::CounterContract;
+
+pub const CONTRACT_ID: &str = "sylvia_counter";
+
+#[interface(
+    sylvia = CounterContract,
+    entry_points(crate::contract::entry_points, migrate),
+    wasm = "sylvia_counter",
+    id = CONTRACT_ID
+)]
+pub struct SylviaCounter;
+// ANCHOR_END: interface
@@ -0,0 +1,13 @@
+pub mod contract;
+
+// ANCHOR: fn_re_export
+pub use crate::contract::sv::{
+    ExecMsgFns as SylviaCounterExecuteMsgFns, QueryMsgFns as SylviaCounterQueryMsgFns,
+};
+// ANCHOR_END: fn_re_export
+
+#[cfg(not(target_arch = "wasm32"))]
+mod interface;
+
+#[cfg(not(target_arch = "wasm32"))]
+pub use crate::interface::SylviaCounter;
@@ -0,0 +1,47 @@
+use cw_orch::prelude::*;
+use sylvia_counter::{
+    contract::sv::{ExecMsg, InstantiateMsg, MigrateMsg, QueryMsg},
+    contract::CountResponse,
+    SylviaCounter, SylviaCounterExecuteMsgFns, SylviaCounterQueryMsgFns,
+};
+
+const ADMIN: &str = "admin";
+
+fn setup(chain: Mock) -> anyhow::Result<SylviaCounter<Mock>> {
+    let contract = SylviaCounter::new(chain);
+    contract.upload()?;
+    contract.instantiate(
+        &InstantiateMsg { count: 7 },
+        Some(&Addr::unchecked(ADMIN)),
+        None,
+    )?;
+    Ok(contract)
+}
+
+#[test]
+fn execute_and_query_fns() -> anyhow::Result<()> {
+    let contract = setup(Mock::new(ADMIN))?;
+
+    contract.increment()?;
+    assert_eq!(contract.count()?.count, 8);
+
+    contract.set_count(3)?;
+    assert_eq!(contract.count()?, CountResponse { count: 3 });
+
+    // The interface messages are the Sylvia ones
+    contract.execute(&ExecMsg::Increment {}, None)?;
+    let count: CountResponse = contract.query(&QueryMsg::Count {})?;
+    assert_eq!(count.count, 4);
+    Ok(())
+}
+
+#[test]
+fn wired_entry_points() -> anyhow::Result<()> {
+    let contract = setup(Mock::new(ADMIN))?;
+    contract.set_count(5)?;
+
+    // The migrate entry point listed in `entry_points` is part of the mock wrapper
+    contract.migrate(&MigrateMsg {}, contract.code_id()?)?;
+    assert_eq!(contract.count()?.count, 0);
+    Ok(())
+}
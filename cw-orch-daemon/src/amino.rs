@@ -0,0 +1,286 @@
+//! Legacy Amino-JSON sign docs, signed with `SIGN_MODE_LEGACY_AMINO_JSON`.
+//!
+//! Ledger devices and some older chain integrations can't sign the protobuf `SignDoc` of `SIGN_MODE_DIRECT`.
+//! They sign a canonical JSON `StdSignDoc` instead, built here from the transaction body and fee.
+//! Only bank sends, multi-sends, wasm messages and authz `MsgExec` wrapping them can be converted.
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use cosmrs::{
+    proto::{
+        cosmos::{
+            authz::v1beta1::MsgExec,
+            bank::v1beta1::{MsgMultiSend, MsgSend},
+            base::v1beta1::Coin,
+        },
+        cosmwasm::wasm::v1::{
+            MsgExecuteContract, MsgInstantiateContract, MsgInstantiateContract2,
+            MsgMigrateContract, MsgStoreCode,
+        },
+    },
+    tx::{Body, Fee},
+    Any,
+};
+use prost::{Message, Name};
+use serde_json::{json, Map, Value};
+
+use crate::{DaemonError, INSTANTIATE_2_TYPE_URL};
+
+/// Amino-JSON `StdSignDoc` of a transaction
+pub fn std_sign_doc(
+    body: &Body,
+    fee: &Fee,
+    chain_id: &str,
+    account_number: u64,
+    sequence: u64,
+) -> Result<Value, DaemonError> {
+    let msgs = body
+        .messages
+        .iter()
+        .map(amino_msg)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut doc = json!({
+        "account_number": account_number.to_string(),
+        "chain_id": chain_id,
+        "fee": std_fee(fee),
+        "memo": body.memo,
+        "msgs": msgs,
+        "sequence": sequence.to_string(),
+    });
+    let timeout_height = body.timeout_height.value();
+    if timeout_height != 0 {
+        doc["timeout_height"] = Value::String(timeout_height.to_string());
+    }
+    Ok(doc)
+}
+
+/// Bytes of the sign doc that are actually signed: compact JSON with sorted keys, escaped like Go's `encoding/json`
+pub fn sign_bytes(sign_doc: &Value) -> Result<Vec<u8>, DaemonError> {
+    let json = serde_json::to_string(&sort_keys(sign_doc.clone()))?;
+    Ok(json
+        .replace('<', "\\u003c")
+        .replace('>', "\\u003e")
+        .replace('&', "\\u0026")
+        .replace('\u{2028}', "\\u2028")
+        .replace('\u{2029}', "\\u2029")
+        .into_bytes())
+}
+
+/// Amino-JSON representation of a message
+pub fn amino_msg(msg: &Any) -> Result<Value, DaemonError> {
+    let (amino_type, value) = match msg.type_url.as_str() {
+        url if url == MsgSend::type_url() => {
+            let msg = MsgSend::decode(msg.value.as_slice())?;
+            (
+                "cosmos-sdk/MsgSend",
+                json!({
+                    "from_address": msg.from_address,
+                    "to_address": msg.to_address,
+                    "amount": coins(&msg.amount),
+                }),
+            )
+        }
+        url if url == MsgMultiSend::type_url() => {
+            let msg = MsgMultiSend::decode(msg.value.as_slice())?;
+            let io = |address: &str, amount: &[Coin]| {
+                json!({
+                    "address": address,
+                    "coins": coins(amount),
+                })
+            };
+            let inputs: Vec<Value> = msg
+                .inputs
+                .iter()
+                .map(|i| io(&i.address, &i.coins))
+                .collect();
+            let outputs: Vec<Value> = msg
+                .outputs
+                .iter()
+                .map(|o| io(&o.address, &o.coins))
+                .collect();
+            (
+                "cosmos-sdk/MsgMultiSend",
+                json!({
+                    "inputs": inputs,
+                    "outputs": outputs,
+                }),
+            )
+        }
+        url if url == MsgExec::type_url() => {
+            let msg = MsgExec::decode(msg.value.as_slice())?;
+            let msgs = msg
+                .msgs
+                .iter()
+                .map(amino_msg)
+                .collect::<Result<Vec<_>, _>>()?;
+            (
+                "cosmos-sdk/MsgExec",
+                json!({
+                    "grantee": msg.grantee,
+                    "msgs": msgs,
+                }),
+            )
+        }
+        url if url == MsgExecuteContract::type_url() => {
+            let msg = MsgExecuteContract::decode(msg.value.as_slice())?;
+            (
+                "wasm/MsgExecuteContract",
+                json!({
+                    "sender": msg.sender,
+                    "contract": msg.contract,
+                    "msg": serde_json::from_slice::<Value>(&msg.msg)?,
+                    "funds": coins(&msg.funds),
+                }),
+            )
+        }
+        url if url == MsgInstantiateContract::type_url() => {
+            let msg = MsgInstantiateContract::decode(msg.value.as_slice())?;
+            let mut value = json!({
+                "sender": msg.sender,
+                "code_id": msg.code_id.to_string(),
+                "label": msg.label,
+                "msg": serde_json::from_slice::<Value>(&msg.msg)?,
+                "funds": coins(&msg.funds),
+            });
+            if !msg.admin.is_empty() {
+                value["admin"] = Value::String(msg.admin);
+            }
+            ("wasm/MsgInstantiateContract", value)
+        }
+        INSTANTIATE_2_TYPE_URL => {
+            let msg = MsgInstantiateContract2::decode(msg.value.as_slice())?;
+            let mut value = json!({
+                "sender": msg.sender,
+                "code_id": msg.code_id.to_string(),
+                "label": msg.label,
+                "msg": serde_json::from_slice::<Value>(&msg.msg)?,
+                "funds": coins(&msg.funds),
+                "salt": STANDARD.encode(&msg.salt),
+            });
+            if !msg.admin.is_empty() {
+                value["admin"] = Value::String(msg.admin);
+            }
+            if msg.fix_msg {
+                value["fix_msg"] = Value::Bool(true);
+            }
+            ("wasm/MsgInstantiateContract2", value)
+        }
+        url if url == MsgMigrateContract::type_url() => {
+            let msg = MsgMigrateContract::decode(msg.value.as_slice())?;
+            (
+                "wasm/MsgMigrateContract",
+                json!({
+                    "sender": msg.sender,
+                    "contract": msg.contract,
+                    "code_id": msg.code_id.to_string(),
+                    "msg": serde_json::from_slice::<Value>(&msg.msg)?,
+                }),
+            )
+        }
+        url if url == MsgStoreCode::type_url() => {
+            let msg = MsgStoreCode::decode(msg.value.as_slice())?;
+            if msg.instantiate_permission.is_some() {
+                return Err(DaemonError::StdErr(
+                    "amino-json signing of MsgStoreCode with an instantiate permission is not supported".to_string(),
+                ));
+            }
+            (
+                "wasm/MsgStoreCode",
+                json!({
+                    "sender": msg.sender,
+                    "wasm_byte_code": STANDARD.encode(&msg.wasm_byte_code),
+                }),
+            )
+        }
+        other => {
+            return Err(DaemonError::StdErr(format!(
+                "message {other} can't be signed in amino-json sign mode"
+            )))
+        }
+    };
+    Ok(json!({ "type": amino_type, "value": value }))
+}
+
+fn std_fee(fee: &Fee) -> Value {
+    let amount: Vec<Value> = fee
+        .amount
+        .iter()
+        .map(|c| json!({ "amount": c.amount.to_string(), "denom": c.denom.to_string() }))
+        .collect();
+    let mut std_fee = json!({
+        "amount": amount,
+        "gas": fee.gas_limit.to_string(),
+    });
+    if let Some(payer) = &fee.payer {
+        std_fee["payer"] = Value::String(payer.to_string());
+    }
+    if let Some(granter) = &fee.granter {
+        std_fee["granter"] = Value::String(granter.to_string());
+    }
+    std_fee
+}
+
+fn coins(coins: &[Coin]) -> Value {
+    coins
+        .iter()
+        .map(|c| json!({ "amount": c.amount, "denom": c.denom }))
+        .collect()
+}
+
+/// Recursively sorts the keys of the JSON objects, regardless of the `preserve_order` feature of `serde_json`
+fn sort_keys(value: Value) -> Value {
+    match value {
+        Value::Object(map) => {
+            let mut entries: Vec<_> = map.into_iter().collect();
+            entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+            Value::Object(
+                entries
+                    .into_iter()
+                    .map(|(k, v)| (k, sort_keys(v)))
+                    .collect::<Map<_, _>>(),
+            )
+        }
+        Value::Array(values) => Value::Array(values.into_iter().map(sort_keys).collect()),
+        other => other,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn execute_sign_bytes() {
+        let msg = MsgExecuteContract {
+            sender: "juno1sender".to_string(),
+            contract: "juno1contract".to_string(),
+            msg: br#"{"transfer":{"recipient":"juno1b","amount":"10"}}"#.to_vec(),
+            funds: vec![],
+        };
+        let body = Body::new(
+            vec![Any {
+                type_url: MsgExecuteContract::type_url(),
+                value: msg.encode_to_vec(),
+            }],
+            "a<b",
+            0u32,
+        );
+        let fee = Fee::from_amount_and_gas(cosmrs::Coin::new(5000, "ujuno").unwrap(), 200_000u64);
+
+        let doc = std_sign_doc(&body, &fee, "juno-1", 7, 3).unwrap();
+        let bytes = String::from_utf8(sign_bytes(&doc).unwrap()).unwrap();
+        assert_eq!(
+            bytes,
+            r#"{"account_number":"7","chain_id":"juno-1","fee":{"amount":[{"amount":"5000","denom":"ujuno"}],"gas":"200000"},"memo":"a\u003cb","msgs":[{"type":"wasm/MsgExecuteContract","value":{"contract":"juno1contract","funds":[],"msg":{"transfer":{"amount":"10","recipient":"juno1b"}},"sender":"juno1sender"}}],"sequence":"3"}"#
+        );
+    }
+
+    #[test]
+    fn unsupported_msg() {
+        let msg = Any {
+            type_url: "/cosmos.staking.v1beta1.MsgDelegate".to_string(),
+            value: vec![],
+        };
+        assert!(amino_msg(&msg).is_err());
+    }
+}
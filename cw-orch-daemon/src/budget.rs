@@ -0,0 +1,237 @@
+//! Caps on the fees and gas spent by the transactions of a [`Wallet`](crate::Wallet), see [`FeeBudget`].
+
+use std::{
+    collections::BTreeMap,
+    fmt::Display,
+    sync::{Arc, Mutex},
+};
+
+use cosmwasm_std::{coin, Coin};
+use cw_orch_core::{log::transaction_target, CoreEnvVars};
+
+use crate::DaemonError;
+
+/// Maximum fee and gas the transactions sent with it can spend in total.
+///
+/// Every transaction checks the spend accumulated so far before being signed,
+/// and is aborted when it would exceed the budget.
+/// With manual interactions enabled (`CW_ORCH_MANUAL_INTERACTION`), the user is asked whether to go over the budget instead.
+/// Only the transactions accepted by the node are counted, they pay their fee even if their execution fails.
+///
+/// The budget is shared by its clones, so one budget can cap a whole deployment across daemons and chains.
+/// A summary of the spend is logged when the last clone is dropped.
+///
+/// ```no_run
+/// use cw_orch_daemon::{budget::FeeBudget, Daemon, networks};
+/// use cosmwasm_std::coin;
+///
+/// let budget = FeeBudget::new().max_fee(coin(5_000_000, "ujuno")).max_gas(20_000_000);
+/// let mut daemon = Daemon::builder(networks::UNI_6).build().unwrap();
+/// daemon.budget(budget.clone());
+/// // ... deploy
+/// println!("{}", budget.summary());
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct FeeBudget {
+    max_fee: Option<Coin>,
+    max_gas: Option<u64>,
+    spent: Arc<SharedSpend>,
+}
+
+/// Spend shared by the clones of a [`FeeBudget`], logged when the last one is dropped
+#[derive(Debug, Default)]
+struct SharedSpend(Mutex<BudgetSpend>);
+
+/// Fees and gas spent by the transactions sent with a [`FeeBudget`]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct BudgetSpend {
+    /// Fees paid, by denom
+    pub fees: BTreeMap<String, u128>,
+    /// Gas limit of the transactions
+    pub gas: u64,
+    /// Number of transactions
+    pub txs: u64,
+}
+
+impl FeeBudget {
+    /// Budget without any limit, only tracking the spend
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Limits the fees paid in the denom of `max_fee`.
+    /// Fees paid in other denoms, like the fee tokens of other chains, are only tracked.
+    pub fn max_fee(mut self, max_fee: Coin) -> Self {
+        self.max_fee = Some(max_fee);
+        self
+    }
+
+    /// Limits the gas of the transactions, counted with their gas limit
+    pub fn max_gas(mut self, max_gas: u64) -> Self {
+        self.max_gas = Some(max_gas);
+        self
+    }
+
+    /// Spend accumulated so far
+    pub fn spent(&self) -> BudgetSpend {
+        self.spent.0.lock().unwrap().clone()
+    }
+
+    /// Fee that can still be spent, `None` if fees are not limited
+    pub fn remaining_fee(&self) -> Option<Coin> {
+        self.max_fee.as_ref().map(|max_fee| {
+            let spent = self.spent_fee(&max_fee.denom);
+            coin(max_fee.amount.u128().saturating_sub(spent), &max_fee.denom)
+        })
+    }
+
+    /// Gas that can still be spent, `None` if gas is not limited
+    pub fn remaining_gas(&self) -> Option<u64> {
+        self.max_gas
+            .map(|max_gas| max_gas.saturating_sub(self.spent.0.lock().unwrap().gas))
+    }
+
+    /// Summary of the spend against the limits of the budget
+    pub fn summary(&self) -> String {
+        let spent = self.spent();
+        let fees = if spent.fees.is_empty() {
+            "0".to_string()
+        } else {
+            spent
+                .fees
+                .iter()
+                .map(|(denom, amount)| format!("{amount}{denom}"))
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        let mut summary = format!(
+            "{} transactions spent {fees} in fees and {} gas",
+            spent.txs, spent.gas
+        );
+        if let Some(max_fee) = &self.max_fee {
+            summary.push_str(&format!(", fee budget {max_fee}"));
+        }
+        if let Some(max_gas) = self.max_gas {
+            summary.push_str(&format!(", gas budget {max_gas}"));
+        }
+        summary
+    }
+
+    /// Errors if a transaction paying `fee` with `gas` as gas limit would go over the budget,
+    /// unless the user accepts to go over it.
+    pub(crate) fn check(&self, fee: &Coin, gas: u64) -> Result<(), DaemonError> {
+        let Some(reason) = self.exceeded_by(fee, gas) else {
+            return Ok(());
+        };
+
+        println!(
+            "The transaction would exceed the budget: {reason}.
+                {}.
+                Press 'y' to send it anyway",
+            self.summary()
+        );
+        if CoreEnvVars::manual_interaction() {
+            let mut input = String::new();
+            std::io::stdin().read_line(&mut input)?;
+            if input.to_lowercase().contains('y') {
+                return Ok(());
+            }
+        } else {
+            println!("No Manual Interactions, defaulting to 'no'");
+        }
+        Err(DaemonError::BudgetExceeded(reason))
+    }
+
+    /// Adds a transaction accepted by the node to the spend
+    pub(crate) fn record(&self, fee: &Coin, gas: u64) {
+        let mut spent = self.spent.0.lock().unwrap();
+        *spent.fees.entry(fee.denom.clone()).or_default() += fee.amount.u128();
+        spent.gas += gas;
+        spent.txs += 1;
+    }
+
+    fn exceeded_by(&self, fee: &Coin, gas: u64) -> Option<String> {
+        if let Some(max_fee) = self.max_fee.as_ref().filter(|max| max.denom == fee.denom) {
+            let total = self.spent_fee(&fee.denom) + fee.amount.u128();
+            if total > max_fee.amount.u128() {
+                return Some(exceeded("fees", total, max_fee));
+            }
+        }
+        if let Some(max_gas) = self.max_gas {
+            let total = self.spent.0.lock().unwrap().gas + gas;
+            if total > max_gas {
+                return Some(exceeded("gas", total, max_gas));
+            }
+        }
+        None
+    }
+
+    fn spent_fee(&self, denom: &str) -> u128 {
+        self.spent
+            .0
+            .lock()
+            .unwrap()
+            .fees
+            .get(denom)
+            .copied()
+            .unwrap_or_default()
+    }
+}
+
+fn exceeded(what: &str, total: impl Display, max: impl Display) -> String {
+    format!("{what} would reach {total}, over the maximum of {max}")
+}
+
+impl Drop for SharedSpend {
+    fn drop(&mut self) {
+        let spent = self.0.get_mut().unwrap();
+        if spent.txs > 0 {
+            log::info!(
+                target: &transaction_target(),
+                "Budget spend: {} transactions, fees {:?}, {} gas",
+                spent.txs,
+                spent.fees,
+                spent.gas
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn budget_is_shared_and_enforced() {
+        let budget = FeeBudget::new().max_fee(coin(1000, "ujuno")).max_gas(500);
+        let clone = budget.clone();
+
+        assert_eq!(budget.exceeded_by(&coin(600, "ujuno"), 200), None);
+        budget.record(&coin(600, "ujuno"), 200);
+        assert_eq!(clone.remaining_fee(), Some(coin(400, "ujuno")));
+        assert_eq!(clone.remaining_gas(), Some(300));
+
+        // Fees in other denoms are only tracked
+        assert_eq!(clone.exceeded_by(&coin(5000, "uosmo"), 100), None);
+        clone.record(&coin(5000, "uosmo"), 100);
+
+        assert_eq!(
+            budget.exceeded_by(&coin(401, "ujuno"), 100),
+            Some("fees would reach 1001, over the maximum of 1000ujuno".to_string())
+        );
+        assert_eq!(
+            budget.exceeded_by(&coin(100, "ujuno"), 201),
+            Some("gas would reach 501, over the maximum of 500".to_string())
+        );
+        assert_eq!(budget.exceeded_by(&coin(400, "ujuno"), 200), None);
+
+        assert_eq!(
+            budget.spent(),
+            BudgetSpend {
+                fees: BTreeMap::from([("ujuno".to_string(), 600), ("uosmo".to_string(), 5000)]),
+                gas: 300,
+                txs: 2,
+            }
+        );
+    }
+}
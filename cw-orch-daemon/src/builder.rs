@@ -1,6 +1,7 @@
 use std::sync::Arc;
 
 use crate::{
+    chain_registry::ChainRegistry,
     log::print_if_log_disabled,
     network_config,
     senders::{builder::SenderBuilder, CosmosOptions, CosmosWalletKey},
@@ -36,6 +37,11 @@ pub struct DaemonAsyncBuilder {
     pub(crate) is_test: bool,
 
     pub(crate) mnemonic: Option<String>,
+    pub(crate) faucet_url: Option<String>,
+
+    /// Path of the chain in the chain registry, its chain info is looked up when building
+    pub(crate) registry_path: Option<String>,
+    pub(crate) chain_registry: ChainRegistry,
 }
 
 impl DaemonAsyncBuilder {
@@ -47,10 +53,29 @@ impl DaemonAsyncBuilder {
             state: None,
             write_on_change: None,
             mnemonic: None,
+            faucet_url: None,
             is_test: false,
+            registry_path: None,
+            chain_registry: ChainRegistry::default(),
+        }
+    }
+
+    /// Builder of a daemon for the chain at `registry_path` in the chain registry, e.g. `juno` or `testnets/junotestnet`.
+    /// The chain info is looked up when building, the chain info overrides set on the builder take precedence.
+    /// See [`ChainRegistry`] for the caching of the registry data.
+    pub fn from_chain_registry(registry_path: impl Into<String>) -> Self {
+        Self {
+            registry_path: Some(registry_path.into()),
+            ..Self::new(ChainInfoOwned::default())
         }
     }
 
+    /// Set the chain registry the chain info is looked up in, see [`DaemonAsyncBuilder::from_chain_registry`]
+    pub fn chain_registry(&mut self, chain_registry: ChainRegistry) -> &mut Self {
+        self.chain_registry = chain_registry;
+        self
+    }
+
     /// Set the deployment id to use for the daemon interactions
     /// Defaults to `default`
     pub fn deployment_id(&mut self, deployment_id: impl Into<String>) -> &mut Self {
@@ -80,6 +105,14 @@ impl DaemonAsyncBuilder {
         self
     }
 
+    /// Request funds from the faucet at `faucet_url` when the wallet balance is too low to pay for a transaction,
+    /// instead of waiting for a manual top-up. The faucet needs to expose the CosmJS faucet API.
+    /// Only applies to the default [`Wallet`] sender.
+    pub fn faucet_top_up(&mut self, faucet_url: impl Into<String>) -> &mut Self {
+        self.faucet_url = Some(faucet_url.into());
+        self
+    }
+
     /// Overwrite the chain info
     pub fn chain(&mut self, chain: impl Into<ChainInfoOwned>) -> &mut Self {
         self.chain = chain.into();
@@ -105,11 +138,7 @@ impl DaemonAsyncBuilder {
 
     /// Build a daemon with provided mnemonic or env-var mnemonic
     pub async fn build(&self) -> Result<DaemonAsyncBase<Wallet>, DaemonError> {
-        let chain_info = if let Some(network_config) = network_config::load(&self.chain.chain_id) {
-            Arc::new(self.chain.clone().overwrite_with(network_config))
-        } else {
-            Arc::new(self.chain.clone())
-        };
+        let chain_info = self.chain_info().await?;
 
         let state = self.build_state(chain_info.clone())?;
         // if mnemonic provided, use it. Else use env variables to retrieve mnemonic
@@ -118,6 +147,7 @@ impl DaemonAsyncBuilder {
             key: self.mnemonic.as_ref().map_or(CosmosWalletKey::Env, |m| {
                 CosmosWalletKey::Mnemonic(m.clone())
             }),
+            faucet_url: self.faucet_url.clone(),
             ..Default::default()
         };
         let sender = options.build(&chain_info).await?;
@@ -133,11 +163,7 @@ impl DaemonAsyncBuilder {
         &self,
         sender_options: T,
     ) -> Result<DaemonAsyncBase<T::Sender>, DaemonError> {
-        let chain_info = if let Some(network_config) = network_config::load(&self.chain.chain_id) {
-            Arc::new(self.chain.clone().overwrite_with(network_config))
-        } else {
-            Arc::new(self.chain.clone())
-        };
+        let chain_info = self.chain_info().await?;
 
         let state = self.build_state(chain_info.clone())?;
 
@@ -152,6 +178,24 @@ impl DaemonAsyncBuilder {
         Ok(daemon)
     }
 
+    /// Chain info of the daemon, looked up in the chain registry if requested and overwritten by the network config
+    async fn chain_info(&self) -> Result<Arc<ChainInfoOwned>, DaemonError> {
+        let chain = match &self.registry_path {
+            Some(registry_path) => self
+                .chain_registry
+                .chain_info(registry_path)
+                .await?
+                .overwrite_with(self.chain.clone()),
+            None => self.chain.clone(),
+        };
+        let chain_info = if let Some(network_config) = network_config::load(&chain.chain_id) {
+            Arc::new(chain.overwrite_with(network_config))
+        } else {
+            Arc::new(chain)
+        };
+        Ok(chain_info)
+    }
+
     /// Returns a built state
     fn build_state(&self, chain_info: Arc<ChainInfoOwned>) -> Result<DaemonState, DaemonError> {
         let deployment_id = self
@@ -218,7 +262,10 @@ impl From<DaemonBuilder> for DaemonAsyncBuilder {
             state_path: value.state_path,
             write_on_change: value.write_on_change,
             mnemonic: value.mnemonic,
+            faucet_url: value.faucet_url,
             is_test: value.is_test,
+            registry_path: value.registry_path,
+            chain_registry: value.chain_registry,
         }
     }
 }
@@ -0,0 +1,305 @@
+//! Lookup of the chains of the [Cosmos chain registry](https://github.com/cosmos/chain-registry), see [`ChainRegistry`].
+//!
+//! The `chain.json` files are cached on disk with a TTL so that repeated lookups, for instance by a [`crate::DaemonBuilder`] in every test,
+//! don't hit the network, and offline runs can still construct daemons for the chains that were fetched before.
+
+use std::{
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime},
+};
+
+use cw_orch_core::environment::{ChainInfoOwned, ChainKind, NetworkInfoOwned, PubKeyType};
+use serde_json::Value;
+
+use crate::{env::default_state_folder, DaemonError};
+
+/// Raw content of the chain registry on GitHub
+pub const CHAIN_REGISTRY_URL: &str =
+    "https://raw.githubusercontent.com/cosmos/chain-registry/master";
+
+/// Default duration a cached `chain.json` is used for
+pub const DEFAULT_CACHE_TTL: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// On-disk cache of the `chain.json` files of a [`ChainRegistry`]
+#[derive(Debug, Clone)]
+pub struct ChainRegistryCache {
+    dir: PathBuf,
+    ttl: Duration,
+}
+
+impl ChainRegistryCache {
+    /// Cache the files in `dir`, using them for `ttl`
+    pub fn new(dir: impl Into<PathBuf>, ttl: Duration) -> Self {
+        Self {
+            dir: dir.into(),
+            ttl,
+        }
+    }
+
+    /// Cache in `~/.cw-orchestrator/chain-registry`, with the [`DEFAULT_CACHE_TTL`]
+    pub fn default_dir() -> Result<Self, DaemonError> {
+        let dir = default_state_folder()?.join("chain-registry");
+        Ok(Self::new(dir, DEFAULT_CACHE_TTL))
+    }
+
+    /// Directory the files are stored in
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Remove all the cached files
+    pub fn clear(&self) -> std::io::Result<()> {
+        match std::fs::remove_dir_all(&self.dir) {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(e),
+            _ => Ok(()),
+        }
+    }
+
+    fn path(&self, registry_path: &str) -> PathBuf {
+        self.dir.join(registry_path).join("chain.json")
+    }
+
+    /// Cached `chain.json` of `registry_path`, if it didn't expire or if `allow_expired` is set
+    fn read(&self, registry_path: &str, allow_expired: bool) -> Option<Value> {
+        let path = self.path(registry_path);
+        let age = std::fs::metadata(&path)
+            .and_then(|metadata| metadata.modified())
+            .ok()
+            .and_then(|modified| SystemTime::now().duration_since(modified).ok())?;
+        if !allow_expired && age > self.ttl {
+            return None;
+        }
+        let content = std::fs::read_to_string(path).ok()?;
+        serde_json::from_str(&content).ok()
+    }
+
+    fn write(&self, registry_path: &str, chain_json: &Value) {
+        let path = self.path(registry_path);
+        let written = std::fs::create_dir_all(path.parent().unwrap_or(&self.dir))
+            .and_then(|_| std::fs::write(&path, chain_json.to_string()));
+        if let Err(e) = written {
+            log::warn!("Could not cache the chain registry data of {registry_path}: {e}");
+        }
+    }
+}
+
+/// Chain registry the chain info of a [`crate::DaemonBuilder`] can be looked up in.
+///
+/// Chains are identified by their path in the registry, e.g. `juno` or `testnets/junotestnet`.
+/// Fetched files are cached in the [`ChainRegistryCache::default_dir`] unless another cache is set.
+/// If the registry can't be reached, expired cached files are used.
+///
+/// ```no_run
+/// use cw_orch_daemon::{chain_registry::ChainRegistry, DaemonBuilder};
+///
+/// let daemon = DaemonBuilder::from_chain_registry("testnets/junotestnet")
+///     .chain_registry(ChainRegistry::default().without_cache())
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Debug, Clone)]
+pub struct ChainRegistry {
+    url: String,
+    cache: Option<ChainRegistryCache>,
+}
+
+impl Default for ChainRegistry {
+    fn default() -> Self {
+        Self {
+            url: CHAIN_REGISTRY_URL.to_string(),
+            cache: ChainRegistryCache::default_dir().ok(),
+        }
+    }
+}
+
+impl ChainRegistry {
+    /// Use the registry served at `url` instead of the one on GitHub
+    pub fn url(mut self, url: impl Into<String>) -> Self {
+        self.url = url.into();
+        self
+    }
+
+    /// Cache the fetched files in `cache`
+    pub fn cache(mut self, cache: ChainRegistryCache) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    /// Always fetch the files from the registry
+    pub fn without_cache(mut self) -> Self {
+        self.cache = None;
+        self
+    }
+
+    /// `chain.json` of the chain at `registry_path`, from the cache if it didn't expire or if the registry can't be reached
+    pub async fn chain_json(&self, registry_path: &str) -> Result<Value, DaemonError> {
+        let Some(cache) = &self.cache else {
+            return self.fetch_chain_json(registry_path).await;
+        };
+        if let Some(chain_json) = cache.read(registry_path, false) {
+            return Ok(chain_json);
+        }
+        match self.fetch_chain_json(registry_path).await {
+            Ok(chain_json) => {
+                cache.write(registry_path, &chain_json);
+                Ok(chain_json)
+            }
+            Err(e) => match cache.read(registry_path, true) {
+                Some(chain_json) => {
+                    log::warn!("Chain registry unreachable, using the expired cached data of {registry_path}: {e}");
+                    Ok(chain_json)
+                }
+                None => Err(e),
+            },
+        }
+    }
+
+    /// Fetch the `chain.json` of the chain at `registry_path` again, replacing the cached one
+    pub async fn refresh(&self, registry_path: &str) -> Result<Value, DaemonError> {
+        let chain_json = self.fetch_chain_json(registry_path).await?;
+        if let Some(cache) = &self.cache {
+            cache.write(registry_path, &chain_json);
+        }
+        Ok(chain_json)
+    }
+
+    /// Chain info of the chain at `registry_path`
+    pub async fn chain_info(&self, registry_path: &str) -> Result<ChainInfoOwned, DaemonError> {
+        let chain_json = self.chain_json(registry_path).await?;
+        chain_info_from_json(&chain_json).ok_or_else(|| {
+            DaemonError::ChainRegistry(format!(
+                "{registry_path}/chain.json is missing the chain id, address prefix or fee token"
+            ))
+        })
+    }
+
+    async fn fetch_chain_json(&self, registry_path: &str) -> Result<Value, DaemonError> {
+        let chain_json = reqwest::get(format!("{}/{registry_path}/chain.json", self.url))
+            .await?
+            .error_for_status()?
+            .text()
+            .await?;
+        Ok(serde_json::from_str(&chain_json)?)
+    }
+}
+
+/// Chain info described by a chain-registry `chain.json`
+pub fn chain_info_from_json(chain_json: &Value) -> Option<ChainInfoOwned> {
+    let fee_token = chain_json["fees"]["fee_tokens"].as_array()?.first()?;
+    let gas_price = ["average_gas_price", "low_gas_price", "fixed_min_gas_price"]
+        .iter()
+        .find_map(|price| fee_token[*price].as_f64())
+        .unwrap_or_default();
+    let api_addresses = |api: &str| -> Vec<String> {
+        chain_json["apis"][api]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(|endpoint| endpoint["address"].as_str())
+            .map(with_scheme)
+            .collect()
+    };
+    let kind = match chain_json["network_type"].as_str() {
+        Some("mainnet") => ChainKind::Mainnet,
+        Some("testnet") => ChainKind::Testnet,
+        Some("devnet") => ChainKind::Local,
+        _ => ChainKind::Unspecified,
+    };
+    let ethermint_keys = chain_json["key_algos"]
+        .as_array()
+        .is_some_and(|algos| algos.iter().any(|algo| algo == "ethsecp256k1"));
+    Some(ChainInfoOwned {
+        chain_id: chain_json["chain_id"].as_str()?.to_string(),
+        gas_denom: fee_token["denom"].as_str()?.to_string(),
+        gas_price,
+        grpc_urls: api_addresses("grpc"),
+        lcd_url: api_addresses("rest").into_iter().next(),
+        fcd_url: None,
+        network_info: NetworkInfoOwned {
+            chain_name: chain_json["chain_name"].as_str()?.to_string(),
+            pub_address_prefix: chain_json["bech32_prefix"].as_str()?.to_string(),
+            coin_type: chain_json["slip44"]
+                .as_u64()
+                .map_or(118, |slip44| slip44 as u32),
+            pub_key_type: ethermint_keys.then_some(PubKeyType::EthermintEthSecp256k1),
+        },
+        kind,
+    })
+}
+
+/// Registry gRPC addresses are usually listed without a scheme, e.g. `grpc.juno.example:443`
+fn with_scheme(address: &str) -> String {
+    if address.contains("://") {
+        address.to_string()
+    } else if address.ends_with(":443") {
+        format!("https://{address}")
+    } else {
+        format!("http://{address}")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use super::*;
+
+    fn juno_chain_json() -> Value {
+        json!({
+            "chain_name": "juno",
+            "chain_id": "juno-1",
+            "network_type": "mainnet",
+            "bech32_prefix": "juno",
+            "slip44": 118,
+            "fees": { "fee_tokens": [{ "denom": "ujuno", "low_gas_price": 0.075, "average_gas_price": 0.1 }] },
+            "apis": {
+                "grpc": [{ "address": "grpc-juno.example.com:443" }, { "address": "http://juno-grpc.example.com:9090" }],
+                "rest": [{ "address": "https://rest-juno.example.com" }]
+            }
+        })
+    }
+
+    #[test]
+    fn chain_info_from_chain_json() {
+        let chain_info = chain_info_from_json(&juno_chain_json()).unwrap();
+        assert_eq!(chain_info.chain_id, "juno-1");
+        assert_eq!(chain_info.gas_denom, "ujuno");
+        assert_eq!(chain_info.gas_price, 0.1);
+        assert_eq!(
+            chain_info.grpc_urls,
+            vec![
+                "https://grpc-juno.example.com:443".to_string(),
+                "http://juno-grpc.example.com:9090".to_string()
+            ]
+        );
+        assert_eq!(
+            chain_info.lcd_url.as_deref(),
+            Some("https://rest-juno.example.com")
+        );
+        assert_eq!(chain_info.network_info.pub_address_prefix, "juno");
+        assert_eq!(chain_info.network_info.pub_key_type, None);
+        assert_eq!(chain_info.kind, ChainKind::Mainnet);
+
+        assert_eq!(chain_info_from_json(&json!({ "chain_id": "juno-1" })), None);
+    }
+
+    #[tokio::test]
+    async fn cached_chain_json_is_used_offline() {
+        let dir = crate::gen_temp_file_path();
+        let cache = ChainRegistryCache::new(&dir, Duration::ZERO);
+        // Nothing listens on this port, every fetch fails
+        let registry = ChainRegistry::default()
+            .url("http://127.0.0.1:9")
+            .cache(cache.clone());
+
+        assert!(registry.chain_info("juno").await.is_err());
+
+        cache.write("juno", &juno_chain_json());
+        let chain_info = registry.chain_info("juno").await.unwrap();
+        assert_eq!(chain_info.chain_id, "juno-1");
+
+        assert!(registry.refresh("juno").await.is_err());
+        cache.clear().unwrap();
+        assert!(registry.chain_info("juno").await.is_err());
+    }
+}
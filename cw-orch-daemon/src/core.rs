@@ -1,6 +1,11 @@
 use crate::{
     queriers::CosmWasm,
-    senders::{builder::SenderBuilder, query::QuerySender},
+    query_cache::QueryCache,
+    senders::{
+        builder::SenderBuilder, fee_from_gas, query::QuerySender, QueryOnlySender, TxOptions,
+    },
+    signed_tx::SignedTx,
+    tx_broadcaster::{assert_broadcast_code_cosm_response, assert_broadcast_code_response},
     DaemonAsyncBuilder, DaemonState,
 };
 
@@ -9,15 +14,19 @@ use super::{
 };
 
 use cosmrs::{
+    bank::MsgSend,
     cosmwasm::{MsgExecuteContract, MsgInstantiateContract, MsgMigrateContract},
     proto::cosmwasm::wasm::v1::MsgInstantiateContract2,
     tendermint::Time,
+    tx::Msg,
     AccountId, Any, Denom,
 };
 use cosmwasm_std::{Addr, Binary, Coin};
 use cw_orch_core::{
     contract::interface_traits::Uploadable,
-    environment::{AsyncWasmQuerier, ChainInfoOwned, ChainState, IndexResponse, Querier},
+    environment::{
+        AsyncWasmQuerier, ChainInfoOwned, ChainState, IndexResponse, Querier, TxSimulation,
+    },
     log::transaction_target,
 };
 use flate2::{write, Compression};
@@ -28,6 +37,7 @@ use std::{
     fmt::Debug,
     io::Write,
     ops::Deref,
+    path::Path,
     str::{from_utf8, FromStr},
     time::Duration,
 };
@@ -73,13 +83,22 @@ pub struct DaemonAsyncBase<Sender = Wallet> {
     sender: Sender,
     /// State of the daemon
     pub(crate) state: DaemonState,
+    /// Block height at which queries are performed, latest block if `None`
+    pub(crate) query_height: Option<u64>,
+    /// Cache of the smart queries, see [`QueryCache`]
+    pub(crate) query_cache: Option<QueryCache>,
 }
 
 pub type DaemonAsync = DaemonAsyncBase<Wallet>;
 
 impl<Sender> DaemonAsyncBase<Sender> {
     pub(crate) fn new(sender: Sender, state: DaemonState) -> Self {
-        Self { sender, state }
+        Self {
+            sender,
+            state,
+            query_height: None,
+            query_cache: None,
+        }
     }
 
     pub fn chain_info(&self) -> &ChainInfoOwned {
@@ -104,9 +123,28 @@ impl<Sender> DaemonAsyncBase<Sender> {
         DaemonAsyncBase {
             sender,
             state: self.state,
+            query_height: self.query_height,
+            query_cache: self.query_cache.clone(),
         }
     }
 
+    /// Block height at which the queries of this daemon are performed.
+    /// `None` means the latest block is used.
+    pub fn query_height(&self) -> Option<u64> {
+        self.query_height
+    }
+
+    /// Cache of the smart queries of this daemon, if any
+    pub fn query_cache(&self) -> Option<&QueryCache> {
+        self.query_cache.as_ref()
+    }
+
+    /// Caches the smart queries of this daemon, see [`QueryCache`].
+    /// Only the queries at a fixed height, see [`DaemonAsyncBase::at_height`], are cached.
+    pub fn set_query_cache(&mut self, cache: QueryCache) {
+        self.query_cache = Some(cache);
+    }
+
     /// Get a mutable Sender
     pub fn sender_mut(&mut self) -> &mut Sender {
         &mut self.sender
@@ -123,6 +161,16 @@ impl<Sender> DaemonAsyncBase<Sender> {
         self.state.flush()
     }
 
+    /// Switches the deployment id under which contract addresses are read and written.
+    /// Contracts created with a clone of this daemon before the switch keep using the previous deployment.
+    /// See [`DaemonState`](crate::DaemonState) to list, copy or prune deployments.
+    pub fn set_deployment_id(
+        &mut self,
+        deployment_id: impl Into<String>,
+    ) -> Result<(), DaemonError> {
+        self.state.set_deployment_id(deployment_id)
+    }
+
     /// Returns a new [`DaemonAsyncBuilder`] with the current configuration.
     /// Does not consume the original [`DaemonAsync`].
     pub fn rebuild(&self) -> DaemonAsyncBuilder {
@@ -133,8 +181,11 @@ impl<Sender> DaemonAsyncBase<Sender> {
             state_path: None,
             write_on_change: None,
             mnemonic: None,
+            faucet_url: None,
             // If it was test it will just use same tempfile as state
             is_test: false,
+            registry_path: None,
+            chain_registry: Default::default(),
         }
     }
 }
@@ -145,21 +196,85 @@ impl<Sender: QuerySender> DaemonAsyncBase<Sender> {
         self.sender().channel()
     }
 
+    /// Returns a query-only handle on this daemon that queries the chain state at the given block height.
+    /// This uses the `x-cosmos-block-height` gRPC header, the targeted node needs to have the state for that height available.
+    pub fn at_height(&self, height: u64) -> DaemonAsyncBase<QueryOnlySender> {
+        DaemonAsyncBase {
+            sender: QueryOnlySender::new(self.channel(), self.state.chain_data.clone()),
+            state: self.state.clone(),
+            query_height: Some(height),
+            query_cache: self.query_cache.clone(),
+        }
+    }
+
+    /// Wasm querier at the query height of this daemon, using its query cache
+    fn cosmwasm_querier(&self) -> CosmWasm {
+        let mut querier = CosmWasm::new_async(self.channel());
+        querier.height = self.query_height;
+        querier.cache = self.query_cache.clone();
+        querier
+    }
+
+    /// Broadcasts an already signed transaction and waits for its inclusion in a block.
+    /// `tx_bytes` is the protobuf encoding of a `TxRaw`, as produced by an offline signing flow.
+    /// Errors if the transaction is rejected by the node or fails on chain.
+    pub async fn broadcast_signed_tx(
+        &self,
+        tx_bytes: Vec<u8>,
+    ) -> Result<CosmTxResponse, DaemonError> {
+        let mut client = cosmos_modules::tx::service_client::ServiceClient::new(self.channel());
+        let commit = client
+            .broadcast_tx(cosmos_modules::tx::BroadcastTxRequest {
+                tx_bytes,
+                mode: cosmos_modules::tx::BroadcastMode::Sync.into(),
+            })
+            .await?
+            .into_inner()
+            .tx_response
+            .unwrap();
+        let commit = assert_broadcast_code_response(commit)?;
+        log::info!(target: &transaction_target(), "Broadcasted signed tx: {}", commit.txhash);
+
+        let resp = Node::new_async(self.channel())
+            ._find_tx(commit.txhash)
+            .await?;
+
+        assert_broadcast_code_cosm_response(resp)
+    }
+
+    /// Broadcasts the transaction signed offline and saved at `path`, see [`SignedTx`] for the supported formats.
+    /// JSON transactions are encoded by the REST endpoint of the chain, so they require its `lcd_url`.
+    pub async fn broadcast_signed_tx_file(
+        &self,
+        path: impl AsRef<Path>,
+    ) -> Result<CosmTxResponse, DaemonError> {
+        let tx_bytes = SignedTx::from_file(path)?
+            .into_tx_bytes(self.chain_info().lcd_url.as_deref())
+            .await?;
+        self.broadcast_signed_tx(tx_bytes).await
+    }
+
     /// Query a contract.
     pub async fn query<Q: Serialize + Debug, T: Serialize + DeserializeOwned>(
         &self,
         query_msg: &Q,
         contract_address: &Addr,
     ) -> Result<T, DaemonError> {
-        let mut client = cosmos_modules::cosmwasm::query_client::QueryClient::new(self.channel());
-        let resp = client
-            .smart_contract_state(cosmos_modules::cosmwasm::QuerySmartContractStateRequest {
-                address: contract_address.to_string(),
-                query_data: serde_json::to_vec(&query_msg)?,
-            })
+        let data = self
+            .cosmwasm_querier()
+            ._contract_state(contract_address, serde_json::to_vec(&query_msg)?)
             .await?;
 
-        Ok(from_str(from_utf8(&resp.into_inner().data).unwrap())?)
+        Ok(from_str(from_utf8(&data).unwrap())?)
+    }
+
+    /// Query many contracts concurrently, with the result of each query returned in the order of `queries`.
+    /// At most [`DaemonEnvVars::max_concurrent_queries`](crate::env::DaemonEnvVars::max_concurrent_queries) queries are sent at the same time.
+    pub async fn query_many<Q: Serialize, T: DeserializeOwned>(
+        &self,
+        queries: Vec<(impl Into<String>, Q)>,
+    ) -> Vec<Result<T, DaemonError>> {
+        self.cosmwasm_querier()._query_many(queries).await
     }
 
     /// Wait for a given amount of blocks.
@@ -187,6 +302,19 @@ impl<Sender: QuerySender> DaemonAsyncBase<Sender> {
         Ok(())
     }
 
+    /// Waits for the transaction `hash` to be included in a block and buried under `confirmations` blocks, for at most `timeout`.
+    /// The transaction is searched again if a re-org drops it before it is confirmed.
+    pub async fn wait_for_tx(
+        &self,
+        hash: impl Into<String>,
+        confirmations: u64,
+        timeout: Duration,
+    ) -> Result<CosmTxResponse, DaemonError> {
+        Node::new_async(self.channel())
+            ._wait_for_tx(hash.into(), confirmations, timeout)
+            .await
+    }
+
     /// Wait for a given amount of seconds.
     pub async fn wait_seconds(&self, secs: u64) -> Result<(), DaemonError> {
         tokio::time::sleep(Duration::from_secs(secs)).await;
@@ -250,6 +378,27 @@ impl<Sender: TxSender> DaemonAsyncBase<Sender> {
         Ok(result)
     }
 
+    /// Send coins to `recipient`.
+    pub async fn bank_send(
+        &self,
+        recipient: &Addr,
+        coins: &[cosmwasm_std::Coin],
+    ) -> Result<CosmTxResponse, DaemonError> {
+        let msg_send = MsgSend {
+            from_address: self.sender().account_id(),
+            to_address: AccountId::from_str(recipient.as_str())?,
+            amount: parse_cw_coins(coins)?,
+        };
+        let result = self
+            .sender()
+            .commit_tx(vec![msg_send], None)
+            .await
+            .map_err(Into::into)?;
+        log::info!(target: &transaction_target(), "Bank send done: {:?}", result.txhash);
+
+        Ok(result)
+    }
+
     /// Instantiate a contract.
     pub async fn instantiate<I: Serialize + Debug>(
         &self,
@@ -338,6 +487,54 @@ impl<Sender: TxSender> DaemonAsyncBase<Sender> {
         Ok(result)
     }
 
+    /// Simulate the execution of a message on a contract and return the gas it needs.
+    pub async fn simulate_execute<E: Serialize>(
+        &self,
+        exec_msg: &E,
+        coins: &[cosmwasm_std::Coin],
+        contract_address: &Addr,
+    ) -> Result<TxSimulation, DaemonError> {
+        let exec_msg: MsgExecuteContract = MsgExecuteContract {
+            sender: self.sender().account_id(),
+            contract: AccountId::from_str(contract_address.as_str())?,
+            msg: serde_json::to_vec(&exec_msg)?,
+            funds: parse_cw_coins(coins)?,
+        };
+        self.simulate_msgs(vec![exec_msg.into_any()?]).await
+    }
+
+    /// Simulate the instantiation of a contract and return the gas it needs.
+    pub async fn simulate_instantiate<I: Serialize + Debug>(
+        &self,
+        code_id: u64,
+        init_msg: &I,
+        label: Option<&str>,
+        admin: Option<&Addr>,
+        coins: &[Coin],
+    ) -> Result<TxSimulation, DaemonError> {
+        let init_msg = MsgInstantiateContract {
+            code_id,
+            label: Some(label.unwrap_or("instantiate_contract").to_string()),
+            admin: admin.map(|a| FromStr::from_str(a.as_str()).unwrap()),
+            sender: self.sender().account_id(),
+            msg: serde_json::to_vec(&init_msg)?,
+            funds: parse_cw_coins(coins)?,
+        };
+        self.simulate_msgs(vec![init_msg.into_any()?]).await
+    }
+
+    async fn simulate_msgs(&self, msgs: Vec<Any>) -> Result<TxSimulation, DaemonError> {
+        let gas_used = self.sender().simulate_any(msgs, None).await?;
+        let chain_info = self.chain_info();
+        let (_, fee_amount) = fee_from_gas(gas_used, chain_info.gas_price);
+        log::debug!(target: &transaction_target(), "Simulation used {gas_used} gas");
+
+        Ok(TxSimulation {
+            gas_used: Some(gas_used),
+            fee: Some(Coin::new(fee_amount, chain_info.gas_denom.clone())),
+        })
+    }
+
     /// Upload a contract to the chain.
     pub async fn upload<T: Uploadable>(
         &self,
@@ -351,6 +548,16 @@ impl<Sender: TxSender> DaemonAsyncBase<Sender> {
         let mut e = write::GzEncoder::new(Vec::new(), Compression::default());
         e.write_all(&file_contents)?;
         let wasm_byte_code = e.finish()?;
+        let wasm = CosmWasm::new_async(self.channel());
+        let uploader = self.sender().account_id().to_string();
+        if let Ok(false) = wasm._can_upload(&uploader).await {
+            log::warn!(
+                target: &transaction_target(),
+                "{uploader} is not allowed to upload code on {} according to the wasm params, the upload will likely be rejected. Permissioned chains require a governance proposal to store code.",
+                self.chain_info().chain_id
+            );
+        }
+
         let store_msg = cosmrs::cosmwasm::MsgStoreCode {
             sender: self.sender().account_id(),
             wasm_byte_code,
@@ -366,9 +573,11 @@ impl<Sender: TxSender> DaemonAsyncBase<Sender> {
         log::info!(target: &transaction_target(), "Uploading done: {:?}", result.txhash);
 
         let code_id = result.uploaded_code_id().unwrap();
+        self.state
+            .clone()
+            .set_checksum(code_id, &wasm_path.checksum()?.to_hex())?;
 
         // wait for the node to return the contract information for this upload
-        let wasm = CosmWasm::new_async(self.channel());
         while wasm._code(code_id).await.is_err() {
             self.next_block().await?;
         }
@@ -376,6 +585,16 @@ impl<Sender: TxSender> DaemonAsyncBase<Sender> {
     }
 }
 
+impl DaemonAsync {
+    /// Returns a clone of this daemon sending its transactions with the memo and timeout height of `tx_options`,
+    /// this daemon keeps its own options.
+    pub fn with_tx_options(&self, tx_options: TxOptions) -> Self {
+        let mut daemon = self.clone();
+        daemon.sender = self.sender.with_tx_options(tx_options);
+        daemon
+    }
+}
+
 impl Querier for DaemonAsync {
     type Error = DaemonError;
 }
@@ -389,15 +608,11 @@ impl AsyncWasmQuerier for DaemonAsync {
     ) -> impl std::future::Future<Output = Result<T, DaemonError>> + Send {
         let query_data = serde_json::to_vec(&query_msg).unwrap();
         async {
-            let mut client =
-                cosmos_modules::cosmwasm::query_client::QueryClient::new(self.channel());
-            let resp = client
-                .smart_contract_state(cosmos_modules::cosmwasm::QuerySmartContractStateRequest {
-                    address: address.into(),
-                    query_data,
-                })
+            let data = self
+                .cosmwasm_querier()
+                ._contract_state(address, query_data)
                 .await?;
-            Ok(from_str(from_utf8(&resp.into_inner().data).unwrap())?)
+            Ok(from_str(from_utf8(&data).unwrap())?)
         }
     }
 }
@@ -0,0 +1,21 @@
+//! Docker helpers shared by the environments running nodes or relayers in containers, see [`crate::LocalChain`].
+
+use std::process::Command;
+
+use crate::DaemonError;
+
+/// Force-remove a container, ignoring errors if it doesn't exist
+pub fn remove_container(name: &str) {
+    let _ = Command::new("docker").args(["rm", "-f", name]).output();
+}
+
+/// Runs a docker `command` and returns its trimmed standard output, or its standard error if it failed
+pub fn run(command: &mut Command) -> Result<String, DaemonError> {
+    let output = command.output()?;
+    if !output.status.success() {
+        return Err(DaemonError::Docker(
+            String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        ));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
@@ -12,6 +12,7 @@ use cosmwasm_std::StdError;
 use std::time::Duration;
 
 const DEFAULT_TX_QUERY_RETRIES: usize = 50;
+const DEFAULT_MAX_CONCURRENT_QUERIES: usize = 16;
 
 #[deprecated(since = "0.24.0", note = "Please use BLOCK_TIME_MIN_ENV_NAME instead")]
 pub const MIN_BLOCK_SPEED_ENV_NAME: &str = "CW_ORCH_MIN_BLOCK_SPEED";
@@ -19,9 +20,12 @@ pub const MIN_BLOCK_SPEED_ENV_NAME: &str = "CW_ORCH_MIN_BLOCK_SPEED";
 pub const BLOCK_TIME_MIN_ENV_NAME: &str = "CW_ORCH_MIN_BLOCK_TIME";
 pub const BLOCK_TIME_MAX_ENV_NAME: &str = "CW_ORCH_MAX_BLOCK_TIME";
 pub const STATE_FILE_ENV_NAME: &str = "STATE_FILE";
+pub const STATE_EXPORT_DIR_ENV_NAME: &str = "CW_ORCH_STATE_EXPORT_DIR";
+pub const TX_TRACE_DIR_ENV_NAME: &str = "CW_ORCH_TX_TRACE_DIR";
 pub const GAS_BUFFER_ENV_NAME: &str = "CW_ORCH_GAS_BUFFER";
 pub const MIN_GAS_ENV_NAME: &str = "CW_ORCH_MIN_GAS";
 pub const MAX_TX_QUERIES_RETRY_ENV_NAME: &str = "CW_ORCH_MAX_TX_QUERY_RETRIES";
+pub const MAX_CONCURRENT_QUERIES_ENV_NAME: &str = "CW_ORCH_MAX_CONCURRENT_QUERIES";
 pub const WALLET_BALANCE_ASSERTION_ENV_NAME: &str = "CW_ORCH_WALLET_BALANCE_ASSERTION";
 pub const LOGS_ACTIVATION_MESSAGE_ENV_NAME: &str = "CW_ORCH_LOGS_ACTIVATION_MESSAGE";
 
@@ -42,6 +46,24 @@ impl DaemonEnvVars {
         parse_with_log(state_file_string, STATE_FILE_ENV_NAME)
     }
 
+    /// Optional - Path
+    /// If set, `deployment.json` and `deployment.ts` are written to this directory on every change of the state
+    /// They contain the code ids, addresses and checksums of the active deployment on every chain
+    pub fn state_export_dir() -> Option<PathBuf> {
+        env::var(STATE_EXPORT_DIR_ENV_NAME)
+            .ok()
+            .map(|str_value| parse_with_log(str_value, STATE_EXPORT_DIR_ENV_NAME))
+    }
+
+    /// Optional - Path
+    /// If set, the request and response of every broadcast transaction are written to `<dir>/<txhash>.json`
+    /// See [`crate::trace`]
+    pub fn tx_trace_dir() -> Option<PathBuf> {
+        env::var(TX_TRACE_DIR_ENV_NAME)
+            .ok()
+            .map(|str_value| parse_with_log(str_value, TX_TRACE_DIR_ENV_NAME))
+    }
+
     /// Optional - Float
     /// This allows changing the gas buffer applied after tx simulation
     /// If not specified, a more complex algorithm is applied for dealing with small gas fee cases
@@ -75,6 +97,17 @@ impl DaemonEnvVars {
         }
     }
 
+    /// Optional - Integer
+    /// Defaults to [`DEFAULT_MAX_CONCURRENT_QUERIES`]
+    /// Maximum number of smart queries sent at the same time by `query_many`
+    pub fn max_concurrent_queries() -> usize {
+        if let Ok(str_value) = env::var(MAX_CONCURRENT_QUERIES_ENV_NAME) {
+            parse_with_log(str_value, MAX_CONCURRENT_QUERIES_ENV_NAME)
+        } else {
+            DEFAULT_MAX_CONCURRENT_QUERIES
+        }
+    }
+
     /// Optional - Block time
     /// Defaults to 1s
     /// Minimum block time in `Duration`. Useful when the block speeds are varying a lot
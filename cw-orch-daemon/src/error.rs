@@ -2,6 +2,7 @@
 
 use cosmwasm_std::{Coin, Instantiate2AddressError};
 use cw_orch_core::CwEnvError;
+use std::{fmt::Display, str::FromStr, time::Duration};
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -31,6 +32,8 @@ pub enum DaemonError {
     #[error(transparent)]
     TendermintError(#[from] ::cosmrs::tendermint::Error),
     #[error(transparent)]
+    TendermintRpcError(#[from] ::cosmrs::rpc::Error),
+    #[error(transparent)]
     CwEnvError(#[from] ::cw_orch_core::CwEnvError),
     #[error(transparent)]
     StripPrefixPath(#[from] std::path::StripPrefixError),
@@ -90,6 +93,12 @@ pub enum DaemonError {
     TendermintValidatorSet(u64, u64),
     #[error("Transaction {0} not found after {1} attempts")]
     TXNotFound(String, usize),
+    #[error("Transaction {hash} didn't reach {confirmations} confirmations within {timeout:?}")]
+    TxNotConfirmed {
+        hash: String,
+        confirmations: u64,
+        timeout: Duration,
+    },
     #[error("unknown API error")]
     Unknown,
     #[error("Generic Error {0}")]
@@ -102,8 +111,12 @@ pub enum DaemonError {
     NewNetwork(String),
     #[error("Can not connect to any grpc endpoint that was provided.")]
     CannotConnectGRPC,
-    #[error("tx failed: {reason} with code {code}")]
-    TxFailed { code: usize, reason: String },
+    #[error("tx failed: {reason} with code {code} in codespace {codespace}")]
+    TxFailed {
+        code: usize,
+        codespace: String,
+        reason: String,
+    },
     #[error("The list of grpc endpoints is empty")]
     GRPCListIsEmpty,
     #[error("no wasm path provided for contract.")]
@@ -116,6 +129,14 @@ pub enum DaemonError {
     InsufficientFee(String),
     #[error("Not enough balance, expected {expected}, found {current}")]
     NotEnoughBalance { expected: Coin, current: Coin },
+    #[error("Fee grant from {granter} can't pay {fee}: {reason}")]
+    FeeGrantInsufficient {
+        granter: String,
+        fee: Coin,
+        reason: String,
+    },
+    #[error("chain registry error: {0}")]
+    ChainRegistry(String),
     #[error("Can't set the daemon state, it's read-only {0}")]
     StateReadOnly(String),
     #[error("You need to pass a runtime to the querier object to do synchronous queries. Use daemon.querier instead")]
@@ -126,12 +147,112 @@ pub enum DaemonError {
     OpenFile(String, String),
     #[error("State file {0} already locked, use another state file, clone daemon which holds the lock, or use `state` method of Builder")]
     StateAlreadyLocked(String),
+    #[error("Deployment {0} not found in state")]
+    DeploymentNotFound(String),
+    #[error("Deployment {0} already exists in state")]
+    DeploymentAlreadyExists(String),
+    #[error("Deployment {0} is the active deployment and can't be removed")]
+    ActiveDeployment(String),
+    #[error("{0} is reserved and can't be used as a deployment id")]
+    ReservedDeploymentId(String),
+    #[error("code {code_id} downloaded from the chain has checksum {actual}, expected {expected}")]
+    CodeHashMismatch {
+        code_id: u64,
+        expected: String,
+        actual: String,
+    },
+    #[error("fee budget exceeded: {0}")]
+    BudgetExceeded(String),
+    #[error("query response not cached: {0}")]
+    QueryNotCached(String),
+    #[error("transaction denied: {0}")]
+    TxDenied(String),
+    #[error("transactions can't be sent with a query-only daemon")]
+    QueryOnly,
+    #[error("chain halted at height {height} for the upgrade {name}")]
+    ChainHalted { name: String, height: u64 },
+    #[error("addresses are derived with the prefix {actual} but the chain uses {expected}, override it in the chain info")]
+    AddressPrefixMismatch { expected: String, actual: String },
+    #[cfg(feature = "indexer")]
+    #[error(transparent)]
+    Sqlite(#[from] ::rusqlite::Error),
+    #[error("docker error: {0}")]
+    Docker(String),
+    #[error("local chain in container {container} did not produce a block within {timeout:?}")]
+    LocalChainTimeout {
+        container: String,
+        timeout: Duration,
+    },
 }
 
 impl DaemonError {
     pub fn ibc_err(msg: impl ToString) -> Self {
         Self::IbcError(msg.to_string())
     }
+
+    /// Whether the error can be caused by a chain halted for an upgrade: the node is unreachable,
+    /// or the transaction isn't included in a block.
+    /// Transactions rejected by the node for other reasons return `false`.
+    pub fn may_be_chain_halt(&self) -> bool {
+        match self {
+            DaemonError::TransportError(_)
+            | DaemonError::TXNotFound(..)
+            | DaemonError::TxNotConfirmed { .. } => true,
+            DaemonError::Status(status) => matches!(
+                status.code(),
+                tonic::Code::Unavailable | tonic::Code::DeadlineExceeded | tonic::Code::Cancelled
+            ),
+            _ => false,
+        }
+    }
+
+    /// Codespace and code of a transaction that failed on chain
+    pub fn abci_code(&self) -> Option<(&str, usize)> {
+        match self {
+            DaemonError::TxFailed {
+                code, codespace, ..
+            } => Some((codespace, *code)),
+            _ => None,
+        }
+    }
+
+    /// Raw log of a transaction that failed on chain, or message of a failed simulation
+    pub fn raw_log(&self) -> Option<&str> {
+        match self {
+            DaemonError::TxFailed { reason, .. } => Some(reason),
+            DaemonError::Status(status) => Some(status.message()),
+            _ => None,
+        }
+    }
+
+    /// Stringified error returned by the contract, if the transaction or its simulation failed in a contract.
+    /// This is the `Display` output of the contract error, like `Unauthorized` for `ContractError::Unauthorized {}`.
+    pub fn contract_error(&self) -> Option<&str> {
+        CwEnvError::contract_error_from_log(self.raw_log()?)
+    }
+
+    /// Whether the contract failed with `expected`, compared through its `Display` output
+    pub fn is_contract_error(&self, expected: &impl Display) -> bool {
+        self.contract_error() == Some(expected.to_string().as_str())
+    }
+
+    /// The contract error of type `E` the transaction failed with, parsed from its [`Self::contract_error`].
+    /// For errors that can't be parsed from their `Display` output, use [`Self::match_contract_error`].
+    pub fn downcast_contract_error<E: FromStr>(&self) -> Option<E> {
+        self.contract_error()?.parse().ok()
+    }
+
+    /// Returns the candidate the contract failed with, compared through their `Display` output:
+    /// `err.match_contract_error([ContractError::Unauthorized {}])`
+    pub fn match_contract_error<E: Display>(
+        &self,
+        candidates: impl IntoIterator<Item = E>,
+    ) -> Option<E> {
+        let contract_error = self.contract_error()?;
+        candidates
+            .into_iter()
+            .find(|candidate| candidate.to_string() == contract_error)
+    }
 }
 
 impl From<DaemonError> for CwEnvError {
@@ -139,3 +260,102 @@ impl From<DaemonError> for CwEnvError {
         CwEnvError::AnyError(val.into())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[derive(Debug, PartialEq, Error)]
+    enum ContractError {
+        #[error("Unauthorized")]
+        Unauthorized {},
+        #[error("Invalid amount {0}")]
+        InvalidAmount(u128),
+    }
+
+    impl FromStr for ContractError {
+        type Err = String;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            match s.strip_prefix("Invalid amount ") {
+                Some(amount) => amount
+                    .parse()
+                    .map(ContractError::InvalidAmount)
+                    .map_err(|_| s.to_string()),
+                None if s == "Unauthorized" => Ok(ContractError::Unauthorized {}),
+                None => Err(s.to_string()),
+            }
+        }
+    }
+
+    #[test]
+    fn contract_error_from_tx_log() {
+        let err = DaemonError::TxFailed {
+            code: 5,
+            codespace: "wasm".to_string(),
+            reason: "failed to execute message; message index: 0: Invalid amount 5: execute wasm contract failed".to_string(),
+        };
+        assert_eq!(err.abci_code(), Some(("wasm", 5)));
+        assert_eq!(err.contract_error(), Some("Invalid amount 5"));
+        assert!(err.is_contract_error(&ContractError::InvalidAmount(5)));
+        assert_eq!(
+            err.match_contract_error([
+                ContractError::Unauthorized {},
+                ContractError::InvalidAmount(5)
+            ]),
+            Some(ContractError::InvalidAmount(5))
+        );
+        assert_eq!(
+            err.downcast_contract_error::<ContractError>(),
+            Some(ContractError::InvalidAmount(5))
+        );
+
+        let err = CwEnvError::from(err);
+        assert_eq!(err.contract_error().as_deref(), Some("Invalid amount 5"));
+        assert!(err.is_contract_error(&ContractError::InvalidAmount(5)));
+        assert!(!err.is_contract_error(&ContractError::Unauthorized {}));
+        assert_eq!(
+            err.downcast_contract_error::<ContractError>(),
+            Some(ContractError::InvalidAmount(5))
+        );
+    }
+
+    #[test]
+    fn contract_error_from_simulation() {
+        let err = DaemonError::Status(tonic::Status::unknown(
+            "failed to execute message; message index: 0: Unauthorized: instantiate wasm contract failed [CosmWasm/wasmd@v0.45.0/x/wasm/keeper/keeper.go:401] With gas wanted: '0' and gas used: '1234' : unknown request",
+        ));
+        assert_eq!(err.abci_code(), None);
+        assert!(err.is_contract_error(&ContractError::Unauthorized {}));
+        assert!(!err.is_contract_error(&ContractError::InvalidAmount(1)));
+    }
+
+    #[test]
+    fn not_a_contract_error() {
+        let err = DaemonError::TxFailed {
+            code: 5,
+            codespace: "sdk".to_string(),
+            reason: "insufficient funds".to_string(),
+        };
+        assert_eq!(err.contract_error(), None);
+    }
+
+    #[test]
+    fn chain_halt_errors() {
+        assert!(
+            DaemonError::Status(tonic::Status::unavailable("connection refused"))
+                .may_be_chain_halt()
+        );
+        assert!(DaemonError::TXNotFound("5C3F".to_string(), 10).may_be_chain_halt());
+        // Transactions rejected by the node don't trigger the upgrade checks
+        assert!(
+            !DaemonError::Status(tonic::Status::unknown("insufficient funds")).may_be_chain_halt()
+        );
+        assert!(!DaemonError::TxFailed {
+            code: 5,
+            codespace: "sdk".to_string(),
+            reason: "insufficient funds".to_string(),
+        }
+        .may_be_chain_halt());
+    }
+}
@@ -0,0 +1,483 @@
+//! Local SQLite index of transactions and events, for historical queries without an external indexer.
+//!
+//! A [`SqliteIndexer`] writes transactions and their events to `txs`, `events` and `attributes` tables.
+//! It is filled from two sources:
+//! - the responses of the transactions sent by a [`Wallet`](crate::Wallet), as a [`SenderHook`],
+//! - the events of every block followed by a [`Reactor`](crate::reactor::Reactor), see [`Reactor::index`](crate::reactor::Reactor::index).
+//!
+//! Indexing is idempotent: indexing a transaction or a block again replaces its events.
+//! Only the transactions sent by the wallet have an entry in the `txs` table, streamed events only fill `events` and `attributes`.
+//!
+//! ```no_run
+//! use cw_orch_daemon::{indexer::{EventQuery, SqliteIndexer}, CosmosOptions, Daemon, networks};
+//!
+//! let indexer = SqliteIndexer::open("txs.sqlite").unwrap();
+//! let daemon = Daemon::builder(networks::LOCAL_JUNO)
+//!     .build_sender(CosmosOptions::default().hook(indexer.clone()))
+//!     .unwrap();
+//!
+//! // ... send transactions
+//!
+//! let transfers = indexer
+//!     .events(&EventQuery::new().event_type("transfer").attribute("recipient", "juno1..."))
+//!     .unwrap();
+//! ```
+
+use std::{
+    path::Path,
+    sync::{Arc, Mutex},
+};
+
+use chrono::{DateTime, Utc};
+use cosmrs::Any;
+use cw_orch_core::log::transaction_target;
+use rusqlite::{params, params_from_iter, types::Value, Connection, OptionalExtension};
+
+use crate::{
+    queriers::BlockResults, senders::hooks::SenderHook, CosmTxResponse, DaemonError,
+    TxResultBlockAttribute, TxResultBlockEvent,
+};
+
+const SCHEMA: &str = "
+PRAGMA foreign_keys = ON;
+CREATE TABLE IF NOT EXISTS txs (
+    txhash TEXT PRIMARY KEY,
+    height INTEGER NOT NULL,
+    code INTEGER NOT NULL,
+    codespace TEXT NOT NULL,
+    gas_wanted INTEGER NOT NULL,
+    gas_used INTEGER NOT NULL,
+    timestamp TEXT NOT NULL,
+    raw_log TEXT NOT NULL
+);
+CREATE INDEX IF NOT EXISTS txs_height ON txs(height);
+CREATE TABLE IF NOT EXISTS events (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    height INTEGER NOT NULL,
+    txhash TEXT,
+    event_index INTEGER NOT NULL,
+    type TEXT NOT NULL
+);
+CREATE INDEX IF NOT EXISTS events_height ON events(height);
+CREATE INDEX IF NOT EXISTS events_txhash ON events(txhash);
+CREATE INDEX IF NOT EXISTS events_type ON events(type);
+CREATE TABLE IF NOT EXISTS attributes (
+    event_id INTEGER NOT NULL REFERENCES events(id) ON DELETE CASCADE,
+    attribute_index INTEGER NOT NULL,
+    key TEXT NOT NULL,
+    value TEXT NOT NULL,
+    PRIMARY KEY (event_id, attribute_index)
+);
+CREATE INDEX IF NOT EXISTS attributes_key_value ON attributes(key, value);
+";
+
+/// Transaction stored in a [`SqliteIndexer`]
+#[derive(Clone, Debug, PartialEq)]
+pub struct IndexedTx {
+    pub txhash: String,
+    pub height: u64,
+    pub code: u32,
+    pub codespace: String,
+    pub gas_wanted: u64,
+    pub gas_used: u64,
+    pub timestamp: DateTime<Utc>,
+    pub raw_log: String,
+}
+
+/// Event stored in a [`SqliteIndexer`]
+#[derive(Clone, Debug)]
+pub struct IndexedEvent {
+    pub height: u64,
+    /// Hash of the transaction that emitted the event, `None` for the events emitted outside of transactions
+    pub txhash: Option<String>,
+    pub event: TxResultBlockEvent,
+}
+
+/// Selects the events returned by [`SqliteIndexer::events`] and the transactions returned by [`SqliteIndexer::txs`].
+/// All the conditions must match.
+#[derive(Clone, Debug, Default)]
+pub struct EventQuery {
+    event_type: Option<String>,
+    attributes: Vec<(String, String)>,
+    min_height: Option<u64>,
+    max_height: Option<u64>,
+    limit: Option<u64>,
+}
+
+impl EventQuery {
+    /// Matches all the events
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only match events of type `event_type`
+    pub fn event_type(mut self, event_type: impl Into<String>) -> Self {
+        self.event_type = Some(event_type.into());
+        self
+    }
+
+    /// Only match events with an attribute `key` set to `value`, can be called multiple times
+    pub fn attribute(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.attributes.push((key.into(), value.into()));
+        self
+    }
+
+    /// Only match events emitted by `contract`
+    pub fn contract(self, contract: impl Into<String>) -> Self {
+        self.attribute("_contract_address", contract)
+    }
+
+    /// Only match events emitted at or after `height`
+    pub fn min_height(mut self, height: u64) -> Self {
+        self.min_height = Some(height);
+        self
+    }
+
+    /// Only match events emitted at or before `height`
+    pub fn max_height(mut self, height: u64) -> Self {
+        self.max_height = Some(height);
+        self
+    }
+
+    /// Return at most `limit` results
+    pub fn limit(mut self, limit: u64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// `WHERE` clause on the `events` table aliased `e`, with its parameters
+    fn where_clause(&self) -> (String, Vec<Value>) {
+        let mut conditions = vec!["1 = 1".to_string()];
+        let mut values = vec![];
+        if let Some(event_type) = &self.event_type {
+            conditions.push("e.type = ?".to_string());
+            values.push(Value::Text(event_type.clone()));
+        }
+        for (key, value) in &self.attributes {
+            conditions.push(
+                "EXISTS (SELECT 1 FROM attributes a WHERE a.event_id = e.id AND a.key = ? AND a.value = ?)"
+                    .to_string(),
+            );
+            values.push(Value::Text(key.clone()));
+            values.push(Value::Text(value.clone()));
+        }
+        if let Some(height) = self.min_height {
+            conditions.push("e.height >= ?".to_string());
+            values.push(Value::Integer(height as i64));
+        }
+        if let Some(height) = self.max_height {
+            conditions.push("e.height <= ?".to_string());
+            values.push(Value::Integer(height as i64));
+        }
+        (conditions.join(" AND "), values)
+    }
+
+    fn limit_clause(&self) -> String {
+        self.limit
+            .map(|limit| format!(" LIMIT {limit}"))
+            .unwrap_or_default()
+    }
+}
+
+/// Writes transactions and events to a SQLite database, see the [module documentation](self)
+#[derive(Clone)]
+pub struct SqliteIndexer {
+    conn: Arc<Mutex<Connection>>,
+}
+
+impl SqliteIndexer {
+    /// Open the database at `path`, creating it and its tables if needed
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, DaemonError> {
+        Self::from_connection(Connection::open(path)?)
+    }
+
+    /// Index into a new in-memory database, dropped with the indexer
+    pub fn in_memory() -> Result<Self, DaemonError> {
+        Self::from_connection(Connection::open_in_memory()?)
+    }
+
+    fn from_connection(conn: Connection) -> Result<Self, DaemonError> {
+        conn.execute_batch(SCHEMA)?;
+        Ok(Self {
+            conn: Arc::new(Mutex::new(conn)),
+        })
+    }
+
+    /// Index a transaction and its events, replacing the events already indexed for it
+    pub fn index_tx(&self, response: &CosmTxResponse) -> Result<(), DaemonError> {
+        let mut conn = self.conn.lock().unwrap();
+        let db_tx = conn.transaction()?;
+        db_tx.execute(
+            "INSERT OR REPLACE INTO txs (txhash, height, code, codespace, gas_wanted, gas_used, timestamp, raw_log)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+            params![
+                response.txhash,
+                response.height,
+                response.code as u32,
+                response.codespace,
+                response.gas_wanted,
+                response.gas_used,
+                response.timestamp,
+                response.raw_log,
+            ],
+        )?;
+        db_tx.execute("DELETE FROM events WHERE txhash = ?1", [&response.txhash])?;
+        insert_events(
+            &db_tx,
+            response.height,
+            Some(&response.txhash),
+            &response.all_events(),
+        )?;
+        db_tx.commit()?;
+        Ok(())
+    }
+
+    /// Index the events of a block, replacing the events already indexed at its height.
+    /// `txhashes` are the hashes of the transactions of the block, in order.
+    pub fn index_block(
+        &self,
+        block: &BlockResults,
+        txhashes: &[String],
+    ) -> Result<(), DaemonError> {
+        let mut conn = self.conn.lock().unwrap();
+        let db_tx = conn.transaction()?;
+        db_tx.execute("DELETE FROM events WHERE height = ?1", [block.height])?;
+        let block_events: Vec<TxResultBlockEvent> = block
+            .begin_block_events
+            .iter()
+            .chain(&block.finalize_block_events)
+            .chain(&block.end_block_events)
+            .cloned()
+            .collect();
+        insert_events(&db_tx, block.height, None, &block_events)?;
+        for (tx_index, events) in block.txs_events.iter().enumerate() {
+            insert_events(&db_tx, block.height, txhashes.get(tx_index), events)?;
+        }
+        db_tx.commit()?;
+        Ok(())
+    }
+
+    /// Query an indexed transaction by hash
+    pub fn tx(&self, txhash: &str) -> Result<Option<IndexedTx>, DaemonError> {
+        let conn = self.conn.lock().unwrap();
+        Ok(conn
+            .query_row(
+                "SELECT txhash, height, code, codespace, gas_wanted, gas_used, timestamp, raw_log
+                FROM txs WHERE txhash = ?1",
+                [txhash],
+                read_tx,
+            )
+            .optional()?)
+    }
+
+    /// Query the indexed transactions that emitted an event matching `query`, ordered by height
+    pub fn txs(&self, query: &EventQuery) -> Result<Vec<IndexedTx>, DaemonError> {
+        let (condition, values) = query.where_clause();
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare(&format!(
+            "SELECT t.txhash, t.height, t.code, t.codespace, t.gas_wanted, t.gas_used, t.timestamp, t.raw_log
+            FROM txs t WHERE t.txhash IN (SELECT e.txhash FROM events e WHERE {condition})
+            ORDER BY t.height{}",
+            query.limit_clause()
+        ))?;
+        let txs = stmt
+            .query_map(params_from_iter(values), read_tx)?
+            .collect::<Result<_, _>>()?;
+        Ok(txs)
+    }
+
+    /// Query the indexed events matching `query`, ordered by height
+    pub fn events(&self, query: &EventQuery) -> Result<Vec<IndexedEvent>, DaemonError> {
+        let (condition, values) = query.where_clause();
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare(&format!(
+            "SELECT e.id, e.height, e.txhash, e.type FROM events e WHERE {condition}
+            ORDER BY e.height, e.id{}",
+            query.limit_clause()
+        ))?;
+        let rows: Vec<(i64, u64, Option<String>, String)> = stmt
+            .query_map(params_from_iter(values), |row| {
+                Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?))
+            })?
+            .collect::<Result<_, _>>()?;
+
+        let mut attributes_stmt = conn.prepare(
+            "SELECT key, value FROM attributes WHERE event_id = ?1 ORDER BY attribute_index",
+        )?;
+        rows.into_iter()
+            .map(|(id, height, txhash, s_type)| {
+                let attributes = attributes_stmt
+                    .query_map([id], |row| {
+                        Ok(TxResultBlockAttribute {
+                            key: row.get(0)?,
+                            value: row.get(1)?,
+                        })
+                    })?
+                    .collect::<Result<_, _>>()?;
+                Ok(IndexedEvent {
+                    height,
+                    txhash,
+                    event: TxResultBlockEvent { s_type, attributes },
+                })
+            })
+            .collect()
+    }
+
+    /// Run `f` on the underlying connection, for queries not covered by the indexer
+    pub fn with_connection<T>(
+        &self,
+        f: impl FnOnce(&Connection) -> rusqlite::Result<T>,
+    ) -> Result<T, DaemonError> {
+        Ok(f(&self.conn.lock().unwrap())?)
+    }
+}
+
+impl SenderHook for SqliteIndexer {
+    fn on_after_broadcast(&self, _msgs: &[Any], response: &CosmTxResponse) {
+        if let Err(e) = self.index_tx(response) {
+            log::warn!(
+                target: &transaction_target(),
+                "Failed to index tx {}: {e}",
+                response.txhash
+            );
+        }
+    }
+}
+
+fn insert_events(
+    conn: &Connection,
+    height: u64,
+    txhash: Option<&String>,
+    events: &[TxResultBlockEvent],
+) -> rusqlite::Result<()> {
+    let mut event_stmt = conn.prepare_cached(
+        "INSERT INTO events (height, txhash, event_index, type) VALUES (?1, ?2, ?3, ?4)",
+    )?;
+    let mut attribute_stmt = conn.prepare_cached(
+        "INSERT INTO attributes (event_id, attribute_index, key, value) VALUES (?1, ?2, ?3, ?4)",
+    )?;
+    for (event_index, event) in events.iter().enumerate() {
+        event_stmt.execute(params![height, txhash, event_index, event.s_type])?;
+        let event_id = conn.last_insert_rowid();
+        for (attribute_index, attribute) in event.attributes.iter().enumerate() {
+            attribute_stmt.execute(params![
+                event_id,
+                attribute_index,
+                attribute.key,
+                attribute.value
+            ])?;
+        }
+    }
+    Ok(())
+}
+
+fn read_tx(row: &rusqlite::Row) -> rusqlite::Result<IndexedTx> {
+    Ok(IndexedTx {
+        txhash: row.get(0)?,
+        height: row.get(1)?,
+        code: row.get(2)?,
+        codespace: row.get(3)?,
+        gas_wanted: row.get(4)?,
+        gas_used: row.get(5)?,
+        timestamp: row.get(6)?,
+        raw_log: row.get(7)?,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const CONTRACT: &str = "juno1contract";
+
+    fn event(s_type: &str, attributes: &[(&str, &str)]) -> TxResultBlockEvent {
+        TxResultBlockEvent {
+            s_type: s_type.to_string(),
+            attributes: attributes
+                .iter()
+                .map(|(key, value)| TxResultBlockAttribute {
+                    key: key.to_string(),
+                    value: value.to_string(),
+                })
+                .collect(),
+        }
+    }
+
+    fn block(height: u64) -> BlockResults {
+        BlockResults {
+            height,
+            begin_block_events: vec![event("mint", &[("amount", "10")])],
+            end_block_events: vec![],
+            finalize_block_events: vec![],
+            txs_events: vec![vec![event(
+                "wasm",
+                &[("_contract_address", CONTRACT), ("action", "liquidate")],
+            )]],
+        }
+    }
+
+    #[test]
+    fn indexes_blocks_idempotently() -> Result<(), DaemonError> {
+        let indexer = SqliteIndexer::in_memory()?;
+        let txhashes = vec!["AB".to_string()];
+        indexer.index_block(&block(10), &txhashes)?;
+        indexer.index_block(&block(10), &txhashes)?;
+        indexer.index_block(&block(11), &[])?;
+
+        assert_eq!(indexer.events(&EventQuery::new())?.len(), 4);
+
+        let liquidations = indexer.events(
+            &EventQuery::new()
+                .contract(CONTRACT)
+                .attribute("action", "liquidate")
+                .max_height(10),
+        )?;
+        assert_eq!(liquidations.len(), 1);
+        assert_eq!(liquidations[0].height, 10);
+        assert_eq!(liquidations[0].txhash.as_deref(), Some("AB"));
+        assert_eq!(
+            liquidations[0].event.get_first_attribute_value("action"),
+            Some("liquidate".to_string())
+        );
+
+        let mints = indexer.events(&EventQuery::new().event_type("mint").min_height(11))?;
+        assert_eq!(mints.len(), 1);
+        assert_eq!(mints[0].txhash, None);
+        Ok(())
+    }
+
+    #[test]
+    fn indexes_tx_responses() -> Result<(), DaemonError> {
+        let indexer = SqliteIndexer::in_memory()?;
+        let response = CosmTxResponse {
+            height: 5,
+            txhash: "CD".to_string(),
+            gas_wanted: 200,
+            gas_used: 100,
+            logs: vec![crate::TxResultBlockMsg {
+                msg_index: Some(0),
+                events: vec![event("wasm", &[("_contract_address", CONTRACT)])],
+            }],
+            ..Default::default()
+        };
+        indexer.on_after_broadcast(&[], &response);
+
+        let tx = indexer.tx("CD")?.unwrap();
+        assert_eq!(tx.height, 5);
+        assert_eq!(tx.gas_used, 100);
+        assert_eq!(tx.timestamp, response.timestamp);
+        assert_eq!(indexer.tx("EF")?, None);
+
+        let txs = indexer.txs(&EventQuery::new().contract(CONTRACT))?;
+        assert_eq!(txs, vec![tx]);
+        assert!(indexer
+            .txs(&EventQuery::new().contract("juno1other"))?
+            .is_empty());
+
+        let count: u64 = indexer.with_connection(|conn| {
+            conn.query_row("SELECT COUNT(*) FROM attributes", [], |row| row.get(0))
+        })?;
+        assert_eq!(count, 1);
+        Ok(())
+    }
+}
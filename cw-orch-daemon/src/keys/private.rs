@@ -1,5 +1,5 @@
 use super::public::PublicKey;
-use crate::proto::injective::{InjectivePubKey, ETHEREUM_COIN_TYPE};
+use crate::proto::injective::{InjectivePubKey, ETHEREUM_COIN_TYPE, ETHERMINT_PUB_KEY_TYPE_URL};
 use crate::DaemonError;
 #[cfg(feature = "eth")]
 use ::ethers_core::k256::ecdsa::SigningKey;
@@ -10,7 +10,10 @@ use bitcoin::{
     Network,
 };
 use cosmrs::tx::SignerPublicKey;
-use cw_orch_core::log::local_target;
+use cw_orch_core::{
+    environment::{NetworkInfoOwned, PubKeyType},
+    log::local_target,
+};
 use hkd32::mnemonic::{Phrase, Seed};
 use prost_types::Any;
 use rand_core::OsRng;
@@ -25,6 +28,8 @@ pub struct PrivateKey {
     pub index: u32,
     #[allow(missing_docs)]
     pub coin_type: u32,
+    /// Type of the public key, which determines the address and the signatures
+    pub pub_key_type: PubKeyType,
     /// The 24 words used to generate this private key
     mnemonic: Option<Phrase>,
     #[allow(dead_code)]
@@ -98,12 +103,30 @@ impl PrivateKey {
         Self::gen_private_key_raw(secp, raw_key, account, index, coin_type)
     }
 
+    /// Address of the first key of `mnemonic` on `network`, the sender of a [`Wallet`](crate::Wallet) built with this mnemonic
+    pub fn mnemonic_address(
+        mnemonic: &str,
+        network: &NetworkInfoOwned,
+    ) -> Result<String, DaemonError> {
+        let secp = Secp256k1::new();
+        PrivateKey::from_words(&secp, mnemonic, 0, 0, network.coin_type)?
+            .with_pub_key_type(network.resolved_pub_key_type())
+            .public_key(&secp)
+            .account(&network.pub_address_prefix)
+    }
+
+    /// Overrides the public key type, derived from the coin type by default
+    pub fn with_pub_key_type(mut self, pub_key_type: PubKeyType) -> Self {
+        self.pub_key_type = pub_key_type;
+        self
+    }
+
     /// generate the public key for this private key
     pub fn public_key<C: secp256k1::Signing + secp256k1::Context>(
         &self,
         secp: &Secp256k1<C>,
     ) -> PublicKey {
-        if self.coin_type == ETHEREUM_COIN_TYPE {
+        if self.pub_key_type.is_ethereum() {
             #[cfg(feature = "eth")]
             return PublicKey::from_ethers_address_bytes(
                 ethers_core::utils::secret_key_to_address(
@@ -111,8 +134,8 @@ impl PrivateKey {
                 ),
             );
             panic!(
-                "Coin Type {} not supported without eth feature",
-                ETHEREUM_COIN_TYPE
+                "Public key type {:?} not supported without eth feature",
+                self.pub_key_type
             );
         }
 
@@ -124,8 +147,32 @@ impl PrivateKey {
         &self,
         secp: &Secp256k1<C>,
     ) -> SignerPublicKey {
-        use base64::engine::general_purpose;
         use cosmrs::tx::MessageExt;
+
+        let inj_key = self.eth_public_key(secp);
+        cosmrs::Any::from_msg(&inj_key).unwrap().try_into().unwrap()
+    }
+
+    pub fn get_ethermint_public_key<C: secp256k1::Signing + secp256k1::Context>(
+        &self,
+        secp: &Secp256k1<C>,
+    ) -> SignerPublicKey {
+        use prost::Message;
+
+        cosmrs::Any {
+            type_url: ETHERMINT_PUB_KEY_TYPE_URL.to_string(),
+            value: self.eth_public_key(secp).encode_to_vec(),
+        }
+        .try_into()
+        .unwrap()
+    }
+
+    /// Compressed public key, as encoded by the `ethsecp256k1` key types
+    fn eth_public_key<C: secp256k1::Signing + secp256k1::Context>(
+        &self,
+        secp: &Secp256k1<C>,
+    ) -> InjectivePubKey {
+        use base64::engine::general_purpose;
         use secp256k1::SecretKey;
 
         let secret_key = SecretKey::from_slice(self.raw_key().as_slice()).unwrap();
@@ -135,22 +182,24 @@ impl PrivateKey {
 
         log::debug!(target: &local_target(), "{:?}, public key", general_purpose::STANDARD.encode(vec_pk));
 
-        let inj_key = InjectivePubKey { key: vec_pk.into() };
-
-        cosmrs::Any::from_msg(&inj_key).unwrap().try_into().unwrap()
+        InjectivePubKey { key: vec_pk.into() }
     }
 
     pub fn get_signer_public_key<C: secp256k1::Signing + secp256k1::Context>(
         &self,
         secp: &Secp256k1<C>,
     ) -> Option<SignerPublicKey> {
-        if self.coin_type == ETHEREUM_COIN_TYPE {
+        match self.pub_key_type {
+            PubKeyType::Secp256k1 => {}
             #[cfg(feature = "eth")]
-            return Some(self.get_injective_public_key(secp));
-            panic!(
-                "Coin Type {} not supported without eth feature",
-                ETHEREUM_COIN_TYPE
-            );
+            PubKeyType::InjectiveEthSecp256k1 => return Some(self.get_injective_public_key(secp)),
+            #[cfg(feature = "eth")]
+            PubKeyType::EthermintEthSecp256k1 => return Some(self.get_ethermint_public_key(secp)),
+            #[cfg(not(feature = "eth"))]
+            _ => panic!(
+                "Public key type {:?} not supported without eth feature",
+                self.pub_key_type
+            ),
         }
 
         Some(
@@ -199,6 +248,11 @@ impl PrivateKey {
             account,
             index,
             coin_type,
+            pub_key_type: if coin_type == ETHEREUM_COIN_TYPE {
+                PubKeyType::InjectiveEthSecp256k1
+            } else {
+                PubKeyType::Secp256k1
+            },
             mnemonic: None,
             root_private_key,
             private_key,
@@ -290,6 +344,13 @@ mod tst {
 
         let account = pub_k.account(prefix)?;
         assert_eq!(&account, "terra1jnzv225hwl3uxc5wtnlgr8mwy6nlt0vztv3qqm");
+        let network = NetworkInfoOwned {
+            chain_name: "terra".to_string(),
+            pub_address_prefix: prefix.to_string(),
+            coin_type,
+            pub_key_type: None,
+        };
+        assert_eq!(PrivateKey::mnemonic_address(str_1, &network)?, account);
         assert_eq!(
             &pub_k.operator_address_public_key(prefix)?,
             "terravaloperpub1addwnpepqt8ha594svjn3nvfk4ggfn5n8xd3sm3cz6ztxyugwcuqzsuuhhfq5y7accr"
@@ -329,6 +390,24 @@ mod tst {
         Ok(())
     }
 
+    #[test]
+    pub fn tst_pub_key_type() -> anyhow::Result<()> {
+        let str_1 = "wonder caution square unveil april art add hover spend smile proud admit modify old copper throw crew happy nature luggage reopen exhibit ordinary napkin";
+        let secp = Secp256k1::new();
+        let pk = PrivateKey::from_words(&secp, str_1, 0, 0, ETHEREUM_COIN_TYPE)?;
+        assert_eq!(pk.pub_key_type, PubKeyType::InjectiveEthSecp256k1);
+
+        let pk = pk.with_pub_key_type(PubKeyType::EthermintEthSecp256k1);
+        assert_eq!(
+            cosmrs::Any::from(pk.get_ethermint_public_key(&secp)).type_url,
+            ETHERMINT_PUB_KEY_TYPE_URL
+        );
+
+        let pk = PrivateKey::from_words(&secp, str_1, 0, 0, 330)?;
+        assert_eq!(pk.pub_key_type, PubKeyType::Secp256k1);
+        Ok(())
+    }
+
     // #[test]
     // pub fn test_sign() -> anyhow::Result<()> {
     //     // This test is using message from python SDK.. so these keys generate same sigs as they do.
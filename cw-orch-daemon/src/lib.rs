@@ -3,15 +3,26 @@
 //! `Daemon` and `DaemonAsync` execution environments.
 //!
 //! The `Daemon` type is a synchronous wrapper around the `DaemonAsync` type and can be used as a contract execution environment.
+pub mod amino;
+pub mod budget;
+pub mod chain_registry;
+pub mod docker;
 pub mod json_lock;
 /// Proto types for different blockchains
 pub mod proto;
+pub mod proto_versions;
+pub mod query_cache;
 // expose these as mods as they can grow
 pub mod env;
+#[cfg(feature = "indexer")]
+pub mod indexer;
 pub mod keys;
 pub mod live_mock;
 pub mod queriers;
+pub mod reactor;
 pub mod senders;
+pub mod signed_tx;
+pub mod trace;
 pub mod tx_broadcaster;
 pub mod tx_builder;
 
@@ -19,13 +30,18 @@ mod builder;
 mod channel;
 mod core;
 mod error;
+mod local_chain;
 mod log;
 mod network_config;
 mod state;
+mod state_export;
 mod sync;
 mod tx_resp;
 
-pub use self::{builder::*, channel::*, core::*, error::*, state::*, sync::*, tx_resp::*};
+pub use self::{
+    builder::*, channel::*, core::*, error::*, local_chain::*, state::*, state_export::*, sync::*,
+    tx_resp::*,
+};
 pub use cw_orch_networks::networks;
 pub use senders::{query::QuerySender, tx::TxSender, CosmosOptions, Wallet};
 pub use tx_builder::TxBuilder;
@@ -38,10 +54,12 @@ pub(crate) mod cosmos_modules {
             authz::v1beta1 as authz,
             bank::v1beta1 as bank,
             base::{abci::v1beta1 as abci, tendermint::v1beta1 as tendermint},
+            distribution::v1beta1 as distribution,
             feegrant::v1beta1 as feegrant,
             gov::v1beta1 as gov,
             staking::v1beta1 as staking,
             tx::v1beta1 as tx,
+            upgrade::v1beta1 as upgrade,
             vesting::v1beta1 as vesting,
         },
         cosmwasm::wasm::v1 as cosmwasm,
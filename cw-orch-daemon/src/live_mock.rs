@@ -111,6 +111,7 @@ impl WasmMockQuerier {
                 let querier = Bank {
                     channel: self.channel.clone(),
                     rt_handle: Some(handle.clone()),
+                    height: None,
                 };
                 match x {
                     BankQuery::Balance { address, denom } => {
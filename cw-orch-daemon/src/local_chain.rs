@@ -0,0 +1,393 @@
+//! Start and stop local chains in Docker containers.
+//!
+//! A [`LocalChain`] runs a single node chain (localjuno, localosmosis, wasmd, ...) in a container whose genesis funds the account of the configured mnemonic.
+//! Once the chain produced its first block, [`LocalChain::daemon`] hands back a [`Daemon`] connected to it.
+//! The container is removed when the [`LocalChain`] is dropped.
+//!
+//! ```no_run
+//! use cw_orch_daemon::LocalChain;
+//!
+//! let chain = LocalChain::juno().start().unwrap();
+//! let daemon = chain.daemon().unwrap();
+//! ```
+
+use std::{
+    process::Command,
+    time::{Duration, Instant},
+};
+
+use cw_orch_core::{environment::ChainInfoOwned, log::connectivity_target};
+use cw_orch_networks::networks::{LOCAL_JUNO, LOCAL_OSMO, LOCAL_WASMD};
+use tokio::runtime::Handle;
+
+use crate::{
+    docker, env::DaemonEnvVars, keys::private::PrivateKey, queriers::Node, Daemon, DaemonBuilder,
+    DaemonError, GrpcChannel, RUNTIME,
+};
+
+/// Placeholder replaced by the funded addresses in the container command
+pub const ACCOUNTS_PLACEHOLDER: &str = "{accounts}";
+
+const JUNO_IMAGE: &str = "ghcr.io/cosmoscontracts/juno:v12.0.0";
+const WASMD_IMAGE: &str = "cosmwasm/wasmd:v0.45.0";
+const OSMOSIS_IMAGE: &str = "osmolabs/osmosis:25.0.0-alpine";
+
+/// Genesis of a single validator localosmosis, funding the accounts with `uosmo` and `stake`.
+/// The image doesn't ship a script taking the accounts to fund, unlike the Juno and wasmd images.
+const OSMOSIS_SETUP: &str = "osmosisd init localosmosis --chain-id localosmosis -o \
+    && osmosisd keys add validator --keyring-backend test \
+    && osmosisd add-genesis-account validator 1000000000000uosmo,1000000000000stake --keyring-backend test \
+    && for account in {accounts}; do osmosisd add-genesis-account $account 1000000000000uosmo,1000000000000stake; done \
+    && osmosisd gentx validator 500000000stake --chain-id localosmosis --keyring-backend test \
+    && osmosisd collect-gentxs \
+    && osmosisd start --rpc.laddr tcp://0.0.0.0:26657 --grpc.address 0.0.0.0:9090 --minimum-gas-prices 0uosmo";
+
+const GRPC_PORT: u16 = 9090;
+const RPC_PORT: u16 = 26657;
+const DEFAULT_STARTUP_TIMEOUT: Duration = Duration::from_secs(60);
+const BLOCK_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Create a [`LocalChain`] through [`LocalChainBuilder`]
+#[derive(Clone, Debug)]
+pub struct LocalChainBuilder {
+    chain: ChainInfoOwned,
+    image: String,
+    entrypoint: Option<String>,
+    command: Vec<String>,
+    env: Vec<(String, String)>,
+    container_name: String,
+    grpc_port: u16,
+    rpc_port: u16,
+    mnemonic: Option<String>,
+    funded_accounts: Vec<String>,
+    startup_timeout: Duration,
+    handle: Option<Handle>,
+}
+
+impl LocalChainBuilder {
+    /// Run `image` with `command` to start `chain`.
+    /// [`ACCOUNTS_PLACEHOLDER`] in the command is replaced by the space-separated addresses to fund in genesis.
+    pub fn new(
+        chain: impl Into<ChainInfoOwned>,
+        image: impl Into<String>,
+        command: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Self {
+        let chain: ChainInfoOwned = chain.into();
+        Self {
+            container_name: format!("cw-orch-{}", chain.network_info.chain_name),
+            chain,
+            image: image.into(),
+            entrypoint: None,
+            command: command.into_iter().map(Into::into).collect(),
+            env: vec![],
+            grpc_port: GRPC_PORT,
+            rpc_port: RPC_PORT,
+            mnemonic: None,
+            funded_accounts: vec![],
+            startup_timeout: DEFAULT_STARTUP_TIMEOUT,
+            handle: None,
+        }
+    }
+
+    /// Set the docker image to run
+    pub fn image(&mut self, image: impl Into<String>) -> &mut Self {
+        self.image = image.into();
+        self
+    }
+
+    /// Override the entrypoint of the image, the command is passed to it
+    pub fn entrypoint(&mut self, entrypoint: impl Into<String>) -> &mut Self {
+        self.entrypoint = Some(entrypoint.into());
+        self
+    }
+
+    /// Set an environment variable of the container
+    pub fn env(&mut self, key: impl Into<String>, value: impl Into<String>) -> &mut Self {
+        self.env.push((key.into(), value.into()));
+        self
+    }
+
+    /// Set the name of the container. An existing container with the same name is replaced.
+    pub fn container_name(&mut self, name: impl Into<String>) -> &mut Self {
+        self.container_name = name.into();
+        self
+    }
+
+    /// Set the host port the gRPC endpoint of the node is published on
+    pub fn grpc_port(&mut self, port: u16) -> &mut Self {
+        self.grpc_port = port;
+        self
+    }
+
+    /// Set the host port the Tendermint RPC endpoint of the node is published on
+    pub fn rpc_port(&mut self, port: u16) -> &mut Self {
+        self.rpc_port = port;
+        self
+    }
+
+    /// Set the mnemonic of the account funded in genesis and used by [`LocalChain::daemon`].
+    /// Defaults to the `LOCAL_MNEMONIC` environment variable.
+    pub fn mnemonic(&mut self, mnemonic: impl Into<String>) -> &mut Self {
+        self.mnemonic = Some(mnemonic.into());
+        self
+    }
+
+    /// Fund an additional address in genesis
+    pub fn fund(&mut self, address: impl Into<String>) -> &mut Self {
+        self.funded_accounts.push(address.into());
+        self
+    }
+
+    /// Set how long to wait for the first block before giving up
+    pub fn startup_timeout(&mut self, timeout: Duration) -> &mut Self {
+        self.startup_timeout = timeout;
+        self
+    }
+
+    /// Set a custom tokio runtime handle to use for the chain and its daemons
+    pub fn handle(&mut self, handle: &Handle) -> &mut Self {
+        self.handle = Some(handle.clone());
+        self
+    }
+
+    /// Start the container and wait until the chain produced its first block
+    pub fn start(&self) -> Result<LocalChain, DaemonError> {
+        let rt_handle = self
+            .handle
+            .clone()
+            .unwrap_or_else(|| RUNTIME.handle().clone());
+        let mnemonic = match &self.mnemonic {
+            Some(mnemonic) => mnemonic.clone(),
+            None => DaemonEnvVars::local_mnemonic().ok_or(DaemonError::MissingPhrase)?,
+        };
+
+        let accounts = self.genesis_accounts(&mnemonic)?;
+
+        // Replace any leftover container of a previous run
+        docker::remove_container(&self.container_name);
+
+        let mut docker = Command::new("docker");
+        docker.args(self.docker_run_args(&accounts));
+        log::info!(target: &connectivity_target(), "Starting local chain {} in container {}", self.chain.chain_id, self.container_name);
+        docker::run(&mut docker)?;
+
+        let mut chain = self.chain.clone();
+        chain.grpc_urls = vec![format!("http://localhost:{}", self.grpc_port)];
+        let local_chain = LocalChain {
+            container_name: self.container_name.clone(),
+            chain,
+            mnemonic,
+            rpc_url: format!("http://localhost:{}", self.rpc_port),
+            rt_handle,
+        };
+        local_chain.wait_for_first_block(self.startup_timeout)?;
+        Ok(local_chain)
+    }
+}
+
+impl LocalChainBuilder {
+    /// Addresses funded in genesis, the address of `mnemonic` first
+    fn genesis_accounts(&self, mnemonic: &str) -> Result<Vec<String>, DaemonError> {
+        let sender = PrivateKey::mnemonic_address(mnemonic, &self.chain.network_info)?;
+        Ok(std::iter::once(sender)
+            .chain(self.funded_accounts.iter().cloned())
+            .collect())
+    }
+
+    /// Arguments of `docker` running the chain, with [`ACCOUNTS_PLACEHOLDER`] replaced by `accounts`
+    fn docker_run_args(&self, accounts: &[String]) -> Vec<String> {
+        let accounts = accounts.join(" ");
+        let mut args: Vec<String> = vec![
+            "run".into(),
+            "-d".into(),
+            "--name".into(),
+            self.container_name.clone(),
+            "-p".into(),
+            format!("{}:{GRPC_PORT}", self.grpc_port),
+            "-p".into(),
+            format!("{}:{RPC_PORT}", self.rpc_port),
+        ];
+        for (key, value) in &self.env {
+            args.extend(["-e".into(), format!("{key}={value}")]);
+        }
+        if let Some(entrypoint) = &self.entrypoint {
+            args.extend(["--entrypoint".into(), entrypoint.clone()]);
+        }
+        args.push(self.image.clone());
+        args.extend(
+            self.command
+                .iter()
+                .map(|arg| arg.replace(ACCOUNTS_PLACEHOLDER, &accounts)),
+        );
+        args
+    }
+}
+
+/// Local chain running in a Docker container, removed on drop.
+pub struct LocalChain {
+    container_name: String,
+    chain: ChainInfoOwned,
+    mnemonic: String,
+    rpc_url: String,
+    rt_handle: Handle,
+}
+
+impl LocalChain {
+    /// localjuno, using the `setup_and_run.sh` script of the Juno image
+    pub fn juno() -> LocalChainBuilder {
+        let mut builder = LocalChainBuilder::new(
+            LOCAL_JUNO,
+            JUNO_IMAGE,
+            ["sh", "-c", "./setup_and_run.sh {accounts}"],
+        );
+        builder
+            .env("STAKE_TOKEN", LOCAL_JUNO.gas_denom)
+            .env("UNSAFE_CORS", "true");
+        builder
+    }
+
+    /// wasmd, using the `setup_wasmd.sh` and `run_wasmd.sh` scripts of the wasmd image
+    pub fn wasmd() -> LocalChainBuilder {
+        LocalChainBuilder::new(
+            LOCAL_WASMD,
+            WASMD_IMAGE,
+            [
+                "sh",
+                "-c",
+                "/opt/setup_wasmd.sh {accounts} && /opt/run_wasmd.sh",
+            ],
+        )
+    }
+
+    /// localosmosis, with a genesis built by `osmosisd` in the Osmosis image
+    pub fn osmosis() -> LocalChainBuilder {
+        let mut builder = LocalChainBuilder::new(LOCAL_OSMO, OSMOSIS_IMAGE, ["-c", OSMOSIS_SETUP]);
+        builder.entrypoint("sh");
+        builder
+    }
+
+    /// Information of the running chain, with the published gRPC endpoint
+    pub fn chain_info(&self) -> &ChainInfoOwned {
+        &self.chain
+    }
+
+    /// Tendermint RPC endpoint of the node
+    pub fn rpc_url(&self) -> &str {
+        &self.rpc_url
+    }
+
+    /// Name of the container running the node
+    pub fn container_name(&self) -> &str {
+        &self.container_name
+    }
+
+    /// Builder of a [`Daemon`] connected to the chain, with the funded mnemonic and a temporary state file
+    pub fn daemon_builder(&self) -> DaemonBuilder {
+        let mut builder = DaemonBuilder::new(self.chain.clone());
+        builder
+            .handle(&self.rt_handle)
+            .mnemonic(self.mnemonic.clone())
+            .is_test(true);
+        builder
+    }
+
+    /// [`Daemon`] connected to the chain, sending transactions from the funded account
+    pub fn daemon(&self) -> Result<Daemon, DaemonError> {
+        self.daemon_builder().build()
+    }
+
+    /// Stop and remove the container
+    pub fn stop(self) {
+        drop(self)
+    }
+
+    fn wait_for_first_block(&self, timeout: Duration) -> Result<(), DaemonError> {
+        let start = Instant::now();
+        self.rt_handle.block_on(async {
+            loop {
+                let height = match GrpcChannel::from_chain_info(&self.chain).await {
+                    Ok(channel) => Node::new_async(channel)._block_height().await.ok(),
+                    Err(_) => None,
+                };
+                if height.is_some_and(|height| height >= 1) {
+                    log::info!(target: &connectivity_target(), "Local chain {} produced its first block", self.chain.chain_id);
+                    return Ok(());
+                }
+                if start.elapsed() > timeout {
+                    return Err(DaemonError::LocalChainTimeout {
+                        container: self.container_name.clone(),
+                        timeout,
+                    });
+                }
+                tokio::time::sleep(BLOCK_POLL_INTERVAL).await;
+            }
+        })
+    }
+}
+
+impl Drop for LocalChain {
+    fn drop(&mut self) {
+        log::info!(target: &connectivity_target(), "Removing local chain container {}", self.container_name);
+        docker::remove_container(&self.container_name);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const MNEMONIC: &str = "chapter wrist alcohol shine angry noise mercy simple rebel recycle vehicle wrap morning giraffe lazy outdoor noise blood ginger sort reunion boss crowd dutch";
+
+    #[test]
+    fn genesis_accounts_start_with_the_mnemonic_address() {
+        let mut builder = LocalChain::juno();
+        builder.fund("juno1extra");
+        let accounts = builder.genesis_accounts(MNEMONIC).unwrap();
+
+        let chain = ChainInfoOwned::from(LOCAL_JUNO);
+        let sender = PrivateKey::mnemonic_address(MNEMONIC, &chain.network_info).unwrap();
+        assert!(sender.starts_with("juno1"));
+        assert_eq!(accounts, vec![sender, "juno1extra".to_string()]);
+
+        // The address is derived with the prefix of the chain
+        let osmosis = LocalChain::osmosis().genesis_accounts(MNEMONIC).unwrap();
+        assert!(osmosis[0].starts_with("osmo1"));
+    }
+
+    #[test]
+    fn accounts_placeholder_substitution() {
+        let mut builder = LocalChain::wasmd();
+        builder.container_name("wasmd-test").grpc_port(19090);
+        let args = builder.docker_run_args(&["wasm1a".to_string(), "wasm1b".to_string()]);
+        assert_eq!(
+            args,
+            vec![
+                "run",
+                "-d",
+                "--name",
+                "wasmd-test",
+                "-p",
+                "19090:9090",
+                "-p",
+                "26657:26657",
+                WASMD_IMAGE,
+                "sh",
+                "-c",
+                "/opt/setup_wasmd.sh wasm1a wasm1b && /opt/run_wasmd.sh",
+            ]
+        );
+
+        let args = LocalChain::osmosis().docker_run_args(&["osmo1a".to_string()]);
+        let image = args.iter().position(|arg| arg == OSMOSIS_IMAGE).unwrap();
+        assert_eq!(args[image - 2..image], ["--entrypoint", "sh"]);
+        assert!(args[image + 2].contains("for account in osmo1a; do"));
+        assert!(!args[image + 2].contains(ACCOUNTS_PLACEHOLDER));
+    }
+
+    #[test]
+    fn juno_env() {
+        let args = LocalChain::juno().docker_run_args(&["juno1a".to_string()]);
+        assert!(args.windows(2).any(|w| w == ["-e", "STAKE_TOKEN=ujunox"]));
+        assert_eq!(args.last().unwrap(), "./setup_and_run.sh juno1a");
+    }
+}
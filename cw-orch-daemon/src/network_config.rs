@@ -43,6 +43,7 @@ mod test {
                 chain_name: "joono".to_owned(),
                 pub_address_prefix: "joo".to_owned(),
                 coin_type: 42,
+                pub_key_type: None,
             },
             kind: cw_orch::environment::ChainKind::Local,
         };
@@ -81,6 +82,7 @@ mod test {
                 chain_name: "juno".to_owned(),
                 pub_address_prefix: "juno".to_owned(),
                 coin_type: 118u32,
+                pub_key_type: None,
             },
             lcd_url: None,
             fcd_url: None,
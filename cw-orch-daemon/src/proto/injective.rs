@@ -12,6 +12,8 @@ use crate::keys::private::PrivateKey;
 use ::{cosmrs::proto, ethers_core::utils::keccak256};
 
 pub const ETHEREUM_COIN_TYPE: u32 = 60;
+/// Type url of the `ethsecp256k1` public keys of the chains built on Ethermint
+pub const ETHERMINT_PUB_KEY_TYPE_URL: &str = "/ethermint.crypto.v1.ethsecp256k1.PubKey";
 
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct InjectiveEthAccount {
@@ -0,0 +1,282 @@
+//! Differences between the Cosmos SDK and wasmd generations of the chains, see [`ProtoVersions`].
+//!
+//! The protos of `cosmos_modules` are wire compatible across generations, but the way transaction results are reported changed:
+//! - Cosmos SDK 0.46 added typed message responses (`msg_responses`) to the `data` of a transaction.
+//! - Cosmos SDK 0.50 stopped filling the message `logs`, the events of a message are tagged with a `msg_index` attribute instead.
+//! - wasmd 0.22 moved the code id and contract address attributes from the `message` event to the `store_code` and `instantiate` events.
+//!
+//! [`ProtoVersions::normalize`] rewrites a [`CosmTxResponse`] of any generation into the layout of the current one,
+//! so the helpers of [`IndexResponse`](cw_orch_core::environment::IndexResponse) work the same on every chain.
+
+use std::fmt::Display;
+
+use cosmrs::proto::tendermint::v0_34::abci::{Event, EventAttribute};
+
+use crate::{
+    cosmos_modules::tendermint::GetNodeInfoResponse,
+    tx_resp::{TxResultBlockAttribute, TxResultBlockEvent, TxResultBlockMsg},
+    CosmTxResponse,
+};
+
+const WASMD_MODULE_PATH: &str = "github.com/CosmWasm/wasmd";
+const MSG_INDEX_ATTRIBUTE: &str = "msg_index";
+
+/// `major.minor` version of a module
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ModuleVersion {
+    pub major: u64,
+    pub minor: u64,
+}
+
+impl ModuleVersion {
+    pub const fn new(major: u64, minor: u64) -> Self {
+        Self { major, minor }
+    }
+
+    /// Parses versions like `v0.50.1` or `0.47.5-ics`
+    pub fn parse(version: &str) -> Option<Self> {
+        let mut parts = version.trim().trim_start_matches('v').split('.');
+        let major = parts.next()?.parse().ok()?;
+        let minor = parts
+            .next()?
+            .split(|c: char| !c.is_ascii_digit())
+            .next()?
+            .parse()
+            .ok()?;
+        Some(Self { major, minor })
+    }
+}
+
+impl Display for ModuleVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "v{}.{}", self.major, self.minor)
+    }
+}
+
+/// First Cosmos SDK version with typed message responses
+pub const SDK_MSG_RESPONSES: ModuleVersion = ModuleVersion::new(0, 46);
+/// First Cosmos SDK version without message logs
+pub const SDK_NO_LOGS: ModuleVersion = ModuleVersion::new(0, 50);
+/// First wasmd version emitting the `store_code` and `instantiate` events
+pub const WASMD_ENTRY_POINT_EVENTS: ModuleVersion = ModuleVersion::new(0, 22);
+
+/// Cosmos SDK and wasmd versions of a chain.
+///
+/// They are negotiated with the node when the first transaction of a [`Wallet`](crate::Wallet) is sent,
+/// or configured per chain with [`CosmosOptions::proto_versions`](crate::CosmosOptions::proto_versions)
+/// when the node doesn't report them.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ProtoVersions {
+    pub cosmos_sdk: ModuleVersion,
+    /// `None` if the chain doesn't run wasmd, or a fork of it under another module path
+    pub wasmd: Option<ModuleVersion>,
+}
+
+impl Default for ProtoVersions {
+    /// Chain of the current generation, whose responses are used as they are
+    fn default() -> Self {
+        Self {
+            cosmos_sdk: SDK_NO_LOGS,
+            wasmd: None,
+        }
+    }
+}
+
+impl ProtoVersions {
+    pub fn new(cosmos_sdk: ModuleVersion, wasmd: Option<ModuleVersion>) -> Self {
+        Self { cosmos_sdk, wasmd }
+    }
+
+    /// Versions reported by the node, defaults to the current generation for what the node doesn't report
+    pub fn from_node_info(node_info: &GetNodeInfoResponse) -> Self {
+        let Some(app_version) = &node_info.application_version else {
+            return Self::default();
+        };
+        Self {
+            cosmos_sdk: ModuleVersion::parse(&app_version.cosmos_sdk_version)
+                .unwrap_or(Self::default().cosmos_sdk),
+            wasmd: app_version
+                .build_deps
+                .iter()
+                .find(|dep| dep.path == WASMD_MODULE_PATH)
+                .and_then(|dep| ModuleVersion::parse(&dep.version)),
+        }
+    }
+
+    /// Whether the `data` of the transactions holds typed message responses
+    pub fn has_msg_responses(&self) -> bool {
+        self.cosmos_sdk >= SDK_MSG_RESPONSES
+    }
+
+    /// Whether the transactions are returned with the logs of their messages
+    pub fn has_msg_logs(&self) -> bool {
+        self.cosmos_sdk < SDK_NO_LOGS
+    }
+
+    /// Whether wasmd reports code ids and contract addresses in the `store_code` and `instantiate` events
+    pub fn has_entry_point_events(&self) -> bool {
+        self.wasmd
+            .map_or(true, |wasmd| wasmd >= WASMD_ENTRY_POINT_EVENTS)
+    }
+
+    /// Rewrites a transaction response of a chain with these versions into the layout of the current generation:
+    /// - the message logs are rebuilt from the `msg_index` attribute of the events when the chain doesn't return them,
+    /// - the `store_code` and `instantiate` events are added from the `message` events of older wasmd versions.
+    pub fn normalize(&self, response: &mut CosmTxResponse) {
+        if !self.has_msg_logs() && response.logs.is_empty() {
+            response.logs = logs_from_events(&response.events);
+        }
+        if !self.has_entry_point_events() {
+            add_entry_point_events(&mut response.events);
+        }
+    }
+}
+
+/// Message logs of a transaction, grouped by the `msg_index` attribute of its events
+fn logs_from_events(events: &[Event]) -> Vec<TxResultBlockMsg> {
+    let mut logs: Vec<TxResultBlockMsg> = vec![];
+    for event in events {
+        let Some(msg_index) = attribute(event, MSG_INDEX_ATTRIBUTE).and_then(|i| i.parse().ok())
+        else {
+            continue;
+        };
+        let block_event = TxResultBlockEvent {
+            s_type: event.r#type.clone(),
+            attributes: event
+                .attributes
+                .iter()
+                .filter(|attr| attr.key != MSG_INDEX_ATTRIBUTE)
+                .map(|attr| TxResultBlockAttribute {
+                    key: String::from_utf8_lossy(&attr.key).to_string(),
+                    value: String::from_utf8_lossy(&attr.value).to_string(),
+                })
+                .collect(),
+        };
+        match logs.iter_mut().find(|log| log.msg_index == Some(msg_index)) {
+            Some(log) => log.events.push(block_event),
+            None => logs.push(TxResultBlockMsg {
+                msg_index: Some(msg_index),
+                events: vec![block_event],
+            }),
+        }
+    }
+    logs
+}
+
+/// Adds the `store_code` and `instantiate` events of the current wasmd versions from the `message` events
+fn add_entry_point_events(events: &mut Vec<Event>) {
+    let mut added = vec![];
+    for event in events.iter().filter(|event| event.r#type == "message") {
+        let contract =
+            attribute(event, "_contract_address").or_else(|| attribute(event, "contract_address"));
+        // Instantiations and migrations also report a code id, and the action isn't always in the same event
+        let instantiation = attribute(event, "action")
+            .map_or(true, |action| action.to_lowercase().contains("instantiate"));
+        match (contract, attribute(event, "code_id")) {
+            (Some(contract), _) if instantiation => {
+                added.push(event_with("instantiate", "_contract_address", contract))
+            }
+            (None, Some(code_id)) => added.push(event_with("store_code", "code_id", code_id)),
+            _ => {}
+        }
+    }
+    events.extend(added);
+}
+
+fn attribute(event: &Event, key: &str) -> Option<String> {
+    event
+        .attributes
+        .iter()
+        .find(|attr| attr.key == key)
+        .map(|attr| String::from_utf8_lossy(&attr.value).to_string())
+}
+
+fn event_with(ty: &str, key: &str, value: String) -> Event {
+    Event {
+        r#type: ty.to_string(),
+        attributes: vec![EventAttribute {
+            key: key.as_bytes().to_vec().into(),
+            value: value.into_bytes().into(),
+            index: false,
+        }],
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use cw_orch_core::environment::IndexResponse;
+
+    fn event(ty: &str, attributes: &[(&str, &str)]) -> Event {
+        Event {
+            r#type: ty.to_string(),
+            attributes: attributes
+                .iter()
+                .map(|(key, value)| EventAttribute {
+                    key: key.as_bytes().to_vec().into(),
+                    value: value.as_bytes().to_vec().into(),
+                    index: false,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn parses_versions() {
+        assert_eq!(
+            ModuleVersion::parse("v0.50.1"),
+            Some(ModuleVersion::new(0, 50))
+        );
+        assert_eq!(
+            ModuleVersion::parse("0.47.5-ics"),
+            Some(ModuleVersion::new(0, 47))
+        );
+        assert_eq!(
+            ModuleVersion::parse("v0.45"),
+            Some(ModuleVersion::new(0, 45))
+        );
+        assert_eq!(ModuleVersion::parse("main"), None);
+    }
+
+    #[test]
+    fn rebuilds_logs_from_events() {
+        let mut response = CosmTxResponse {
+            events: vec![
+                event("tx", &[("fee", "100ujuno")]),
+                event("store_code", &[("code_id", "4"), ("msg_index", "0")]),
+                event(
+                    "instantiate",
+                    &[("_contract_address", "juno1a"), ("msg_index", "1")],
+                ),
+            ],
+            ..Default::default()
+        };
+        ProtoVersions::new(ModuleVersion::new(0, 50), Some(ModuleVersion::new(0, 51)))
+            .normalize(&mut response);
+
+        assert_eq!(response.logs.len(), 2);
+        assert_eq!(
+            response.get_attribute_from_logs("instantiate", "_contract_address"),
+            vec![(1, "juno1a".to_string())]
+        );
+    }
+
+    #[test]
+    fn adds_entry_point_events_for_old_wasmd() {
+        let mut response = CosmTxResponse {
+            events: vec![
+                event("message", &[("module", "wasm"), ("code_id", "7")]),
+                event(
+                    "message",
+                    &[("action", "instantiate"), ("contract_address", "juno1b")],
+                ),
+            ],
+            ..Default::default()
+        };
+        ProtoVersions::new(ModuleVersion::new(0, 44), Some(ModuleVersion::new(0, 20)))
+            .normalize(&mut response);
+
+        assert_eq!(response.uploaded_code_id().unwrap(), 7);
+        assert_eq!(response.instantiated_contract_address().unwrap(), "juno1b");
+    }
+}
@@ -32,7 +32,10 @@ macro_rules! cosmos_query {
         let mut client = QueryClient::new($self.channel.clone());
         #[allow(clippy::redundant_field_names)]
         let request = $request_type { $($field : $value),* };
-        let response = client.$func_name(request.clone()).await?.into_inner();
+        let response = client
+            .$func_name($crate::queriers::request_at_height(request.clone(), $self.height))
+            .await?
+            .into_inner();
         ::log::trace!(
             "cosmos_query: {:?} resulted in: {:?}",
             request,
@@ -43,23 +46,59 @@ macro_rules! cosmos_query {
 };
 }
 
+/// gRPC header used by Cosmos SDK nodes to answer a query with the state at a given block height.
+pub const BLOCK_HEIGHT_HEADER: &str = "x-cosmos-block-height";
+
+/// Wraps a query request into a [`tonic::Request`], pinning it to `height` if provided.
+pub fn request_at_height<T>(request: T, height: Option<u64>) -> tonic::Request<T> {
+    let mut request = tonic::Request::new(request);
+    if let Some(height) = height {
+        request
+            .metadata_mut()
+            .insert(BLOCK_HEIGHT_HEADER, height.into());
+    }
+    request
+}
+
 mod authz;
 mod bank;
 mod cosmwasm;
+mod distribution;
 mod env;
 mod feegrant;
 mod gov;
 mod ibc;
 mod node;
+mod rpc;
 mod staking;
+mod upgrade;
 
 pub use authz::Authz;
-pub use bank::{cosmrs_to_cosmwasm_coins, Bank};
-pub use cosmwasm::{CosmWasm, CosmWasmBase};
+pub use bank::{cosmrs_to_cosmwasm_coins, Bank, ResolvedCoin};
+pub use cosmwasm::{access_allows, CosmWasm, CosmWasmBase};
+pub use distribution::{cosmrs_to_cosmwasm_dec_coins, Distribution};
 pub use feegrant::FeeGrant;
 pub use ibc::Ibc;
 pub use node::Node;
+pub use rpc::{BlockResults, ContractEvent, Rpc};
+pub(crate) use upgrade::resumed_after_upgrade;
+pub use upgrade::Upgrade;
 
 // this two containt structs that are helpers for the queries
 pub use gov::*;
 pub use staking::*;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn block_height_header() {
+        let request = request_at_height((), Some(42));
+        let header = request.metadata().get(BLOCK_HEIGHT_HEADER).unwrap();
+        assert_eq!(header.to_str().unwrap(), "42");
+
+        let request = request_at_height((), None);
+        assert!(request.metadata().get(BLOCK_HEIGHT_HEADER).is_none());
+    }
+}
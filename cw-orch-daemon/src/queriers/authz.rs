@@ -1,4 +1,5 @@
-use crate::{cosmos_modules, error::DaemonError, Daemon};
+use super::request_at_height;
+use crate::{cosmos_modules, error::DaemonError, senders::query::QuerySender, DaemonBase};
 use cosmrs::proto::cosmos::base::query::v1beta1::PageRequest;
 use cw_orch_core::environment::{Querier, QuerierGetter};
 use tokio::runtime::Handle;
@@ -9,13 +10,15 @@ use tonic::transport::Channel;
 pub struct Authz {
     pub channel: Channel,
     pub rt_handle: Option<Handle>,
+    pub height: Option<u64>,
 }
 
 impl Authz {
-    pub fn new(daemon: &Daemon) -> Self {
+    pub fn new<Sender: QuerySender>(daemon: &DaemonBase<Sender>) -> Self {
         Self {
             channel: daemon.channel(),
             rt_handle: Some(daemon.rt_handle.clone()),
+            height: daemon.query_height(),
         }
     }
 
@@ -23,6 +26,7 @@ impl Authz {
         Self {
             channel,
             rt_handle: None,
+            height: None,
         }
     }
 }
@@ -31,7 +35,7 @@ impl Querier for Authz {
     type Error = DaemonError;
 }
 
-impl QuerierGetter<Authz> for Daemon {
+impl<Sender: QuerySender> QuerierGetter<Authz> for DaemonBase<Sender> {
     fn querier(&self) -> Authz {
         Authz::new(self)
     }
@@ -49,12 +53,15 @@ impl Authz {
         use cosmos_modules::authz::{query_client::QueryClient, QueryGrantsRequest};
         let mut client: QueryClient<Channel> = QueryClient::new(self.channel.clone());
         let grants = client
-            .grants(QueryGrantsRequest {
-                granter,
-                grantee,
-                msg_type_url,
-                pagination,
-            })
+            .grants(request_at_height(
+                QueryGrantsRequest {
+                    granter,
+                    grantee,
+                    msg_type_url,
+                    pagination,
+                },
+                self.height,
+            ))
             .await?
             .into_inner();
         Ok(grants)
@@ -70,10 +77,13 @@ impl Authz {
         use cosmos_modules::authz::{query_client::QueryClient, QueryGranteeGrantsRequest};
         let mut client: QueryClient<Channel> = QueryClient::new(self.channel.clone());
         let grants = client
-            .grantee_grants(QueryGranteeGrantsRequest {
-                grantee,
-                pagination,
-            })
+            .grantee_grants(request_at_height(
+                QueryGranteeGrantsRequest {
+                    grantee,
+                    pagination,
+                },
+                self.height,
+            ))
             .await?
             .into_inner();
         Ok(grants)
@@ -89,10 +99,13 @@ impl Authz {
         use cosmos_modules::authz::{query_client::QueryClient, QueryGranterGrantsRequest};
         let mut client: QueryClient<Channel> = QueryClient::new(self.channel.clone());
         let grants = client
-            .granter_grants(QueryGranterGrantsRequest {
-                granter,
-                pagination,
-            })
+            .granter_grants(request_at_height(
+                QueryGranterGrantsRequest {
+                    granter,
+                    pagination,
+                },
+                self.height,
+            ))
             .await?
             .into_inner();
         Ok(grants)
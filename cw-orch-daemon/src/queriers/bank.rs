@@ -1,6 +1,9 @@
+use super::{request_at_height, Ibc};
 use crate::{cosmos_modules, error::DaemonError, senders::query::QuerySender, DaemonBase};
-use cosmrs::proto::cosmos::base::query::v1beta1::PageRequest;
-use cosmwasm_std::{Coin, StdError};
+use cosmrs::proto::{
+    cosmos::base::query::v1beta1::PageRequest, ibc::applications::transfer::v1::DenomTrace,
+};
+use cosmwasm_std::{Coin, DenomMetadata, DenomUnit, StdError};
 use cw_orch_core::environment::{BankQuerier, Querier, QuerierGetter};
 use tokio::runtime::Handle;
 use tonic::transport::Channel;
@@ -10,6 +13,7 @@ use tonic::transport::Channel;
 pub struct Bank {
     pub channel: Channel,
     pub rt_handle: Option<Handle>,
+    pub height: Option<u64>,
 }
 
 impl Bank {
@@ -17,12 +21,14 @@ impl Bank {
         Self {
             channel: daemon.channel(),
             rt_handle: Some(daemon.rt_handle.clone()),
+            height: daemon.query_height(),
         }
     }
     pub fn new_async(channel: Channel) -> Self {
         Self {
             channel,
             rt_handle: None,
+            height: None,
         }
     }
 }
@@ -53,7 +59,10 @@ impl Bank {
                     address: address.into(),
                     denom,
                 };
-                let resp = client.balance(request).await?.into_inner();
+                let resp = client
+                    .balance(request_at_height(request, self.height))
+                    .await?
+                    .into_inner();
                 let coin = resp.balance.unwrap();
                 Ok(vec![cosmrs_to_cosmwasm_coin(coin)?])
             }
@@ -63,12 +72,33 @@ impl Bank {
                     address: address.into(),
                     ..Default::default()
                 };
-                let resp = client.all_balances(request).await?.into_inner();
+                let resp = client
+                    .all_balances(request_at_height(request, self.height))
+                    .await?
+                    .into_inner();
                 Ok(cosmrs_to_cosmwasm_coins(resp.balances)?)
             }
         }
     }
 
+    /// Query all the balances of an address, resolving the traces of the IBC denoms
+    pub async fn _resolved_balances(
+        &self,
+        address: impl Into<String>,
+    ) -> Result<Vec<ResolvedCoin>, DaemonError> {
+        let ibc = Ibc {
+            channel: self.channel.clone(),
+            rt_handle: None,
+            height: self.height,
+        };
+        let mut resolved = vec![];
+        for coin in self._balance(address, None).await? {
+            let trace = ibc._resolve_denom(&coin.denom).await?;
+            resolved.push(ResolvedCoin { coin, trace });
+        }
+        Ok(resolved)
+    }
+
     /// Query spendable balance for address
     pub async fn _spendable_balances(
         &self,
@@ -86,15 +116,80 @@ impl Bank {
         Ok(cosmrs_to_cosmwasm_coins(spendable_balances.balances)?)
     }
 
-    /// Query total supply in the bank
-    pub async fn _total_supply(&self) -> Result<Vec<Coin>, DaemonError> {
-        let total_supply: cosmos_modules::bank::QueryTotalSupplyResponse = cosmos_query!(
+    /// Query the spendable balance of `denom` for address, excluding the coins locked by vesting schedules
+    ///
+    /// Nodes older than Cosmos SDK 0.47 don't support the query by denom,
+    /// the spendable balances are then searched page by page.
+    pub async fn _spendable_balance(
+        &self,
+        address: impl Into<String>,
+        denom: impl Into<String>,
+    ) -> Result<Coin, DaemonError> {
+        use cosmos_modules::bank::query_client::QueryClient;
+        let address = address.into();
+        let denom = denom.into();
+
+        let mut client: QueryClient<Channel> = QueryClient::new(self.channel.clone());
+        let request = cosmos_modules::bank::QuerySpendableBalanceByDenomRequest {
+            address: address.clone(),
+            denom: denom.clone(),
+        };
+        let by_denom = match client
+            .spendable_balance_by_denom(request_at_height(request, self.height))
+            .await
+        {
+            Ok(resp) => resp
+                .into_inner()
+                .balance
+                .map(cosmrs_to_cosmwasm_coin)
+                .transpose()
+                .map_err(Into::into),
+            Err(status) => Err(status.into()),
+        };
+        spendable_balance_or_paginate(&denom, by_denom, |key| {
+            self._spendable_balances_page(&address, key)
+        })
+        .await
+    }
+
+    /// Query a page of the spendable balances for address, returns the balances and the key of the next page
+    async fn _spendable_balances_page(
+        &self,
+        address: &str,
+        key: Vec<u8>,
+    ) -> Result<(Vec<Coin>, Vec<u8>), DaemonError> {
+        let page: cosmos_modules::bank::QuerySpendableBalancesResponse = cosmos_query!(
             self,
             bank,
-            total_supply,
-            QueryTotalSupplyRequest { pagination: None }
+            spendable_balances,
+            QuerySpendableBalancesRequest {
+                address: address.to_string(),
+                pagination: Some(page_request(key)),
+            }
         );
-        Ok(cosmrs_to_cosmwasm_coins(total_supply.supply)?)
+        let next_key = page.pagination.map(|p| p.next_key).unwrap_or_default();
+        Ok((cosmrs_to_cosmwasm_coins(page.balances)?, next_key))
+    }
+
+    /// Query total supply in the bank, across all the pages
+    pub async fn _total_supply(&self) -> Result<Vec<Coin>, DaemonError> {
+        let mut supply = vec![];
+        let mut key = vec![];
+        loop {
+            let page: cosmos_modules::bank::QueryTotalSupplyResponse = cosmos_query!(
+                self,
+                bank,
+                total_supply,
+                QueryTotalSupplyRequest {
+                    pagination: Some(page_request(key))
+                }
+            );
+            supply.extend(cosmrs_to_cosmwasm_coins(page.supply)?);
+            match page.pagination.filter(|p| !p.next_key.is_empty()) {
+                Some(pagination) => key = pagination.next_key,
+                None => return Ok(supply),
+            }
+        }
     }
 
     /// Query total supply in the bank for a denom
@@ -150,6 +245,93 @@ impl Bank {
         );
         Ok(denoms_metadata.metadatas)
     }
+
+    /// Query the metadata of all the denoms, across all the pages
+    pub async fn _all_denoms_metadata(
+        &self,
+    ) -> Result<Vec<cosmos_modules::bank::Metadata>, DaemonError> {
+        let mut metadata = vec![];
+        let mut key = vec![];
+        loop {
+            let page: cosmos_modules::bank::QueryDenomsMetadataResponse = cosmos_query!(
+                self,
+                bank,
+                denoms_metadata,
+                QueryDenomsMetadataRequest {
+                    pagination: Some(page_request(key))
+                }
+            );
+            metadata.extend(page.metadatas);
+            match page.pagination.filter(|p| !p.next_key.is_empty()) {
+                Some(pagination) => key = pagination.next_key,
+                None => return Ok(metadata),
+            }
+        }
+    }
+}
+
+/// Spendable balance of `denom` from the answer of the query by denom,
+/// or from the pages of all the spendable balances when the node doesn't implement that query
+async fn spendable_balance_or_paginate<Page, PageFut>(
+    denom: &str,
+    by_denom: Result<Option<Coin>, DaemonError>,
+    mut page: Page,
+) -> Result<Coin, DaemonError>
+where
+    Page: FnMut(Vec<u8>) -> PageFut,
+    PageFut: std::future::Future<Output = Result<(Vec<Coin>, Vec<u8>), DaemonError>>,
+{
+    match by_denom {
+        Ok(balance) => return Ok(balance.unwrap_or_else(|| Coin::new(0u128, denom))),
+        Err(DaemonError::Status(status)) if status.code() == tonic::Code::Unimplemented => {
+            log::debug!(
+                "Spendable balance by denom not supported, searching all the spendable balances"
+            );
+        }
+        Err(e) => return Err(e),
+    }
+    let mut key = vec![];
+    loop {
+        let (balances, next_key) = page(key).await?;
+        if let Some(balance) = balances.into_iter().find(|c| c.denom == denom) {
+            return Ok(balance);
+        }
+        if next_key.is_empty() {
+            return Ok(Coin::new(0u128, denom));
+        }
+        key = next_key;
+    }
+}
+
+fn page_request(key: Vec<u8>) -> PageRequest {
+    PageRequest {
+        key,
+        offset: 0,
+        limit: 100,
+        count_total: false,
+        reverse: false,
+    }
+}
+
+/// Balance with the trace of its denom, displayed with the base denom and path of IBC tokens
+#[derive(Clone, Debug, PartialEq)]
+pub struct ResolvedCoin {
+    pub coin: Coin,
+    pub trace: DenomTrace,
+}
+
+impl std::fmt::Display for ResolvedCoin {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.trace.path.is_empty() {
+            write!(f, "{}", self.coin)
+        } else {
+            write!(
+                f,
+                "{}{} (via {}, {})",
+                self.coin.amount, self.trace.base_denom, self.trace.path, self.coin.denom
+            )
+        }
+    }
 }
 
 pub fn cosmrs_to_cosmwasm_coin(
@@ -161,11 +343,46 @@ pub fn cosmrs_to_cosmwasm_coin(
     })
 }
 
+pub fn cosmrs_to_cosmwasm_metadata(metadata: cosmos_modules::bank::Metadata) -> DenomMetadata {
+    DenomMetadata {
+        description: metadata.description,
+        denom_units: metadata
+            .denom_units
+            .into_iter()
+            .map(|unit| DenomUnit {
+                denom: unit.denom,
+                exponent: unit.exponent,
+                aliases: unit.aliases,
+            })
+            .collect(),
+        base: metadata.base,
+        display: metadata.display,
+        name: metadata.name,
+        symbol: metadata.symbol,
+        uri: metadata.uri,
+        uri_hash: metadata.uri_hash,
+    }
+}
+
 pub fn cosmrs_to_cosmwasm_coins(
     c: Vec<cosmrs::proto::cosmos::base::v1beta1::Coin>,
 ) -> Result<Vec<Coin>, StdError> {
     c.into_iter().map(cosmrs_to_cosmwasm_coin).collect()
 }
+impl Bank {
+    /// Sync version of [`Self::_spendable_balance`]
+    pub fn spendable_balance(
+        &self,
+        address: impl Into<String>,
+        denom: impl Into<String>,
+    ) -> Result<Coin, DaemonError> {
+        self.rt_handle
+            .as_ref()
+            .ok_or(DaemonError::QuerierNeedRuntime)?
+            .block_on(self._spendable_balance(address, denom))
+    }
+}
+
 impl BankQuerier for Bank {
     fn balance(
         &self,
@@ -191,4 +408,106 @@ impl BankQuerier for Bank {
             .ok_or(DaemonError::QuerierNeedRuntime)?
             .block_on(self._supply_of(denom))
     }
+
+    fn denom_metadata(&self, denom: impl Into<String>) -> Result<DenomMetadata, Self::Error> {
+        let metadata = self
+            .rt_handle
+            .as_ref()
+            .ok_or(DaemonError::QuerierNeedRuntime)?
+            .block_on(self._denom_metadata(denom))?;
+        Ok(cosmrs_to_cosmwasm_metadata(metadata))
+    }
+
+    fn denoms_metadata(&self) -> Result<Vec<DenomMetadata>, Self::Error> {
+        let metadata = self
+            .rt_handle
+            .as_ref()
+            .ok_or(DaemonError::QuerierNeedRuntime)?
+            .block_on(self._all_denoms_metadata())?;
+        Ok(metadata
+            .into_iter()
+            .map(cosmrs_to_cosmwasm_metadata)
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn pages() -> Vec<(Vec<Coin>, Vec<u8>)> {
+        vec![
+            (
+                vec![
+                    Coin::new(5u128, "ibc/ABC"),
+                    Coin::new(7u128, "factory/dust"),
+                ],
+                vec![1],
+            ),
+            (vec![Coin::new(42u128, "ujuno")], vec![]),
+        ]
+    }
+
+    fn paginate(
+        pages: Vec<(Vec<Coin>, Vec<u8>)>,
+    ) -> impl FnMut(Vec<u8>) -> std::future::Ready<Result<(Vec<Coin>, Vec<u8>), DaemonError>> {
+        move |key| {
+            let index = key.first().copied().unwrap_or_default() as usize;
+            std::future::ready(Ok(pages[index].clone()))
+        }
+    }
+
+    fn unimplemented() -> Result<Option<Coin>, DaemonError> {
+        Err(tonic::Status::unimplemented("unknown method SpendableBalanceByDenom").into())
+    }
+
+    #[tokio::test]
+    async fn spendable_balance_by_denom() {
+        // No page is queried when the node answers the query by denom
+        let no_pages = paginate(vec![]);
+        let balance =
+            spendable_balance_or_paginate("ujuno", Ok(Some(Coin::new(3u128, "ujuno"))), no_pages)
+                .await
+                .unwrap();
+        assert_eq!(balance, Coin::new(3u128, "ujuno"));
+
+        let balance = spendable_balance_or_paginate("ujuno", Ok(None), paginate(vec![]))
+            .await
+            .unwrap();
+        assert_eq!(balance, Coin::new(0u128, "ujuno"));
+    }
+
+    #[tokio::test]
+    async fn spendable_balance_fallback_searches_all_pages() {
+        let balance = spendable_balance_or_paginate("ujuno", unimplemented(), paginate(pages()))
+            .await
+            .unwrap();
+        assert_eq!(balance, Coin::new(42u128, "ujuno"));
+
+        let balance = spendable_balance_or_paginate("uatom", unimplemented(), paginate(pages()))
+            .await
+            .unwrap();
+        assert_eq!(balance, Coin::new(0u128, "uatom"));
+    }
+
+    #[tokio::test]
+    async fn spendable_balance_other_errors_are_returned() {
+        let by_denom = Err(tonic::Status::unavailable("node is down").into());
+        let err = spendable_balance_or_paginate("ujuno", by_denom, paginate(pages()))
+            .await
+            .unwrap_err();
+        assert!(
+            matches!(err, DaemonError::Status(status) if status.code() == tonic::Code::Unavailable)
+        );
+    }
+
+    #[tokio::test]
+    async fn sync_spendable_balance_needs_a_runtime() {
+        let channel =
+            tonic::transport::Channel::from_static("http://localhost:9090").connect_lazy();
+        assert!(matches!(
+            Bank::new_async(channel).spendable_balance("juno1address", "ujuno"),
+            Err(DaemonError::QuerierNeedRuntime)
+        ));
+    }
 }
@@ -1,20 +1,29 @@
-use std::{marker::PhantomData, str::FromStr};
+use std::{marker::PhantomData, str::FromStr, sync::Arc};
 
+use super::request_at_height;
 use crate::senders::query::QuerySender;
 use crate::senders::QueryOnlySender;
-use crate::{cosmos_modules, error::DaemonError, DaemonBase};
+use crate::{
+    cosmos_modules,
+    env::{default_state_folder, DaemonEnvVars},
+    error::DaemonError,
+    query_cache::QueryCache,
+    DaemonBase,
+};
 use cosmrs::proto::cosmos::base::query::v1beta1::PageRequest;
 use cosmrs::AccountId;
 use cosmwasm_std::{
-    from_json, instantiate2_address, to_json_binary, CanonicalAddr, CodeInfoResponse,
+    from_json, instantiate2_address, to_json_binary, to_json_vec, CanonicalAddr, CodeInfoResponse,
     ContractInfoResponse, HexBinary,
 };
 use cw_orch_core::environment::Environment;
 use cw_orch_core::{
-    contract::interface_traits::Uploadable,
+    contract::{interface_traits::Uploadable, WasmPath},
     environment::{Querier, QuerierGetter, WasmQuerier},
+    log::query_target,
 };
-use tokio::runtime::Handle;
+use sha2::{Digest, Sha256};
+use tokio::{runtime::Handle, sync::Semaphore, task::JoinSet};
 use tonic::transport::Channel;
 
 /// Querier for the CosmWasm SDK module
@@ -22,16 +31,24 @@ use tonic::transport::Channel;
 pub struct CosmWasmBase<Sender = QueryOnlySender> {
     pub channel: Channel,
     pub rt_handle: Option<Handle>,
+    pub height: Option<u64>,
+    /// Cache of the smart queries, see [`QueryCache`]
+    pub cache: Option<QueryCache>,
     _sender: PhantomData<Sender>,
 }
 
 pub type CosmWasm = CosmWasmBase<QueryOnlySender>;
 
+/// Directory of the downloaded codes, relative to the cw-orchestrator state folder
+const CODE_CACHE_DIR: &str = "code";
+
 impl<Sender: QuerySender> CosmWasmBase<Sender> {
     pub fn new(daemon: &DaemonBase<Sender>) -> Self {
         Self {
             channel: daemon.channel(),
             rt_handle: Some(daemon.rt_handle.clone()),
+            height: daemon.query_height(),
+            cache: daemon.query_cache().cloned(),
             _sender: PhantomData,
         }
     }
@@ -39,6 +56,8 @@ impl<Sender: QuerySender> CosmWasmBase<Sender> {
         Self {
             channel,
             rt_handle: None,
+            height: None,
+            cache: None,
             _sender: PhantomData,
         }
     }
@@ -46,6 +65,8 @@ impl<Sender: QuerySender> CosmWasmBase<Sender> {
         Self {
             channel,
             rt_handle: Some(handle.clone()),
+            height: None,
+            cache: None,
             _sender: PhantomData,
         }
     }
@@ -67,7 +88,10 @@ impl<Sender: QuerySender> CosmWasmBase<Sender> {
         use cosmos_modules::cosmwasm::{query_client::*, QueryCodeRequest};
         let mut client: QueryClient<Channel> = QueryClient::new(self.channel.clone());
         let request = QueryCodeRequest { code_id };
-        let resp = client.code(request).await?.into_inner();
+        let resp = client
+            .code(request_at_height(request, self.height))
+            .await?
+            .into_inner();
         let contract_hash = resp.code_info.unwrap().data_hash;
         Ok(contract_hash.into())
     }
@@ -82,7 +106,10 @@ impl<Sender: QuerySender> CosmWasmBase<Sender> {
         let request = QueryContractInfoRequest {
             address: address.into(),
         };
-        let resp = client.contract_info(request).await?.into_inner();
+        let resp = client
+            .contract_info(request_at_height(request, self.height))
+            .await?
+            .into_inner();
         let contract_info = resp.contract_info.unwrap();
 
         let mut c = ContractInfoResponse::default();
@@ -113,26 +140,104 @@ impl<Sender: QuerySender> CosmWasmBase<Sender> {
             address: address.into(),
             pagination,
         };
-        Ok(client.contract_history(request).await?.into_inner())
+        Ok(client
+            .contract_history(request_at_height(request, self.height))
+            .await?
+            .into_inner())
     }
 
-    /// Query contract state
+    /// Query contract state, through the query cache if one is set
     pub async fn _contract_state(
         &self,
         address: impl Into<String>,
         query_data: Vec<u8>,
     ) -> Result<Vec<u8>, DaemonError> {
         use cosmos_modules::cosmwasm::{query_client::*, QuerySmartContractStateRequest};
-        let mut client: QueryClient<Channel> = QueryClient::new(self.channel.clone());
-        let request = QuerySmartContractStateRequest {
-            address: address.into(),
-            query_data,
+        let address = address.into();
+        let fetch = async {
+            let mut client: QueryClient<Channel> = QueryClient::new(self.channel.clone());
+            let request = QuerySmartContractStateRequest {
+                address: address.clone(),
+                query_data: query_data.clone(),
+            };
+            Ok(client
+                .smart_contract_state(request_at_height(request, self.height))
+                .await?
+                .into_inner()
+                .data)
         };
-        Ok(client
-            .smart_contract_state(request)
-            .await?
-            .into_inner()
-            .data)
+        match &self.cache {
+            Some(cache) => {
+                cache
+                    .get_or_fetch(&address, &query_data, self.height, fetch)
+                    .await
+            }
+            None => fetch.await,
+        }
+    }
+
+    /// Smart queries of many contracts, sent concurrently with [`CosmWasmBase::_contract_states`].
+    /// The result of each query is returned in the order of `queries`.
+    pub async fn _query_many<Q: serde::Serialize, T: serde::de::DeserializeOwned>(
+        &self,
+        queries: Vec<(impl Into<String>, Q)>,
+    ) -> Vec<Result<T, DaemonError>> {
+        let requests: Vec<Result<(String, Vec<u8>), DaemonError>> = queries
+            .into_iter()
+            .map(|(address, query_msg)| Ok((address.into(), to_json_vec(&query_msg)?)))
+            .collect();
+        let valid = requests
+            .iter()
+            .filter_map(|request| request.as_ref().ok().cloned())
+            .collect();
+        let mut responses = self._contract_states(valid).await.into_iter();
+
+        requests
+            .into_iter()
+            .map(|request| {
+                request?;
+                let response = responses.next().expect("one response per valid query")?;
+                Ok(from_json(response)?)
+            })
+            .collect()
+    }
+
+    /// Query the state of many contracts concurrently, at most [`DaemonEnvVars::max_concurrent_queries`] at a time.
+    /// The responses are returned in the order of `queries`.
+    pub async fn _contract_states(
+        &self,
+        queries: Vec<(String, Vec<u8>)>,
+    ) -> Vec<Result<Vec<u8>, DaemonError>> {
+        let permits = Arc::new(Semaphore::new(
+            DaemonEnvVars::max_concurrent_queries().max(1),
+        ));
+        let mut tasks = JoinSet::new();
+        let mut responses: Vec<Option<Result<Vec<u8>, DaemonError>>> =
+            queries.iter().map(|_| None).collect();
+        for (index, (address, query_data)) in queries.into_iter().enumerate() {
+            let querier = CosmWasm {
+                channel: self.channel.clone(),
+                rt_handle: None,
+                height: self.height,
+                cache: self.cache.clone(),
+                _sender: PhantomData,
+            };
+            let permits = permits.clone();
+            tasks.spawn(async move {
+                let _permit = permits.acquire_owned().await;
+                (index, querier._contract_state(address, query_data).await)
+            });
+        }
+        while let Some(joined) = tasks.join_next().await {
+            match joined {
+                Ok((index, response)) => responses[index] = Some(response),
+                Err(e) => std::panic::resume_unwind(e.into_panic()),
+            }
+        }
+        responses
+            .into_iter()
+            .map(|response| response.expect("every query task returns"))
+            .collect()
     }
 
     /// Query all contract state
@@ -147,7 +252,10 @@ impl<Sender: QuerySender> CosmWasmBase<Sender> {
             address: address.into(),
             pagination,
         };
-        Ok(client.all_contract_state(request).await?.into_inner())
+        Ok(client
+            .all_contract_state(request_at_height(request, self.height))
+            .await?
+            .into_inner())
     }
 
     /// Query code
@@ -155,7 +263,12 @@ impl<Sender: QuerySender> CosmWasmBase<Sender> {
         use cosmos_modules::cosmwasm::{query_client::*, QueryCodeRequest};
         let mut client: QueryClient<Channel> = QueryClient::new(self.channel.clone());
         let request = QueryCodeRequest { code_id };
-        let response = client.code(request).await?.into_inner().code_info.unwrap();
+        let response = client
+            .code(request_at_height(request, self.height))
+            .await?
+            .into_inner()
+            .code_info
+            .unwrap();
 
         Ok(cosmrs_to_cosmwasm_code_info(response))
     }
@@ -165,7 +278,11 @@ impl<Sender: QuerySender> CosmWasmBase<Sender> {
         use cosmos_modules::cosmwasm::{query_client::*, QueryCodeRequest};
         let mut client: QueryClient<Channel> = QueryClient::new(self.channel.clone());
         let request = QueryCodeRequest { code_id };
-        Ok(client.code(request).await?.into_inner().data)
+        Ok(client
+            .code(request_at_height(request, self.height))
+            .await?
+            .into_inner()
+            .data)
     }
 
     /// Query codes
@@ -176,7 +293,11 @@ impl<Sender: QuerySender> CosmWasmBase<Sender> {
         use cosmos_modules::cosmwasm::{query_client::*, QueryCodesRequest};
         let mut client: QueryClient<Channel> = QueryClient::new(self.channel.clone());
         let request = QueryCodesRequest { pagination };
-        let response = client.codes(request).await?.into_inner().code_infos;
+        let response = client
+            .codes(request_at_height(request, self.height))
+            .await?
+            .into_inner()
+            .code_infos;
 
         Ok(response
             .into_iter()
@@ -191,7 +312,10 @@ impl<Sender: QuerySender> CosmWasmBase<Sender> {
         use cosmos_modules::cosmwasm::{query_client::*, QueryPinnedCodesRequest};
         let mut client: QueryClient<Channel> = QueryClient::new(self.channel.clone());
         let request = QueryPinnedCodesRequest { pagination: None };
-        Ok(client.pinned_codes(request).await?.into_inner())
+        Ok(client
+            .pinned_codes(request_at_height(request, self.height))
+            .await?
+            .into_inner())
     }
 
     /// Query contracts by code
@@ -205,7 +329,10 @@ impl<Sender: QuerySender> CosmWasmBase<Sender> {
             code_id,
             pagination: None,
         };
-        Ok(client.contracts_by_code(request).await?.into_inner())
+        Ok(client
+            .contracts_by_code(request_at_height(request, self.height))
+            .await?
+            .into_inner())
     }
 
     /// Query raw contract state
@@ -220,7 +347,10 @@ impl<Sender: QuerySender> CosmWasmBase<Sender> {
             address: address.into(),
             query_data,
         };
-        Ok(client.raw_contract_state(request).await?.into_inner())
+        Ok(client
+            .raw_contract_state(request_at_height(request, self.height))
+            .await?
+            .into_inner())
     }
 
     /// Query params
@@ -229,7 +359,129 @@ impl<Sender: QuerySender> CosmWasmBase<Sender> {
     ) -> Result<cosmos_modules::cosmwasm::QueryParamsResponse, DaemonError> {
         use cosmos_modules::cosmwasm::{query_client::*, QueryParamsRequest};
         let mut client: QueryClient<Channel> = QueryClient::new(self.channel.clone());
-        Ok(client.params(QueryParamsRequest {}).await?.into_inner())
+        Ok(client
+            .params(request_at_height(QueryParamsRequest {}, self.height))
+            .await?
+            .into_inner())
+    }
+
+    /// Query who is allowed to instantiate a code
+    pub async fn _code_access_config(
+        &self,
+        code_id: u64,
+    ) -> Result<cosmos_modules::cosmwasm::AccessConfig, DaemonError> {
+        use cosmos_modules::cosmwasm::{query_client::*, QueryCodeRequest};
+        let mut client: QueryClient<Channel> = QueryClient::new(self.channel.clone());
+        let request = QueryCodeRequest { code_id };
+        let code_info = client
+            .code(request_at_height(request, self.height))
+            .await?
+            .into_inner()
+            .code_info
+            .ok_or_else(|| DaemonError::StdErr(format!("code {code_id} not found")))?;
+        Ok(code_info.instantiate_permission.unwrap_or_default())
+    }
+
+    /// Whether `address` is allowed to upload code according to the chain wasm params.
+    /// Uploads from other addresses are rejected and need to go through a governance proposal.
+    pub async fn _can_upload(&self, address: impl AsRef<str>) -> Result<bool, DaemonError> {
+        let params = self._params().await?.params.unwrap_or_default();
+        Ok(access_allows(
+            &params.code_upload_access.unwrap_or_default(),
+            address.as_ref(),
+        ))
+    }
+
+    /// Whether `address` is allowed to instantiate the code `code_id`
+    pub async fn _can_instantiate(
+        &self,
+        code_id: u64,
+        address: impl AsRef<str>,
+    ) -> Result<bool, DaemonError> {
+        let access_config = self._code_access_config(code_id).await?;
+        Ok(access_allows(&access_config, address.as_ref()))
+    }
+
+    /// Download the code `code_id` stored on chain, for instance to upload third-party code to a local chain.
+    /// The code is verified against its on-chain checksum and cached by checksum in `~/.cw-orchestrator/code`,
+    /// only the code info is queried when the code is already cached.
+    pub async fn _download_code(&self, code_id: u64) -> Result<WasmPath, DaemonError> {
+        let checksum = self._code_checksum(code_id).await?;
+        let cache_dir = default_state_folder()?.join(CODE_CACHE_DIR);
+        let path = cached_code(&cache_dir, code_id, &checksum, self._code_data(code_id)).await?;
+        Ok(WasmPath::new(path)?)
+    }
+
+    /// Checksum of the code `code_id`, queried from the list of code infos which doesn't contain the code bytes
+    async fn _code_checksum(&self, code_id: u64) -> Result<HexBinary, DaemonError> {
+        // The codes are stored under their big-endian id, the page starts at the code `code_id`
+        let page = PageRequest {
+            key: code_id.to_be_bytes().to_vec(),
+            offset: 0,
+            limit: 1,
+            count_total: false,
+            reverse: false,
+        };
+        match self._codes(Some(page)).await?.into_iter().next() {
+            Some(code_info) if code_info.code_id == code_id => Ok(code_info.checksum),
+            _ => Ok(self._code(code_id).await?.checksum),
+        }
+    }
+
+    /// Sync version of [`Self::_download_code`]
+    pub fn download_code(&self, code_id: u64) -> Result<WasmPath, DaemonError> {
+        self.rt_handle
+            .as_ref()
+            .ok_or(DaemonError::QuerierNeedRuntime)?
+            .block_on(self._download_code(code_id))
+    }
+}
+
+/// Path of the code with `checksum` in `cache_dir`, the code is only downloaded with `download` when it isn't cached yet.
+/// The downloaded code is verified against `checksum` before being cached.
+async fn cached_code(
+    cache_dir: &std::path::Path,
+    code_id: u64,
+    checksum: &HexBinary,
+    download: impl std::future::Future<Output = Result<Vec<u8>, DaemonError>>,
+) -> Result<std::path::PathBuf, DaemonError> {
+    let path = cache_dir.join(format!("{}.wasm", checksum.to_hex()));
+    if path.exists() {
+        log::debug!(target: &query_target(), "Code {code_id} found in the cache at {path:?}");
+        return Ok(path);
+    }
+
+    let data = download.await?;
+    let actual = HexBinary::from(Sha256::digest(&data).as_slice());
+    if checksum != &actual {
+        return Err(DaemonError::CodeHashMismatch {
+            code_id,
+            expected: checksum.to_hex(),
+            actual: actual.to_hex(),
+        });
+    }
+    std::fs::create_dir_all(cache_dir)?;
+    // Write to a temporary file first so an interrupted download doesn't leave a corrupted cache entry
+    let tmp_path = path.with_extension("wasm.tmp");
+    std::fs::write(&tmp_path, &data)?;
+    std::fs::rename(&tmp_path, &path)?;
+    log::debug!(target: &query_target(), "Code {code_id} cached at {path:?}");
+    Ok(path)
+}
+
+/// Whether `address` is allowed by a wasm module access config.
+/// The deprecated `OnlyAddress` access type is still returned by chains running older wasmd versions.
+#[allow(deprecated)]
+pub fn access_allows(config: &cosmos_modules::cosmwasm::AccessConfig, address: &str) -> bool {
+    use cosmos_modules::cosmwasm::AccessType;
+    if config.permission == AccessType::Everybody as i32 {
+        true
+    } else if config.permission == AccessType::AnyOfAddresses as i32 {
+        config.addresses.iter().any(|allowed| allowed == address)
+    } else if config.permission == AccessType::OnlyAddress as i32 {
+        config.address == address
+    } else {
+        false
     }
 }
 
@@ -280,6 +532,19 @@ impl<Sender: QuerySender> WasmQuerier for CosmWasmBase<Sender> {
         Ok(from_json(response)?)
     }
 
+    fn query_many<Q: serde::Serialize, T: serde::de::DeserializeOwned>(
+        &self,
+        queries: Vec<(impl Into<String>, Q)>,
+    ) -> Vec<Result<T, Self::Error>> {
+        let Some(rt_handle) = self.rt_handle.as_ref() else {
+            return queries
+                .iter()
+                .map(|_| Err(DaemonError::QuerierNeedRuntime))
+                .collect();
+        };
+        rt_handle.block_on(self._query_many(queries))
+    }
+
     fn code(&self, code_id: u64) -> Result<cosmwasm_std::CodeInfoResponse, Self::Error> {
         self.rt_handle
             .as_ref()
@@ -323,3 +588,96 @@ pub fn cosmrs_to_cosmwasm_code_info(
     c.checksum = code_info.data_hash.into();
     c
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    #[allow(deprecated)]
+    fn access_config_permissions() {
+        use cosmos_modules::cosmwasm::{AccessConfig, AccessType};
+
+        let config = |permission: AccessType, address: &str, addresses: &[&str]| AccessConfig {
+            permission: permission as i32,
+            address: address.to_string(),
+            addresses: addresses.iter().map(ToString::to_string).collect(),
+        };
+        assert!(access_allows(
+            &config(AccessType::Everybody, "", &[]),
+            "juno1a"
+        ));
+        assert!(!access_allows(
+            &config(AccessType::Nobody, "", &[]),
+            "juno1a"
+        ));
+        assert!(access_allows(
+            &config(AccessType::AnyOfAddresses, "", &["juno1b", "juno1a"]),
+            "juno1a"
+        ));
+        assert!(!access_allows(
+            &config(AccessType::AnyOfAddresses, "", &["juno1b"]),
+            "juno1a"
+        ));
+        assert!(access_allows(
+            &config(AccessType::OnlyAddress, "juno1a", &[]),
+            "juno1a"
+        ));
+        assert!(!access_allows(
+            &config(AccessType::OnlyAddress, "juno1b", &[]),
+            "juno1a"
+        ));
+        assert!(!access_allows(&AccessConfig::default(), "juno1a"));
+    }
+
+    fn cache_dir() -> std::path::PathBuf {
+        let dir = crate::gen_temp_file_path();
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn checksum(data: &[u8]) -> HexBinary {
+        HexBinary::from(Sha256::digest(data).as_slice())
+    }
+
+    #[tokio::test]
+    async fn download_code_cache() {
+        let dir = cache_dir();
+        let code = b"\0asm code".to_vec();
+
+        let path = cached_code(&dir, 1, &checksum(&code), async { Ok(code.clone()) })
+            .await
+            .unwrap();
+        assert_eq!(path, dir.join(format!("{}.wasm", checksum(&code).to_hex())));
+        assert_eq!(std::fs::read(&path).unwrap(), code);
+
+        // The cached code is not downloaded again
+        let cached = cached_code(&dir, 1, &checksum(&code), async {
+            Err(DaemonError::StdErr("the code is cached".to_string()))
+        })
+        .await
+        .unwrap();
+        assert_eq!(cached, path);
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn download_code_checksum_mismatch() {
+        let dir = cache_dir();
+        let expected = checksum(b"\0asm code");
+
+        let err = cached_code(&dir, 7, &expected, async { Ok(b"\0asm other".to_vec()) })
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            DaemonError::CodeHashMismatch { code_id: 7, expected: e, actual: a }
+                if e == expected.to_hex() && a == checksum(b"\0asm other").to_hex()
+        ));
+        // Nothing is cached for a code that doesn't match its checksum
+        assert_eq!(std::fs::read_dir(&dir).unwrap().count(), 0);
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,236 @@
+use std::str::FromStr;
+
+use crate::{cosmos_modules, error::DaemonError, senders::query::QuerySender, DaemonBase};
+use cosmrs::proto::cosmos::base::{query::v1beta1::PageRequest, v1beta1::DecCoin};
+use cosmwasm_std::{Coin, StdError, Uint128, Uint256};
+use cw_orch_core::environment::{DistributionQuerier, Querier, QuerierGetter};
+use tokio::runtime::Handle;
+use tonic::transport::Channel;
+
+/// Querier for the Cosmos Distribution module
+/// All the async function are prefixed with `_`
+pub struct Distribution {
+    pub channel: Channel,
+    pub rt_handle: Option<Handle>,
+    pub height: Option<u64>,
+}
+
+impl Distribution {
+    pub fn new<Sender: QuerySender>(daemon: &DaemonBase<Sender>) -> Self {
+        Self {
+            channel: daemon.channel(),
+            rt_handle: Some(daemon.rt_handle.clone()),
+            height: daemon.query_height(),
+        }
+    }
+
+    pub fn new_async(channel: Channel) -> Self {
+        Self {
+            channel,
+            rt_handle: None,
+            height: None,
+        }
+    }
+}
+
+impl Querier for Distribution {
+    type Error = DaemonError;
+}
+
+impl<Sender: QuerySender> QuerierGetter<Distribution> for DaemonBase<Sender> {
+    fn querier(&self) -> Distribution {
+        Distribution::new(self)
+    }
+}
+
+impl Distribution {
+    /// Query the parameters of the distribution module
+    pub async fn _params(&self) -> Result<cosmos_modules::distribution::Params, DaemonError> {
+        let params: cosmos_modules::distribution::QueryParamsResponse =
+            cosmos_query!(self, distribution, params, QueryParamsRequest {});
+        Ok(params.params.unwrap())
+    }
+
+    /// Query the outstanding (un-withdrawn) rewards of a validator and all its delegations
+    pub async fn _validator_outstanding_rewards(
+        &self,
+        validator_address: impl Into<String>,
+    ) -> Result<Vec<DecCoin>, DaemonError> {
+        let rewards: cosmos_modules::distribution::QueryValidatorOutstandingRewardsResponse = cosmos_query!(
+            self,
+            distribution,
+            validator_outstanding_rewards,
+            QueryValidatorOutstandingRewardsRequest {
+                validator_address: validator_address.into()
+            }
+        );
+        Ok(rewards.rewards.map(|r| r.rewards).unwrap_or_default())
+    }
+
+    /// Query the accumulated commission of a validator
+    pub async fn _validator_commission(
+        &self,
+        validator_address: impl Into<String>,
+    ) -> Result<Vec<DecCoin>, DaemonError> {
+        let commission: cosmos_modules::distribution::QueryValidatorCommissionResponse = cosmos_query!(
+            self,
+            distribution,
+            validator_commission,
+            QueryValidatorCommissionRequest {
+                validator_address: validator_address.into()
+            }
+        );
+        Ok(commission
+            .commission
+            .map(|c| c.commission)
+            .unwrap_or_default())
+    }
+
+    /// Query the slash events of a validator between two heights
+    ///
+    /// see [PageRequest] for pagination
+    pub async fn _validator_slashes(
+        &self,
+        validator_address: impl Into<String>,
+        starting_height: u64,
+        ending_height: u64,
+        pagination: Option<PageRequest>,
+    ) -> Result<cosmos_modules::distribution::QueryValidatorSlashesResponse, DaemonError> {
+        let slashes: cosmos_modules::distribution::QueryValidatorSlashesResponse = cosmos_query!(
+            self,
+            distribution,
+            validator_slashes,
+            QueryValidatorSlashesRequest {
+                validator_address: validator_address.into(),
+                starting_height: starting_height,
+                ending_height: ending_height,
+                pagination: pagination
+            }
+        );
+        Ok(slashes)
+    }
+
+    /// Query the rewards accrued by a delegation
+    pub async fn _delegation_rewards(
+        &self,
+        delegator_address: impl Into<String>,
+        validator_address: impl Into<String>,
+    ) -> Result<Vec<DecCoin>, DaemonError> {
+        let rewards: cosmos_modules::distribution::QueryDelegationRewardsResponse = cosmos_query!(
+            self,
+            distribution,
+            delegation_rewards,
+            QueryDelegationRewardsRequest {
+                delegator_address: delegator_address.into(),
+                validator_address: validator_address.into()
+            }
+        );
+        Ok(rewards.rewards)
+    }
+
+    /// Query the total rewards accrued by each validator a delegator delegated to
+    pub async fn _delegation_total_rewards(
+        &self,
+        delegator_address: impl Into<String>,
+    ) -> Result<cosmos_modules::distribution::QueryDelegationTotalRewardsResponse, DaemonError>
+    {
+        let rewards: cosmos_modules::distribution::QueryDelegationTotalRewardsResponse = cosmos_query!(
+            self,
+            distribution,
+            delegation_total_rewards,
+            QueryDelegationTotalRewardsRequest {
+                delegator_address: delegator_address.into()
+            }
+        );
+        Ok(rewards)
+    }
+
+    /// Query the validators a delegator delegated to
+    pub async fn _delegator_validators(
+        &self,
+        delegator_address: impl Into<String>,
+    ) -> Result<Vec<String>, DaemonError> {
+        let validators: cosmos_modules::distribution::QueryDelegatorValidatorsResponse = cosmos_query!(
+            self,
+            distribution,
+            delegator_validators,
+            QueryDelegatorValidatorsRequest {
+                delegator_address: delegator_address.into()
+            }
+        );
+        Ok(validators.validators)
+    }
+
+    /// Query the address rewards of a delegator are withdrawn to
+    pub async fn _delegator_withdraw_address(
+        &self,
+        delegator_address: impl Into<String>,
+    ) -> Result<String, DaemonError> {
+        let withdraw_address: cosmos_modules::distribution::QueryDelegatorWithdrawAddressResponse = cosmos_query!(
+            self,
+            distribution,
+            delegator_withdraw_address,
+            QueryDelegatorWithdrawAddressRequest {
+                delegator_address: delegator_address.into()
+            }
+        );
+        Ok(withdraw_address.withdraw_address)
+    }
+
+    /// Query the community pool coins
+    pub async fn _community_pool(&self) -> Result<Vec<DecCoin>, DaemonError> {
+        let pool: cosmos_modules::distribution::QueryCommunityPoolResponse = cosmos_query!(
+            self,
+            distribution,
+            community_pool,
+            QueryCommunityPoolRequest {}
+        );
+        Ok(pool.pool)
+    }
+}
+
+impl DistributionQuerier for Distribution {
+    fn delegation_rewards(
+        &self,
+        delegator: impl Into<String>,
+        validator: impl Into<String>,
+    ) -> Result<Vec<Coin>, Self::Error> {
+        let rewards = self
+            .rt_handle
+            .as_ref()
+            .ok_or(DaemonError::QuerierNeedRuntime)?
+            .block_on(self._delegation_rewards(delegator, validator))?;
+        Ok(cosmrs_to_cosmwasm_dec_coins(rewards)?)
+    }
+
+    fn delegation_total_rewards(
+        &self,
+        delegator: impl Into<String>,
+    ) -> Result<Vec<Coin>, Self::Error> {
+        let rewards = self
+            .rt_handle
+            .as_ref()
+            .ok_or(DaemonError::QuerierNeedRuntime)?
+            .block_on(self._delegation_total_rewards(delegator))?;
+        Ok(cosmrs_to_cosmwasm_dec_coins(rewards.total)?)
+    }
+}
+
+/// Converts a decimal coin to a [`Coin`], rounding the amount down.
+pub fn cosmrs_to_cosmwasm_dec_coin(c: DecCoin) -> Result<Coin, StdError> {
+    let amount = match c.amount.split_once('.') {
+        Some((integer, _)) => integer.parse()?,
+        // Decimals are encoded as integers with 18 decimal places over gRPC
+        None => Uint128::try_from(
+            Uint256::from_str(&c.amount)? / Uint256::from(1_000_000_000_000_000_000u128),
+        )?,
+    };
+    Ok(Coin {
+        amount,
+        denom: c.denom,
+    })
+}
+
+pub fn cosmrs_to_cosmwasm_dec_coins(c: Vec<DecCoin>) -> Result<Vec<Coin>, StdError> {
+    c.into_iter().map(cosmrs_to_cosmwasm_dec_coin).collect()
+}
@@ -1,4 +1,4 @@
-use crate::{cosmos_modules, error::DaemonError, Daemon};
+use crate::{cosmos_modules, error::DaemonError, senders::query::QuerySender, DaemonBase};
 use cosmrs::proto::cosmos::base::query::v1beta1::PageRequest;
 use cw_orch_core::environment::{Querier, QuerierGetter};
 use tokio::runtime::Handle;
@@ -9,13 +9,15 @@ use tonic::transport::Channel;
 pub struct FeeGrant {
     pub channel: Channel,
     pub rt_handle: Option<Handle>,
+    pub height: Option<u64>,
 }
 
 impl FeeGrant {
-    pub fn new(daemon: &Daemon) -> Self {
+    pub fn new<Sender: QuerySender>(daemon: &DaemonBase<Sender>) -> Self {
         Self {
             channel: daemon.channel(),
             rt_handle: Some(daemon.rt_handle.clone()),
+            height: daemon.query_height(),
         }
     }
 
@@ -23,6 +25,7 @@ impl FeeGrant {
         Self {
             channel,
             rt_handle: None,
+            height: None,
         }
     }
 }
@@ -31,7 +34,7 @@ impl Querier for FeeGrant {
     type Error = DaemonError;
 }
 
-impl QuerierGetter<FeeGrant> for Daemon {
+impl<Sender: QuerySender> QuerierGetter<FeeGrant> for DaemonBase<Sender> {
     fn querier(&self) -> FeeGrant {
         FeeGrant::new(self)
     }
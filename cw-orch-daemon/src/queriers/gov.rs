@@ -1,6 +1,6 @@
-use crate::{cosmos_modules, error::DaemonError, Daemon};
+use crate::{cosmos_modules, error::DaemonError, senders::query::QuerySender, DaemonBase};
 use cosmrs::proto::cosmos::base::query::v1beta1::PageRequest;
-use cw_orch_core::environment::{Querier, QuerierGetter};
+use cw_orch_core::environment::{GovQuerier, ProposalStatus, Querier, QuerierGetter};
 use tokio::runtime::Handle;
 use tonic::transport::Channel;
 
@@ -9,13 +9,15 @@ use tonic::transport::Channel;
 pub struct Gov {
     pub channel: Channel,
     pub rt_handle: Option<Handle>,
+    pub height: Option<u64>,
 }
 
 impl Gov {
-    pub fn new(daemon: &Daemon) -> Self {
+    pub fn new<Sender: QuerySender>(daemon: &DaemonBase<Sender>) -> Self {
         Self {
             channel: daemon.channel(),
             rt_handle: Some(daemon.rt_handle.clone()),
+            height: daemon.query_height(),
         }
     }
 
@@ -23,6 +25,7 @@ impl Gov {
         Self {
             channel,
             rt_handle: None,
+            height: None,
         }
     }
 }
@@ -31,7 +34,7 @@ impl Querier for Gov {
     type Error = DaemonError;
 }
 
-impl QuerierGetter<Gov> for Daemon {
+impl<Sender: QuerySender> QuerierGetter<Gov> for DaemonBase<Sender> {
     fn querier(&self) -> Gov {
         Gov::new(self)
     }
@@ -187,6 +190,17 @@ impl Gov {
     }
 }
 
+impl GovQuerier for Gov {
+    fn proposal_status(&self, proposal_id: u64) -> Result<ProposalStatus, Self::Error> {
+        let proposal = self
+            .rt_handle
+            .as_ref()
+            .ok_or(DaemonError::QuerierNeedRuntime)?
+            .block_on(self._proposal(proposal_id))?;
+        Ok(ProposalStatus::try_from(proposal.status)?)
+    }
+}
+
 /// Proposal status
 #[allow(missing_docs)]
 pub enum GovProposalStatus {
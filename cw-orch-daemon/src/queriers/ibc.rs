@@ -1,4 +1,4 @@
-use crate::{cosmos_modules, error::DaemonError, Daemon};
+use crate::{cosmos_modules, error::DaemonError, senders::query::QuerySender, DaemonBase};
 use cosmos_modules::ibc_channel;
 use cosmrs::proto::ibc::{
     applications::transfer::v1::{DenomTrace, QueryDenomHashResponse, QueryDenomTraceResponse},
@@ -9,7 +9,10 @@ use cosmrs::proto::ibc::{
     },
     lightclients::tendermint::v1::ClientState,
 };
-use cw_orch_core::environment::{Querier, QuerierGetter};
+use cw_orch_core::{
+    environment::{Querier, QuerierGetter},
+    ibc::ibc_hash,
+};
 use prost::Message;
 use tokio::runtime::Handle;
 use tonic::transport::Channel;
@@ -19,13 +22,15 @@ use tonic::transport::Channel;
 pub struct Ibc {
     pub channel: Channel,
     pub rt_handle: Option<Handle>,
+    pub height: Option<u64>,
 }
 
 impl Ibc {
-    pub fn new(daemon: &Daemon) -> Self {
+    pub fn new<Sender: QuerySender>(daemon: &DaemonBase<Sender>) -> Self {
         Self {
             channel: daemon.channel(),
             rt_handle: Some(daemon.rt_handle.clone()),
+            height: daemon.query_height(),
         }
     }
 
@@ -33,6 +38,7 @@ impl Ibc {
         Self {
             channel,
             rt_handle: None,
+            height: None,
         }
     }
 }
@@ -41,7 +47,7 @@ impl Querier for Ibc {
     type Error = DaemonError;
 }
 
-impl QuerierGetter<Ibc> for Daemon {
+impl<Sender: QuerySender> QuerierGetter<Ibc> for DaemonBase<Sender> {
     fn querier(&self) -> Ibc {
         Ibc::new(self)
     }
@@ -72,6 +78,22 @@ impl Ibc {
         Ok(denom_hash.hash)
     }
 
+    /// Get the trace of a denom, resolving `ibc/{hash}` denoms through the transfer module.
+    /// Native denoms have an empty path.
+    pub async fn _resolve_denom(
+        &self,
+        denom: impl Into<String>,
+    ) -> Result<DenomTrace, DaemonError> {
+        let denom = denom.into();
+        match ibc_hash(&denom) {
+            Some(hash) => self._denom_trace(hash.to_string()).await,
+            None => Ok(DenomTrace {
+                path: String::new(),
+                base_denom: denom,
+            }),
+        }
+    }
+
     // ### Client queries ###
 
     /// Get all the IBC clients for this daemon
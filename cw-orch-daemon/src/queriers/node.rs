@@ -239,14 +239,8 @@ impl Node {
             cosmos_modules::tx::service_client::ServiceClient::new(self.channel.clone());
 
         let request = cosmos_modules::tx::GetTxRequest { hash: hash.clone() };
-        let mut block_speed = self._average_block_speed(Some(0.7)).await?;
+        let mut block_speed = self.bounded_block_speed(0.7).await?;
         let max_block_time = DaemonEnvVars::max_block_time();
-        if let Some(max_time) = max_block_time {
-            block_speed = block_speed.min(max_time);
-        } else {
-            let min_block_time = DaemonEnvVars::min_block_time();
-            block_speed = block_speed.max(min_block_time);
-        }
 
         for _ in 0..retries {
             match client.get_tx(request.clone()).await {
@@ -272,6 +266,68 @@ impl Node {
         Err(DaemonError::TXNotFound(hash, retries))
     }
 
+    /// Waits for the TX to be included in a block and buried under `confirmations` blocks, for at most `timeout`.
+    /// See [`Node::_find_confirmed_tx`] for how re-orgs are handled.
+    pub async fn _wait_for_tx(
+        &self,
+        hash: String,
+        confirmations: u64,
+        timeout: Duration,
+    ) -> Result<CosmTxResponse, DaemonError> {
+        tokio::time::timeout(
+            timeout,
+            self._find_confirmed_tx(hash.clone(), confirmations),
+        )
+        .await
+        .map_err(|_| DaemonError::TxNotConfirmed {
+            hash,
+            confirmations,
+            timeout,
+        })?
+    }
+
+    /// Find TX by hash once it is buried under `confirmations` blocks, `0` returns it as soon as it is included.
+    /// The TX is looked up again once buried, if a re-org dropped it or moved it to another block in the meantime
+    /// it is searched again and its confirmations start over.
+    /// Only bounded by the TX query retries while the TX can't be found, see [`Node::_wait_for_tx`] for a timeout.
+    pub async fn _find_confirmed_tx(
+        &self,
+        hash: String,
+        confirmations: u64,
+    ) -> Result<CosmTxResponse, DaemonError> {
+        loop {
+            let tx = self._find_tx(hash.clone()).await?;
+            if confirmations == 0 {
+                return Ok(tx);
+            }
+
+            let confirmed_height = tx.height + confirmations;
+            let block_speed = self.bounded_block_speed(1.0).await?;
+            while self._block_height().await? < confirmed_height {
+                tokio::time::sleep(block_speed).await;
+            }
+
+            match self._find_tx_with_retries(hash.clone(), 1).await {
+                Ok(confirmed) if confirmed.height == tx.height => return Ok(confirmed),
+                Ok(moved) => {
+                    log::warn!(target: &query_target(), "TX {hash} moved from height {} to {} by a re-org, waiting for its confirmations again", tx.height, moved.height);
+                }
+                Err(_) => {
+                    log::warn!(target: &query_target(), "TX {hash} dropped from height {} by a re-org, waiting for its inclusion again", tx.height);
+                }
+            }
+        }
+    }
+
+    /// Average block speed multiplied by `multiplier`, bounded by the configured minimum and maximum block times
+    async fn bounded_block_speed(&self, multiplier: f32) -> Result<Duration, DaemonError> {
+        let block_speed = self._average_block_speed(Some(multiplier)).await?;
+        Ok(match DaemonEnvVars::max_block_time() {
+            Some(max_time) => block_speed.min(max_time),
+            None => block_speed.max(DaemonEnvVars::min_block_time()),
+        })
+    }
+
     /// Find TX by events
     pub async fn _find_tx_by_events(
         &self,
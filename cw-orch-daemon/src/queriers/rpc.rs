@@ -0,0 +1,281 @@
+use std::str::FromStr;
+
+use crate::{
+    error::DaemonError, senders::query::QuerySender, tx_resp::TxResultBlockEvent, DaemonBase,
+};
+
+use cosmrs::{
+    rpc::{
+        endpoint::{block_results, tx_search},
+        query::Query,
+        Client, HttpClient, Order,
+    },
+    tendermint::{block::Height, consensus::Params, Block},
+};
+use cw_orch_core::{environment::Querier, log::query_target};
+use serde::Serialize;
+use tokio::runtime::Handle;
+
+/// Querier for the Tendermint RPC endpoint of a node (usually exposed on port 26657).
+/// Complements the gRPC queriers with data that is only available over RPC, like block results events.
+/// All the async function are prefixed with `_`, their sync versions need a querier created with [`Rpc::new`]
+pub struct Rpc {
+    pub client: HttpClient,
+    pub rt_handle: Option<Handle>,
+}
+
+impl Rpc {
+    pub fn new<Sender: QuerySender>(
+        daemon: &DaemonBase<Sender>,
+        rpc_url: &str,
+    ) -> Result<Self, DaemonError> {
+        Ok(Self {
+            client: HttpClient::new(rpc_url)?,
+            rt_handle: Some(daemon.rt_handle.clone()),
+        })
+    }
+
+    pub fn new_async(rpc_url: &str) -> Result<Self, DaemonError> {
+        Ok(Self {
+            client: HttpClient::new(rpc_url)?,
+            rt_handle: None,
+        })
+    }
+}
+
+impl Querier for Rpc {
+    type Error = DaemonError;
+}
+
+/// Decoded events emitted while processing a block.
+#[derive(Clone, Debug)]
+pub struct BlockResults {
+    /// Height of the block
+    pub height: u64,
+    /// Events emitted during `BeginBlock`
+    pub begin_block_events: Vec<TxResultBlockEvent>,
+    /// Events emitted during `EndBlock`
+    pub end_block_events: Vec<TxResultBlockEvent>,
+    /// Events emitted during `FinalizeBlock` (CometBFT 0.38+)
+    pub finalize_block_events: Vec<TxResultBlockEvent>,
+    /// Events of every transaction included in the block, in order
+    pub txs_events: Vec<Vec<TxResultBlockEvent>>,
+}
+
+impl From<block_results::Response> for BlockResults {
+    fn from(resp: block_results::Response) -> Self {
+        let decode = |events: Vec<cosmrs::tendermint::abci::Event>| {
+            events
+                .into_iter()
+                .map(TxResultBlockEvent::from)
+                .collect::<Vec<_>>()
+        };
+        Self {
+            height: resp.height.value(),
+            begin_block_events: decode(resp.begin_block_events.unwrap_or_default()),
+            end_block_events: decode(resp.end_block_events.unwrap_or_default()),
+            finalize_block_events: decode(resp.finalize_block_events),
+            txs_events: resp
+                .txs_results
+                .unwrap_or_default()
+                .into_iter()
+                .map(|tx| decode(tx.events))
+                .collect(),
+        }
+    }
+}
+
+/// Event emitted by a contract (`wasm` or `wasm-*` event) in a transaction.
+#[derive(Clone, Debug, Serialize)]
+pub struct ContractEvent {
+    /// Height of the block that included the transaction
+    pub height: u64,
+    /// Hash of the transaction
+    pub txhash: String,
+    /// The event itself
+    pub event: TxResultBlockEvent,
+}
+
+const TX_SEARCH_PAGE_SIZE: u8 = 100;
+
+impl Rpc {
+    /// Get the block at the given height, or the latest block if `None`
+    pub async fn _block(&self, height: Option<u64>) -> Result<Block, DaemonError> {
+        let resp = match height {
+            Some(height) => self.client.block(Height::try_from(height)?).await?,
+            None => self.client.latest_block().await?,
+        };
+        Ok(resp.block)
+    }
+
+    /// Get the raw block results at the given height, or of the latest block if `None`
+    pub async fn _raw_block_results(
+        &self,
+        height: Option<u64>,
+    ) -> Result<block_results::Response, DaemonError> {
+        let resp = match height {
+            Some(height) => self.client.block_results(Height::try_from(height)?).await?,
+            None => self.client.latest_block_results().await?,
+        };
+        Ok(resp)
+    }
+
+    /// Get the decoded begin/end-block and transaction events at the given height, or of the latest block if `None`
+    pub async fn _block_results(&self, height: Option<u64>) -> Result<BlockResults, DaemonError> {
+        let results = self._raw_block_results(height).await?;
+        log::debug!(target: &query_target(), "Block results at height {}", results.height);
+        Ok(results.into())
+    }
+
+    /// Search for transactions matching a Tendermint query, e.g. `"wasm._contract_address='juno1...'"`
+    /// Results are returned oldest first.
+    pub async fn _tx_search(
+        &self,
+        query: &str,
+        page: u32,
+        per_page: u8,
+    ) -> Result<tx_search::Response, DaemonError> {
+        let query = Query::from_str(query)?;
+        let resp = self
+            .client
+            .tx_search(query, false, page, per_page, Order::Ascending)
+            .await?;
+        Ok(resp)
+    }
+
+    /// Get the consensus parameters at the given height, or the latest ones if `None`
+    pub async fn _consensus_params(&self, height: Option<u64>) -> Result<Params, DaemonError> {
+        let resp = match height {
+            Some(height) => {
+                self.client
+                    .consensus_params(Height::try_from(height)?)
+                    .await?
+            }
+            None => self.client.latest_consensus_params().await?,
+        };
+        Ok(resp.consensus_params)
+    }
+
+    /// Get the events emitted by `contract` in transactions included from height `from_height` (inclusive), oldest first.
+    /// Calling this method again with the height following the last returned event allows tailing the activity of a contract.
+    pub async fn _contract_events(
+        &self,
+        contract: &str,
+        from_height: u64,
+    ) -> Result<Vec<ContractEvent>, DaemonError> {
+        let query = format!("wasm._contract_address='{contract}' AND tx.height>={from_height}");
+        let mut events = vec![];
+        let mut page = 1;
+        loop {
+            let resp = self._tx_search(&query, page, TX_SEARCH_PAGE_SIZE).await?;
+            let fetched = resp.txs.len();
+            for tx in resp.txs {
+                let txhash = tx.hash.to_string();
+                events.extend(
+                    tx.tx_result
+                        .events
+                        .into_iter()
+                        .map(TxResultBlockEvent::from)
+                        .filter(|event| {
+                            event.is_wasm()
+                                && event
+                                    .get_first_attribute_value("_contract_address")
+                                    .as_deref()
+                                    == Some(contract)
+                        })
+                        .map(|event| ContractEvent {
+                            height: tx.height.value(),
+                            txhash: txhash.clone(),
+                            event,
+                        }),
+                );
+            }
+            if fetched < TX_SEARCH_PAGE_SIZE as usize
+                || (page * TX_SEARCH_PAGE_SIZE as u32) >= resp.total_count
+            {
+                break;
+            }
+            page += 1;
+        }
+        log::debug!(target: &query_target(), "Found {} events for contract {contract} since height {from_height}", events.len());
+        Ok(events)
+    }
+
+    /// Sync version of [`Self::_block`]
+    pub fn block(&self, height: Option<u64>) -> Result<Block, DaemonError> {
+        self.rt_handle
+            .as_ref()
+            .ok_or(DaemonError::QuerierNeedRuntime)?
+            .block_on(self._block(height))
+    }
+
+    /// Sync version of [`Self::_raw_block_results`]
+    pub fn raw_block_results(
+        &self,
+        height: Option<u64>,
+    ) -> Result<block_results::Response, DaemonError> {
+        self.rt_handle
+            .as_ref()
+            .ok_or(DaemonError::QuerierNeedRuntime)?
+            .block_on(self._raw_block_results(height))
+    }
+
+    /// Sync version of [`Self::_block_results`]
+    pub fn block_results(&self, height: Option<u64>) -> Result<BlockResults, DaemonError> {
+        self.rt_handle
+            .as_ref()
+            .ok_or(DaemonError::QuerierNeedRuntime)?
+            .block_on(self._block_results(height))
+    }
+
+    /// Sync version of [`Self::_tx_search`]
+    pub fn tx_search(
+        &self,
+        query: &str,
+        page: u32,
+        per_page: u8,
+    ) -> Result<tx_search::Response, DaemonError> {
+        self.rt_handle
+            .as_ref()
+            .ok_or(DaemonError::QuerierNeedRuntime)?
+            .block_on(self._tx_search(query, page, per_page))
+    }
+
+    /// Sync version of [`Self::_consensus_params`]
+    pub fn consensus_params(&self, height: Option<u64>) -> Result<Params, DaemonError> {
+        self.rt_handle
+            .as_ref()
+            .ok_or(DaemonError::QuerierNeedRuntime)?
+            .block_on(self._consensus_params(height))
+    }
+
+    /// Sync version of [`Self::_contract_events`]
+    pub fn contract_events(
+        &self,
+        contract: &str,
+        from_height: u64,
+    ) -> Result<Vec<ContractEvent>, DaemonError> {
+        self.rt_handle
+            .as_ref()
+            .ok_or(DaemonError::QuerierNeedRuntime)?
+            .block_on(self._contract_events(contract, from_height))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn sync_queries_need_a_runtime() {
+        let rpc = Rpc::new_async("http://localhost:26657").unwrap();
+        assert!(matches!(
+            rpc.block(None),
+            Err(DaemonError::QuerierNeedRuntime)
+        ));
+        assert!(matches!(
+            rpc.contract_events("juno1contract", 1),
+            Err(DaemonError::QuerierNeedRuntime)
+        ));
+    }
+}
@@ -1,9 +1,9 @@
 use std::fmt::Display;
 
-use crate::{cosmos_modules, error::DaemonError, Daemon};
+use crate::{cosmos_modules, error::DaemonError, senders::query::QuerySender, DaemonBase};
 use cosmrs::proto::cosmos::base::query::v1beta1::PageRequest;
 use cosmwasm_std::{Addr, StdError};
-use cw_orch_core::environment::{Querier, QuerierGetter};
+use cw_orch_core::environment::{Querier, QuerierGetter, StakingQuerier};
 use tokio::runtime::Handle;
 use tonic::transport::Channel;
 
@@ -14,13 +14,15 @@ use super::bank::cosmrs_to_cosmwasm_coin;
 pub struct Staking {
     pub channel: Channel,
     pub rt_handle: Option<Handle>,
+    pub height: Option<u64>,
 }
 
 impl Staking {
-    pub fn new(daemon: &Daemon) -> Self {
+    pub fn new<Sender: QuerySender>(daemon: &DaemonBase<Sender>) -> Self {
         Self {
             channel: daemon.channel(),
             rt_handle: Some(daemon.rt_handle.clone()),
+            height: daemon.query_height(),
         }
     }
 
@@ -28,6 +30,7 @@ impl Staking {
         Self {
             channel,
             rt_handle: None,
+            height: None,
         }
     }
 }
@@ -36,7 +39,7 @@ impl Querier for Staking {
     type Error = DaemonError;
 }
 
-impl QuerierGetter<Staking> for Daemon {
+impl<Sender: QuerySender> QuerierGetter<Staking> for DaemonBase<Sender> {
     fn querier(&self) -> Staking {
         Staking::new(self)
     }
@@ -299,7 +302,76 @@ impl Staking {
     }
 }
 
+impl StakingQuerier for Staking {
+    fn bonded_denom(&self) -> Result<String, Self::Error> {
+        let params = self
+            .rt_handle
+            .as_ref()
+            .ok_or(DaemonError::QuerierNeedRuntime)?
+            .block_on(self._params())?;
+        Ok(params.params.unwrap().bond_denom)
+    }
+
+    fn all_validators(&self) -> Result<Vec<cosmwasm_std::Validator>, Self::Error> {
+        self.rt_handle
+            .as_ref()
+            .ok_or(DaemonError::QuerierNeedRuntime)?
+            .block_on(self._validators(StakingBondStatus::Bonded))
+    }
+
+    fn validator(
+        &self,
+        address: impl Into<String>,
+    ) -> Result<Option<cosmwasm_std::Validator>, Self::Error> {
+        not_found_to_none(
+            self.rt_handle
+                .as_ref()
+                .ok_or(DaemonError::QuerierNeedRuntime)?
+                .block_on(self._validator(address)),
+        )
+    }
+
+    fn all_delegations(
+        &self,
+        delegator: impl Into<String>,
+    ) -> Result<Vec<cosmwasm_std::Delegation>, Self::Error> {
+        let delegations = self
+            .rt_handle
+            .as_ref()
+            .ok_or(DaemonError::QuerierNeedRuntime)?
+            .block_on(self._delegator_delegations(delegator, None))?;
+        Ok(delegations
+            .delegation_responses
+            .into_iter()
+            .map(cosmrs_to_cosmwasm_delegation)
+            .collect::<Result<_, _>>()?)
+    }
+
+    fn delegation(
+        &self,
+        delegator: impl Into<String>,
+        validator: impl Into<String>,
+    ) -> Result<Option<cosmwasm_std::Delegation>, Self::Error> {
+        not_found_to_none(
+            self.rt_handle
+                .as_ref()
+                .ok_or(DaemonError::QuerierNeedRuntime)?
+                .block_on(self._delegation(validator, delegator)),
+        )
+    }
+}
+
+/// Maps a gRPC `NotFound` error to `None`
+fn not_found_to_none<T>(result: Result<T, DaemonError>) -> Result<Option<T>, DaemonError> {
+    match result {
+        Ok(value) => Ok(Some(value)),
+        Err(DaemonError::Status(status)) if status.code() == tonic::Code::NotFound => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
 /// Staking bond statuses
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum StakingBondStatus {
     /// UNSPECIFIED defines an invalid validator status.
     Unspecified = 0,
@@ -0,0 +1,119 @@
+use crate::{cosmos_modules, error::DaemonError, senders::query::QuerySender, DaemonBase};
+use cw_orch_core::environment::{Querier, QuerierGetter};
+use tokio::runtime::Handle;
+use tonic::transport::Channel;
+
+use super::Node;
+
+/// Querier for the Cosmos Upgrade module
+/// All the async function are prefixed with `_`
+pub struct Upgrade {
+    pub channel: Channel,
+    pub rt_handle: Option<Handle>,
+    pub height: Option<u64>,
+}
+
+impl Upgrade {
+    pub fn new<Sender: QuerySender>(daemon: &DaemonBase<Sender>) -> Self {
+        Self {
+            channel: daemon.channel(),
+            rt_handle: Some(daemon.rt_handle.clone()),
+            height: daemon.query_height(),
+        }
+    }
+
+    pub fn new_async(channel: Channel) -> Self {
+        Self {
+            channel,
+            rt_handle: None,
+            height: None,
+        }
+    }
+}
+
+impl Querier for Upgrade {
+    type Error = DaemonError;
+}
+
+impl<Sender: QuerySender> QuerierGetter<Upgrade> for DaemonBase<Sender> {
+    fn querier(&self) -> Upgrade {
+        Upgrade::new(self)
+    }
+}
+
+impl Upgrade {
+    /// Query the upgrade plan scheduled by governance, `None` if no upgrade is pending
+    pub async fn _current_plan(
+        &self,
+    ) -> Result<Option<cosmos_modules::upgrade::Plan>, DaemonError> {
+        let current_plan: cosmos_modules::upgrade::QueryCurrentPlanResponse =
+            cosmos_query!(self, upgrade, current_plan, QueryCurrentPlanRequest {});
+        Ok(current_plan.plan)
+    }
+
+    /// Query the height the upgrade `name` was applied at, `None` if it wasn't applied
+    pub async fn _applied_plan(&self, name: impl Into<String>) -> Result<Option<u64>, DaemonError> {
+        let applied_plan: cosmos_modules::upgrade::QueryAppliedPlanResponse = cosmos_query!(
+            self,
+            upgrade,
+            applied_plan,
+            QueryAppliedPlanRequest { name: name.into() }
+        );
+        Ok((applied_plan.height > 0).then_some(applied_plan.height as u64))
+    }
+
+    /// Query the upgrade plan the chain is halted for, `None` if the chain isn't halted at an upgrade height.
+    /// A chain halts once the block before the upgrade height is committed, until the nodes run the upgraded binary.
+    pub async fn _upgrade_halt(
+        &self,
+    ) -> Result<Option<cosmos_modules::upgrade::Plan>, DaemonError> {
+        let Some(plan) = self._current_plan().await? else {
+            return Ok(None);
+        };
+        let latest_height = Node::new_async(self.channel.clone())
+            ._block_height()
+            .await?;
+        Ok(halted_for_upgrade(&plan, latest_height).then_some(plan))
+    }
+}
+
+/// Whether a chain whose latest block is `latest_height` is halted for the upgrade `plan`.
+/// The chain halts once the block before the upgrade height is committed, plans without a height never halt it.
+pub(crate) fn halted_for_upgrade(plan: &cosmos_modules::upgrade::Plan, latest_height: u64) -> bool {
+    plan.height > 0 && latest_height + 1 >= plan.height as u64
+}
+
+/// Whether a chain halted for an upgrade at `upgrade_height` resumed, the upgraded binary committing the upgrade block
+pub(crate) fn resumed_after_upgrade(upgrade_height: u64, latest_height: u64) -> bool {
+    latest_height >= upgrade_height
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn plan(height: i64) -> cosmos_modules::upgrade::Plan {
+        cosmos_modules::upgrade::Plan {
+            name: "v2".to_string(),
+            height,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn halt_at_upgrade_height() {
+        assert!(!halted_for_upgrade(&plan(100), 98));
+        assert!(halted_for_upgrade(&plan(100), 99));
+        assert!(halted_for_upgrade(&plan(100), 100));
+        // Plans without a height don't halt the chain
+        assert!(!halted_for_upgrade(&plan(0), 0));
+        assert!(!halted_for_upgrade(&plan(0), 99));
+    }
+
+    #[test]
+    fn resume_after_upgrade() {
+        assert!(!resumed_after_upgrade(100, 99));
+        assert!(resumed_after_upgrade(100, 100));
+        assert!(resumed_after_upgrade(100, 101));
+    }
+}
@@ -0,0 +1,266 @@
+//! Read-through cache of smart queries persisted to disk, see [`QueryCache`].
+
+use std::{
+    collections::BTreeMap,
+    future::Future,
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+};
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use cosmwasm_std::Binary;
+
+use crate::{env::default_state_folder, DaemonError};
+
+/// Directory of the query caches, relative to the cw-orchestrator state folder
+const QUERY_CACHE_DIR: &str = "query_cache";
+
+/// How a [`QueryCache`] treats the queries it doesn't have a response for
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum CacheMode {
+    /// Queries the chain and records the response
+    #[default]
+    ReadThrough,
+    /// Errors with [`DaemonError::QueryNotCached`] without querying the chain, to run from a recorded fixture
+    Offline,
+}
+
+/// Responses of smart queries by contract, query message and block height, persisted to a JSON file.
+///
+/// The state of a contract at a given height never changes, so the queries of a daemon pinned to a height with
+/// [`Daemon::at_height`](crate::Daemon::at_height) are only sent to the chain once, across runs.
+/// Queries of the latest state are never cached.
+///
+/// Recorded files can be committed as fixtures, fork tests and analysis scripts then run without a node with [`CacheMode::Offline`].
+///
+/// The responses are kept in memory and written to the file by [`QueryCache::flush`],
+/// or when the last clone of the cache is dropped.
+///
+/// ```no_run
+/// use cw_orch_daemon::{query_cache::QueryCache, Daemon, networks};
+///
+/// let daemon = Daemon::builder(networks::JUNO_1).build().unwrap();
+/// let mut past = daemon.at_height(15_000_000);
+/// let cache = QueryCache::load("fixtures/juno.json").unwrap();
+/// past.set_query_cache(cache.clone());
+/// // ... queries
+/// cache.flush().unwrap();
+/// ```
+#[derive(Clone, Debug)]
+pub struct QueryCache {
+    mode: CacheMode,
+    inner: Arc<CacheFile>,
+}
+
+/// Responses shared by the clones of a [`QueryCache`], written to `path` when dropped
+#[derive(Debug)]
+struct CacheFile {
+    path: PathBuf,
+    entries: Mutex<BTreeMap<String, Binary>>,
+    /// Whether responses were recorded since the last write
+    dirty: AtomicBool,
+}
+
+impl CacheFile {
+    fn flush(&self) -> Result<(), DaemonError> {
+        if !self.dirty.swap(false, Ordering::AcqRel) {
+            return Ok(());
+        }
+        // Serialize under the lock, but write the file outside of it
+        let content = {
+            let entries = self.entries.lock().unwrap();
+            serde_json::to_string_pretty(&*entries)
+        };
+        let write = content.map_err(DaemonError::from).and_then(|content| {
+            if let Some(dir) = self.path.parent() {
+                std::fs::create_dir_all(dir)?;
+            }
+            // Write to a temporary file first so an interrupted write doesn't leave a corrupted cache
+            let tmp_path = self.path.with_extension("json.tmp");
+            std::fs::write(&tmp_path, content)?;
+            std::fs::rename(&tmp_path, &self.path)?;
+            Ok(())
+        });
+        if write.is_err() {
+            self.dirty.store(true, Ordering::Release);
+        }
+        write
+    }
+}
+
+impl Drop for CacheFile {
+    fn drop(&mut self) {
+        if let Err(e) = self.flush() {
+            log::warn!("Failed to write the query cache {:?}: {e}", self.path);
+        }
+    }
+}
+
+impl QueryCache {
+    /// Read-through cache persisted to `path`, loaded from it if it exists
+    pub fn load(path: impl Into<PathBuf>) -> Result<Self, DaemonError> {
+        let path = path.into();
+        let entries = if path.exists() {
+            serde_json::from_slice(&std::fs::read(&path)?)?
+        } else {
+            BTreeMap::new()
+        };
+        Ok(Self {
+            mode: CacheMode::ReadThrough,
+            inner: Arc::new(CacheFile {
+                path,
+                entries: Mutex::new(entries),
+                dirty: AtomicBool::new(false),
+            }),
+        })
+    }
+
+    /// Cache that never queries the chain, loaded from the fixture at `path`
+    pub fn offline(path: impl Into<PathBuf>) -> Result<Self, DaemonError> {
+        Ok(Self::load(path)?.mode(CacheMode::Offline))
+    }
+
+    /// Read-through cache of the chain `chain_id`, in the cw-orchestrator state folder
+    pub fn for_chain(chain_id: &str) -> Result<Self, DaemonError> {
+        Self::load(
+            default_state_folder()?
+                .join(QUERY_CACHE_DIR)
+                .join(format!("{chain_id}.json")),
+        )
+    }
+
+    /// Sets how the queries missing from the cache are handled
+    pub fn mode(mut self, mode: CacheMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// File the cache is persisted to
+    pub fn path(&self) -> &Path {
+        &self.inner.path
+    }
+
+    /// Number of cached responses
+    pub fn len(&self) -> usize {
+        self.inner.entries.lock().unwrap().len()
+    }
+
+    /// Whether no response is cached
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Writes the responses recorded since the last write to the cache file
+    pub fn flush(&self) -> Result<(), DaemonError> {
+        self.inner.flush()
+    }
+
+    /// Response of the query `msg` on `address` at `height`, from the cache or from `fetch`
+    pub(crate) async fn get_or_fetch(
+        &self,
+        address: &str,
+        msg: &[u8],
+        height: Option<u64>,
+        fetch: impl Future<Output = Result<Vec<u8>, DaemonError>>,
+    ) -> Result<Vec<u8>, DaemonError> {
+        let Some(height) = height else {
+            if self.mode == CacheMode::Offline {
+                return Err(DaemonError::QueryNotCached(format!(
+                    "query on {address} at the latest height, offline caches need a query height"
+                )));
+            }
+            return fetch.await;
+        };
+        let key = cache_key(address, msg, height);
+        if let Some(response) = self.inner.entries.lock().unwrap().get(&key) {
+            log::trace!("Query cache hit for {key}");
+            return Ok(response.to_vec());
+        }
+        if self.mode == CacheMode::Offline {
+            return Err(DaemonError::QueryNotCached(key));
+        }
+
+        let response = fetch.await?;
+        self.inner
+            .entries
+            .lock()
+            .unwrap()
+            .insert(key, Binary::from(response.clone()));
+        self.inner.dirty.store(true, Ordering::Release);
+        Ok(response)
+    }
+}
+
+fn cache_key(address: &str, msg: &[u8], height: u64) -> String {
+    format!("{address}/{height}/{}", STANDARD.encode(msg))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[tokio::test]
+    async fn records_and_replays_queries() -> anyhow::Result<()> {
+        let path =
+            std::env::temp_dir().join(format!("cw-orch-query-cache-{}.json", std::process::id()));
+        let cache = QueryCache::load(&path)?;
+        let fetched = cache
+            .get_or_fetch("juno1contract", b"{}", Some(10), async {
+                Ok(b"1".to_vec())
+            })
+            .await?;
+        assert_eq!(fetched, b"1");
+        // Latest state queries are not cached
+        cache
+            .get_or_fetch("juno1contract", b"{}", None, async { Ok(b"2".to_vec()) })
+            .await?;
+        assert_eq!(cache.len(), 1);
+        // Responses are only written to disk on flush
+        assert!(!path.exists());
+        cache.flush()?;
+        assert!(path.exists());
+
+        let offline = QueryCache::offline(&path)?;
+        let replayed = offline
+            .get_or_fetch("juno1contract", b"{}", Some(10), async {
+                Err(DaemonError::StdErr(
+                    "offline cache queried the chain".to_string(),
+                ))
+            })
+            .await?;
+        assert_eq!(replayed, b"1");
+        let missing = offline
+            .get_or_fetch("juno1contract", b"{}", Some(11), async { Ok(vec![]) })
+            .await;
+        assert!(matches!(missing, Err(DaemonError::QueryNotCached(_))));
+
+        std::fs::remove_file(path)?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn written_when_dropped() -> anyhow::Result<()> {
+        let path = std::env::temp_dir().join(format!(
+            "cw-orch-query-cache-drop-{}.json",
+            std::process::id()
+        ));
+        let cache = QueryCache::load(&path)?;
+        let clone = cache.clone();
+        clone
+            .get_or_fetch("juno1contract", b"{}", Some(10), async {
+                Ok(b"1".to_vec())
+            })
+            .await?;
+        drop(clone);
+        // The file is written once the last clone is dropped
+        assert!(!path.exists());
+        drop(cache);
+
+        assert_eq!(QueryCache::offline(&path)?.len(), 1);
+        std::fs::remove_file(path)?;
+        Ok(())
+    }
+}
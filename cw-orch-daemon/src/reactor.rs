@@ -0,0 +1,284 @@
+//! Event-driven bots on top of [`DaemonAsync`](crate::DaemonAsync).
+//!
+//! A [`Reactor`] follows the chain block by block over the Tendermint RPC endpoint and calls the registered handlers
+//! for every new block and for every contract event matching a [`WasmEventFilter`].
+//! Handlers get a clone of the daemon, so they can send follow-up transactions.
+//!
+//! Delivery is at-least-once: a block is only considered processed once all its handlers succeeded.
+//! If a query or a handler fails, the whole block is retried with an exponential backoff, so handlers should be idempotent.
+//!
+//! ```no_run
+//! use cw_orch_daemon::{reactor::{Reactor, WasmEventFilter}, DaemonAsync, networks};
+//! # tokio_test::block_on(async {
+//! let daemon = DaemonAsync::builder(networks::LOCAL_JUNO).build().await.unwrap();
+//! let mut reactor = Reactor::new(daemon, "http://localhost:26657").unwrap();
+//! reactor
+//!     .on_block(|_daemon, block| async move {
+//!         log::info!("New block {}", block.height);
+//!         Ok(())
+//!     })
+//!     .on_wasm_event(
+//!         WasmEventFilter::new().event_type("wasm-liquidate"),
+//!         |_daemon, event| async move {
+//!             log::info!("Liquidation in tx {}", event.txhash);
+//!             Ok(())
+//!         },
+//!     );
+//! reactor.run().await.unwrap();
+//! # })
+//! ```
+
+use std::{future::Future, pin::Pin, time::Duration};
+
+use cosmrs::rpc::Client;
+use cw_orch_core::log::connectivity_target;
+use sha2::{Digest, Sha256};
+
+#[cfg(feature = "indexer")]
+use crate::indexer::SqliteIndexer;
+use crate::{
+    queriers::{BlockResults, ContractEvent, Rpc},
+    DaemonAsyncBase, DaemonError, TxResultBlockEvent, TxSender, Wallet,
+};
+
+const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(1);
+const MIN_BACKOFF: Duration = Duration::from_secs(1);
+const MAX_BACKOFF: Duration = Duration::from_secs(60);
+
+type HandlerFuture = Pin<Box<dyn Future<Output = Result<(), DaemonError>> + Send>>;
+type Handler<Sender, T> = Box<dyn Fn(DaemonAsyncBase<Sender>, T) -> HandlerFuture + Send + Sync>;
+
+/// Selects the contract events a handler is called for
+#[derive(Clone, Debug, Default)]
+pub struct WasmEventFilter {
+    contract: Option<String>,
+    event_type: Option<String>,
+}
+
+impl WasmEventFilter {
+    /// Matches all the events emitted by contracts
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only match events emitted by `contract`
+    pub fn contract(mut self, contract: impl Into<String>) -> Self {
+        self.contract = Some(contract.into());
+        self
+    }
+
+    /// Only match events of type `event_type`, as emitted on chain: `wasm` for attributes added to the response,
+    /// `wasm-{ty}` for a custom event of type `ty`
+    pub fn event_type(mut self, event_type: impl Into<String>) -> Self {
+        self.event_type = Some(event_type.into());
+        self
+    }
+
+    /// Whether `event` is a contract event matching the filter
+    pub fn matches(&self, event: &TxResultBlockEvent) -> bool {
+        event.is_wasm()
+            && self
+                .event_type
+                .as_ref()
+                .map_or(true, |event_type| &event.s_type == event_type)
+            && self.contract.as_ref().map_or(true, |contract| {
+                event
+                    .get_first_attribute_value("_contract_address")
+                    .as_ref()
+                    == Some(contract)
+            })
+    }
+}
+
+/// Calls handlers for new blocks and contract events, see the [module documentation](self)
+pub struct Reactor<Sender = Wallet> {
+    daemon: DaemonAsyncBase<Sender>,
+    rpc: Rpc,
+    start_height: Option<u64>,
+    poll_interval: Duration,
+    block_handlers: Vec<Handler<Sender, BlockResults>>,
+    wasm_handlers: Vec<(WasmEventFilter, Handler<Sender, ContractEvent>)>,
+    #[cfg(feature = "indexer")]
+    indexer: Option<SqliteIndexer>,
+}
+
+impl<Sender: TxSender + Send + Sync + 'static> Reactor<Sender> {
+    /// Follow the chain of `daemon` through the Tendermint RPC endpoint at `rpc_url`
+    pub fn new(daemon: DaemonAsyncBase<Sender>, rpc_url: &str) -> Result<Self, DaemonError> {
+        Ok(Self {
+            daemon,
+            rpc: Rpc::new_async(rpc_url)?,
+            start_height: None,
+            poll_interval: DEFAULT_POLL_INTERVAL,
+            block_handlers: vec![],
+            wasm_handlers: vec![],
+            #[cfg(feature = "indexer")]
+            indexer: None,
+        })
+    }
+
+    /// Process blocks starting at `height`, to resume after a restart. Defaults to the block following the latest one.
+    pub fn start_height(&mut self, height: u64) -> &mut Self {
+        self.start_height = Some(height);
+        self
+    }
+
+    /// Set how often the node is polled for new blocks
+    pub fn poll_interval(&mut self, interval: Duration) -> &mut Self {
+        self.poll_interval = interval;
+        self
+    }
+
+    /// Call `handler` with the events of every new block
+    pub fn on_block<F, Fut>(&mut self, handler: F) -> &mut Self
+    where
+        F: Fn(DaemonAsyncBase<Sender>, BlockResults) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<(), DaemonError>> + Send + 'static,
+    {
+        self.block_handlers.push(Box::new(move |daemon, block| {
+            Box::pin(handler(daemon, block))
+        }));
+        self
+    }
+
+    /// Call `handler` for every contract event matching `filter`
+    pub fn on_wasm_event<F, Fut>(&mut self, filter: WasmEventFilter, handler: F) -> &mut Self
+    where
+        F: Fn(DaemonAsyncBase<Sender>, ContractEvent) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<(), DaemonError>> + Send + 'static,
+    {
+        self.wasm_handlers.push((
+            filter,
+            Box::new(move |daemon, event| Box::pin(handler(daemon, event))),
+        ));
+        self
+    }
+
+    /// Write the events of every processed block to `indexer`, before calling the handlers
+    #[cfg(feature = "indexer")]
+    pub fn index(&mut self, indexer: SqliteIndexer) -> &mut Self {
+        self.indexer = Some(indexer);
+        self
+    }
+
+    /// Follow the chain forever, only returning if the latest height can't be queried at startup
+    pub async fn run(&self) -> Result<(), DaemonError> {
+        let mut height = match self.start_height {
+            Some(height) => height,
+            None => self.latest_height().await? + 1,
+        };
+        let mut backoff = MIN_BACKOFF;
+        loop {
+            let result = match self.latest_height().await {
+                Ok(latest) if height > latest => {
+                    tokio::time::sleep(self.poll_interval).await;
+                    continue;
+                }
+                Ok(_) => self.process_block(height).await,
+                Err(e) => Err(e),
+            };
+            match result {
+                Ok(()) => {
+                    height += 1;
+                    backoff = MIN_BACKOFF;
+                }
+                Err(e) => {
+                    log::warn!(target: &connectivity_target(), "Reactor failed at height {height}, retrying in {backoff:?}: {e}");
+                    tokio::time::sleep(backoff).await;
+                    backoff = (backoff * 2).min(MAX_BACKOFF);
+                }
+            }
+        }
+    }
+
+    /// Call the handlers for the block at `height`, stopping at the first failing one
+    pub async fn process_block(&self, height: u64) -> Result<(), DaemonError> {
+        let block_results = self.rpc._block_results(Some(height)).await?;
+
+        let txhashes: Vec<String> = if self.wasm_handlers.is_empty() && !self.indexes() {
+            vec![]
+        } else {
+            let block = self.rpc._block(Some(height)).await?;
+            block
+                .data
+                .iter()
+                .map(|tx| hex::encode_upper(Sha256::digest(tx)))
+                .collect()
+        };
+
+        #[cfg(feature = "indexer")]
+        if let Some(indexer) = &self.indexer {
+            indexer.index_block(&block_results, &txhashes)?;
+        }
+
+        if !self.wasm_handlers.is_empty() {
+            for (tx_index, events) in block_results.txs_events.iter().enumerate() {
+                for event in events.iter().filter(|event| event.is_wasm()) {
+                    for (filter, handler) in &self.wasm_handlers {
+                        if !filter.matches(event) {
+                            continue;
+                        }
+                        let contract_event = ContractEvent {
+                            height,
+                            txhash: txhashes.get(tx_index).cloned().unwrap_or_default(),
+                            event: event.clone(),
+                        };
+                        handler(self.daemon.clone(), contract_event).await?;
+                    }
+                }
+            }
+        }
+
+        for handler in &self.block_handlers {
+            handler(self.daemon.clone(), block_results.clone()).await?;
+        }
+        log::debug!(target: &connectivity_target(), "Reactor processed block {height}");
+        Ok(())
+    }
+
+    #[cfg(feature = "indexer")]
+    fn indexes(&self) -> bool {
+        self.indexer.is_some()
+    }
+
+    #[cfg(not(feature = "indexer"))]
+    fn indexes(&self) -> bool {
+        false
+    }
+
+    async fn latest_height(&self) -> Result<u64, DaemonError> {
+        let status = self.rpc.client.status().await?;
+        Ok(status.sync_info.latest_block_height.value())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::TxResultBlockAttribute;
+
+    fn event(s_type: &str, contract: &str) -> TxResultBlockEvent {
+        TxResultBlockEvent {
+            s_type: s_type.to_string(),
+            attributes: vec![TxResultBlockAttribute {
+                key: "_contract_address".to_string(),
+                value: contract.to_string(),
+            }],
+        }
+    }
+
+    #[test]
+    fn wasm_event_filter() {
+        let filter = WasmEventFilter::new();
+        assert!(filter.matches(&event("wasm", "juno1a")));
+        assert!(filter.matches(&event("wasm-liquidate", "juno1a")));
+        assert!(!filter.matches(&event("transfer", "juno1a")));
+
+        let filter = WasmEventFilter::new()
+            .contract("juno1a")
+            .event_type("wasm-liquidate");
+        assert!(filter.matches(&event("wasm-liquidate", "juno1a")));
+        assert!(!filter.matches(&event("wasm-liquidate", "juno1b")));
+        assert!(!filter.matches(&event("wasm", "juno1a")));
+    }
+}
@@ -1,7 +1,10 @@
 use crate::{
+    amino,
+    budget::FeeBudget,
     env::DaemonEnvVars,
-    proto::injective::ETHEREUM_COIN_TYPE,
-    queriers::Bank,
+    proto_versions::ProtoVersions,
+    queriers::{resumed_after_upgrade, Bank, FeeGrant, Upgrade},
+    trace,
     tx_broadcaster::{
         account_sequence_strategy, assert_broadcast_code_cosm_response, insufficient_fee_strategy,
         TxBroadcaster,
@@ -25,33 +28,59 @@ use crate::{core::parse_cw_coins, keys::private::PrivateKey};
 use cosmrs::{
     bank::MsgSend,
     crypto::secp256k1::SigningKey,
-    proto::{cosmos::authz::v1beta1::MsgExec, traits::Message},
+    proto::{
+        cosmos::{
+            authz::v1beta1::MsgExec,
+            bank::v1beta1::{Input, MsgMultiSend, Output},
+            base::v1beta1::Coin as ProtoCoin,
+        },
+        traits::Message,
+    },
     tendermint::chain::Id,
-    tx::{self, ModeInfo, Msg, Raw, SignDoc, SignMode, SignerInfo},
+    tx::{self, AuthInfo, ModeInfo, Msg, Raw, SignDoc, SignMode, SignerInfo},
     AccountId, Any,
 };
-use cosmwasm_std::{coin, Coin};
+use cosmwasm_std::{coin, Coin, Uint128};
 use cw_orch_core::{
     environment::{ChainInfoOwned, ChainKind},
+    log::{local_target, transaction_target},
     CoreEnvVars, CwEnvError,
 };
 
 use crate::env::{LOCAL_MNEMONIC_ENV_NAME, MAIN_MNEMONIC_ENV_NAME, TEST_MNEMONIC_ENV_NAME};
 use bitcoin::secp256k1::{All, Secp256k1, Signing};
-use std::{str::FromStr, sync::Arc};
-
-use cosmos_modules::vesting::PeriodicVestingAccount;
+use prost::Name;
+use std::{collections::BTreeMap, str::FromStr, sync::Arc, time::Duration};
+
+use cosmos_modules::{
+    auth::ModuleAccount,
+    vesting::{
+        BaseVestingAccount, ContinuousVestingAccount, DelayedVestingAccount,
+        PeriodicVestingAccount, PermanentLockedAccount,
+    },
+};
+use tokio::sync::OnceCell;
 use tonic::transport::Channel;
 
-use super::{cosmos_options::CosmosWalletKey, query::QuerySender, tx::TxSender};
+use super::{
+    cosmos_options::{CosmosWalletKey, TimeoutHeight, TxOptions},
+    hooks::SenderHook,
+    query::QuerySender,
+    tx::TxSender,
+};
 
 const GAS_BUFFER: f64 = 1.3;
 const BUFFER_THRESHOLD: u64 = 200_000;
 const SMALL_GAS_BUFFER: f64 = 1.4;
+/// Interval between the checks of a chain halted for an upgrade
+const UPGRADE_POLL_INTERVAL: Duration = Duration::from_secs(5);
 
 /// A wallet is a sender of transactions, can be safely cloned and shared within the same thread.
 pub type Wallet = CosmosSender<All>;
 
+/// Number of blocks to wait for faucet funds before falling back to manual interaction
+const FAUCET_TOP_UP_MAX_BLOCKS: usize = 10;
+
 /// Signer of the transactions and helper for address derivation
 /// This is the main interface for simulating and signing transactions
 #[derive(Clone)]
@@ -63,6 +92,8 @@ pub struct CosmosSender<C: Signing + Clone> {
     pub chain_info: Arc<ChainInfoOwned>,
     pub(crate) options: CosmosOptions,
     pub secp: Secp256k1<C>,
+    /// Versions negotiated with the node, shared by the clones of the sender
+    pub(crate) negotiated_versions: Arc<OnceCell<ProtoVersions>>,
 }
 
 impl Wallet {
@@ -95,7 +126,8 @@ impl Wallet {
                 options.hd_index.unwrap_or(0),
                 chain_info.network_info.coin_type,
             )?,
-        };
+        }
+        .with_pub_key_type(chain_info.network_info.resolved_pub_key_type());
 
         // ensure address is valid
         AccountId::new(
@@ -103,12 +135,20 @@ impl Wallet {
             &pk.public_key(&secp).raw_address.unwrap(),
         )?;
 
+        let grpc_channel = GrpcChannel::from_chain_info(chain_info.as_ref()).await?;
+        check_address_prefix(
+            grpc_channel.clone(),
+            &chain_info.network_info.pub_address_prefix,
+        )
+        .await?;
+
         Ok(Self {
             chain_info: chain_info.clone(),
-            grpc_channel: GrpcChannel::from_chain_info(chain_info.as_ref()).await?,
+            grpc_channel,
             private_key: pk,
             secp,
             options,
+            negotiated_versions: Default::default(),
         })
     }
 
@@ -140,6 +180,131 @@ impl Wallet {
         self.options.fee_granter = Some(granter.into());
     }
 
+    /// Request funds from `faucet_url` when the wallet balance is too low to pay for a transaction
+    pub fn set_faucet_top_up(&mut self, faucet_url: impl Into<String>) {
+        self.options.faucet_url = Some(faucet_url.into());
+    }
+
+    /// Set the memo of the next transactions, used when the caller doesn't provide one
+    pub fn set_memo(&mut self, memo: impl Into<String>) {
+        self.options.memo = Some(memo.into());
+    }
+
+    /// Set the timeout height of the next transactions
+    pub fn set_timeout_height(&mut self, timeout_height: TimeoutHeight) {
+        self.options.timeout_height = timeout_height;
+    }
+
+    /// Clone of this wallet sending its transactions with the memo and timeout height of `tx_options`,
+    /// to use with `call_as` for a single call
+    pub fn with_tx_options(&self, tx_options: TxOptions) -> Self {
+        let mut wallet = self.clone();
+        wallet.options.apply_tx_options(tx_options);
+        wallet
+    }
+
+    /// Add a hook called around the next transactions, see [`SenderHook`]
+    pub fn add_hook(&mut self, hook: impl SenderHook + 'static) {
+        self.options.add_hook(hook);
+    }
+
+    /// Set the budget capping the fees and gas of the next transactions
+    pub fn set_budget(&mut self, budget: FeeBudget) {
+        self.options.budget = Some(budget);
+    }
+
+    /// Budget capping the fees and gas of the transactions, if any
+    pub fn budget(&self) -> Option<&FeeBudget> {
+        self.options.budget.as_ref()
+    }
+
+    /// Set how long to wait for the chain to resume when it halts for an upgrade, before failing the transaction
+    pub fn set_upgrade_grace_period(&mut self, grace_period: Duration) {
+        self.options.upgrade_grace_period = Some(grace_period);
+    }
+
+    /// Set the number of blocks the next transactions must be buried under before being returned, waiting at most `timeout` for them
+    pub fn set_confirmations(&mut self, confirmations: u64, timeout: Duration) {
+        self.options.set_confirmations(confirmations, timeout);
+    }
+
+    /// Waits for the chain to resume when `error` happened while it is halted for an upgrade, for at most the upgrade grace period.
+    /// Returns `error` if the chain isn't halted, or if the node can't tell,
+    /// and [`DaemonError::ChainHalted`] if no grace period is set or the chain doesn't resume in time.
+    /// Only errors a halted chain can cause are checked against the upgrade plan, other errors are returned right away.
+    async fn wait_for_upgrade(&self, error: DaemonError) -> Result<(), DaemonError> {
+        if !error.may_be_chain_halt() {
+            return Err(error);
+        }
+        let Ok(Some(plan)) = Upgrade::new_async(self.channel())._upgrade_halt().await else {
+            return Err(error);
+        };
+        let upgrade_height = plan.height as u64;
+        let halted = DaemonError::ChainHalted {
+            name: plan.name.clone(),
+            height: upgrade_height,
+        };
+        let Some(grace_period) = self.options.upgrade_grace_period else {
+            return Err(halted);
+        };
+
+        log::warn!(
+            target: &transaction_target(),
+            "Chain {} halted at height {upgrade_height} for the upgrade {}, waiting up to {:?} for it to resume",
+            self.chain_info.chain_id,
+            plan.name,
+            grace_period
+        );
+        let deadline = tokio::time::Instant::now() + grace_period;
+        let node = Node::new_async(self.channel());
+        while tokio::time::Instant::now() < deadline {
+            tokio::time::sleep(UPGRADE_POLL_INTERVAL.min(deadline - tokio::time::Instant::now()))
+                .await;
+            // The node can be unreachable while it restarts with the upgraded binary
+            if matches!(node._block_height().await, Ok(height) if resumed_after_upgrade(upgrade_height, height))
+            {
+                log::info!(
+                    target: &transaction_target(),
+                    "Chain {} resumed after the upgrade {}, retrying the transaction",
+                    self.chain_info.chain_id,
+                    plan.name
+                );
+                return Ok(());
+            }
+        }
+        Err(halted)
+    }
+
+    /// Cosmos SDK and wasmd versions of the chain, from the options or negotiated with the node on first use
+    pub async fn proto_versions(&self) -> Result<ProtoVersions, DaemonError> {
+        if let Some(proto_versions) = self.options.proto_versions {
+            return Ok(proto_versions);
+        }
+        self.negotiated_versions
+            .get_or_try_init(|| async {
+                let node_info = Node::new_async(self.channel())._info().await?;
+                let proto_versions = ProtoVersions::from_node_info(&node_info);
+                log::debug!(
+                    "Chain {} runs Cosmos SDK {} and wasmd {:?}",
+                    self.chain_info.chain_id,
+                    proto_versions.cosmos_sdk,
+                    proto_versions.wasmd
+                );
+                Ok::<_, DaemonError>(proto_versions)
+            })
+            .await
+            .copied()
+    }
+
+    /// Height after which the transaction can't be included in a block, 0 if it never times out
+    pub async fn timeout_height(&self) -> Result<u64, DaemonError> {
+        let current_height = match self.options.timeout_height {
+            TimeoutHeight::Relative(_) => Node::new_async(self.channel())._block_height().await?,
+            TimeoutHeight::Absolute(_) | TimeoutHeight::Disabled => 0,
+        };
+        Ok(self.options.timeout_height.height(current_height))
+    }
+
     pub fn pub_addr_str(&self) -> String {
         self.account_id().to_string()
     }
@@ -160,6 +325,18 @@ impl Wallet {
         Ok(commit)
     }
 
+    /// Requests the fee token of the chain for this wallet from the faucet at `faucet_url`.
+    /// The faucet needs to expose the CosmJS faucet API (`POST /credit`).
+    pub async fn request_faucet_funds(&self, faucet_url: &str) -> Result<(), DaemonError> {
+        log::info!(
+            "Requesting {} from faucet {} for address {}",
+            self.get_fee_token(),
+            faucet_url,
+            self.pub_addr_str()
+        );
+        credit_from_faucet(faucet_url, &self.pub_addr_str(), &self.get_fee_token()).await
+    }
+
     pub async fn bank_send(
         &self,
         recipient: &str,
@@ -180,6 +357,28 @@ impl Wallet {
         self.commit_tx(vec![msg_send], Some("sending tokens")).await
     }
 
+    /// Sends coins to many recipients in a single transaction, with a `MsgMultiSend`
+    pub async fn bank_multi_send(
+        &self,
+        sends: Vec<(String, Vec<cosmwasm_std::Coin>)>,
+    ) -> Result<CosmTxResponse, DaemonError> {
+        let from_address = self
+            .options
+            .authz_granter
+            .clone()
+            .unwrap_or_else(|| self.pub_addr_str());
+        let msg = multi_send(&from_address, &sends)?;
+
+        self.commit_tx_any(
+            vec![Any {
+                type_url: MsgMultiSend::type_url(),
+                value: msg.encode_to_vec(),
+            }],
+            Some("sending tokens"),
+        )
+        .await
+    }
+
     /// Computes the gas needed for submitting a transaction
     pub async fn calculate_gas(
         &self,
@@ -222,8 +421,12 @@ impl Wallet {
         msgs: Vec<Any>,
         memo: Option<&str>,
     ) -> Result<(u64, Coin), DaemonError> {
-        let timeout_height = Node::new_async(self.channel())._block_height().await? + 10u64;
+        let timeout_height = self.timeout_height().await?;
+        let memo = self.options.tx_memo(memo);
 
+        // Simulate the messages as they will be sent
+        let msgs = self.wrap_authz(msgs);
+        let msg_type_urls: Vec<String> = msgs.iter().map(|msg| msg.type_url.clone()).collect();
         let tx_body = TxBuilder::build_body(msgs, memo, timeout_height);
 
         let tx_builder = TxBuilder::new(tx_body);
@@ -235,7 +438,8 @@ impl Wallet {
         // During simulation, we also make sure the account has enough balance to submit the transaction
         // This is disabled by an env variable
         if DaemonEnvVars::wallet_balance_assertion() {
-            self.assert_wallet_balance(&expected_fee).await?;
+            self.assert_wallet_balance(&expected_fee, &msg_type_urls)
+                .await?;
         }
 
         Ok((gas_for_submission, expected_fee))
@@ -256,11 +460,11 @@ impl Wallet {
     }
 
     pub fn sign(&self, sign_doc: SignDoc) -> Result<Raw, DaemonError> {
-        let tx_raw = if self.private_key.coin_type == ETHEREUM_COIN_TYPE {
+        let tx_raw = if self.private_key.pub_key_type.is_ethereum() {
             #[cfg(not(feature = "eth"))]
             panic!(
-                "Coin Type {} not supported without eth feature",
-                ETHEREUM_COIN_TYPE
+                "Public key type {:?} not supported without eth feature",
+                self.private_key.pub_key_type
             );
             #[cfg(feature = "eth")]
             self.private_key.sign_injective(sign_doc)?
@@ -270,7 +474,40 @@ impl Wallet {
         Ok(tx_raw)
     }
 
-    pub async fn base_account(&self) -> Result<BaseAccount, DaemonError> {
+    /// Signs the transaction in `SIGN_MODE_LEGACY_AMINO_JSON`, see [`crate::amino`]
+    pub fn sign_amino(
+        &self,
+        body: &tx::Body,
+        auth_info: &AuthInfo,
+        account_number: u64,
+        sequence: u64,
+    ) -> Result<Raw, DaemonError> {
+        if self.private_key.pub_key_type.is_ethereum() {
+            return Err(DaemonError::StdErr(
+                "amino-json sign mode is not supported for ethereum keys".to_string(),
+            ));
+        }
+        let sign_doc = amino::std_sign_doc(
+            body,
+            &auth_info.fee,
+            &self.chain_info.chain_id,
+            account_number,
+            sequence,
+        )?;
+        let signature = self
+            .cosmos_private_key()
+            .sign(&amino::sign_bytes(&sign_doc)?)?;
+
+        Ok(cosmos_modules::tx::TxRaw {
+            body_bytes: body.clone().into_bytes()?,
+            auth_info_bytes: auth_info.clone().into_bytes()?,
+            signatures: vec![signature.to_vec()],
+        }
+        .into())
+    }
+
+    /// Account of the wallet as stored by the auth module
+    pub async fn account(&self) -> Result<Any, DaemonError> {
         let addr = self.address().to_string();
 
         let mut client = cosmos_modules::auth::query_client::QueryClient::new(self.channel());
@@ -280,43 +517,68 @@ impl Wallet {
             .await?
             .into_inner();
 
-        let account = resp.account.unwrap().value;
+        Ok(resp.account.unwrap())
+    }
 
-        let acc = if let Ok(acc) = BaseAccount::decode(account.as_ref()) {
-            acc
-        } else if let Ok(acc) = PeriodicVestingAccount::decode(account.as_ref()) {
+    pub async fn base_account(&self) -> Result<BaseAccount, DaemonError> {
+        let account = self.account().await?;
+        let value = account.value.as_slice();
+
+        let acc = if account.type_url == "/cosmos.auth.v1beta1.BaseAccount" {
+            Some(BaseAccount::decode(value)?)
+        } else if account.type_url == "/cosmos.auth.v1beta1.ModuleAccount" {
+            ModuleAccount::decode(value)?.base_account
+        } else if let Some(vesting) = base_vesting_account(&account)? {
+            vesting.base_account
+        } else if let Ok(acc) = BaseAccount::decode(value) {
+            Some(acc)
+        } else if let Ok(acc) = PeriodicVestingAccount::decode(value) {
             // try vesting account, (used by Terra2)
-            acc.base_vesting_account.unwrap().base_account.unwrap()
-        } else if let Ok(acc) = InjectiveEthAccount::decode(account.as_ref()) {
-            acc.base_account.unwrap()
+            acc.base_vesting_account.and_then(|acc| acc.base_account)
+        } else if let Ok(acc) = InjectiveEthAccount::decode(value) {
+            acc.base_account
         } else {
-            return Err(DaemonError::StdErr(
-                "Unknown account type returned from QueryAccountRequest".into(),
-            ));
+            None
         };
 
-        Ok(acc)
+        acc.ok_or(DaemonError::StdErr(
+            "Unknown account type returned from QueryAccountRequest".into(),
+        ))
     }
 
-    /// Allows for checking wether the sender is able to broadcast a transaction that necessitates the provided `gas`
+    /// Balance of `denom` the wallet can spend, excluding the coins still locked by a vesting schedule
+    pub async fn spendable_balance(&self, denom: impl Into<String>) -> Result<Coin, DaemonError> {
+        Bank::new_async(self.channel())
+            ._spendable_balance(self.address(), denom)
+            .await
+    }
+
+    /// Allows for checking wether the sender is able to broadcast a transaction that necessitates the provided `gas`.
+    /// The messages a fee grant is restricted to are not checked, as the messages of the transaction are unknown.
     pub async fn has_enough_balance_for_gas(&self, gas: u64) -> Result<(), DaemonError> {
         let (_gas_expected, fee_amount) = self.get_fee_from_gas(gas)?;
         let fee_denom = self.get_fee_token();
 
-        self.assert_wallet_balance(&coin(fee_amount, fee_denom))
+        self.assert_wallet_balance(&coin(fee_amount, fee_denom), &[])
             .await
     }
 
-    /// Allows checking wether the sender has more funds than the provided `fee` argument
+    /// Allows checking wether the sender has more funds than the provided `fee` argument,
+    /// to send a transaction with messages of the `msg_type_urls` types
     #[async_recursion::async_recursion(?Send)]
-    async fn assert_wallet_balance(&self, fee: &Coin) -> Result<(), DaemonError> {
+    async fn assert_wallet_balance(
+        &self,
+        fee: &Coin,
+        msg_type_urls: &[String],
+    ) -> Result<(), DaemonError> {
+        // The fees are paid by the fee granter
+        if let Some(granter) = &self.options.fee_granter {
+            return self.assert_fee_grant(granter, fee, msg_type_urls).await;
+        }
+
         let chain_info = self.chain_info.clone();
 
-        let bank = Bank::new_async(self.channel());
-        let balance = bank
-            ._balance(self.address(), Some(fee.denom.clone()))
-            .await?[0]
-            .clone();
+        let balance = self.spendable_balance(fee.denom.clone()).await?;
 
         log::debug!(
             "Checking balance {} on chain {}, address {}. Expecting {}{}",
@@ -332,6 +594,26 @@ impl Wallet {
             return Ok(());
         }
 
+        // If a faucet is available, we try to top up the wallet before asking the user
+        if let Some(faucet_url) = &self.options.faucet_url {
+            self.request_faucet_funds(faucet_url).await?;
+            let block_speed = Node::new_async(self.channel())
+                ._average_block_speed(None)
+                .await?;
+            for _ in 0..FAUCET_TOP_UP_MAX_BLOCKS {
+                tokio::time::sleep(block_speed).await;
+                let balance = self.spendable_balance(fee.denom.clone()).await?;
+                if balance.amount >= fee.amount {
+                    log::info!("Wallet topped up by the faucet, new balance: {}", balance);
+                    return Ok(());
+                }
+            }
+            log::warn!(
+                "Faucet funds didn't arrive after {} blocks",
+                FAUCET_TOP_UP_MAX_BLOCKS
+            );
+        }
+
         // If there is not enough asset balance, we need to warn the user
         println!(
             "Not enough funds on chain {} at address {} to deploy the contract. 
@@ -349,7 +631,7 @@ impl Wallet {
             std::io::stdin().read_line(&mut input)?;
             if input.to_lowercase().contains('y') {
                 // We retry asserting the balance
-                self.assert_wallet_balance(fee).await
+                self.assert_wallet_balance(fee, msg_type_urls).await
             } else {
                 Err(DaemonError::NotEnoughBalance {
                     expected: fee.clone(),
@@ -365,6 +647,62 @@ impl Wallet {
         }
     }
 
+    /// Checks that `granter` granted an allowance to this wallet covering `fee` for messages of the `msg_type_urls` types
+    /// and has the funds to pay it
+    async fn assert_fee_grant(
+        &self,
+        granter: &str,
+        fee: &Coin,
+        msg_type_urls: &[String],
+    ) -> Result<(), DaemonError> {
+        let insufficient = |reason: String| DaemonError::FeeGrantInsufficient {
+            granter: granter.to_string(),
+            fee: fee.clone(),
+            reason,
+        };
+
+        let grant = FeeGrant::new_async(self.channel())
+            ._allowance(granter, self.pub_addr_str())
+            .await
+            .map_err(|e| {
+                insufficient(format!("no allowance granted to {}: {e}", self.address()))
+            })?;
+        let allowance = grant
+            .allowance
+            .ok_or_else(|| insufficient("empty allowance".to_string()))?;
+        if let Some(limit) =
+            allowance_spend_limit(&allowance, &fee.denom, msg_type_urls).map_err(insufficient)?
+        {
+            if limit < fee.amount.u128() {
+                return Err(insufficient(format!(
+                    "the allowance only has {limit}{} left",
+                    fee.denom
+                )));
+            }
+        }
+
+        let balance = Bank::new_async(self.channel())
+            ._balance(granter, Some(fee.denom.clone()))
+            .await?
+            .into_iter()
+            .find(|c| c.denom == fee.denom)
+            .unwrap_or_else(|| coin(0, &fee.denom));
+        log::debug!(
+            "Checking fee granter balance {} on chain {}, address {}. Expecting {}",
+            balance,
+            self.chain_info.chain_id,
+            granter,
+            fee
+        );
+        if balance.amount < fee.amount {
+            return Err(DaemonError::NotEnoughBalance {
+                expected: fee.clone(),
+                current: balance,
+            });
+        }
+        Ok(())
+    }
+
     pub(crate) fn get_fee_token(&self) -> String {
         self.chain_info.gas_denom.to_string()
     }
@@ -373,24 +711,65 @@ impl Wallet {
         SigningKey::from_slice(&self.private_key.raw_key()).unwrap()
     }
 
+    /// Wraps the messages in an authz `MsgExec` if an authz granter is set
+    fn wrap_authz(&self, msgs: Vec<Any>) -> Vec<Any> {
+        if self.options.authz_granter.is_some() {
+            vec![Any {
+                type_url: MsgExec::type_url(),
+                value: MsgExec {
+                    grantee: self.pub_addr_str(),
+                    msgs,
+                }
+                .encode_to_vec(),
+            }]
+        } else {
+            msgs
+        }
+    }
+
     /// Compute the gas fee from the expected gas in the transaction
     /// Applies a Gas Buffer for including signature verification
     pub(crate) fn get_fee_from_gas(&self, gas: u64) -> Result<(u64, u128), DaemonError> {
-        let mut gas_expected = if let Some(gas_buffer) = DaemonEnvVars::gas_buffer() {
-            gas as f64 * gas_buffer
-        } else if gas < BUFFER_THRESHOLD {
-            gas as f64 * SMALL_GAS_BUFFER
-        } else {
-            gas as f64 * GAS_BUFFER
-        };
+        Ok(fee_from_gas(gas, self.chain_info.gas_price))
+    }
+}
 
-        let min_gas = DaemonEnvVars::min_gas();
-        gas_expected = (min_gas as f64).max(gas_expected);
+/// Requests `denom` for `address` from a faucet exposing the CosmJS faucet API
+async fn credit_from_faucet(
+    faucet_url: &str,
+    address: &str,
+    denom: &str,
+) -> Result<(), DaemonError> {
+    let body = serde_json::json!({
+        "address": address,
+        "denom": denom,
+    });
+    reqwest::Client::new()
+        .post(format!("{}/credit", faucet_url.trim_end_matches('/')))
+        .header(reqwest::header::CONTENT_TYPE, "application/json")
+        .body(body.to_string())
+        .send()
+        .await?
+        .error_for_status()?;
+    Ok(())
+}
 
-        let fee_amount = gas_expected * (self.chain_info.gas_price + 0.00001);
+/// Gas limit and fee amount of a transaction using `gas`, with the gas buffer applied
+pub(crate) fn fee_from_gas(gas: u64, gas_price: f64) -> (u64, u128) {
+    let mut gas_expected = if let Some(gas_buffer) = DaemonEnvVars::gas_buffer() {
+        gas as f64 * gas_buffer
+    } else if gas < BUFFER_THRESHOLD {
+        gas as f64 * SMALL_GAS_BUFFER
+    } else {
+        gas as f64 * GAS_BUFFER
+    };
 
-        Ok((gas_expected as u64, fee_amount as u128))
-    }
+    let min_gas = DaemonEnvVars::min_gas();
+    gas_expected = (min_gas as f64).max(gas_expected);
+
+    let fee_amount = gas_expected * (gas_price + 0.00001);
+
+    (gas_expected as u64, fee_amount as u128)
 }
 
 impl QuerySender for Wallet {
@@ -408,41 +787,75 @@ impl TxSender for Wallet {
         msgs: Vec<Any>,
         memo: Option<&str>,
     ) -> Result<CosmTxResponse, DaemonError> {
-        let timeout_height = Node::new_async(self.channel())._block_height().await? + 10u64;
-
-        let msgs = if self.options.authz_granter.is_some() {
-            // We wrap authz messages
-            vec![Any {
-                type_url: "/cosmos.authz.v1beta1.MsgExec".to_string(),
-                value: MsgExec {
-                    grantee: self.pub_addr_str(),
-                    msgs,
+        let memo = self.options.tx_memo(memo);
+
+        let msgs = self.wrap_authz(msgs);
+
+        // A transaction failing because the chain halted for an upgrade is sent again once the chain resumes
+        let mut resp = loop {
+            let timeout_height = self.timeout_height().await?;
+            let tx_body = TxBuilder::build_body(msgs.clone(), memo, timeout_height);
+            let tx_builder = TxBuilder::new(tx_body);
+
+            // We retry broadcasting the tx, with the following strategies
+            // 1. In case there is an `incorrect account sequence` error, we can retry as much as possible (doesn't cost anything to the user)
+            // 2. In case there is an insufficient_fee error, we retry once (costs fee to the user everytime we submit this kind of tx)
+            // 3. In case there is an other error, we fail
+            let tx_response = match TxBroadcaster::default()
+                .add_strategy(insufficient_fee_strategy())
+                .add_strategy(account_sequence_strategy())
+                .broadcast(tx_builder, self)
+                .await
+            {
+                Ok(tx_response) => tx_response,
+                Err(e) => {
+                    self.wait_for_upgrade(e).await?;
+                    continue;
                 }
-                .encode_to_vec(),
-            }]
-        } else {
-            msgs
+            };
+
+            let node = Node::new_async(self.channel());
+            let confirmed_tx = match self.options.confirmation_timeout {
+                Some(timeout) => {
+                    node._wait_for_tx(
+                        tx_response.txhash.clone(),
+                        self.options.confirmations,
+                        timeout,
+                    )
+                    .await
+                }
+                None => {
+                    node._find_confirmed_tx(tx_response.txhash.clone(), self.options.confirmations)
+                        .await
+                }
+            };
+            match confirmed_tx {
+                Ok(resp) => break resp,
+                Err(e) => {
+                    self.wait_for_upgrade(e).await?;
+                    // The transaction can have been included right before the halt
+                    if let Ok(resp) = node._find_tx_with_retries(tx_response.txhash, 1).await {
+                        break resp;
+                    }
+                }
+            }
         };
+        self.proto_versions().await?.normalize(&mut resp);
+        for hook in &self.options.hooks {
+            hook.on_after_broadcast(&msgs, &resp);
+        }
+        trace::trace_tx(self.channel(), &self.chain_info.chain_id, &resp.txhash).await;
 
-        let tx_body = TxBuilder::build_body(msgs, memo, timeout_height);
-
-        let tx_builder = TxBuilder::new(tx_body);
+        assert_broadcast_code_cosm_response(resp)
+    }
 
-        // We retry broadcasting the tx, with the following strategies
-        // 1. In case there is an `incorrect account sequence` error, we can retry as much as possible (doesn't cost anything to the user)
-        // 2. In case there is an insufficient_fee error, we retry once (costs fee to the user everytime we submit this kind of tx)
-        // 3. In case there is an other error, we fail
-        let tx_response = TxBroadcaster::default()
-            .add_strategy(insufficient_fee_strategy())
-            .add_strategy(account_sequence_strategy())
-            .broadcast(tx_builder, self)
-            .await?;
+    async fn simulate_any(&self, msgs: Vec<Any>, memo: Option<&str>) -> Result<u64, DaemonError> {
+        let timeout_height = self.timeout_height().await?;
+        let memo = self.options.tx_memo(memo);
 
-        let resp = Node::new_async(self.channel())
-            ._find_tx(tx_response.txhash)
-            .await?;
+        let tx_body = TxBuilder::build_body(self.wrap_authz(msgs), memo, timeout_height);
 
-        assert_broadcast_code_cosm_response(resp)
+        TxBuilder::new(tx_body).simulate(self).await
     }
 
     fn account_id(&self) -> AccountId {
@@ -455,6 +868,161 @@ impl TxSender for Wallet {
     }
 }
 
+/// Checks that `prefix` is the bech32 prefix of the chain, so that the derived addresses are the ones of the chain.
+/// Nodes running a Cosmos SDK older than 0.46 don't expose their prefix, the check is skipped for them.
+async fn check_address_prefix(channel: Channel, prefix: &str) -> Result<(), DaemonError> {
+    let mut client = cosmos_modules::auth::query_client::QueryClient::new(channel);
+    match client
+        .bech32_prefix(cosmos_modules::auth::Bech32PrefixRequest {})
+        .await
+    {
+        Ok(resp) => {
+            let chain_prefix = resp.into_inner().bech32_prefix;
+            if chain_prefix.is_empty() || chain_prefix == prefix {
+                Ok(())
+            } else {
+                Err(DaemonError::AddressPrefixMismatch {
+                    expected: chain_prefix,
+                    actual: prefix.to_string(),
+                })
+            }
+        }
+        Err(e) => {
+            log::debug!(target: &local_target(), "Could not check the address prefix {prefix}: {e}");
+            Ok(())
+        }
+    }
+}
+
+/// Vesting part of `account`, `None` if it's not a vesting account
+fn base_vesting_account(account: &Any) -> Result<Option<BaseVestingAccount>, DaemonError> {
+    let value = account.value.as_slice();
+    let vesting = if account.type_url == "/cosmos.vesting.v1beta1.ContinuousVestingAccount" {
+        ContinuousVestingAccount::decode(value)?.base_vesting_account
+    } else if account.type_url == "/cosmos.vesting.v1beta1.DelayedVestingAccount" {
+        DelayedVestingAccount::decode(value)?.base_vesting_account
+    } else if account.type_url == "/cosmos.vesting.v1beta1.PeriodicVestingAccount" {
+        PeriodicVestingAccount::decode(value)?.base_vesting_account
+    } else if account.type_url == "/cosmos.vesting.v1beta1.PermanentLockedAccount" {
+        PermanentLockedAccount::decode(value)?.base_vesting_account
+    } else {
+        None
+    };
+    Ok(vesting)
+}
+
+/// `MsgMultiSend` from `from_address` to the recipients, with the coins of each recipient merged and sorted by denom
+fn multi_send(
+    from_address: &str,
+    sends: &[(String, Vec<Coin>)],
+) -> Result<MsgMultiSend, DaemonError> {
+    let merge = |coins: &mut BTreeMap<String, Uint128>, new: &[Coin]| {
+        for c in new.iter().filter(|c| !c.amount.is_zero()) {
+            *coins.entry(c.denom.clone()).or_default() += c.amount;
+        }
+    };
+    let to_proto = |coins: BTreeMap<String, Uint128>| {
+        coins
+            .into_iter()
+            .map(|(denom, amount)| ProtoCoin {
+                denom,
+                amount: amount.to_string(),
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let mut total = BTreeMap::new();
+    let mut outputs = vec![];
+    for (recipient, coins) in sends {
+        AccountId::from_str(recipient)?;
+        let mut output = BTreeMap::new();
+        merge(&mut output, coins);
+        merge(&mut total, coins);
+        if !output.is_empty() {
+            outputs.push(Output {
+                address: recipient.clone(),
+                coins: to_proto(output),
+            });
+        }
+    }
+    if outputs.is_empty() {
+        return Err(DaemonError::StdErr(
+            "a multi-send needs at least one recipient with coins".to_string(),
+        ));
+    }
+
+    Ok(MsgMultiSend {
+        inputs: vec![Input {
+            address: from_address.to_string(),
+            coins: to_proto(total),
+        }],
+        outputs,
+    })
+}
+
+/// Amount of `denom` a fee allowance can still spend for messages of the `msg_type_urls` types, `None` if it's unlimited.
+/// Errors if the allowance expired or doesn't allow one of the messages.
+fn allowance_spend_limit(
+    allowance: &Any,
+    denom: &str,
+    msg_type_urls: &[String],
+) -> Result<Option<u128>, String> {
+    use cosmos_modules::feegrant::{AllowedMsgAllowance, BasicAllowance, PeriodicAllowance};
+
+    let coin_limit = |coins: &[cosmrs::proto::cosmos::base::v1beta1::Coin]| {
+        if coins.is_empty() {
+            return Ok(None);
+        }
+        coins
+            .iter()
+            .find(|c| c.denom == denom)
+            .map_or(Ok(0), |c| c.amount.parse::<u128>())
+            .map(Some)
+            .map_err(|e| e.to_string())
+    };
+    let basic_limit = |basic: &BasicAllowance| {
+        if let Some(expiration) = &basic.expiration {
+            if expiration.seconds <= chrono::Utc::now().timestamp() {
+                return Err("the allowance expired".to_string());
+            }
+        }
+        coin_limit(&basic.spend_limit)
+    };
+
+    if allowance.type_url == BasicAllowance::type_url() {
+        let basic =
+            BasicAllowance::decode(allowance.value.as_slice()).map_err(|e| e.to_string())?;
+        basic_limit(&basic)
+    } else if allowance.type_url == PeriodicAllowance::type_url() {
+        let periodic =
+            PeriodicAllowance::decode(allowance.value.as_slice()).map_err(|e| e.to_string())?;
+        let basic = periodic.basic.as_ref().map_or(Ok(None), basic_limit)?;
+        let period = coin_limit(&periodic.period_can_spend)?;
+        Ok(match (basic, period) {
+            (Some(basic), Some(period)) => Some(basic.min(period)),
+            (basic, period) => basic.or(period),
+        })
+    } else if allowance.type_url == AllowedMsgAllowance::type_url() {
+        let allowed =
+            AllowedMsgAllowance::decode(allowance.value.as_slice()).map_err(|e| e.to_string())?;
+        if let Some(msg) = msg_type_urls
+            .iter()
+            .find(|msg| !allowed.allowed_messages.contains(msg))
+        {
+            return Err(format!("the allowance doesn't allow {msg} messages"));
+        }
+        allowed.allowance.as_ref().map_or(Ok(None), |inner| {
+            allowance_spend_limit(inner, denom, msg_type_urls)
+        })
+    } else {
+        log::debug!(
+            "Unknown fee allowance {}, assuming it can pay the fee",
+            allowance.type_url
+        );
+        Ok(None)
+    }
+}
+
 fn get_mnemonic_env(chain_kind: &ChainKind) -> Result<String, CwEnvError> {
     match chain_kind {
         ChainKind::Local => DaemonEnvVars::local_mnemonic(),
@@ -475,3 +1043,189 @@ fn get_mnemonic_env_name(chain_kind: &ChainKind) -> &str {
         _ => panic!("Can't set mnemonic for unspecified chainkind"),
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use cosmos_modules::feegrant::{AllowedMsgAllowance, BasicAllowance, PeriodicAllowance};
+
+    fn any(type_url: &str, msg: impl Message) -> Any {
+        Any {
+            type_url: type_url.to_string(),
+            value: msg.encode_to_vec(),
+        }
+    }
+
+    fn coins(amount: u128) -> Vec<ProtoCoin> {
+        vec![ProtoCoin {
+            denom: "ujuno".to_string(),
+            amount: amount.to_string(),
+        }]
+    }
+
+    #[test]
+    fn fee_allowance_limits() {
+        let unlimited = any(&BasicAllowance::type_url(), BasicAllowance::default());
+        assert_eq!(allowance_spend_limit(&unlimited, "ujuno", &[]), Ok(None));
+
+        let basic = BasicAllowance {
+            spend_limit: coins(100),
+            expiration: None,
+        };
+        let basic = any(&BasicAllowance::type_url(), basic);
+        assert_eq!(allowance_spend_limit(&basic, "ujuno", &[]), Ok(Some(100)));
+        assert_eq!(allowance_spend_limit(&basic, "uatom", &[]), Ok(Some(0)));
+
+        let expired = BasicAllowance {
+            spend_limit: vec![],
+            // Expired at the unix epoch
+            expiration: Some(Default::default()),
+        };
+        assert!(
+            allowance_spend_limit(&any(&BasicAllowance::type_url(), expired), "ujuno", &[])
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn allowed_msg_allowance_limits() {
+        let periodic = PeriodicAllowance {
+            basic: Some(BasicAllowance {
+                spend_limit: coins(100),
+                expiration: None,
+            }),
+            period_can_spend: coins(30),
+            ..Default::default()
+        };
+        let allowed = any(
+            &AllowedMsgAllowance::type_url(),
+            AllowedMsgAllowance {
+                allowance: Some(any(&PeriodicAllowance::type_url(), periodic)),
+                allowed_messages: vec![MsgExec::type_url()],
+            },
+        );
+        assert_eq!(
+            allowance_spend_limit(&allowed, "ujuno", &[MsgExec::type_url()]),
+            Ok(Some(30))
+        );
+        assert_eq!(
+            allowance_spend_limit(
+                &allowed,
+                "ujuno",
+                &[MsgExec::type_url(), MsgMultiSend::type_url()]
+            ),
+            Err(format!(
+                "the allowance doesn't allow {} messages",
+                MsgMultiSend::type_url()
+            ))
+        );
+    }
+
+    #[test]
+    fn vesting_account_base_account() {
+        let base_vesting = BaseVestingAccount {
+            base_account: Some(BaseAccount {
+                account_number: 42,
+                ..Default::default()
+            }),
+            original_vesting: coins(100),
+            end_time: 200,
+            ..Default::default()
+        };
+        let continuous = any(
+            "/cosmos.vesting.v1beta1.ContinuousVestingAccount",
+            ContinuousVestingAccount {
+                base_vesting_account: Some(base_vesting.clone()),
+                start_time: 100,
+            },
+        );
+        assert_eq!(
+            base_vesting_account(&continuous).unwrap(),
+            Some(base_vesting)
+        );
+
+        let base = any("/cosmos.auth.v1beta1.BaseAccount", BaseAccount::default());
+        assert_eq!(base_vesting_account(&base).unwrap(), None);
+    }
+
+    #[test]
+    fn multi_send_merges_coins() {
+        let recipient_a = "juno1qyqszqgpqyqszqgpqyqszqgpqyqszqgpypz92q".to_string();
+        let recipient_b = "juno1qgpqyqszqgpqyqszqgpqyqszqgpqyqsz49yqpk".to_string();
+        let msg = multi_send(
+            "juno1sender",
+            &[
+                (
+                    recipient_a.clone(),
+                    vec![coin(10, "uosmo"), coin(5, "ujuno"), coin(5, "ujuno")],
+                ),
+                (recipient_b.clone(), vec![coin(0, "ujuno")]),
+                (recipient_b.clone(), vec![coin(20, "ujuno")]),
+            ],
+        )
+        .unwrap();
+
+        assert_eq!(
+            msg.inputs[0].coins,
+            vec![proto_coin(30, "ujuno"), proto_coin(10, "uosmo")]
+        );
+        assert_eq!(msg.outputs.len(), 2);
+        assert_eq!(
+            msg.outputs[0].coins,
+            vec![proto_coin(10, "ujuno"), proto_coin(10, "uosmo")]
+        );
+        assert_eq!(msg.outputs[1].address, recipient_b);
+
+        assert!(multi_send("juno1sender", &[(recipient_a, vec![])]).is_err());
+        assert!(multi_send(
+            "juno1sender",
+            &[("invalid".to_string(), vec![coin(1, "ujuno")])]
+        )
+        .is_err());
+    }
+
+    fn proto_coin(amount: u128, denom: &str) -> ProtoCoin {
+        ProtoCoin {
+            denom: denom.to_string(),
+            amount: amount.to_string(),
+        }
+    }
+
+    /// Serves a single HTTP request with `status`, returning the raw request
+    fn faucet_server(status: &'static str) -> (String, std::thread::JoinHandle<String>) {
+        use std::io::{Read, Write};
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/", listener.local_addr().unwrap());
+        let handle = std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = vec![];
+            let mut buf = [0; 1024];
+            // The JSON body is the last part of the request
+            while !request.ends_with(b"}") {
+                let read = stream.read(&mut buf).unwrap();
+                request.extend_from_slice(&buf[..read]);
+            }
+            write!(stream, "HTTP/1.1 {status}\r\nContent-Length: 0\r\n\r\n").unwrap();
+            String::from_utf8(request).unwrap()
+        });
+        (url, handle)
+    }
+
+    #[tokio::test]
+    async fn faucet_credit_request() {
+        let (url, server) = faucet_server("200 OK");
+        credit_from_faucet(&url, "juno1address", "ujunox")
+            .await
+            .unwrap();
+        let request = server.join().unwrap();
+        assert!(request.starts_with("POST /credit HTTP/1.1"));
+        assert!(request.ends_with(r#"{"address":"juno1address","denom":"ujunox"}"#));
+
+        let (url, server) = faucet_server("429 Too Many Requests");
+        assert!(credit_from_faucet(&url, "juno1address", "ujunox")
+            .await
+            .is_err());
+        server.join().unwrap();
+    }
+}
@@ -125,6 +125,10 @@ impl TxSender for CosmosBatchSender {
         }
     }
 
+    async fn simulate_any(&self, msgs: Vec<Any>, memo: Option<&str>) -> Result<u64, DaemonError> {
+        self.sender.simulate_any(msgs, memo).await
+    }
+
     fn address(&self) -> Addr {
         self.sender.address()
     }
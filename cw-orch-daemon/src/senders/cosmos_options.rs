@@ -1,11 +1,11 @@
-use std::{str::FromStr, sync::Arc};
+use std::{str::FromStr, sync::Arc, time::Duration};
 
 use cosmrs::AccountId;
 use cw_orch_core::environment::ChainInfoOwned;
 
-use crate::{DaemonError, Wallet};
+use crate::{budget::FeeBudget, proto_versions::ProtoVersions, DaemonError, Wallet};
 
-use super::{builder::SenderBuilder, CosmosSender};
+use super::{builder::SenderBuilder, hooks::SenderHook, CosmosSender};
 
 /// Options for how txs should be constructed for this sender.
 #[derive(Default, Clone)]
@@ -14,10 +14,95 @@ pub struct CosmosOptions {
     pub authz_granter: Option<String>,
     pub fee_granter: Option<String>,
     pub hd_index: Option<u32>,
+    /// Faucet exposing the CosmJS faucet API (`POST /credit`),
+    /// funds are requested from it when the wallet balance is too low to pay for a transaction
+    pub faucet_url: Option<String>,
+    /// Memo of the transactions, when none is provided by the caller
+    pub memo: Option<String>,
+    /// Height after which the transactions can't be included in a block anymore
+    pub timeout_height: TimeoutHeight,
+    /// Sign mode of the transactions
+    pub sign_mode: SignMode,
+    /// Maximum fee and gas the transactions can spend
+    pub budget: Option<FeeBudget>,
+    /// Cosmos SDK and wasmd versions of the chain, negotiated with the node if not set
+    pub proto_versions: Option<ProtoVersions>,
+    /// Hooks called around the signature and broadcast of the transactions
+    pub hooks: Vec<Arc<dyn SenderHook>>,
+    /// How long to wait for the chain to resume when it halts for an upgrade, the transactions fail right away if not set
+    pub upgrade_grace_period: Option<Duration>,
+    /// Number of blocks built on top of the block including a transaction before it is returned, `0` returns it once included
+    pub confirmations: u64,
+    /// How long to wait for the confirmations of a transaction, only bounded by the tx query retries if not set
+    pub confirmation_timeout: Option<Duration>,
     /// Used to derive the private key
     pub(crate) key: CosmosWalletKey,
 }
 
+/// Timeout height of the transactions
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TimeoutHeight {
+    /// Number of blocks after the current height
+    Relative(u64),
+    /// Fixed block height
+    Absolute(u64),
+    /// Transactions never time out
+    Disabled,
+}
+
+impl Default for TimeoutHeight {
+    fn default() -> Self {
+        TimeoutHeight::Relative(DEFAULT_TIMEOUT_BLOCKS)
+    }
+}
+
+impl TimeoutHeight {
+    /// Timeout height of a transaction sent at `current_height`, 0 if it never times out
+    pub fn height(self, current_height: u64) -> u64 {
+        match self {
+            TimeoutHeight::Relative(blocks) => current_height + blocks,
+            TimeoutHeight::Absolute(height) => height,
+            TimeoutHeight::Disabled => 0,
+        }
+    }
+}
+
+/// Memo and timeout height overriding the [`CosmosOptions`] of a sender for some calls,
+/// see [`Wallet::with_tx_options`] and [`DaemonBase::with_tx_options`](crate::DaemonBase::with_tx_options)
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TxOptions {
+    /// Memo of the transactions, the memo of the sender is used if not set
+    pub memo: Option<String>,
+    /// Timeout height of the transactions, the timeout height of the sender is used if not set
+    pub timeout_height: Option<TimeoutHeight>,
+}
+
+impl TxOptions {
+    pub fn memo(mut self, memo: impl ToString) -> Self {
+        self.memo = Some(memo.to_string());
+        self
+    }
+
+    pub fn timeout_height(mut self, timeout_height: TimeoutHeight) -> Self {
+        self.timeout_height = Some(timeout_height);
+        self
+    }
+}
+
+/// Default number of blocks a transaction can wait before being included
+pub const DEFAULT_TIMEOUT_BLOCKS: u64 = 10;
+
+/// How the transactions are signed
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SignMode {
+    /// Sign the protobuf encoded transaction
+    #[default]
+    Direct,
+    /// Sign the Amino-JSON sign doc of the transaction, needed by Ledger devices and some older chains.
+    /// Only bank sends and wasm messages are supported, see [`crate::amino`]
+    LegacyAminoJson,
+}
+
 #[derive(Default, Clone)]
 pub enum CosmosWalletKey {
     Mnemonic(String),
@@ -39,6 +124,21 @@ impl CosmosOptions {
         Ok(())
     }
 
+    /// Memo of a transaction, the memo passed by the caller takes precedence over the one of the options
+    pub(crate) fn tx_memo<'a>(&'a self, memo: Option<&'a str>) -> Option<&'a str> {
+        memo.or(self.memo.as_deref())
+    }
+
+    /// Overrides the memo and timeout height set in `tx_options`
+    pub(crate) fn apply_tx_options(&mut self, tx_options: TxOptions) {
+        if let Some(memo) = tx_options.memo {
+            self.memo = Some(memo);
+        }
+        if let Some(timeout_height) = tx_options.timeout_height {
+            self.timeout_height = timeout_height;
+        }
+    }
+
     pub fn authz_granter(mut self, granter: impl ToString) -> Self {
         self.authz_granter = Some(granter.to_string());
         self
@@ -54,6 +154,54 @@ impl CosmosOptions {
         self
     }
 
+    pub fn faucet_top_up(mut self, faucet_url: impl Into<String>) -> Self {
+        self.faucet_url = Some(faucet_url.into());
+        self
+    }
+
+    pub fn memo(mut self, memo: impl ToString) -> Self {
+        self.memo = Some(memo.to_string());
+        self
+    }
+
+    pub fn timeout_height(mut self, timeout_height: TimeoutHeight) -> Self {
+        self.timeout_height = timeout_height;
+        self
+    }
+
+    pub fn sign_mode(mut self, sign_mode: SignMode) -> Self {
+        self.sign_mode = sign_mode;
+        self
+    }
+
+    pub fn budget(mut self, budget: FeeBudget) -> Self {
+        self.budget = Some(budget);
+        self
+    }
+
+    pub fn proto_versions(mut self, proto_versions: ProtoVersions) -> Self {
+        self.proto_versions = Some(proto_versions);
+        self
+    }
+
+    /// Adds a hook called around the transactions, after the ones already added
+    pub fn hook(mut self, hook: impl SenderHook + 'static) -> Self {
+        self.hooks.push(Arc::new(hook));
+        self
+    }
+
+    pub fn upgrade_grace_period(mut self, grace_period: Duration) -> Self {
+        self.upgrade_grace_period = Some(grace_period);
+        self
+    }
+
+    /// Waits for the transactions to be buried under `confirmations` blocks, for at most `timeout`
+    pub fn confirmations(mut self, confirmations: u64, timeout: Duration) -> Self {
+        self.confirmations = confirmations;
+        self.confirmation_timeout = Some(timeout);
+        self
+    }
+
     pub fn mnemonic(mut self, mnemonic: impl ToString) -> Self {
         self.key = CosmosWalletKey::Mnemonic(mnemonic.to_string());
         self
@@ -71,6 +219,43 @@ impl CosmosOptions {
         self.hd_index = Some(index);
     }
 
+    pub fn set_faucet_top_up(&mut self, faucet_url: impl Into<String>) {
+        self.faucet_url = Some(faucet_url.into());
+    }
+
+    pub fn set_memo(&mut self, memo: impl ToString) {
+        self.memo = Some(memo.to_string());
+    }
+
+    pub fn set_timeout_height(&mut self, timeout_height: TimeoutHeight) {
+        self.timeout_height = timeout_height;
+    }
+
+    pub fn set_sign_mode(&mut self, sign_mode: SignMode) {
+        self.sign_mode = sign_mode;
+    }
+
+    pub fn set_budget(&mut self, budget: FeeBudget) {
+        self.budget = Some(budget);
+    }
+
+    pub fn set_proto_versions(&mut self, proto_versions: ProtoVersions) {
+        self.proto_versions = Some(proto_versions);
+    }
+
+    pub fn add_hook(&mut self, hook: impl SenderHook + 'static) {
+        self.hooks.push(Arc::new(hook));
+    }
+
+    pub fn set_upgrade_grace_period(&mut self, grace_period: Duration) {
+        self.upgrade_grace_period = Some(grace_period);
+    }
+
+    pub fn set_confirmations(&mut self, confirmations: u64, timeout: Duration) {
+        self.confirmations = confirmations;
+        self.confirmation_timeout = Some(timeout);
+    }
+
     pub fn set_mnemonic(&mut self, mnemonic: impl ToString) {
         self.key = CosmosWalletKey::Mnemonic(mnemonic.to_string());
     }
@@ -84,3 +269,51 @@ impl SenderBuilder for CosmosOptions {
         CosmosSender::new(chain_info, self.clone()).await
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn timeout_height_resolution() {
+        assert_eq!(TimeoutHeight::Relative(10).height(100), 110);
+        assert_eq!(
+            TimeoutHeight::default().height(100),
+            100 + DEFAULT_TIMEOUT_BLOCKS
+        );
+        assert_eq!(TimeoutHeight::Absolute(150).height(100), 150);
+        assert_eq!(TimeoutHeight::Disabled.height(100), 0);
+    }
+
+    #[test]
+    fn memo_precedence() {
+        let options = CosmosOptions::default();
+        assert_eq!(options.tx_memo(None), None);
+        assert_eq!(options.tx_memo(Some("call")), Some("call"));
+
+        let options = CosmosOptions::default().memo("options");
+        assert_eq!(options.tx_memo(None), Some("options"));
+        assert_eq!(options.tx_memo(Some("call")), Some("call"));
+    }
+
+    #[test]
+    fn tx_options_override() {
+        let mut options = CosmosOptions::default()
+            .memo("options")
+            .timeout_height(TimeoutHeight::Absolute(150));
+
+        // Unset tx options keep the options of the sender
+        options.apply_tx_options(TxOptions::default());
+        assert_eq!(options.tx_memo(None), Some("options"));
+        assert_eq!(options.timeout_height, TimeoutHeight::Absolute(150));
+
+        options.apply_tx_options(
+            TxOptions::default()
+                .memo("exchange-memo")
+                .timeout_height(TimeoutHeight::Disabled),
+        );
+        assert_eq!(options.tx_memo(None), Some("exchange-memo"));
+        assert_eq!(options.tx_memo(Some("call")), Some("call"));
+        assert_eq!(options.timeout_height, TimeoutHeight::Disabled);
+    }
+}
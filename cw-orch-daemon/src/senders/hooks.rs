@@ -0,0 +1,288 @@
+//! Policy checks and audit sinks plugged into the signing flow of a [`Wallet`](crate::Wallet), see [`SenderHook`].
+
+use std::{
+    fs::OpenOptions,
+    io::Write,
+    path::PathBuf,
+    sync::{Arc, Mutex},
+};
+
+use cosmrs::{
+    proto::{
+        cosmos::base::v1beta1::Coin as ProtoCoin,
+        cosmwasm::wasm::v1::{MsgExecuteContract, MsgInstantiateContract, MsgInstantiateContract2},
+        ibc::applications::transfer::v1::MsgTransfer,
+    },
+    AccountId, Any,
+};
+use cosmwasm_std::Coin;
+use cw_orch_core::{log::transaction_target, CoreEnvVars};
+use prost::{Message, Name};
+use serde_json::json;
+
+use crate::{
+    cosmos_modules::{
+        authz::MsgExec,
+        bank::{MsgMultiSend, MsgSend},
+    },
+    CosmTxResponse, DaemonError, INSTANTIATE_2_TYPE_URL,
+};
+
+/// Transaction about to be signed by a [`Wallet`](crate::Wallet)
+#[derive(Debug)]
+pub struct SignRequest<'a> {
+    pub chain_id: &'a str,
+    pub signer: &'a AccountId,
+    /// Messages of the transaction, wrapped in an authz `MsgExec` if the wallet uses an authz granter
+    pub msgs: &'a [Any],
+    pub memo: &'a str,
+    pub fee: &'a Coin,
+    pub gas_limit: u64,
+}
+
+/// Hook called by a [`Wallet`](crate::Wallet) around the transactions it sends.
+///
+/// Hooks are added with [`CosmosOptions::hook`](crate::CosmosOptions::hook) and called in the order they were added.
+/// Any hook can deny a transaction by returning an error from [`SenderHook::on_before_sign`],
+/// which aborts the transaction before it is signed.
+pub trait SenderHook: Send + Sync {
+    /// Called before every signature of a transaction, including the ones of retried transactions
+    fn on_before_sign(&self, _request: &SignRequest) -> Result<(), DaemonError> {
+        Ok(())
+    }
+
+    /// Called once a transaction is included in a block, whether its execution succeeded or not
+    fn on_after_broadcast(&self, _msgs: &[Any], _response: &CosmTxResponse) {}
+}
+
+/// Denies the transactions containing messages of the given type urls, also inside authz `MsgExec`
+#[derive(Clone, Debug)]
+pub struct DenyMsgTypes(pub Vec<String>);
+
+impl SenderHook for DenyMsgTypes {
+    fn on_before_sign(&self, request: &SignRequest) -> Result<(), DaemonError> {
+        match flatten_msgs(request.msgs)
+            .into_iter()
+            .find(|msg| self.0.contains(&msg.type_url))
+        {
+            Some(msg) => Err(DaemonError::TxDenied(format!(
+                "messages of type {} are not allowed",
+                msg.type_url
+            ))),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Asks for a confirmation before signing transactions sending more than `threshold` in its denom.
+///
+/// The funds sent by bank sends, contract instantiations and executions and ICS20 transfers are counted.
+/// Transactions above the threshold are denied when manual interactions are disabled.
+#[derive(Clone, Debug)]
+pub struct ApproveFundsAbove {
+    pub threshold: Coin,
+}
+
+impl SenderHook for ApproveFundsAbove {
+    fn on_before_sign(&self, request: &SignRequest) -> Result<(), DaemonError> {
+        let sent = sent_funds(request.msgs, &self.threshold.denom);
+        if sent <= self.threshold.amount.u128() {
+            return Ok(());
+        }
+
+        println!(
+            "The transaction on chain {} sends {sent}{}, above the approval threshold of {}.
+                Press 'y' to approve it",
+            request.chain_id, self.threshold.denom, self.threshold
+        );
+        if CoreEnvVars::manual_interaction() {
+            let mut input = String::new();
+            std::io::stdin().read_line(&mut input)?;
+            if input.to_lowercase().contains('y') {
+                return Ok(());
+            }
+        } else {
+            println!("No Manual Interactions, defaulting to 'no'");
+        }
+        Err(DaemonError::TxDenied(format!(
+            "sending {sent}{} was not approved",
+            self.threshold.denom
+        )))
+    }
+}
+
+/// Appends a JSON line per broadcast transaction to a file, with its hash, messages and result
+#[derive(Clone, Debug)]
+pub struct AuditLog {
+    path: PathBuf,
+    lock: Arc<Mutex<()>>,
+}
+
+impl AuditLog {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            lock: Default::default(),
+        }
+    }
+}
+
+impl SenderHook for AuditLog {
+    fn on_after_broadcast(&self, msgs: &[Any], response: &CosmTxResponse) {
+        let entry = json!({
+            "timestamp": response.timestamp.to_rfc3339(),
+            "txhash": response.txhash,
+            "height": response.height,
+            "code": response.code,
+            "gas_used": response.gas_used,
+            "msgs": flatten_msgs(msgs).iter().map(|msg| msg.type_url.clone()).collect::<Vec<_>>(),
+        });
+        let _lock = self.lock.lock().unwrap();
+        let written = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .and_then(|mut file| writeln!(file, "{entry}"));
+        if let Err(e) = written {
+            log::warn!(
+                target: &transaction_target(),
+                "Failed to write tx {} to the audit log {:?}: {e}",
+                response.txhash,
+                self.path
+            );
+        }
+    }
+}
+
+/// Messages of a transaction, with the ones executed through authz `MsgExec` instead of the `MsgExec`
+fn flatten_msgs(msgs: &[Any]) -> Vec<Any> {
+    msgs.iter()
+        .flat_map(|msg| {
+            if msg.type_url == MsgExec::type_url() {
+                if let Ok(exec) = MsgExec::decode(msg.value.as_slice()) {
+                    return flatten_msgs(&exec.msgs);
+                }
+            }
+            vec![msg.clone()]
+        })
+        .collect()
+}
+
+/// Total amount of `denom` sent by the messages
+fn sent_funds(msgs: &[Any], denom: &str) -> u128 {
+    let amount = |coins: &[ProtoCoin]| -> u128 {
+        coins
+            .iter()
+            .filter(|c| c.denom == denom)
+            .filter_map(|c| c.amount.parse::<u128>().ok())
+            .sum()
+    };
+    flatten_msgs(msgs)
+        .iter()
+        .map(|msg| {
+            let value = msg.value.as_slice();
+            match msg.type_url.as_str() {
+                url if url == MsgSend::type_url() => MsgSend::decode(value)
+                    .map(|m| amount(&m.amount))
+                    .unwrap_or_default(),
+                url if url == MsgMultiSend::type_url() => MsgMultiSend::decode(value)
+                    .map(|m| m.inputs.iter().map(|i| amount(&i.coins)).sum::<u128>())
+                    .unwrap_or_default(),
+                url if url == MsgExecuteContract::type_url() => MsgExecuteContract::decode(value)
+                    .map(|m| amount(&m.funds))
+                    .unwrap_or_default(),
+                url if url == MsgInstantiateContract::type_url() => {
+                    MsgInstantiateContract::decode(value)
+                        .map(|m| amount(&m.funds))
+                        .unwrap_or_default()
+                }
+                INSTANTIATE_2_TYPE_URL => MsgInstantiateContract2::decode(value)
+                    .map(|m| amount(&m.funds))
+                    .unwrap_or_default(),
+                url if url == MsgTransfer::type_url() => MsgTransfer::decode(value)
+                    .map(|m| m.token.map_or(0, |token| amount(&[token])))
+                    .unwrap_or_default(),
+                _ => 0,
+            }
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use cosmwasm_std::coin;
+    use std::str::FromStr;
+
+    const SIGNER: &str = "juno1qyqszqgpqyqszqgpqyqszqgpqyqszqgpjnp7du";
+
+    fn any<M: Message + Name>(msg: M) -> Any {
+        Any {
+            type_url: M::type_url(),
+            value: msg.encode_to_vec(),
+        }
+    }
+
+    fn request<'a>(signer: &'a AccountId, msgs: &'a [Any], fee: &'a Coin) -> SignRequest<'a> {
+        SignRequest {
+            chain_id: "juno-1",
+            signer,
+            msgs,
+            memo: "",
+            fee,
+            gas_limit: 100_000,
+        }
+    }
+
+    #[test]
+    fn denies_msg_types_inside_authz() {
+        let signer = AccountId::from_str(SIGNER).unwrap();
+        let fee = coin(1000, "ujuno");
+        let send = any(MsgSend {
+            from_address: SIGNER.to_string(),
+            to_address: SIGNER.to_string(),
+            amount: vec![ProtoCoin {
+                denom: "ujuno".to_string(),
+                amount: "5".to_string(),
+            }],
+        });
+        let exec = any(MsgExec {
+            grantee: SIGNER.to_string(),
+            msgs: vec![send.clone()],
+        });
+
+        let hook = DenyMsgTypes(vec![MsgSend::type_url()]);
+        assert!(matches!(
+            hook.on_before_sign(&request(&signer, &[exec.clone()], &fee)),
+            Err(DaemonError::TxDenied(_))
+        ));
+        assert!(DenyMsgTypes(vec![MsgTransfer::type_url()])
+            .on_before_sign(&request(&signer, &[exec], &fee))
+            .is_ok());
+    }
+
+    #[test]
+    fn counts_sent_funds() {
+        let funds = |amount: &str| {
+            vec![ProtoCoin {
+                denom: "ujuno".to_string(),
+                amount: amount.to_string(),
+            }]
+        };
+        let msgs = vec![
+            any(MsgSend {
+                from_address: SIGNER.to_string(),
+                to_address: SIGNER.to_string(),
+                amount: funds("100"),
+            }),
+            any(MsgExecuteContract {
+                sender: SIGNER.to_string(),
+                contract: SIGNER.to_string(),
+                msg: b"{}".to_vec(),
+                funds: funds("20"),
+            }),
+        ];
+        assert_eq!(sent_funds(&msgs, "ujuno"), 120);
+        assert_eq!(sent_funds(&msgs, "uatom"), 0);
+    }
+}
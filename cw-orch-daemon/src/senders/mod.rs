@@ -1,5 +1,6 @@
 // Core Sender traits
 pub mod builder;
+pub mod hooks;
 pub mod query;
 pub mod tx;
 
@@ -7,11 +8,17 @@ pub mod tx;
 mod cosmos;
 mod cosmos_batch;
 mod cosmos_options;
+mod pool;
 mod query_only;
 
 pub use {
     cosmos::{CosmosSender, Wallet},
     cosmos_batch::{options::CosmosBatchOptions, BatchDaemon, CosmosBatchSender},
-    cosmos_options::{CosmosOptions, CosmosWalletKey},
-    query_only::{QueryOnlyDaemon, QueryOnlySender},
+    cosmos_options::{
+        CosmosOptions, CosmosWalletKey, SignMode, TimeoutHeight, TxOptions, DEFAULT_TIMEOUT_BLOCKS,
+    },
+    pool::{PoolDaemon, SenderPool, SenderPoolOptions, DEFAULT_POOL_WALLET_GAS},
+    query_only::{QueryOnlyDaemon, QueryOnlyOptions, QueryOnlySender},
 };
+
+pub(crate) use cosmos::fee_from_gas;
@@ -0,0 +1,377 @@
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+
+use cosmrs::{
+    proto::{
+        cosmos::{
+            authz::v1beta1::{GenericAuthorization, Grant, MsgGrant},
+            bank::v1beta1::{MsgSend, SendAuthorization},
+            base::v1beta1::Coin as ProtoCoin,
+        },
+        cosmwasm::wasm::v1::{
+            MsgExecuteContract, MsgInstantiateContract, MsgMigrateContract, MsgStoreCode,
+        },
+    },
+    AccountId, Any,
+};
+use cosmwasm_std::{coin, Addr, Coin};
+use cw_orch_core::{environment::ChainInfoOwned, log::transaction_target};
+use prost::{Message, Name};
+use tokio::sync::{Mutex, MutexGuard};
+
+use crate::{
+    queriers::{Authz, Bank},
+    CosmTxResponse, DaemonBase, DaemonError, INSTANTIATE_2_TYPE_URL,
+};
+
+use super::{
+    builder::SenderBuilder, cosmos::Wallet, fee_from_gas, query::QuerySender, tx::TxSender,
+    CosmosOptions,
+};
+
+pub type PoolDaemon = DaemonBase<SenderPool>;
+
+/// Gas the wallets of the pool are funded for by default
+pub const DEFAULT_POOL_WALLET_GAS: u64 = 10_000_000;
+const SEND_AUTHORIZATION_TYPE_URL: &str = "/cosmos.bank.v1beta1.SendAuthorization";
+
+/// Options of a [`SenderPool`]
+#[derive(Clone)]
+pub struct SenderPoolOptions {
+    pub(crate) options: CosmosOptions,
+    pub(crate) size: u32,
+    pub(crate) funds: Option<Vec<Coin>>,
+    pub(crate) grants: Vec<String>,
+    pub(crate) send_limit: Vec<Coin>,
+}
+
+impl SenderPoolOptions {
+    /// Pool of `size` wallets, derived from the key of `options` at the hd indices following the one of the primary wallet.
+    /// The wallets are granted the right to send wasm messages on behalf of the primary wallet
+    /// and are funded with enough gas tokens to pay for [`DEFAULT_POOL_WALLET_GAS`].
+    pub fn new(options: CosmosOptions, size: u32) -> Self {
+        Self {
+            options,
+            size,
+            funds: None,
+            grants: vec![
+                MsgExecuteContract::type_url(),
+                MsgInstantiateContract::type_url(),
+                INSTANTIATE_2_TYPE_URL.to_string(),
+                MsgMigrateContract::type_url(),
+                MsgStoreCode::type_url(),
+            ],
+            send_limit: vec![],
+        }
+    }
+
+    /// Top up the balance of every wallet of the pool to `funds` from the primary wallet, to pay for the fees
+    pub fn funds(mut self, funds: Vec<Coin>) -> Self {
+        self.funds = Some(funds);
+        self
+    }
+
+    /// Allow every wallet of the pool to send up to `spend_limit` of the funds of the primary wallet with bank `MsgSend`s
+    pub fn send_limit(mut self, spend_limit: Vec<Coin>) -> Self {
+        self.send_limit = spend_limit;
+        self
+    }
+
+    /// Also allow the wallets of the pool to send messages of type `type_url` on behalf of the primary wallet.
+    /// Use [`Self::send_limit`] for bank `MsgSend`s.
+    pub fn grant(mut self, type_url: impl Into<String>) -> Self {
+        self.grants.push(type_url.into());
+        self
+    }
+
+    /// Balance every wallet of the pool is topped up to, the fee of [`DEFAULT_POOL_WALLET_GAS`] unless set with [`Self::funds`]
+    fn wallet_funds(&self, chain_info: &ChainInfoOwned) -> Vec<Coin> {
+        self.funds.clone().unwrap_or_else(|| {
+            let (_, fee) = fee_from_gas(DEFAULT_POOL_WALLET_GAS, chain_info.gas_price);
+            vec![coin(fee, &chain_info.gas_denom)]
+        })
+    }
+
+    /// Authorizations granted to every wallet of the pool, with the message type they authorize
+    fn authorizations(&self) -> Vec<(String, Any)> {
+        let mut authorizations: Vec<(String, Any)> = self
+            .grants
+            .iter()
+            .map(|type_url| {
+                let authorization = Any {
+                    type_url: GenericAuthorization::type_url(),
+                    value: GenericAuthorization {
+                        msg: type_url.clone(),
+                    }
+                    .encode_to_vec(),
+                };
+                (type_url.clone(), authorization)
+            })
+            .collect();
+        if !self.send_limit.is_empty() {
+            let authorization = Any {
+                type_url: SEND_AUTHORIZATION_TYPE_URL.to_string(),
+                value: SendAuthorization {
+                    spend_limit: self
+                        .send_limit
+                        .iter()
+                        .map(|c| ProtoCoin {
+                            denom: c.denom.clone(),
+                            amount: c.amount.to_string(),
+                        })
+                        .collect(),
+                    allow_list: vec![],
+                }
+                .encode_to_vec(),
+            };
+            authorizations.push((MsgSend::type_url(), authorization));
+        }
+        authorizations
+    }
+}
+
+/// Wallet of the pool, locked while it's broadcasting a transaction
+struct PoolWallet {
+    wallet: Wallet,
+    lock: Mutex<()>,
+}
+
+/// Sends transactions from a pool of wallets to increase throughput.
+///
+/// The messages are sent on behalf of the primary wallet, wrapped in an authz `MsgExec` by the wallet of the pool that is free,
+/// so contracts see the primary wallet as sender.
+/// Each wallet of the pool has its own account sequence, so transactions can be sent concurrently, for instance to load-test a contract.
+/// The grants and funds of the wallets are set up by the primary wallet when the pool is built.
+#[derive(Clone)]
+pub struct SenderPool {
+    /// Wallet the messages are sent on behalf of
+    pub primary: Wallet,
+    wallets: Arc<Vec<PoolWallet>>,
+    next: Arc<AtomicUsize>,
+}
+
+impl SenderPool {
+    /// Wallets of the pool, paying the fees of the transactions
+    pub fn wallets(&self) -> Vec<Wallet> {
+        self.wallets.iter().map(|w| w.wallet.clone()).collect()
+    }
+
+    /// Round-robins over the wallets, skipping the ones that are busy unless all of them are
+    async fn acquire(&self) -> (&Wallet, MutexGuard<'_, ()>) {
+        let start = self.next.fetch_add(1, Ordering::Relaxed);
+        let len = self.wallets.len();
+        for offset in 0..len {
+            let pool_wallet = &self.wallets[(start + offset) % len];
+            if let Ok(guard) = pool_wallet.lock.try_lock() {
+                return (&pool_wallet.wallet, guard);
+            }
+        }
+        let pool_wallet = &self.wallets[start % len];
+        (&pool_wallet.wallet, pool_wallet.lock.lock().await)
+    }
+
+    /// Grants the missing authorizations to the wallets of the pool and tops up their balances
+    async fn setup(&self, options: &SenderPoolOptions) -> Result<(), DaemonError> {
+        let granter = self.primary.pub_addr_str();
+        let authorizations = options.authorizations();
+        let funds = options.wallet_funds(&self.primary.chain_info);
+        let authz = Authz::new_async(self.primary.channel());
+        let bank = Bank::new_async(self.primary.channel());
+
+        let mut msgs = vec![];
+        for pool_wallet in self.wallets.iter() {
+            let grantee = pool_wallet.wallet.pub_addr_str();
+            for (type_url, authorization) in &authorizations {
+                let granted = authz
+                    ._grants(granter.clone(), grantee.clone(), type_url.clone(), None)
+                    .await
+                    .is_ok_and(|resp| !resp.grants.is_empty());
+                if granted {
+                    continue;
+                }
+                msgs.push(grant_msg(&granter, &grantee, authorization.clone()));
+            }
+
+            let mut top_up = vec![];
+            for coin in &funds {
+                let balance = bank
+                    ._balance(&grantee, Some(coin.denom.clone()))
+                    .await?
+                    .first()
+                    .map(|c| c.amount)
+                    .unwrap_or_default();
+                if balance < coin.amount {
+                    top_up.push(ProtoCoin {
+                        denom: coin.denom.clone(),
+                        amount: (coin.amount - balance).to_string(),
+                    });
+                }
+            }
+            if !top_up.is_empty() {
+                msgs.push(Any {
+                    type_url: MsgSend::type_url(),
+                    value: MsgSend {
+                        from_address: granter.clone(),
+                        to_address: grantee,
+                        amount: top_up,
+                    }
+                    .encode_to_vec(),
+                });
+            }
+        }
+
+        if !msgs.is_empty() {
+            log::info!(target: &transaction_target(), "Setting up sender pool of {} wallets for {granter}", self.wallets.len());
+            self.primary
+                .commit_tx_any(msgs, Some("cw-orch sender pool setup"))
+                .await?;
+        }
+        Ok(())
+    }
+}
+
+/// `MsgGrant` of `authorization` from `granter` to `grantee`, without expiration
+fn grant_msg(granter: &str, grantee: &str, authorization: Any) -> Any {
+    Any {
+        type_url: MsgGrant::type_url(),
+        value: MsgGrant {
+            granter: granter.to_string(),
+            grantee: grantee.to_string(),
+            grant: Some(Grant {
+                authorization: Some(authorization),
+                expiration: None,
+            }),
+        }
+        .encode_to_vec(),
+    }
+}
+
+impl SenderBuilder for SenderPoolOptions {
+    type Error = DaemonError;
+    type Sender = SenderPool;
+
+    async fn build(&self, chain_info: &Arc<ChainInfoOwned>) -> Result<Self::Sender, Self::Error> {
+        if self.size == 0 {
+            return Err(DaemonError::StdErr(
+                "a sender pool needs at least one wallet".to_string(),
+            ));
+        }
+        let primary = self.options.build(chain_info).await?;
+        let primary_index = self.options.hd_index.unwrap_or(0);
+
+        let mut wallets = vec![];
+        for i in 1..=self.size {
+            let mut options = self.options.clone();
+            options.set_hd_index(primary_index + i);
+            options.set_authz_granter(primary.pub_addr_str());
+            wallets.push(PoolWallet {
+                wallet: options.build(chain_info).await?,
+                lock: Mutex::new(()),
+            });
+        }
+
+        let pool = SenderPool {
+            primary,
+            wallets: Arc::new(wallets),
+            next: Default::default(),
+        };
+        pool.setup(self).await?;
+        Ok(pool)
+    }
+}
+
+impl QuerySender for SenderPool {
+    type Error = DaemonError;
+    type Options = SenderPoolOptions;
+
+    fn channel(&self) -> tonic::transport::Channel {
+        self.primary.channel()
+    }
+}
+
+impl TxSender for SenderPool {
+    async fn commit_tx_any(
+        &self,
+        msgs: Vec<Any>,
+        memo: Option<&str>,
+    ) -> Result<CosmTxResponse, DaemonError> {
+        let (wallet, _guard) = self.acquire().await;
+        log::debug!(target: &transaction_target(), "Sending transaction from pool wallet {}", wallet.pub_addr_str());
+        wallet.commit_tx_any(msgs, memo).await
+    }
+
+    async fn simulate_any(&self, msgs: Vec<Any>, memo: Option<&str>) -> Result<u64, DaemonError> {
+        // Simulations don't increase the account sequence, any wallet can be used
+        let pool_wallet = &self.wallets[self.next.load(Ordering::Relaxed) % self.wallets.len()];
+        pool_wallet.wallet.simulate_any(msgs, memo).await
+    }
+
+    fn address(&self) -> Addr {
+        self.primary.address()
+    }
+
+    fn account_id(&self) -> AccountId {
+        self.primary.account_id()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::networks::JUNO_1;
+
+    #[test]
+    fn wallets_are_funded_for_fees_by_default() {
+        let chain_info: ChainInfoOwned = JUNO_1.into();
+        let options = SenderPoolOptions::new(CosmosOptions::default(), 2);
+
+        let (_, fee) = fee_from_gas(DEFAULT_POOL_WALLET_GAS, JUNO_1.gas_price);
+        assert!(fee > 0);
+        assert_eq!(options.wallet_funds(&chain_info), vec![coin(fee, "ujuno")]);
+
+        let options = options.funds(vec![coin(5, "ujuno")]);
+        assert_eq!(options.wallet_funds(&chain_info), vec![coin(5, "ujuno")]);
+    }
+
+    #[test]
+    fn bank_sends_are_limited() {
+        let options = SenderPoolOptions::new(CosmosOptions::default(), 2);
+        let authorizations = options.authorizations();
+        assert!(authorizations
+            .iter()
+            .all(|(type_url, authorization)| type_url != &MsgSend::type_url()
+                && authorization.type_url == GenericAuthorization::type_url()));
+
+        let options = options.send_limit(vec![coin(100, "ujuno")]);
+        let (type_url, authorization) = options.authorizations().pop().unwrap();
+        assert_eq!(type_url, MsgSend::type_url());
+        assert_eq!(authorization.type_url, SEND_AUTHORIZATION_TYPE_URL);
+        let send = SendAuthorization::decode(authorization.value.as_slice()).unwrap();
+        assert_eq!(
+            send.spend_limit,
+            vec![ProtoCoin {
+                denom: "ujuno".to_string(),
+                amount: "100".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn grant_msg_without_expiration() {
+        let authorization = SenderPoolOptions::new(CosmosOptions::default(), 1)
+            .authorizations()
+            .remove(0)
+            .1;
+        let msg = grant_msg("juno1granter", "juno1grantee", authorization.clone());
+        assert_eq!(msg.type_url, MsgGrant::type_url());
+
+        let grant = MsgGrant::decode(msg.value.as_slice()).unwrap();
+        assert_eq!(grant.granter, "juno1granter");
+        assert_eq!(grant.grantee, "juno1grantee");
+        let grant = grant.grant.unwrap();
+        assert_eq!(grant.authorization, Some(authorization));
+        assert_eq!(grant.expiration, None);
+    }
+}
@@ -1,54 +1,141 @@
-use std::sync::Arc;
+use std::{str::FromStr, sync::Arc};
 
-use crate::{error::DaemonError, DaemonBase, GrpcChannel};
+use crate::{error::DaemonError, CosmTxResponse, DaemonBase, GrpcChannel};
 
+use cosmrs::{AccountId, Any};
+use cosmwasm_std::Addr;
 use cw_orch_core::environment::ChainInfoOwned;
 
 use tonic::transport::Channel;
 
-use super::{builder::SenderBuilder, query::QuerySender};
+use super::{builder::SenderBuilder, query::QuerySender, tx::TxSender};
 
 /// Daemon that does not support signing.
-/// Will err on any attempt to sign a transaction or retrieve a sender address.
+/// Supports all the queriers and the state, but errs on any attempt to send or simulate a transaction.
+/// Doesn't need a mnemonic, see [`QueryOnlyOptions`].
 pub type QueryOnlyDaemon = DaemonBase<QueryOnlySender>;
 
-/// Signer of the transactions and helper for address derivation
+/// Sender of a [`QueryOnlyDaemon`], without any key
 #[derive(Clone)]
 pub struct QueryOnlySender {
     /// gRPC channel
     pub channel: Channel,
     /// Information about the chain
     pub chain_info: Arc<ChainInfoOwned>,
+    /// Account returned as the sender, see [`QueryOnlyOptions::address`]
+    account_id: Option<AccountId>,
 }
 
-impl SenderBuilder for () {
+impl QueryOnlySender {
+    /// Query-only sender without a sender address
+    pub fn new(channel: Channel, chain_info: Arc<ChainInfoOwned>) -> Self {
+        Self {
+            channel,
+            chain_info,
+            account_id: None,
+        }
+    }
+
+    /// All-zero account of the chain, returned as the sender when no address is configured
+    fn empty_account(&self) -> AccountId {
+        AccountId::new(&self.chain_info.network_info.pub_address_prefix, &[0; 20])
+            // Only fails for an invalid chain prefix, which can't be used to connect to the chain anyway
+            .unwrap_or_else(|_| AccountId::new("cosmos", &[0; 20]).unwrap())
+    }
+}
+
+/// Options of a [`QueryOnlySender`]
+///
+/// ```no_run
+/// use cw_orch_daemon::{senders::{QueryOnlyDaemon, QueryOnlyOptions}, Daemon, networks};
+///
+/// let daemon: QueryOnlyDaemon = Daemon::builder(networks::JUNO_1)
+///     .build_sender(QueryOnlyOptions::default().address("juno1..."))
+///     .unwrap();
+/// ```
+#[derive(Clone, Debug, Default)]
+#[non_exhaustive]
+pub struct QueryOnlyOptions {
+    /// Address returned as the sender address, for instance to read the state of a monitored account.
+    /// The all-zero address of the chain is returned if not set.
+    pub address: Option<Addr>,
+}
+
+impl QueryOnlyOptions {
+    pub fn address(mut self, address: impl Into<String>) -> Self {
+        self.address = Some(Addr::unchecked(address));
+        self
+    }
+
+    pub fn set_address(&mut self, address: impl Into<String>) {
+        self.address = Some(Addr::unchecked(address));
+    }
+}
+
+impl SenderBuilder for QueryOnlyOptions {
     type Error = DaemonError;
     type Sender = QueryOnlySender;
 
     async fn build(&self, chain_info: &Arc<ChainInfoOwned>) -> Result<Self::Sender, Self::Error> {
+        let account_id = self
+            .address
+            .as_ref()
+            .map(|address| AccountId::from_str(address.as_str()))
+            .transpose()?;
         let channel = GrpcChannel::from_chain_info(chain_info.as_ref()).await?;
 
         Ok(QueryOnlySender {
             channel,
             chain_info: chain_info.clone(),
+            account_id,
         })
     }
 }
 
+impl SenderBuilder for () {
+    type Error = DaemonError;
+    type Sender = QueryOnlySender;
+
+    async fn build(&self, chain_info: &Arc<ChainInfoOwned>) -> Result<Self::Sender, Self::Error> {
+        QueryOnlyOptions::default().build(chain_info).await
+    }
+}
+
 impl QuerySender for QueryOnlySender {
     type Error = DaemonError;
-    type Options = ();
+    type Options = QueryOnlyOptions;
 
     fn channel(&self) -> Channel {
         self.channel.clone()
     }
 }
 
+impl TxSender for QueryOnlySender {
+    /// Account of the address configured with [`QueryOnlyOptions::address`], the all-zero account of the chain if none is set
+    fn account_id(&self) -> AccountId {
+        self.account_id
+            .clone()
+            .unwrap_or_else(|| self.empty_account())
+    }
+
+    async fn commit_tx_any(
+        &self,
+        _msgs: Vec<Any>,
+        _memo: Option<&str>,
+    ) -> Result<CosmTxResponse, DaemonError> {
+        Err(DaemonError::QueryOnly)
+    }
+
+    async fn simulate_any(&self, _msgs: Vec<Any>, _memo: Option<&str>) -> Result<u64, DaemonError> {
+        Err(DaemonError::QueryOnly)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use cw_orch_networks::networks::JUNO_1;
 
-    use super::QueryOnlyDaemon;
+    use super::*;
     use crate::DaemonBuilder;
 
     #[test]
@@ -57,4 +144,30 @@ mod tests {
         let _query_only_daemon: QueryOnlyDaemon =
             DaemonBuilder::new(JUNO_1).build_sender(()).unwrap();
     }
+
+    #[tokio::test]
+    async fn rejects_transactions() {
+        let mut sender = QueryOnlySender::new(
+            Channel::from_static("http://localhost:9090").connect_lazy(),
+            Arc::new(JUNO_1.into()),
+        );
+        assert_eq!(
+            sender.address().as_str(),
+            "juno1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq93ryqp"
+        );
+        sender.account_id =
+            Some(AccountId::from_str("juno1qyqszqgpqyqszqgpqyqszqgpqyqszqgpjnp7du").unwrap());
+        assert_eq!(
+            sender.address().as_str(),
+            "juno1qyqszqgpqyqszqgpqyqszqgpqyqszqgpjnp7du"
+        );
+        assert!(matches!(
+            sender.commit_tx_any(vec![], None).await,
+            Err(DaemonError::QueryOnly)
+        ));
+        assert!(matches!(
+            sender.simulate_any(vec![], None).await,
+            Err(DaemonError::QueryOnly)
+        ));
+    }
 }
@@ -1,7 +1,8 @@
 use cosmrs::{tx::Msg, AccountId, Any};
 use cosmwasm_std::Addr;
+use cw_orch_core::CwEnvError;
 
-use crate::CosmTxResponse;
+use crate::{CosmTxResponse, DaemonError};
 
 use super::query::QuerySender;
 
@@ -16,6 +17,16 @@ pub trait TxSender: QuerySender {
         memo: Option<&str>,
     ) -> impl std::future::Future<Output = Result<CosmTxResponse, Self::Error>> + Send;
 
+    /// Simulate proto `Any` messages with this sender and return the gas they use.
+    /// Errs with [`CwEnvError::Unsupported`] for senders that can't simulate transactions.
+    fn simulate_any(
+        &self,
+        _msgs: Vec<Any>,
+        _memo: Option<&str>,
+    ) -> impl std::future::Future<Output = Result<u64, DaemonError>> + Send {
+        async { Err(CwEnvError::Unsupported("simulate_any".to_string()).into()) }
+    }
+
     /// Get the address of the sender.
     fn address(&self) -> Addr {
         Addr::unchecked(self.account_id().to_string())
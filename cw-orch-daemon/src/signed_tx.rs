@@ -0,0 +1,149 @@
+//! Transactions signed offline and saved to a file, broadcast with [`crate::DaemonAsync::broadcast_signed_tx_file`].
+//!
+//! The file can contain:
+//! - the protobuf encoding of a `TxRaw`,
+//! - the same bytes encoded in base64, as output by `wasmd tx encode`,
+//! - the JSON encoding of a `Tx`, as output by `wasmd tx sign` in the direct or amino-json sign mode.
+//!
+//! JSON transactions are encoded by the REST endpoint of the chain (`lcd_url`), which knows how to encode all the messages it supports.
+
+use std::path::Path;
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use prost::Message;
+use serde_json::{json, Value};
+
+use crate::{cosmos_modules, DaemonError};
+
+/// Signed transaction read from a file or a string, see the [module](self) docs for the supported formats
+#[derive(Clone, Debug, PartialEq)]
+pub enum SignedTx {
+    /// Protobuf encoding of a `TxRaw`, ready to be broadcast
+    Raw(Vec<u8>),
+    /// JSON encoding of a `Tx`, which needs to be encoded by the chain before being broadcast
+    Json(Value),
+}
+
+impl SignedTx {
+    /// Read the signed transaction saved at `path`
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, DaemonError> {
+        Self::parse(&std::fs::read(path)?)
+    }
+
+    /// Parse a signed transaction in any of the supported formats
+    pub fn parse(content: &[u8]) -> Result<Self, DaemonError> {
+        let tx_bytes = match std::str::from_utf8(content).map(str::trim) {
+            Ok(text) if text.starts_with('{') => {
+                return Ok(Self::Json(serde_json::from_str(text)?))
+            }
+            Ok(text) => match STANDARD.decode(text) {
+                Ok(tx_bytes) => tx_bytes,
+                Err(_) => content.to_vec(),
+            },
+            Err(_) => content.to_vec(),
+        };
+        let tx = cosmos_modules::tx::TxRaw::decode(tx_bytes.as_slice())?;
+        if tx.body_bytes.is_empty() || tx.signatures.is_empty() {
+            return Err(DaemonError::StdErr(
+                "the content is not a signed transaction".to_string(),
+            ));
+        }
+        Ok(Self::Raw(tx_bytes))
+    }
+
+    /// Protobuf encoding of the `TxRaw`, JSON transactions are encoded by the REST endpoint at `lcd_url`
+    pub async fn into_tx_bytes(self, lcd_url: Option<&str>) -> Result<Vec<u8>, DaemonError> {
+        let tx = match self {
+            Self::Raw(tx_bytes) => return Ok(tx_bytes),
+            Self::Json(tx) => tx,
+        };
+        let lcd_url = lcd_url.ok_or_else(|| {
+            DaemonError::StdErr(
+                "JSON transactions are encoded by the REST endpoint of the chain, set its `lcd_url`"
+                    .to_string(),
+            )
+        })?;
+        let resp = reqwest::Client::new()
+            .post(format!(
+                "{}/cosmos/tx/v1beta1/encode",
+                lcd_url.trim_end_matches('/')
+            ))
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .body(json!({ "tx": tx }).to_string())
+            .send()
+            .await?
+            .error_for_status()?
+            .text()
+            .await?;
+        let resp: Value = serde_json::from_str(&resp)?;
+        let tx_bytes = resp["tx_bytes"].as_str().ok_or_else(|| {
+            DaemonError::StdErr(format!("unexpected encode response of {lcd_url}: {resp}"))
+        })?;
+        Ok(STANDARD.decode(tx_bytes)?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn tx_bytes() -> Vec<u8> {
+        cosmos_modules::tx::TxRaw {
+            body_bytes: vec![10, 2, 1, 2],
+            auth_info_bytes: vec![18, 0],
+            signatures: vec![vec![7; 64]],
+        }
+        .encode_to_vec()
+    }
+
+    #[test]
+    fn raw_and_base64_txs() {
+        assert_eq!(
+            SignedTx::parse(&tx_bytes()).unwrap(),
+            SignedTx::Raw(tx_bytes())
+        );
+
+        let encoded = format!("{}\n", STANDARD.encode(tx_bytes()));
+        assert_eq!(
+            SignedTx::parse(encoded.as_bytes()).unwrap(),
+            SignedTx::Raw(tx_bytes())
+        );
+    }
+
+    #[test]
+    fn json_tx() {
+        let tx = json!({
+            "body": { "messages": [], "memo": "" },
+            "auth_info": { "signer_infos": [], "fee": { "amount": [], "gas_limit": "200000" } },
+            "signatures": ["c2lnbmF0dXJl"]
+        });
+        let signed_tx = SignedTx::parse(tx.to_string().as_bytes()).unwrap();
+        assert_eq!(signed_tx, SignedTx::Json(tx));
+
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        assert!(rt.block_on(signed_tx.into_tx_bytes(None)).is_err());
+    }
+
+    #[test]
+    fn unsigned_content() {
+        let unsigned = cosmos_modules::tx::TxRaw {
+            body_bytes: vec![10, 2, 1, 2],
+            auth_info_bytes: vec![],
+            signatures: vec![],
+        }
+        .encode_to_vec();
+        assert!(SignedTx::parse(&unsigned).is_err());
+        assert!(SignedTx::parse(b"not a transaction").is_err());
+    }
+
+    #[test]
+    fn signed_tx_file() {
+        let path = crate::gen_temp_file_path();
+        std::fs::write(&path, tx_bytes()).unwrap();
+        assert_eq!(
+            SignedTx::from_file(&path).unwrap(),
+            SignedTx::Raw(tx_bytes())
+        );
+        std::fs::remove_file(path).unwrap();
+    }
+}
@@ -1,6 +1,6 @@
 use super::error::DaemonError;
 use crate::env::{default_state_folder, DaemonEnvVars};
-use crate::{json_lock::JsonLockedState, networks::ChainKind};
+use crate::{json_lock::JsonLockedState, networks::ChainKind, DeploymentExport};
 
 use cosmwasm_std::Addr;
 use cw_orch_core::environment::ChainInfoOwned;
@@ -157,22 +157,7 @@ impl DaemonState {
 
     /// Retrieve a stateful value using the chainId and networkId
     pub fn get(&self, key: &str) -> Result<Value, DaemonError> {
-        let json = match &self.json_state {
-            DaemonStateFile::ReadOnly { path } => {
-                let j = crate::json_lock::read(path)?;
-
-                j[&self.chain_data.network_info.chain_name][&self.chain_data.chain_id].clone()
-            }
-            DaemonStateFile::FullAccess { json_file_state } => json_file_state
-                .lock()
-                .unwrap()
-                .get(
-                    &self.chain_data.network_info.chain_name,
-                    &self.chain_data.chain_id,
-                )
-                .clone(),
-        };
-        Ok(json[key].clone())
+        Ok(self.chain_state()?[key].clone())
     }
 
     /// Set a stateful value using the chainId and networkId
@@ -199,6 +184,7 @@ impl DaemonState {
         if self.write_on_change {
             json_file_lock.force_write();
         }
+        export_on_change(&json_file_lock, &self.deployment_id);
 
         Ok(())
     }
@@ -222,6 +208,7 @@ impl DaemonState {
         if self.write_on_change {
             json_file_lock.force_write();
         }
+        export_on_change(&json_file_lock, &self.deployment_id);
 
         Ok(())
     }
@@ -262,8 +249,170 @@ impl DaemonState {
         if self.write_on_change {
             json_file_lock.force_write();
         }
+        export_on_change(&json_file_lock, &self.deployment_id);
+        Ok(())
+    }
+}
+
+/// Key under which code ids are stored, next to the deployment ids
+pub(crate) const CODE_IDS_KEY: &str = "code_ids";
+/// Key under which the checksums of the uploaded wasm are stored, indexed by code id
+pub(crate) const CHECKSUMS_KEY: &str = "checksums";
+const RESERVED_KEYS: &[&str] = &[CODE_IDS_KEY, CHECKSUMS_KEY];
+
+/// Deployment management
+/// A deployment groups the contract addresses registered under a deployment id for the current chain.
+/// Code ids are shared between all the deployments of a chain.
+impl DaemonState {
+    /// Returns the deployment ids registered for the current chain, sorted alphabetically
+    pub fn deployment_ids(&self) -> Result<Vec<String>, DaemonError> {
+        let chain_state = self.chain_state()?;
+        let mut ids: Vec<String> = chain_state
+            .as_object()
+            .map(|o| {
+                o.keys()
+                    .filter(|k| !RESERVED_KEYS.contains(&k.as_str()))
+                    .cloned()
+                    .collect()
+            })
+            .unwrap_or_default();
+        ids.sort();
+        Ok(ids)
+    }
+
+    /// Changes the deployment id used to read and write contract addresses
+    pub fn set_deployment_id(
+        &mut self,
+        deployment_id: impl Into<String>,
+    ) -> Result<(), DaemonError> {
+        let deployment_id: String = deployment_id.into();
+        if RESERVED_KEYS.contains(&deployment_id.as_str()) {
+            return Err(DaemonError::ReservedDeploymentId(deployment_id));
+        }
+        self.deployment_id = deployment_id;
         Ok(())
     }
+
+    /// Copies all the contract addresses of deployment `from` to a new deployment `to`
+    /// Errors if `from` doesn't exist or if `to` already exists
+    pub fn copy_deployment(&mut self, from: &str, to: &str) -> Result<(), DaemonError> {
+        if RESERVED_KEYS.contains(&to) {
+            return Err(DaemonError::ReservedDeploymentId(to.to_string()));
+        }
+        self.update_chain_state(|chain_state| {
+            let source = chain_state
+                .get(from)
+                .filter(|_| !RESERVED_KEYS.contains(&from))
+                .cloned()
+                .ok_or_else(|| DaemonError::DeploymentNotFound(from.to_string()))?;
+            if chain_state.get(to).is_some() {
+                return Err(DaemonError::DeploymentAlreadyExists(to.to_string()));
+            }
+            chain_state[to] = source;
+            Ok(())
+        })?
+    }
+
+    /// Removes a deployment and all its contract addresses
+    /// The active deployment can't be removed
+    pub fn remove_deployment(&mut self, deployment_id: &str) -> Result<(), DaemonError> {
+        if deployment_id == self.deployment_id {
+            return Err(DaemonError::ActiveDeployment(deployment_id.to_string()));
+        }
+        self.update_chain_state(|chain_state| {
+            chain_state
+                .as_object_mut()
+                .filter(|_| !RESERVED_KEYS.contains(&deployment_id))
+                .and_then(|o| o.remove(deployment_id))
+                .map(|_| ())
+                .ok_or_else(|| DaemonError::DeploymentNotFound(deployment_id.to_string()))
+        })?
+    }
+
+    /// Removes every deployment that is not listed in `keep` and is not the active one
+    /// Returns the removed deployment ids
+    pub fn prune_deployments(&mut self, keep: &[&str]) -> Result<Vec<String>, DaemonError> {
+        let stale: Vec<String> = self
+            .deployment_ids()?
+            .into_iter()
+            .filter(|id| *id != self.deployment_id && !keep.contains(&id.as_str()))
+            .collect();
+        for id in &stale {
+            self.remove_deployment(id)?;
+        }
+        Ok(stale)
+    }
+
+    /// Stores the checksum of the wasm uploaded with `code_id`
+    pub fn set_checksum(&mut self, code_id: u64, checksum: &str) -> Result<(), DaemonError> {
+        self.set(CHECKSUMS_KEY, &code_id.to_string(), checksum)
+    }
+
+    /// Exports the active deployment of every chain in the state file.
+    /// Set the `CW_ORCH_STATE_EXPORT_DIR` env variable to write this export on every state change.
+    pub fn export(&self) -> Result<DeploymentExport, DaemonError> {
+        let state = match &self.json_state {
+            DaemonStateFile::ReadOnly { path } => crate::json_lock::read(path)?,
+            DaemonStateFile::FullAccess { json_file_state } => {
+                json_file_state.lock().unwrap().state()
+            }
+        };
+        Ok(DeploymentExport::from_state(&state, &self.deployment_id))
+    }
+
+    /// Returns the whole state of the current chain
+    fn chain_state(&self) -> Result<Value, DaemonError> {
+        let json = match &self.json_state {
+            DaemonStateFile::ReadOnly { path } => {
+                let j = crate::json_lock::read(path)?;
+
+                j[&self.chain_data.network_info.chain_name][&self.chain_data.chain_id].clone()
+            }
+            DaemonStateFile::FullAccess { json_file_state } => json_file_state
+                .lock()
+                .unwrap()
+                .get(
+                    &self.chain_data.network_info.chain_name,
+                    &self.chain_data.chain_id,
+                )
+                .clone(),
+        };
+        Ok(json)
+    }
+
+    /// Applies `f` to the state of the current chain
+    fn update_chain_state<R>(&mut self, f: impl FnOnce(&mut Value) -> R) -> Result<R, DaemonError> {
+        let json_file_state = match &mut self.json_state {
+            DaemonStateFile::ReadOnly { path } => {
+                return Err(DaemonError::StateReadOnly(path.clone()))
+            }
+            DaemonStateFile::FullAccess { json_file_state } => json_file_state,
+        };
+
+        let mut json_file_lock = json_file_state.lock().unwrap();
+        let chain_state = json_file_lock.get_mut(
+            &self.chain_data.network_info.chain_name,
+            &self.chain_data.chain_id,
+        );
+        let res = f(chain_state);
+
+        if self.write_on_change {
+            json_file_lock.force_write();
+        }
+        export_on_change(&json_file_lock, &self.deployment_id);
+        Ok(res)
+    }
+}
+
+/// Writes the deployment export if the `CW_ORCH_STATE_EXPORT_DIR` env variable is set
+fn export_on_change(json_file_lock: &JsonLockedState, deployment_id: &str) {
+    let Some(dir) = DaemonEnvVars::state_export_dir() else {
+        return;
+    };
+    let export = DeploymentExport::from_state(&json_file_lock.state(), deployment_id);
+    if let Err(e) = export.write(&dir) {
+        log::warn!(target: &local_target(), "Failed to export the deployment state to {dir:?}: {e}");
+    }
 }
 
 impl StateInterface for DaemonState {
@@ -293,7 +442,7 @@ impl StateInterface for DaemonState {
     /// Get the locally-saved version of the contract's version on this network
     fn get_code_id(&self, contract_id: &str) -> Result<u64, CwEnvError> {
         let value = self
-            .get("code_ids")
+            .get(CODE_IDS_KEY)
             .ok()
             .and_then(|v| v.get(contract_id).cloned())
             .ok_or_else(|| CwEnvError::CodeIdNotInStore(contract_id.to_owned()))?
@@ -303,10 +452,10 @@ impl StateInterface for DaemonState {
 
     /// Set the locally-saved version of the contract's latest version on this network
     fn set_code_id(&mut self, contract_id: &str, code_id: u64) {
-        self.set("code_ids", contract_id, code_id).unwrap();
+        self.set(CODE_IDS_KEY, contract_id, code_id).unwrap();
     }
     fn remove_code_id(&mut self, contract_id: &str) {
-        self.remove("code_ids", contract_id).unwrap();
+        self.remove(CODE_IDS_KEY, contract_id).unwrap();
     }
 
     /// Get all addresses for deployment id from state file
@@ -322,7 +471,7 @@ impl StateInterface for DaemonState {
 
     fn get_all_code_ids(&self) -> Result<HashMap<String, u64>, CwEnvError> {
         let mut store = HashMap::new();
-        let code_ids = self.get("code_ids")?;
+        let code_ids = self.get(CODE_IDS_KEY)?;
         let value = code_ids.as_object().cloned().unwrap_or_default();
         for (id, code_id) in value {
             store.insert(id, code_id.as_u64().unwrap());
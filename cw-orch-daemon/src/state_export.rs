@@ -0,0 +1,136 @@
+use std::{collections::BTreeMap, path::Path};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    state::{CHECKSUMS_KEY, CODE_IDS_KEY},
+    DaemonError,
+};
+
+const JSON_EXPORT_FILE: &str = "deployment.json";
+const TS_EXPORT_FILE: &str = "deployment.ts";
+
+/// Deployment information of a single contract
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct ExportedContract {
+    pub code_id: Option<u64>,
+    pub address: Option<String>,
+    /// Hex encoded checksum of the uploaded wasm
+    pub checksum: Option<String>,
+}
+
+/// Flat view of a deployment, indexed by chain id and then by contract id.
+/// Meant to be consumed by frontends and bots, see [`DeploymentExport::write`].
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct DeploymentExport(pub BTreeMap<String, BTreeMap<String, ExportedContract>>);
+
+impl DeploymentExport {
+    /// Builds the export of `deployment_id` from the content of a state file
+    pub(crate) fn from_state(state: &Value, deployment_id: &str) -> Self {
+        let mut export = BTreeMap::new();
+        let networks = state.as_object().into_iter().flat_map(|n| n.values());
+        for chains in networks {
+            let Some(chains) = chains.as_object() else {
+                continue;
+            };
+            for (chain_id, chain_state) in chains {
+                let mut contracts: BTreeMap<String, ExportedContract> = BTreeMap::new();
+                let code_ids = chain_state[CODE_IDS_KEY].as_object().into_iter().flatten();
+                for (contract_id, code_id) in code_ids {
+                    let contract = contracts.entry(contract_id.clone()).or_default();
+                    contract.code_id = code_id.as_u64();
+                    contract.checksum = code_id
+                        .as_u64()
+                        .and_then(|code_id| {
+                            chain_state[CHECKSUMS_KEY][code_id.to_string()].as_str()
+                        })
+                        .map(ToString::to_string);
+                }
+                let addresses = chain_state[deployment_id].as_object().into_iter().flatten();
+                for (contract_id, address) in addresses {
+                    contracts.entry(contract_id.clone()).or_default().address =
+                        address.as_str().map(ToString::to_string);
+                }
+                // Entries removed from the state are set to null
+                contracts.retain(|_, c| c.code_id.is_some() || c.address.is_some());
+                if !contracts.is_empty() {
+                    export.insert(chain_id.clone(), contracts);
+                }
+            }
+        }
+        Self(export)
+    }
+
+    /// Contracts deployed on `chain_id`
+    pub fn chain(&self, chain_id: &str) -> Option<&BTreeMap<String, ExportedContract>> {
+        self.0.get(chain_id)
+    }
+
+    /// Pretty printed JSON export
+    pub fn to_json(&self) -> Result<String, DaemonError> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    /// TypeScript module exporting the deployment as a typed constant
+    pub fn to_typescript(&self) -> Result<String, DaemonError> {
+        Ok(format!(
+            "// Generated by cw-orchestrator, do not edit manually
+
+export interface ContractDeployment {{
+  code_id: number | null;
+  address: string | null;
+  checksum: string | null;
+}}
+
+export const deployment: Record<string, Record<string, ContractDeployment>> = {};
+",
+            self.to_json()?
+        ))
+    }
+
+    /// Writes `deployment.json` and `deployment.ts` to `dir`
+    pub fn write(&self, dir: impl AsRef<Path>) -> Result<(), DaemonError> {
+        let dir = dir.as_ref();
+        std::fs::create_dir_all(dir)?;
+        std::fs::write(dir.join(JSON_EXPORT_FILE), self.to_json()?)?;
+        std::fs::write(dir.join(TS_EXPORT_FILE), self.to_typescript()?)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn export_from_state() {
+        let state = json!({
+            "juno": {
+                "juno-1": {
+                    "code_ids": { "counter": 42, "removed": null },
+                    "checksums": { "42": "abcd" },
+                    "default": { "counter": "juno1counter" },
+                    "staging": { "counter": "juno1staging" }
+                },
+                "uni-6": {
+                    "code_ids": {},
+                    "default": {}
+                }
+            }
+        });
+        let export = DeploymentExport::from_state(&state, "default");
+        assert_eq!(export.0.len(), 1);
+        assert_eq!(
+            export.chain("juno-1").unwrap()["counter"],
+            ExportedContract {
+                code_id: Some(42),
+                address: Some("juno1counter".to_string()),
+                checksum: Some("abcd".to_string()),
+            }
+        );
+        assert!(export.to_typescript().unwrap().contains("\"juno-1\""));
+    }
+}
@@ -1,7 +1,8 @@
 use crate::senders::builder::SenderBuilder;
 
+use crate::chain_registry::ChainRegistry;
 use crate::{DaemonAsyncBuilder, DaemonBase, DaemonState, Wallet, RUNTIME};
-use cw_orch_core::environment::ChainInfoOwned;
+use cw_orch_core::environment::{ChainInfoOwned, PubKeyType};
 
 use super::super::error::DaemonError;
 
@@ -31,6 +32,11 @@ pub struct DaemonBuilder {
     pub(crate) is_test: bool,
 
     pub(crate) mnemonic: Option<String>,
+    pub(crate) faucet_url: Option<String>,
+
+    // # Look up the chain info in the chain registry
+    pub(crate) registry_path: Option<String>,
+    pub(crate) chain_registry: ChainRegistry,
 }
 
 impl DaemonBuilder {
@@ -43,10 +49,29 @@ impl DaemonBuilder {
             state: None,
             write_on_change: None,
             mnemonic: None,
+            faucet_url: None,
             is_test: false,
+            registry_path: None,
+            chain_registry: ChainRegistry::default(),
+        }
+    }
+
+    /// Builder of a Daemon for the chain at `registry_path` in the chain registry, e.g. `juno` or `testnets/junotestnet`.
+    /// The chain info is looked up when building, the chain info overrides set on the builder take precedence.
+    /// See [`ChainRegistry`] for the caching of the registry data.
+    pub fn from_chain_registry(registry_path: impl Into<String>) -> Self {
+        Self {
+            registry_path: Some(registry_path.into()),
+            ..Self::new(ChainInfoOwned::default())
         }
     }
 
+    /// Set the chain registry the chain info is looked up in, see [`DaemonBuilder::from_chain_registry`]
+    pub fn chain_registry(&mut self, chain_registry: ChainRegistry) -> &mut Self {
+        self.chain_registry = chain_registry;
+        self
+    }
+
     /// Set a custom tokio runtime handle to use for the Daemon
     ///
     /// ## Example
@@ -84,6 +109,14 @@ impl DaemonBuilder {
         self
     }
 
+    /// Request funds from the faucet at `faucet_url` when the wallet balance is too low to pay for a transaction,
+    /// instead of waiting for a manual top-up. The faucet needs to expose the CosmJS faucet API.
+    /// Only applies to the default [`Wallet`] sender.
+    pub fn faucet_top_up(&mut self, faucet_url: impl Into<String>) -> &mut Self {
+        self.faucet_url = Some(faucet_url.into());
+        self
+    }
+
     /// Overwrites the gas denom used for broadcasting transactions.
     /// Behavior :
     /// - If no gas denom is provided, the first gas denom specified in the `self.chain` is used
@@ -99,6 +132,24 @@ impl DaemonBuilder {
         self
     }
 
+    /// Overwrites the bech32 prefix of the addresses, checked against the one of the chain when the wallet is built
+    pub fn address_prefix(&mut self, prefix: impl Into<String>) -> &mut Self {
+        self.chain.network_info.pub_address_prefix = prefix.into();
+        self
+    }
+
+    /// Overwrites the coin type used to derive the keys
+    pub fn coin_type(&mut self, coin_type: u32) -> &mut Self {
+        self.chain.network_info.coin_type = coin_type;
+        self
+    }
+
+    /// Overwrites the type of the account public keys, derived from the coin type by default
+    pub fn pub_key_type(&mut self, pub_key_type: PubKeyType) -> &mut Self {
+        self.chain.network_info.pub_key_type = Some(pub_key_type);
+        self
+    }
+
     /// Reuse already existent [`DaemonState`]
     /// Useful for multi-chain scenarios
     pub fn state(&mut self, state: DaemonState) -> &mut Self {
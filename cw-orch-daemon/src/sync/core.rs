@@ -1,15 +1,21 @@
-use std::{fmt::Debug, ops::DerefMut};
+use std::{fmt::Debug, ops::DerefMut, path::Path};
 
-use super::super::senders::Wallet;
+use super::super::senders::{hooks::SenderHook, TimeoutHeight, TxOptions, Wallet};
 use crate::{
-    queriers::{Bank, CosmWasmBase, Node},
-    senders::{builder::SenderBuilder, query::QuerySender},
+    budget::FeeBudget,
+    queriers::{Bank, CosmWasmBase, Distribution, Gov, Node, Staking},
+    query_cache::QueryCache,
+    senders::{builder::SenderBuilder, query::QuerySender, QueryOnlySender},
     CosmTxResponse, DaemonAsyncBase, DaemonBuilder, DaemonError, DaemonState,
 };
 use cosmwasm_std::{Addr, Coin};
 use cw_orch_core::{
     contract::{interface_traits::Uploadable, WasmPath},
-    environment::{ChainInfoOwned, ChainState, DefaultQueriers, QueryHandler, TxHandler},
+    environment::{
+        ChainInfoOwned, ChainState, DefaultQueriers, ModuleQueriers, QueryHandler, TxHandler,
+        TxSimulation,
+    },
+    CwEnvError,
 };
 use cw_orch_traits::stargate::Stargate;
 use serde::Serialize;
@@ -88,10 +94,37 @@ impl<Sender> DaemonBase<Sender> {
         self.daemon.flush_state()
     }
 
+    /// Switches the deployment id under which contract addresses are read and written.
+    /// Contracts created with a clone of this daemon before the switch keep using the previous deployment.
+    /// See [`DaemonState`](crate::DaemonState) to list, copy or prune deployments.
+    pub fn set_deployment_id(
+        &mut self,
+        deployment_id: impl Into<String>,
+    ) -> Result<(), DaemonError> {
+        self.daemon.set_deployment_id(deployment_id)
+    }
+
     /// Return the chain info for this daemon
     pub fn chain_info(&self) -> &ChainInfoOwned {
         self.daemon.chain_info()
     }
+
+    /// Block height at which the queries of this daemon are performed.
+    /// `None` means the latest block is used.
+    pub fn query_height(&self) -> Option<u64> {
+        self.daemon.query_height()
+    }
+
+    /// Cache of the smart queries of this daemon, if any
+    pub fn query_cache(&self) -> Option<&QueryCache> {
+        self.daemon.query_cache()
+    }
+
+    /// Caches the smart queries of this daemon, see [`QueryCache`].
+    /// Only the queries at a fixed height, see [`DaemonBase::at_height`], are cached.
+    pub fn set_query_cache(&mut self, cache: QueryCache) {
+        self.daemon.set_query_cache(cache)
+    }
 }
 
 impl<Sender: QuerySender> DaemonBase<Sender> {
@@ -100,6 +133,62 @@ impl<Sender: QuerySender> DaemonBase<Sender> {
         self.daemon.sender().channel()
     }
 
+    /// Returns a query-only handle on this daemon that queries the chain state at the given block height.
+    /// All the queriers created from it (wasm, bank, staking, ...) will use the `x-cosmos-block-height` gRPC header.
+    /// The targeted node needs to have the state for that height available (e.g. an archive node).
+    ///
+    /// ```rust,no_run
+    /// use cw_orch_daemon::{Daemon, networks, queriers::Bank};
+    /// use cw_orch_core::environment::{BankQuerier, QuerierGetter};
+    ///
+    /// let daemon = Daemon::builder(networks::JUNO_1).build().unwrap();
+    /// let past = daemon.at_height(15_000_000);
+    /// let bank: Bank = past.querier();
+    /// let balance = bank.balance("juno1...", None).unwrap();
+    /// ```
+    pub fn at_height(&self, height: u64) -> DaemonBase<QueryOnlySender> {
+        DaemonBase {
+            daemon: self.daemon.at_height(height),
+            rt_handle: self.rt_handle.clone(),
+        }
+    }
+
+    /// Broadcasts an already signed transaction and waits for its inclusion in a block.
+    /// `tx_bytes` is the protobuf encoding of a `TxRaw`, as produced by an offline signing flow.
+    pub fn broadcast_signed_tx(&self, tx_bytes: Vec<u8>) -> Result<CosmTxResponse, DaemonError> {
+        self.rt_handle
+            .block_on(self.daemon.broadcast_signed_tx(tx_bytes))
+    }
+
+    /// Broadcasts the transaction signed offline and saved at `path`, see [`crate::signed_tx::SignedTx`] for the supported formats.
+    pub fn broadcast_signed_tx_file(
+        &self,
+        path: impl AsRef<Path>,
+    ) -> Result<CosmTxResponse, DaemonError> {
+        self.rt_handle
+            .block_on(self.daemon.broadcast_signed_tx_file(path))
+    }
+
+    /// Waits for the transaction `hash` to be included in a block and buried under `confirmations` blocks, for at most `timeout`.
+    /// The transaction is searched again if a re-org drops it before it is confirmed.
+    ///
+    /// ```rust,no_run
+    /// use std::time::Duration;
+    /// use cw_orch_daemon::{Daemon, networks};
+    ///
+    /// let daemon = Daemon::builder(networks::JUNO_1).build().unwrap();
+    /// let tx = daemon.wait_for_tx("5C3F...", 3, Duration::from_secs(60)).unwrap();
+    /// ```
+    pub fn wait_for_tx(
+        &self,
+        hash: impl Into<String>,
+        confirmations: u64,
+        timeout: std::time::Duration,
+    ) -> Result<CosmTxResponse, DaemonError> {
+        self.rt_handle
+            .block_on(self.daemon.wait_for_tx(hash, confirmations, timeout))
+    }
+
     /// Returns a new [`DaemonBuilder`] with the current configuration.
     /// **Does not copy the `Sender`**
     /// Does not consume the original [`Daemon`].
@@ -112,8 +201,11 @@ impl<Sender: QuerySender> DaemonBase<Sender> {
             write_on_change: None,
             handle: Some(self.rt_handle.clone()),
             mnemonic: None,
+            faucet_url: None,
             // If it was test it will just use same tempfile as state
             is_test: false,
+            registry_path: None,
+            chain_registry: Default::default(),
         }
     }
 }
@@ -131,6 +223,72 @@ impl Daemon {
         self.sender_mut().set_fee_granter(granter.to_string());
         self
     }
+
+    /// Request funds from `faucet_url` when the wallet balance is too low to pay for a transaction
+    pub fn faucet_top_up(&mut self, faucet_url: impl Into<String>) -> &mut Self {
+        self.sender_mut().set_faucet_top_up(faucet_url);
+        self
+    }
+
+    /// Specifies the memo of the transactions sent with this daemon
+    pub fn memo(&mut self, memo: impl ToString) -> &mut Self {
+        self.sender_mut().set_memo(memo.to_string());
+        self
+    }
+
+    /// Specifies the timeout height of the transactions sent with this daemon
+    pub fn timeout_height(&mut self, timeout_height: TimeoutHeight) -> &mut Self {
+        self.sender_mut().set_timeout_height(timeout_height);
+        self
+    }
+
+    /// Returns a clone of this daemon sending its transactions with the memo and timeout height of `tx_options`,
+    /// this daemon keeps its own options.
+    ///
+    /// ```rust,no_run
+    /// use cw_orch_daemon::{Daemon, networks, senders::TxOptions};
+    ///
+    /// let daemon = Daemon::builder(networks::JUNO_1).build().unwrap();
+    /// let deposit = daemon.with_tx_options(TxOptions::default().memo("exchange-memo"));
+    /// // contract.call_as(deposit.sender()).deposit()?;
+    /// ```
+    pub fn with_tx_options(&self, tx_options: TxOptions) -> Self {
+        let mut daemon = self.clone();
+        *daemon.sender_mut() = self.sender().with_tx_options(tx_options);
+        daemon
+    }
+
+    /// Adds a hook called around the transactions sent with this daemon, see [`SenderHook`]
+    pub fn add_hook(&mut self, hook: impl SenderHook + 'static) -> &mut Self {
+        self.sender_mut().add_hook(hook);
+        self
+    }
+
+    /// Caps the fees and gas spent by the transactions sent with this daemon, see [`FeeBudget`]
+    pub fn budget(&mut self, budget: FeeBudget) -> &mut Self {
+        self.sender_mut().set_budget(budget);
+        self
+    }
+
+    /// Waits up to `grace_period` for the chain to resume when a transaction fails because it halted for an upgrade,
+    /// and sends the transaction again then
+    pub fn upgrade_grace_period(&mut self, grace_period: std::time::Duration) -> &mut Self {
+        self.sender_mut().set_upgrade_grace_period(grace_period);
+        self
+    }
+
+    /// Waits for the transactions sent with this daemon to be buried under `confirmations` blocks before returning them.
+    /// Fails the transactions with [`DaemonError::TxNotConfirmed`] if they aren't confirmed within `timeout`
+    pub fn confirmations(&mut self, confirmations: u64, timeout: std::time::Duration) -> &mut Self {
+        self.sender_mut().set_confirmations(confirmations, timeout);
+        self
+    }
+
+    /// Requests the fee token for the sender from the faucet at `faucet_url`, which needs to expose the CosmJS faucet API
+    pub fn request_faucet_funds(&self, faucet_url: &str) -> Result<(), DaemonError> {
+        self.rt_handle
+            .block_on(self.sender().request_faucet_funds(faucet_url))
+    }
 }
 
 impl<Sender> ChainState for DaemonBase<Sender> {
@@ -177,6 +335,12 @@ impl<Sender: TxSender> TxHandler for DaemonBase<Sender> {
             .block_on(self.daemon.execute(exec_msg, coins, contract_address))
     }
 
+    fn bank_send(&self, recipient: &Addr, amount: &[Coin]) -> Result<Self::Response, CwEnvError> {
+        self.rt_handle
+            .block_on(self.daemon.bank_send(recipient, amount))
+            .map_err(Into::into)
+    }
+
     fn instantiate<I: Serialize + Debug>(
         &self,
         code_id: u64,
@@ -217,6 +381,36 @@ impl<Sender: TxSender> TxHandler for DaemonBase<Sender> {
                 .instantiate2(code_id, init_msg, label, admin, coins, salt),
         )
     }
+
+    fn simulate_execute<E: Serialize + Debug>(
+        &self,
+        exec_msg: &E,
+        coins: &[Coin],
+        contract_address: &Addr,
+    ) -> Result<TxSimulation, CwEnvError> {
+        self.rt_handle
+            .block_on(
+                self.daemon
+                    .simulate_execute(exec_msg, coins, contract_address),
+            )
+            .map_err(Into::into)
+    }
+
+    fn simulate_instantiate<I: Serialize + Debug>(
+        &self,
+        code_id: u64,
+        init_msg: &I,
+        label: Option<&str>,
+        admin: Option<&Addr>,
+        coins: &[Coin],
+    ) -> Result<TxSimulation, CwEnvError> {
+        self.rt_handle
+            .block_on(
+                self.daemon
+                    .simulate_instantiate(code_id, init_msg, label, admin, coins),
+            )
+            .map_err(Into::into)
+    }
 }
 
 impl<Sender: TxSender> Stargate for DaemonBase<Sender> {
@@ -268,3 +462,59 @@ impl<Sender: QuerySender> DefaultQueriers for DaemonBase<Sender> {
     type Wasm = CosmWasmBase<Sender>;
     type Node = Node;
 }
+
+impl<Sender: QuerySender> ModuleQueriers for DaemonBase<Sender> {
+    type Staking = Staking;
+    type Distribution = Distribution;
+    type Gov = Gov;
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use cw_orch_core::environment::QuerierGetter;
+    use cw_orch_networks::networks::JUNO_1;
+
+    use super::*;
+
+    /// Query-only daemon on a channel that never connects, with a read-only state
+    fn offline_daemon() -> DaemonBase<QueryOnlySender> {
+        let chain = Arc::new(ChainInfoOwned::from(JUNO_1));
+        let channel = Channel::from_static("http://localhost:9090").connect_lazy();
+        let state = DaemonState::new(
+            crate::gen_temp_file_path().display().to_string(),
+            &chain,
+            "default".to_string(),
+            true,
+            false,
+        )
+        .unwrap();
+        DaemonBase {
+            daemon: DaemonAsyncBase::new(QueryOnlySender::new(channel, chain), state),
+            rt_handle: Handle::current(),
+        }
+    }
+
+    #[tokio::test]
+    async fn queriers_at_height() {
+        let daemon = offline_daemon();
+        assert_eq!(daemon.query_height(), None);
+        assert_eq!(Bank::new(&daemon).height, None);
+
+        let past = daemon.at_height(15_000_000);
+        assert_eq!(past.query_height(), Some(15_000_000));
+        assert_eq!(Bank::new(&past).height, Some(15_000_000));
+        assert_eq!(CosmWasmBase::new(&past).height, Some(15_000_000));
+        assert_eq!(Staking::new(&past).height, Some(15_000_000));
+
+        // The queriers of the environment traits too
+        let bank: Bank = past.querier();
+        assert_eq!(bank.height, Some(15_000_000));
+        let staking: Staking = past.querier();
+        assert_eq!(staking.height, Some(15_000_000));
+
+        // The original daemon still queries the latest block
+        assert_eq!(Bank::new(&daemon).height, None);
+    }
+}
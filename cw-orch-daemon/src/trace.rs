@@ -0,0 +1,291 @@
+//! Traces of the broadcast transactions, for debugging and block explorers.
+//!
+//! When the `CW_ORCH_TX_TRACE_DIR` env variable is set, every transaction broadcast by a [`Wallet`](crate::Wallet)
+//! is written to `<dir>/<txhash>.json`, whether it succeeded, failed during its execution or was rejected by the node (`CheckTx` failure).
+//! The trace bundles the request, with the messages decoded when possible, the fee and the memo,
+//! and the response with the gas, the raw log and the events.
+//!
+//! The explorer page of a transaction can be found from the [chain registry](https://github.com/cosmos/chain-registry)
+//! with [`fetch_explorer_tx_url`].
+
+use std::path::{Path, PathBuf};
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use cosmrs::{proto::cosmos::base::abci::v1beta1::TxResponse, Any};
+use cw_orch_core::{environment::IndexResponse, log::transaction_target};
+use prost::Message;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use tonic::transport::Channel;
+
+use crate::{
+    amino, chain_registry::ChainRegistry, cosmos_modules, env::DaemonEnvVars, CosmTxResponse,
+    DaemonError,
+};
+
+/// Request and response of a broadcast transaction
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct TxTrace {
+    pub chain_id: String,
+    pub txhash: String,
+    /// Height of the block, 0 if the transaction was rejected by the node
+    pub height: u64,
+    /// RFC 3339 timestamp of the block, empty if the transaction was rejected by the node
+    pub timestamp: String,
+    pub request: TxTraceRequest,
+    pub response: TxTraceResponse,
+}
+
+/// Body and fee of a traced transaction
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct TxTraceRequest {
+    /// Messages in their amino-JSON form, or with their raw base64 value if they can't be decoded
+    pub msgs: Vec<Value>,
+    pub memo: String,
+    pub timeout_height: u64,
+    pub fee: Value,
+}
+
+/// Result of a traced transaction
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct TxTraceResponse {
+    pub code: usize,
+    pub codespace: String,
+    pub gas_wanted: u64,
+    pub gas_used: u64,
+    pub raw_log: String,
+    /// Events of the transaction, empty if it was rejected by the node
+    pub events: Vec<cosmwasm_std::Event>,
+}
+
+impl TxTraceRequest {
+    fn new(body: cosmos_modules::tx::TxBody, fee: Option<cosmos_modules::tx::Fee>) -> Self {
+        Self {
+            msgs: body.messages.iter().map(decode_msg).collect(),
+            memo: body.memo,
+            timeout_height: body.timeout_height,
+            fee: fee.map_or(Value::Null, |fee| {
+                let amount: Vec<Value> = fee
+                    .amount
+                    .iter()
+                    .map(|c| json!({ "amount": c.amount, "denom": c.denom }))
+                    .collect();
+                json!({
+                    "amount": amount,
+                    "gas_limit": fee.gas_limit,
+                    "payer": fee.payer,
+                    "granter": fee.granter,
+                })
+            }),
+        }
+    }
+}
+
+impl TxTrace {
+    /// Queries the transaction `txhash` with its response
+    pub async fn fetch(
+        channel: Channel,
+        chain_id: &str,
+        txhash: &str,
+    ) -> Result<Self, DaemonError> {
+        let mut client = cosmos_modules::tx::service_client::ServiceClient::new(channel);
+        let resp = client
+            .get_tx(cosmos_modules::tx::GetTxRequest {
+                hash: txhash.to_string(),
+            })
+            .await?
+            .into_inner();
+        let tx = resp.tx.unwrap_or_default();
+        let body = tx.body.unwrap_or_default();
+        let fee = tx.auth_info.and_then(|auth_info| auth_info.fee);
+        let response: CosmTxResponse = resp
+            .tx_response
+            .ok_or_else(|| DaemonError::StdErr(format!("transaction {txhash} not found")))?
+            .into();
+
+        Ok(Self {
+            chain_id: chain_id.to_string(),
+            txhash: response.txhash.clone(),
+            height: response.height,
+            timestamp: response.timestamp.to_rfc3339(),
+            request: TxTraceRequest::new(body, fee),
+            response: TxTraceResponse {
+                events: response.events(),
+                code: response.code,
+                codespace: response.codespace,
+                gas_wanted: response.gas_wanted,
+                gas_used: response.gas_used,
+                raw_log: response.raw_log,
+            },
+        })
+    }
+
+    /// Trace of the encoded transaction `tx_bytes`, rejected by the node before its inclusion in a block (`CheckTx` failure).
+    /// The transaction can't be queried, its request is decoded from `tx_bytes` and its response is the broadcast `response`.
+    pub fn rejected(
+        chain_id: &str,
+        tx_bytes: &[u8],
+        response: &TxResponse,
+    ) -> Result<Self, DaemonError> {
+        let tx = cosmos_modules::tx::TxRaw::decode(tx_bytes)?;
+        let body = cosmos_modules::tx::TxBody::decode(tx.body_bytes.as_slice())?;
+        let fee = cosmos_modules::tx::AuthInfo::decode(tx.auth_info_bytes.as_slice())?.fee;
+
+        Ok(Self {
+            chain_id: chain_id.to_string(),
+            txhash: response.txhash.clone(),
+            height: 0,
+            timestamp: String::new(),
+            request: TxTraceRequest::new(body, fee),
+            response: TxTraceResponse {
+                code: response.code as usize,
+                codespace: response.codespace.clone(),
+                gas_wanted: response.gas_wanted as u64,
+                gas_used: response.gas_used as u64,
+                raw_log: response.raw_log.clone(),
+                events: vec![],
+            },
+        })
+    }
+
+    /// Writes the trace as pretty-printed JSON to `<dir>/<txhash>.json`, returning the path of the file
+    pub fn write(&self, dir: &Path) -> Result<PathBuf, DaemonError> {
+        std::fs::create_dir_all(dir)?;
+        let path = dir.join(format!("{}.json", self.txhash));
+        std::fs::write(&path, serde_json::to_string_pretty(self)?)?;
+        Ok(path)
+    }
+}
+
+/// Writes the trace of `txhash` if the `CW_ORCH_TX_TRACE_DIR` env variable is set. Failures are only logged.
+pub(crate) async fn trace_tx(channel: Channel, chain_id: &str, txhash: &str) {
+    let Some(dir) = DaemonEnvVars::tx_trace_dir() else {
+        return;
+    };
+    let trace = TxTrace::fetch(channel, chain_id, txhash).await;
+    write_trace(&dir, txhash, trace);
+}
+
+/// Writes the trace of the transaction `tx_bytes` rejected with `response` if the `CW_ORCH_TX_TRACE_DIR` env variable is set.
+/// Failures are only logged.
+pub(crate) fn trace_rejected_tx(chain_id: &str, tx_bytes: &[u8], response: &TxResponse) {
+    let Some(dir) = DaemonEnvVars::tx_trace_dir() else {
+        return;
+    };
+    let trace = TxTrace::rejected(chain_id, tx_bytes, response);
+    write_trace(&dir, &response.txhash, trace);
+}
+
+fn write_trace(dir: &Path, txhash: &str, trace: Result<TxTrace, DaemonError>) {
+    match trace.and_then(|trace| trace.write(dir)) {
+        Ok(path) => {
+            log::debug!(target: &transaction_target(), "Trace of tx {txhash} written to {path:?}")
+        }
+        Err(e) => {
+            log::warn!(target: &transaction_target(), "Failed to write the trace of tx {txhash} to {dir:?}: {e}")
+        }
+    }
+}
+
+fn decode_msg(msg: &Any) -> Value {
+    amino::amino_msg(msg).unwrap_or_else(|_| {
+        json!({
+            "type_url": msg.type_url,
+            "value": STANDARD.encode(&msg.value),
+        })
+    })
+}
+
+/// Transaction page of `txhash` on the first explorer of a chain-registry `chain.json` that has one
+pub fn explorer_tx_url(chain_json: &Value, txhash: &str) -> Option<String> {
+    chain_json["explorers"]
+        .as_array()?
+        .iter()
+        .find_map(|explorer| explorer["tx_page"].as_str())
+        .map(|tx_page| tx_page.replace("${txHash}", txhash))
+}
+
+/// Transaction page of `txhash` for the chain at `registry_path` in the chain registry,
+/// e.g. `juno` or `testnets/junotestnet`
+pub async fn fetch_explorer_tx_url(
+    registry_path: &str,
+    txhash: &str,
+) -> Result<Option<String>, DaemonError> {
+    let chain_json = ChainRegistry::default().chain_json(registry_path).await?;
+    Ok(explorer_tx_url(&chain_json, txhash))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn explorer_url_from_chain_registry() {
+        let chain_json = json!({
+            "chain_name": "juno",
+            "explorers": [
+                { "kind": "ezstaking", "url": "https://ezstaking.app/juno" },
+                { "kind": "mintscan", "url": "https://www.mintscan.io/juno", "tx_page": "https://www.mintscan.io/juno/transactions/${txHash}" }
+            ]
+        });
+        assert_eq!(
+            explorer_tx_url(&chain_json, "ABCD"),
+            Some("https://www.mintscan.io/juno/transactions/ABCD".to_string())
+        );
+        assert_eq!(explorer_tx_url(&json!({}), "ABCD"), None);
+    }
+
+    #[test]
+    fn undecodable_msg_is_kept_raw() {
+        let msg = Any {
+            type_url: "/cosmos.staking.v1beta1.MsgDelegate".to_string(),
+            value: vec![1, 2, 3],
+        };
+        assert_eq!(
+            decode_msg(&msg),
+            json!({ "type_url": "/cosmos.staking.v1beta1.MsgDelegate", "value": "AQID" })
+        );
+    }
+
+    #[test]
+    fn rejected_tx_is_traced_from_its_bytes() -> Result<(), DaemonError> {
+        let body = cosmos_modules::tx::TxBody {
+            messages: vec![Any {
+                type_url: "/cosmos.staking.v1beta1.MsgDelegate".to_string(),
+                value: vec![1, 2, 3],
+            }],
+            memo: "memo".to_string(),
+            ..Default::default()
+        };
+        let auth_info = cosmos_modules::tx::AuthInfo {
+            fee: Some(cosmos_modules::tx::Fee {
+                gas_limit: 200_000,
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let tx_bytes = cosmos_modules::tx::TxRaw {
+            body_bytes: body.encode_to_vec(),
+            auth_info_bytes: auth_info.encode_to_vec(),
+            signatures: vec![],
+        }
+        .encode_to_vec();
+        let response = TxResponse {
+            txhash: "ABCD".to_string(),
+            code: 13,
+            codespace: "sdk".to_string(),
+            raw_log: "insufficient fee".to_string(),
+            ..Default::default()
+        };
+
+        let trace = TxTrace::rejected("juno-1", &tx_bytes, &response)?;
+        assert_eq!(trace.txhash, "ABCD");
+        assert_eq!(trace.height, 0);
+        assert_eq!(trace.request.memo, "memo");
+        assert_eq!(trace.request.msgs.len(), 1);
+        assert_eq!(trace.request.fee["gas_limit"], 200_000);
+        assert_eq!(trace.response.code, 13);
+        assert_eq!(trace.response.raw_log, "insufficient fee");
+        Ok(())
+    }
+}
@@ -1,7 +1,7 @@
 use cosmrs::proto::cosmos::base::abci::v1beta1::TxResponse;
 use cw_orch_core::log::transaction_target;
 
-use crate::{queriers::Node, CosmTxResponse, DaemonError, TxBuilder, Wallet};
+use crate::{queriers::Node, trace, CosmTxResponse, DaemonError, TxBuilder, Wallet};
 
 pub type StrategyAction =
     fn(&mut TxBuilder, &Result<TxResponse, DaemonError>) -> Result<(), DaemonError>;
@@ -125,10 +125,21 @@ async fn broadcast_helper(
     wallet: &Wallet,
 ) -> Result<TxResponse, DaemonError> {
     let tx = tx_builder.build(wallet).await?;
+    let tx_bytes = tx.to_bytes()?;
     let tx_response = wallet.broadcast_tx(tx).await?;
     log::debug!(target: &transaction_target(), "TX broadcast response: {:?}", tx_response);
 
-    assert_broadcast_code_response(tx_response)
+    if tx_response.code != 0 {
+        // The rejected tx isn't included in a block, it can't be traced from the chain like the others
+        trace::trace_rejected_tx(&wallet.chain_info.chain_id, &tx_bytes, &tx_response);
+    }
+    let tx_response = assert_broadcast_code_response(tx_response)?;
+    // The tx passed the node checks, its fee will be paid even if its execution fails
+    if let (Some(budget), Some((fee, gas_limit))) = (&wallet.options.budget, tx_builder.signed_fee)
+    {
+        budget.record(&cosmwasm_std::coin(fee, wallet.get_fee_token()), gas_limit);
+    }
+    Ok(tx_response)
 }
 
 /// Tx Responses with a non 0 code, should also error with the raw loq
@@ -142,6 +153,7 @@ pub(crate) fn assert_broadcast_code_response(
     } else {
         Err(DaemonError::TxFailed {
             code: tx_response.code as usize,
+            codespace: tx_response.codespace,
             reason: tx_response.raw_log,
         })
     }
@@ -158,6 +170,7 @@ pub fn assert_broadcast_code_cosm_response(
     } else {
         Err(DaemonError::TxFailed {
             code: tx_response.code,
+            codespace: tx_response.codespace,
             reason: tx_response.raw_log,
         })
     }
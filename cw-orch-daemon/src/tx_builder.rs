@@ -10,7 +10,10 @@ use cosmrs::{
 };
 use cw_orch_core::log::transaction_target;
 
-use crate::Wallet;
+use crate::{
+    senders::{self, hooks::SignRequest, tx::TxSender},
+    Wallet,
+};
 
 use super::DaemonError;
 
@@ -24,6 +27,8 @@ pub struct TxBuilder {
     pub(crate) gas_limit: Option<u64>,
     // if defined, use this sequence, else get it from the node
     pub(crate) sequence: Option<SequenceNumber>,
+    // fee and gas limit of the last signed tx, added to the wallet budget once broadcast
+    pub(crate) signed_fee: Option<(u128, u64)>,
 }
 
 impl TxBuilder {
@@ -34,6 +39,7 @@ impl TxBuilder {
             fee_amount: None,
             gas_limit: None,
             sequence: None,
+            signed_fee: None,
         }
     }
     /// Set a fixed fee amount for the tx
@@ -130,6 +136,29 @@ impl TxBuilder {
             (fee_amount, gas_expected)
         };
 
+        if let Some(budget) = &wallet.options.budget {
+            budget.check(
+                &cosmwasm_std::coin(tx_fee, wallet.get_fee_token()),
+                gas_limit,
+            )?;
+        }
+        self.signed_fee = Some((tx_fee, gas_limit));
+
+        if !wallet.options.hooks.is_empty() {
+            let fee = cosmwasm_std::coin(tx_fee, wallet.get_fee_token());
+            let request = SignRequest {
+                chain_id: &wallet.chain_info.chain_id,
+                signer: &wallet.account_id(),
+                msgs: &self.body.messages,
+                memo: &self.body.memo,
+                fee: &fee,
+                gas_limit,
+            };
+            for hook in &wallet.options.hooks {
+                hook.on_before_sign(&request)?;
+            }
+        }
+
         let fee = Self::build_fee(
             tx_fee,
             &wallet.get_fee_token(),
@@ -145,13 +174,21 @@ impl TxBuilder {
             sequence
         );
 
+        let sign_mode = match wallet.options.sign_mode {
+            senders::SignMode::Direct => SignMode::Direct,
+            senders::SignMode::LegacyAminoJson => SignMode::LegacyAminoJson,
+        };
         let auth_info = SignerInfo {
             public_key: wallet.private_key.get_signer_public_key(&wallet.secp),
-            mode_info: ModeInfo::single(SignMode::Direct),
+            mode_info: ModeInfo::single(sign_mode),
             sequence,
         }
         .auth_info(fee);
 
+        if sign_mode == SignMode::LegacyAminoJson {
+            return wallet.sign_amino(&self.body, &auth_info, account_number, sequence);
+        }
+
         let sign_doc = SignDoc::new(
             &self.body,
             &auth_info,
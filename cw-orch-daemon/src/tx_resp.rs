@@ -1,15 +1,19 @@
-use prost::bytes::Bytes;
+use prost::{bytes::Bytes, Message, Name};
 
 use super::{
     cosmos_modules::{
-        abci::{AbciMessageLog, Attribute, StringEvent, TxResponse},
+        abci::{AbciMessageLog, Attribute, StringEvent, TxMsgData, TxResponse},
+        cosmwasm::{
+            MsgInstantiateContract2Response, MsgInstantiateContractResponse, MsgStoreCodeResponse,
+        },
         tendermint_abci::Event,
     },
     error::DaemonError,
 };
 use chrono::{DateTime, NaiveDateTime, TimeZone, Utc};
 
-use cosmwasm_std::{to_json_binary, Binary, StdError, StdResult};
+use cosmrs::Any;
+use cosmwasm_std::{to_json_binary, Addr, Binary, StdError, StdResult};
 use cw_orch_core::environment::IndexResponse;
 use serde::{Deserialize, Serialize};
 
@@ -18,10 +22,28 @@ const FORMAT_TZ_SUPPLIED: &str = "%Y-%m-%dT%H:%M:%S.%f%:z";
 const FORMAT_SHORT_Z: &str = "%Y-%m-%dT%H:%M:%SZ";
 const FORMAT_SHORT_Z2: &str = "%Y-%m-%dT%H:%M:%S.%fZ";
 
+const STORE_CODE_RESPONSE_TYPE_URL: &str = "/cosmwasm.wasm.v1.MsgStoreCodeResponse";
+const INSTANTIATE_RESPONSE_TYPE_URL: &str = "/cosmwasm.wasm.v1.MsgInstantiateContractResponse";
+const INSTANTIATE2_RESPONSE_TYPE_URL: &str = "/cosmwasm.wasm.v1.MsgInstantiateContract2Response";
+
 fn parse_attribute_bytes(value: &Bytes) -> String {
     String::from_utf8_lossy(value).to_string()
 }
 
+fn decode_event(event: &Event) -> TxResultBlockEvent {
+    TxResultBlockEvent {
+        s_type: event.r#type.clone(),
+        attributes: event
+            .attributes
+            .iter()
+            .map(|attr| TxResultBlockAttribute {
+                key: parse_attribute_bytes(&attr.key),
+                value: parse_attribute_bytes(&attr.value),
+            })
+            .collect(),
+    }
+}
+
 /// The response from a transaction performed on a blockchain.
 #[derive(Debug, Default, Clone)]
 pub struct CosmTxResponse {
@@ -97,17 +119,7 @@ impl CosmTxResponse {
                 .events
                 .iter()
                 .filter(|event| event.r#type == event_type)
-                .map(|event| TxResultBlockEvent {
-                    s_type: event.r#type.clone(),
-                    attributes: event
-                        .attributes
-                        .iter()
-                        .map(|attr| TxResultBlockAttribute {
-                            key: parse_attribute_bytes(&attr.key),
-                            value: parse_attribute_bytes(&attr.value),
-                        })
-                        .collect(),
-                })
+                .map(decode_event)
                 .collect::<Vec<_>>();
 
             events_filtered
@@ -116,6 +128,18 @@ impl CosmTxResponse {
         }
     }
 
+    /// get all the events of a TX record, in the order they were emitted
+    pub fn all_events(&self) -> Vec<TxResultBlockEvent> {
+        if self.events.is_empty() {
+            self.logs
+                .iter()
+                .flat_map(|log_part| log_part.events.iter().cloned())
+                .collect()
+        } else {
+            self.events.iter().map(decode_event).collect()
+        }
+    }
+
     fn get_events_from_logs(&self, event_type: &str) -> Vec<TxResultBlockEvent> {
         let mut response: Vec<TxResultBlockEvent> = Default::default();
 
@@ -134,6 +158,72 @@ impl CosmTxResponse {
 
         response
     }
+
+    /// Responses of the messages of the transaction, in order, decoded from the `data` field.
+    /// Before Cosmos SDK 0.46, responses are not typed and their type url is derived from the type url of the message.
+    pub fn msg_responses(&self) -> Result<Vec<Any>, DaemonError> {
+        if self.data.is_empty() {
+            return Ok(vec![]);
+        }
+        let tx_msg_data = TxMsgData::decode(hex::decode(&self.data)?.as_slice())?;
+        if !tx_msg_data.msg_responses.is_empty() {
+            return Ok(tx_msg_data.msg_responses);
+        }
+        #[allow(deprecated)]
+        let legacy_data = tx_msg_data.data;
+        Ok(legacy_data
+            .into_iter()
+            .map(|msg_data| Any {
+                type_url: format!("{}Response", msg_data.msg_type),
+                value: msg_data.data,
+            })
+            .collect())
+    }
+
+    /// Decoded responses with type url `type_url` of the messages of the transaction, in order
+    pub fn msg_responses_of<T: Message + Default>(
+        &self,
+        type_url: &str,
+    ) -> Result<Vec<T>, DaemonError> {
+        self.msg_responses()?
+            .into_iter()
+            .filter(|response| response.type_url == type_url)
+            .map(|response| T::decode(response.value.as_slice()).map_err(Into::into))
+            .collect()
+    }
+
+    /// Decoded responses of type `T` of the messages of the transaction, in order.
+    /// For types that don't implement [`Name`], like the ones of `osmosis-std`, use [`Self::msg_responses_of`] with their `TYPE_URL`.
+    pub fn msg_responses_as<T: Name + Default>(&self) -> Result<Vec<T>, DaemonError> {
+        self.msg_responses_of(&T::type_url())
+    }
+
+    /// Code ids of the codes stored by the `MsgStoreCode` messages of the transaction
+    pub fn stored_code_ids(&self) -> Result<Vec<u64>, DaemonError> {
+        Ok(self
+            .msg_responses_of::<MsgStoreCodeResponse>(STORE_CODE_RESPONSE_TYPE_URL)?
+            .into_iter()
+            .map(|response| response.code_id)
+            .collect())
+    }
+
+    /// Addresses of the contracts instantiated by the `MsgInstantiateContract` and `MsgInstantiateContract2` messages of the transaction, in order
+    pub fn instantiated_contract_addresses(&self) -> Result<Vec<Addr>, DaemonError> {
+        let mut addresses = vec![];
+        for response in self.msg_responses()? {
+            let address = match response.type_url.as_str() {
+                INSTANTIATE_RESPONSE_TYPE_URL => {
+                    MsgInstantiateContractResponse::decode(response.value.as_slice())?.address
+                }
+                INSTANTIATE2_RESPONSE_TYPE_URL => {
+                    MsgInstantiateContract2Response::decode(response.value.as_slice())?.address
+                }
+                _ => continue,
+            };
+            addresses.push(Addr::unchecked(address));
+        }
+        Ok(addresses)
+    }
 }
 
 // NOTE: Should we keep this here or only for tests?
@@ -192,6 +282,10 @@ impl IndexResponse for CosmTxResponse {
         }
     }
 
+    fn gas_used(&self) -> Option<u64> {
+        Some(self.gas_used)
+    }
+
     fn event_attr_value(&self, event_type: &str, attr_key: &str) -> StdResult<String> {
         for event in &self.events {
             if event.r#type == event_type {
@@ -208,6 +302,21 @@ impl IndexResponse for CosmTxResponse {
         )))
     }
 
+    fn instantiated_contract_address(&self) -> StdResult<Addr> {
+        // The typed message responses don't depend on the events emitted by the chain
+        match self.instantiated_contract_addresses() {
+            Ok(addresses) if !addresses.is_empty() => Ok(addresses[0].clone()),
+            _ => self.instantiated_contract_address_from_events(),
+        }
+    }
+
+    fn uploaded_code_id(&self) -> StdResult<u64> {
+        match self.stored_code_ids() {
+            Ok(code_ids) if !code_ids.is_empty() => Ok(code_ids[0]),
+            _ => self.uploaded_code_id_from_events(),
+        }
+    }
+
     fn event_attr_values(&self, event_type: &str, attr_key: &str) -> Vec<String> {
         let mut all_results = vec![];
 
@@ -269,6 +378,22 @@ impl From<StringEvent> for TxResultBlockEvent {
     }
 }
 
+impl From<cosmrs::tendermint::abci::Event> for TxResultBlockEvent {
+    fn from(event: cosmrs::tendermint::abci::Event) -> Self {
+        Self {
+            s_type: event.kind,
+            attributes: event
+                .attributes
+                .into_iter()
+                .map(|attr| TxResultBlockAttribute {
+                    key: attr.key,
+                    value: attr.value,
+                })
+                .collect(),
+        }
+    }
+}
+
 impl TxResultBlockEvent {
     /// get all key/values from the event that have the key 'key'
     pub fn get_attributes(&self, key: &str) -> Vec<TxResultBlockAttribute> {
@@ -285,6 +410,11 @@ impl TxResultBlockEvent {
             .first()
             .map(|attr| attr.value.clone())
     }
+
+    /// whether the event was emitted by a contract, as `wasm` or as a custom `wasm-{type}` event
+    pub(crate) fn is_wasm(&self) -> bool {
+        self.s_type == "wasm" || self.s_type.starts_with("wasm-")
+    }
 }
 
 /// A single attribute of an event.
@@ -335,3 +465,82 @@ pub fn parse_timestamp(s: String) -> Result<DateTime<Utc>, DaemonError> {
         Ok(dt) => Ok(Utc.from_utc_datetime(&dt)),
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::cosmos_modules::abci::MsgData;
+
+    fn response_with_data(tx_msg_data: TxMsgData) -> CosmTxResponse {
+        CosmTxResponse {
+            data: hex::encode_upper(tx_msg_data.encode_to_vec()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn typed_msg_responses() {
+        let response = response_with_data(TxMsgData {
+            msg_responses: vec![
+                Any {
+                    type_url: STORE_CODE_RESPONSE_TYPE_URL.to_string(),
+                    value: MsgStoreCodeResponse {
+                        code_id: 42,
+                        checksum: vec![],
+                    }
+                    .encode_to_vec(),
+                },
+                Any {
+                    type_url: INSTANTIATE2_RESPONSE_TYPE_URL.to_string(),
+                    value: MsgInstantiateContract2Response {
+                        address: "juno1contract".to_string(),
+                        data: vec![],
+                    }
+                    .encode_to_vec(),
+                },
+            ],
+            ..Default::default()
+        });
+
+        // No events are needed
+        assert_eq!(response.uploaded_code_id().unwrap(), 42);
+        assert_eq!(
+            response.instantiated_contract_address().unwrap(),
+            Addr::unchecked("juno1contract")
+        );
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn legacy_msg_data() {
+        let response = response_with_data(TxMsgData {
+            data: vec![MsgData {
+                msg_type: "/cosmwasm.wasm.v1.MsgInstantiateContract".to_string(),
+                data: MsgInstantiateContractResponse {
+                    address: "juno1contract".to_string(),
+                    data: vec![],
+                }
+                .encode_to_vec(),
+            }],
+            ..Default::default()
+        });
+
+        assert_eq!(
+            response.instantiated_contract_addresses().unwrap(),
+            vec![Addr::unchecked("juno1contract")]
+        );
+        assert!(response.stored_code_ids().unwrap().is_empty());
+    }
+
+    #[test]
+    fn wasm_events() {
+        let event = |s_type: &str| TxResultBlockEvent {
+            s_type: s_type.to_string(),
+            attributes: vec![],
+        };
+        assert!(event("wasm").is_wasm());
+        assert!(event("wasm-liquidate").is_wasm());
+        assert!(!event("wasmd").is_wasm());
+        assert!(!event("instantiate").is_wasm());
+    }
+}
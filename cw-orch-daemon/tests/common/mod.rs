@@ -3,18 +3,19 @@ pub use node::*;
 
 #[cfg(feature = "node-tests")]
 mod node {
-    use std::{env, fs, path::Path, thread::sleep, time::Duration};
+    use std::{env, fs, path::Path, sync::Mutex};
 
     use ctor::{ctor, dtor};
 
-    use cw_orch_daemon::env::DaemonEnvVars;
-    use duct::cmd;
+    use cw_orch_daemon::{env::DaemonEnvVars, LocalChain};
 
     // Config
     const JUNO_IMAGE: &str = "ghcr.io/cosmoscontracts/juno:v12.0.0";
     #[allow(unused)]
     pub const STAKE_TOKEN: &str = "ujunox";
 
+    static LOCAL_CHAIN: Mutex<Option<LocalChain>> = Mutex::new(None);
+
     // Defaults for env vars
     const CONTAINER_NAME: &str = "juno_node_1";
     // From https://github.com/CosmosContracts/juno/blob/32568dba828ff7783aea8cb5bb4b8b5832888255/docker/test-user.env#L2
@@ -41,92 +42,6 @@ mod node {
         }
     }
 
-    pub mod container {
-        use crate::common::STAKE_TOKEN;
-
-        use super::cmd;
-
-        pub fn find(name: &String) -> bool {
-            let read = cmd!("docker", "container", "ls", "--all")
-                .pipe(cmd!("grep", name))
-                .pipe(cmd!("rev"))
-                .pipe(cmd!("cut", "-d", r#" "#, "-f1"))
-                .pipe(cmd!("rev"))
-                .read();
-
-            match read {
-                Ok(val) => {
-                    log::info!("Container found: {}", name);
-                    val == *name
-                }
-                Err(_) => false,
-            }
-        }
-
-        pub fn start(name: &String, image: &String) -> bool {
-            if self::find(name) {
-                return false;
-            }
-
-            // Start Docker with the appropriate ports and the provided environment variables and script
-            cmd!(
-                "docker",
-                "run",
-                "-d", // Run the container in detached mode
-                "--name",
-                name,
-                "-p",
-                "1317:1317",
-                "-p",
-                "26656:26656",
-                "-p",
-                "9090:9090",
-                "-e",
-                format!("STAKE_TOKEN={}", STAKE_TOKEN),
-                "-e",
-                "UNSAFE_CORS=true",
-                image,
-                "./setup_and_run.sh",
-                "juno16g2rahf5846rxzp3fwlswy08fz8ccuwk03k57y"
-            )
-            .read()
-            .is_ok()
-        }
-
-        pub fn stop(name: &String) -> bool {
-            if !self::find(name) {
-                return true;
-            }
-
-            log::info!("Stopping container: {}", name);
-
-            let res = cmd!("docker", "container", "stop", name)
-                .read()
-                .ok()
-                .unwrap();
-
-            res == *name
-        }
-
-        pub fn remove(name: &String) -> bool {
-            if !self::find(name) {
-                return true;
-            }
-
-            log::info!("Removing container: {}", name);
-
-            let res = cmd!("docker", "container", "rm", name).read().ok().unwrap();
-
-            res == *name
-        }
-
-        pub fn ensure_removal(name: &String) {
-            if self::stop(name) {
-                self::remove(name);
-            }
-        }
-    }
-
     pub fn docker_container_start() {
         log::info!("Running docker_container_start");
 
@@ -161,15 +76,19 @@ mod node {
             DaemonEnvVars::local_mnemonic()
         );
 
-        container::start(&container, &image);
-
-        // Wait for docker to start
-        sleep(Duration::from_secs(10));
+        // Starts the node with the local mnemonic funded and waits for its first block
+        let chain = LocalChain::juno()
+            .container_name(container)
+            .image(image)
+            .start()
+            .unwrap();
+        *LOCAL_CHAIN.lock().unwrap() = Some(chain);
     }
 
     pub fn docker_container_stop() {
         log::info!("Running docker_container_stop");
-        container::ensure_removal(&env::var("CONTAINER_NAME").unwrap());
+        // Dropping the chain removes its container
+        LOCAL_CHAIN.lock().unwrap().take();
         let temp_dir = env::temp_dir();
         let expected_state_file = temp_dir.join("cw_orch_test_local.json");
         if let Some(state_file) = expected_state_file.to_str() {
@@ -133,4 +133,32 @@ mod tests {
             .that(&contract.upload_if_needed().unwrap())
             .is_none();
     }
+
+    #[test]
+    #[serial_test::serial]
+    fn wait_for_tx() {
+        use cw_orch_core::environment::QueryHandler;
+        use cw_orch_networks::networks;
+        use std::time::Duration;
+
+        let mut daemon = Daemon::builder(networks::LOCAL_JUNO)
+            .is_test(true)
+            .build()
+            .unwrap();
+        daemon.confirmations(2, Duration::from_secs(60));
+
+        let contract = mock_contract::MockContract::new("test:mock_contract", daemon.clone());
+        let upload_res = contract.upload().unwrap();
+        let height = daemon.block_info().unwrap().height;
+        asserting!("tx is buried under 2 blocks")
+            .that(&height)
+            .is_greater_than_or_equal_to(upload_res.height + 2);
+
+        let confirmed = daemon
+            .wait_for_tx(upload_res.txhash.clone(), 1, Duration::from_secs(60))
+            .unwrap();
+        asserting!("confirmed tx is the uploaded one")
+            .that(&confirmed.height)
+            .is_equal_to(upload_res.height);
+    }
 }
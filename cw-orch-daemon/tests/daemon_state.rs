@@ -1,6 +1,6 @@
 use std::sync::Arc;
 
-use cw_orch_core::environment::ChainState;
+use cw_orch_core::environment::{ChainState, StateInterface};
 use cw_orch_daemon::{
     env::STATE_FILE_ENV_NAME,
     json_lock::JsonLockedState,
@@ -217,6 +217,49 @@ fn reuse_same_state_multichain() {
     std::env::remove_var(STATE_FILE_ENV_NAME);
 }
 
+#[test]
+#[serial_test::serial]
+fn manage_deployments() {
+    let mut daemon = DaemonBuilder::new(JUNO_1)
+        .mnemonic(DUMMY_MNEMONIC)
+        .deployment_id("staging")
+        .is_test(true)
+        .build()
+        .unwrap();
+
+    let mut state = daemon.state();
+    state.set("staging", "counter", "juno1counter").unwrap();
+    state.copy_deployment("staging", "prod-candidate").unwrap();
+    state.set("stale", "counter", "juno1stale").unwrap();
+    assert_eq!(
+        state.deployment_ids().unwrap(),
+        vec!["prod-candidate", "stale", "staging"]
+    );
+    assert!(matches!(
+        state.copy_deployment("staging", "stale"),
+        Err(DaemonError::DeploymentAlreadyExists(_))
+    ));
+
+    daemon.set_deployment_id("prod-candidate").unwrap();
+    assert_eq!(
+        daemon.state().get_address("counter").unwrap().as_str(),
+        "juno1counter"
+    );
+
+    let mut state = daemon.state();
+    assert!(matches!(
+        state.remove_deployment("prod-candidate"),
+        Err(DaemonError::ActiveDeployment(_))
+    ));
+    let pruned = state.prune_deployments(&["staging"]).unwrap();
+    assert_eq!(pruned, vec!["stale"]);
+    assert_eq!(
+        state.deployment_ids().unwrap(),
+        vec!["prod-candidate", "staging"]
+    );
+    std::env::remove_var(STATE_FILE_ENV_NAME);
+}
+
 #[test]
 #[serial_test::serial]
 #[should_panic]
@@ -0,0 +1,42 @@
+use cw_orch_core::environment::{BankQuerier, QuerierGetter, TxHandler};
+use cw_orch_daemon::{queriers::Bank, LocalChain, LocalChainBuilder};
+use cw_orch_networks::networks::{LOCAL_JUNO, LOCAL_OSMO, LOCAL_WASMD};
+
+pub const DUMMY_MNEMONIC:&str = "chapter wrist alcohol shine angry noise mercy simple rebel recycle vehicle wrap morning giraffe lazy outdoor noise blood ginger sort reunion boss crowd dutch";
+
+/// Start the chain and check the sender got funded in genesis
+fn funded_sender(mut builder: LocalChainBuilder, denom: &str) {
+    let chain = builder
+        .mnemonic(DUMMY_MNEMONIC)
+        .container_name(format!("cw-orch-test-{denom}"))
+        .start()
+        .unwrap();
+    let daemon = chain.daemon().unwrap();
+
+    let bank: Bank = daemon.querier();
+    let balance = bank
+        .balance(&daemon.sender_addr(), Some(denom.to_string()))
+        .unwrap();
+    assert!(!balance[0].amount.is_zero());
+}
+
+#[test]
+#[serial_test::serial]
+#[ignore = "This test starts Docker containers"]
+fn local_juno() {
+    funded_sender(LocalChain::juno(), LOCAL_JUNO.gas_denom)
+}
+
+#[test]
+#[serial_test::serial]
+#[ignore = "This test starts Docker containers"]
+fn local_wasmd() {
+    funded_sender(LocalChain::wasmd(), LOCAL_WASMD.gas_denom)
+}
+
+#[test]
+#[serial_test::serial]
+#[ignore = "This test starts Docker containers"]
+fn local_osmosis() {
+    funded_sender(LocalChain::osmosis(), LOCAL_OSMO.gas_denom)
+}
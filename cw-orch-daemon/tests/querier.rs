@@ -12,7 +12,7 @@ mod queriers {
 
     use cw_orch_daemon::{
         queriers::StakingBondStatus,
-        queriers::{CosmWasm, Gov, Ibc, Node, Staking},
+        queriers::{CosmWasm, Gov, Ibc, Node, Rpc, Staking},
         Daemon, DaemonError,
     };
     use tokio::runtime::Runtime;
@@ -137,6 +137,12 @@ mod queriers {
 
         let params = rt.block_on(cw._params());
         asserting!("params is ok").that(&params).is_ok();
+
+        let pinned_codes = rt.block_on(cw._pinned_codes());
+        asserting!("pinned_codes is ok").that(&pinned_codes).is_ok();
+
+        let can_upload = rt.block_on(cw._can_upload("juno16g2rahf5846rxzp3fwlswy08fz8ccuwk03k57y"));
+        asserting!("can_upload is ok").that(&can_upload).is_ok();
     }
 
     /*
@@ -159,6 +165,36 @@ mod queriers {
         asserting!("block_time is ok").that(&block_time).is_ok();
     }
 
+    /*
+        Querier - Rpc
+    */
+    #[test]
+    fn rpc() {
+        let daemon = Daemon::builder(networks::LOCAL_JUNO)
+            .is_test(true)
+            .build()
+            .unwrap();
+        let rpc = Rpc::new(&daemon, "http://localhost:26657").unwrap();
+
+        let block = rpc.block(None);
+        asserting!("block is ok").that(&block).is_ok();
+        let height = block.unwrap().header.height.value();
+
+        let block_results = rpc.block_results(Some(height));
+        asserting!("block_results is ok")
+            .that(&block_results)
+            .is_ok();
+        assert_eq!(block_results.unwrap().height, height);
+
+        let consensus_params = rpc.consensus_params(None);
+        asserting!("consensus_params is ok")
+            .that(&consensus_params)
+            .is_ok();
+
+        let tx_search = rpc.tx_search(&format!("tx.height>={height}"), 1, 10);
+        asserting!("tx_search is ok").that(&tx_search).is_ok();
+    }
+
     #[test]
     #[serial_test::serial]
     fn simulate_tx() {
@@ -234,6 +270,14 @@ mod queriers {
         asserting!("contract info is ok")
             .that(&contract_info)
             .is_ok();
+
+        let code_id = contract.code_id().unwrap();
+        let downloaded = rt.block_on(cosm_wasm._download_code(code_id)).unwrap();
+        assert_eq!(
+            downloaded.checksum().unwrap(),
+            rt.block_on(cosm_wasm._code_id_hash(code_id)).unwrap(),
+            "downloaded code matches the uploaded one"
+        );
     }
 
     fn parse_cw_coins(coins: &[cosmwasm_std::Coin]) -> Result<Vec<cosmrs::Coin>, DaemonError> {
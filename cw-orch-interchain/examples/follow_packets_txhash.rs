@@ -9,6 +9,7 @@ pub const NOBLE: NetworkInfo = NetworkInfo {
     chain_name: "noble",
     pub_address_prefix: "noble",
     coin_type: 118,
+    pub_key_type: None,
 };
 pub const NOBLE_1: ChainInfo = ChainInfo {
     chain_id: "noble-1",
@@ -17,24 +17,26 @@ pub use crate::contract::interface_traits::{
     InstantiableContract, MigratableContract, QueryableContract, Uploadable,
 };
 
-pub use cw_orch_core::contract::Deploy;
+pub use cw_orch_core::contract::{Deploy, DeployCheckpoint};
 
 pub use crate::environment::ChainState;
 pub use crate::environment::StateInterface;
 
 // Response trait
+pub use crate::environment::ContractEntryPoint;
 pub use crate::environment::IndexResponse;
 
 // Environment
 pub use crate::environment::{
-    BankQuerier, BankSetter, CwEnv, DefaultQueriers, EnvironmentInfo, EnvironmentQuerier,
-    NodeQuerier, QuerierGetter, QueryHandler, TxHandler, TxResponse, WasmQuerier,
+    BankQuerier, BankSetter, CwEnv, DefaultQueriers, DistributionQuerier, EnvironmentInfo,
+    EnvironmentQuerier, GovQuerier, ModuleQueriers, NodeQuerier, QuerierGetter, QueryAssertions,
+    QueryHandler, Scenario, StakingQuerier, TokenHelpers, TxHandler, TxResponse, WasmQuerier,
 };
 
 pub use cw_orch_core::environment::Environment;
 
 // Chains
-pub use crate::environment::{ChainInfo, ChainInfoOwned};
+pub use crate::environment::{ChainInfo, ChainInfoBuilder, ChainInfoOwned};
 
 // Mock for testing
 pub use crate::mock::{Mock, MockBech32};
@@ -70,4 +72,4 @@ pub use crate::contract::artifacts_dir_from_workspace;
 pub use cw_orch_traits::*;
 
 #[cfg(feature = "snapshot-testing")]
-pub use crate::take_storage_snapshot;
+pub use crate::{take_deployment_snapshot, take_storage_snapshot};
@@ -1,6 +1,14 @@
 //! Defined the snapshot testing macro on the Mock environment
 //! This is included here and not in the mock package because it needs to import traits to work
 
+use std::collections::BTreeMap;
+
+use cw_orch_core::{
+    environment::{ChainState, StateInterface},
+    CwEnvError,
+};
+use serde::Serialize;
+
 /// Function helper used to parse storage into readable strings
 pub fn parse_storage(storage: &[(Vec<u8>, Vec<u8>)]) -> Vec<(String, String)> {
     storage
@@ -48,6 +56,71 @@ macro_rules! take_storage_snapshot {
     };
 }
 
+/// Stable view of a deployment (code ids, addresses and selected query responses) used for snapshot testing.
+/// Works with any environment, Mock or Daemon.
+/// Maps are sorted by key so that the serialized snapshot doesn't depend on insertion order.
+#[derive(Serialize, Debug, Default, Clone)]
+pub struct DeploymentSnapshot {
+    /// Code ids of the uploaded contracts, indexed by contract id
+    pub code_ids: BTreeMap<String, u64>,
+    /// Addresses of the instantiated contracts, indexed by contract id
+    pub addresses: BTreeMap<String, String>,
+    /// Query responses added with [`DeploymentSnapshot::with_query`]
+    pub queries: BTreeMap<String, serde_json::Value>,
+}
+
+impl DeploymentSnapshot {
+    /// Reads the code ids and addresses registered in the state of `chain`
+    pub fn new<Chain: ChainState>(chain: &Chain) -> Result<Self, CwEnvError> {
+        let state = chain.state();
+        Ok(Self {
+            code_ids: state.get_all_code_ids()?.into_iter().collect(),
+            addresses: state
+                .get_all_addresses()?
+                .into_iter()
+                .map(|(id, addr)| (id, addr.to_string()))
+                .collect(),
+            queries: BTreeMap::new(),
+        })
+    }
+
+    /// Adds a query response to the snapshot under `name`
+    pub fn with_query(
+        mut self,
+        name: impl Into<String>,
+        response: &impl Serialize,
+    ) -> Result<Self, CwEnvError> {
+        self.queries
+            .insert(name.into(), serde_json::to_value(response)?);
+        Ok(self)
+    }
+}
+
+/// Snapshots the code ids and contract addresses of a deployment, along with optional query responses.
+/// Use it to make sure changes to deployment scripts don't have an unintended impact on the resulting deployment.
+/// This should ONLY be used when cw_orch is in scope
+/// Usage:
+/// ```rust,ignore
+/// take_deployment_snapshot!(chain, "deployment");
+/// take_deployment_snapshot!(chain, "deployment_with_queries", {
+///     "count" => counter.get_count()?,
+/// });
+/// ```
+/// The name you input to the function should be different from all other snapshots in your repository
+/// This function will panic if the snapshot is different from the reference snapshot
+#[macro_export]
+macro_rules! take_deployment_snapshot {
+    ($chain: expr, $name: literal $(, { $($query_name: literal => $query: expr),* $(,)? })?) => {
+        let snapshot = ::cw_orch::snapshots::DeploymentSnapshot::new(&$chain)?
+            $($(.with_query($query_name, &$query)?)*)?;
+
+        ::cw_orch::insta::assert_yaml_snapshot!(
+            ::cw_orch::sanitize_filename::sanitize(format!("{}", $name)),
+            snapshot
+        )
+    };
+}
+
 #[cfg(test)]
 pub mod tests {
     use crate::mock::cw_multi_test::ContractWrapper;
@@ -145,4 +218,25 @@ pub mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn deployment_snapshot() -> anyhow::Result<()> {
+        use counter_contract::{CounterExecuteMsgFns, CounterQueryMsgFns};
+        let chain = Mock::new("sender");
+
+        let contract = counter_contract::CounterContract::new(chain.clone());
+        contract.upload()?;
+        contract.instantiate(
+            &counter_contract::msg::InstantiateMsg { count: 0 },
+            None,
+            None,
+        )?;
+        contract.increment()?;
+
+        take_deployment_snapshot!(chain, "deployment_snapshot_test", {
+            "count" => contract.get_count()?,
+        });
+
+        Ok(())
+    }
 }
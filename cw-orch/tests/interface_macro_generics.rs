@@ -1,5 +1,5 @@
 use cw_orch::{interface, prelude::*};
-use mock_contract::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
+use mock_contract::{ExecuteMsg, ExecuteMsgFns, InstantiateMsg, MigrateMsg, QueryMsg, QueryMsgFns};
 
 use cosmwasm_std::Event;
 use cw_orch::prelude::Mock;
@@ -20,6 +20,24 @@ impl<Chain, T> Uploadable for MockContract<Chain, T> {
     }
 }
 
+#[interface(InstantiateMsg, ExecuteMsg<T>, QueryMsg<T>, MigrateMsg, id = "test:mock_contract")]
+pub struct SharedGenericContract;
+
+impl<Chain, T> Uploadable for SharedGenericContract<Chain, T> {
+    fn wrapper() -> <Mock as TxHandler>::ContractSource {
+        MockContract::<Chain, T>::wrapper()
+    }
+}
+
+#[interface(InstantiateMsg, ExecuteMsg<T>, QueryMsg<Q>, MigrateMsg, id = "test:mock_contract")]
+pub struct DefaultGenericContract<Chain, T = u64, Q: std::fmt::Debug = u64>;
+
+impl<Chain, T, Q: std::fmt::Debug> Uploadable for DefaultGenericContract<Chain, T, Q> {
+    fn wrapper() -> <Mock as TxHandler>::ContractSource {
+        MockContract::<Chain, T>::wrapper()
+    }
+}
+
 #[test]
 fn test_instantiate() {
     let contract = MockContract::<_, u64>::new(Mock::new("Ghazshag"));
@@ -107,3 +125,29 @@ fn test_migrate() {
         .unwrap();
     assert_eq!(response.events.len(), 1);
 }
+
+#[test]
+fn test_shared_generic() {
+    let contract = SharedGenericContract::<_, u64>::new(Mock::new("Ghazshag"));
+    contract.upload().unwrap();
+    contract
+        .instantiate(&InstantiateMsg {}, None, None)
+        .unwrap();
+
+    contract.third_message(67u64).unwrap();
+    contract.third_query(67u64).unwrap();
+}
+
+#[test]
+fn test_default_generics() {
+    let contract: DefaultGenericContract<Mock> = DefaultGenericContract::new(Mock::new("Ghazshag"));
+    contract.upload().unwrap();
+    contract
+        .instantiate(&InstantiateMsg {}, None, None)
+        .unwrap();
+
+    // The execute and query functions use the default message types
+    contract.third_message(67u64).unwrap();
+    contract.third_query(67u64).unwrap();
+    contract.second_query(45u64).unwrap_err();
+}
@@ -0,0 +1,52 @@
+use cw_orch::interface;
+use cw_orch::prelude::{ChainInfoOwned, Mock, Uploadable};
+
+use mock_contract::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
+
+#[interface(
+    InstantiateMsg,
+    ExecuteMsg,
+    QueryMsg,
+    MigrateMsg,
+    wasm = "mock_contract"
+)]
+pub struct WorkspaceArtifacts;
+
+#[interface(
+    InstantiateMsg,
+    ExecuteMsg,
+    QueryMsg,
+    MigrateMsg,
+    wasm = "mock_contract",
+    artifacts = "../artifacts",
+    build_postfix = "juno"
+)]
+pub struct RelativeArtifacts;
+
+#[interface(
+    InstantiateMsg,
+    ExecuteMsg,
+    QueryMsg,
+    MigrateMsg,
+    wasm = "unknown_contract"
+)]
+pub struct MissingArtifact;
+
+#[test]
+fn wasm_in_workspace_artifacts() {
+    let wasm = WorkspaceArtifacts::<Mock>::wasm(&ChainInfoOwned::default());
+    assert!(wasm.path().ends_with("artifacts/mock_contract.wasm"));
+}
+
+#[test]
+fn wasm_in_relative_artifacts_without_postfix() {
+    // There is no `mock_contract-juno.wasm`, the artifact without post-fix is used
+    let wasm = RelativeArtifacts::<Mock>::wasm(&ChainInfoOwned::default());
+    assert!(wasm.path().ends_with("artifacts/mock_contract.wasm"));
+}
+
+#[test]
+#[should_panic(expected = "Wasm artifact of the MissingArtifact interface not found")]
+fn missing_wasm() {
+    MissingArtifact::<Mock>::wasm(&ChainInfoOwned::default());
+}
@@ -6,6 +6,7 @@ pub const NEW_NETWORK_INFO: NetworkInfo = NetworkInfo {
     chain_name: "osmosis",
     pub_address_prefix: "osmo",
     coin_type: 118,
+    pub_key_type: None,
 };
 
 pub const NEW_CHAIN_INFO: ChainInfo = ChainInfo {
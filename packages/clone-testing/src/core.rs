@@ -16,7 +16,7 @@ use cw_orch_core::{
     contract::interface_traits::Uploadable,
     environment::{
         BankQuerier, BankSetter, ChainInfoOwned, ChainState, DefaultQueriers, IndexResponse,
-        StateInterface, TxHandler,
+        StateInterface, StorageSnapshot, TxHandler, TxSimulation,
     },
     CwEnvError,
 };
@@ -78,6 +78,8 @@ pub struct CloneTesting<S: StateInterface = MockState> {
     pub state: Rc<RefCell<S>>,
     /// Inner mutable cw-multi-test app backend
     pub app: Rc<RefCell<CloneTestingApp>>,
+    /// Channel to the forked chain, for the queries that are not forked
+    pub(crate) remote: RemoteChannel,
 }
 
 impl CloneTesting {
@@ -269,12 +271,22 @@ impl<S: StateInterface> CloneTesting<S> {
             sender: sender.clone(),
             state,
             app,
+            remote: remote_channel,
         })
     }
 
     pub fn storage_analysis(&self) -> StorageAnalyzer {
         StorageAnalyzer::new(&self.app.borrow()).unwrap()
     }
+
+    /// Runs `action` and reverts all the changes it made to the local app storage.
+    /// Addresses generated during `action` are not reused.
+    fn dry_run<T>(&self, action: impl FnOnce() -> Result<T, CwEnvError>) -> Result<T, CwEnvError> {
+        let snapshot = StorageSnapshot::take(self.app.borrow().storage());
+        let result = action();
+        snapshot.restore(self.app.borrow_mut().storage_mut());
+        result
+    }
 }
 
 impl<S: StateInterface> ChainState for CloneTesting<S> {
@@ -335,6 +347,18 @@ impl<S: StateInterface> TxHandler for CloneTesting<S> {
             .map(Into::into)
     }
 
+    fn bank_send(
+        &self,
+        recipient: &Addr,
+        amount: &[cosmwasm_std::Coin],
+    ) -> Result<Self::Response, CwEnvError> {
+        self.app
+            .borrow_mut()
+            .send_tokens(self.sender.clone(), recipient.clone(), amount)
+            .map_err(From::from)
+            .map(Into::into)
+    }
+
     fn instantiate<I: Serialize + Debug>(
         &self,
         code_id: u64,
@@ -407,6 +431,28 @@ impl<S: StateInterface> TxHandler for CloneTesting<S> {
 
         Ok(app_resp)
     }
+
+    fn simulate_execute<E: Serialize + Debug>(
+        &self,
+        exec_msg: &E,
+        coins: &[cosmwasm_std::Coin],
+        contract_address: &Addr,
+    ) -> Result<TxSimulation, CwEnvError> {
+        self.dry_run(|| self.execute(exec_msg, coins, contract_address))?;
+        Ok(TxSimulation::default())
+    }
+
+    fn simulate_instantiate<I: Serialize + Debug>(
+        &self,
+        code_id: u64,
+        init_msg: &I,
+        label: Option<&str>,
+        admin: Option<&Addr>,
+        coins: &[cosmwasm_std::Coin],
+    ) -> Result<TxSimulation, CwEnvError> {
+        self.dry_run(|| self.instantiate(code_id, init_msg, label, admin, coins))?;
+        Ok(TxSimulation::default())
+    }
 }
 
 /// Custom AppResponse type for working with the IndexResponse trait
@@ -1,6 +1,6 @@
 use std::{cell::RefCell, rc::Rc};
 
-use cosmwasm_std::Coin;
+use cosmwasm_std::{Coin, DenomMetadata, PageRequest};
 use cw_orch_core::{
     environment::{BankQuerier, Querier, QuerierGetter, StateInterface},
     CwEnvError,
@@ -57,4 +57,29 @@ impl BankQuerier for CloneBankQuerier {
     fn total_supply(&self) -> Result<Vec<cosmwasm_std::Coin>, Self::Error> {
         unimplemented!()
     }
+
+    fn denom_metadata(&self, denom: impl Into<String>) -> Result<DenomMetadata, Self::Error> {
+        Ok(self.app.borrow().wrap().query_denom_metadata(denom)?)
+    }
+
+    fn denoms_metadata(&self) -> Result<Vec<DenomMetadata>, Self::Error> {
+        let mut metadata = vec![];
+        let mut key = None;
+        loop {
+            let page = self
+                .app
+                .borrow()
+                .wrap()
+                .query_all_denom_metadata(PageRequest {
+                    key,
+                    limit: 100,
+                    reverse: false,
+                })?;
+            metadata.extend(page.metadata);
+            match page.next_key {
+                Some(next_key) => key = Some(next_key),
+                None => return Ok(metadata),
+            }
+        }
+    }
 }
@@ -0,0 +1,69 @@
+use clone_cw_multi_test::wasm_emulation::channel::RemoteChannel;
+use cw_orch_core::{
+    environment::{GovQuerier, ProposalStatus, Querier, QuerierGetter, StateInterface},
+    CwEnvError,
+};
+use cw_orch_daemon::queriers::Gov;
+
+use crate::CloneTesting;
+
+/// Governance proposals are not forked, their status is queried on the remote chain.
+pub struct CloneGovQuerier {
+    remote: RemoteChannel,
+}
+
+impl CloneGovQuerier {
+    fn new<S: StateInterface>(mock: &CloneTesting<S>) -> Self {
+        Self {
+            remote: mock.remote.clone(),
+        }
+    }
+}
+
+impl<S: StateInterface> QuerierGetter<CloneGovQuerier> for CloneTesting<S> {
+    fn querier(&self) -> CloneGovQuerier {
+        CloneGovQuerier::new(self)
+    }
+}
+
+impl Querier for CloneGovQuerier {
+    type Error = CwEnvError;
+}
+
+impl GovQuerier for CloneGovQuerier {
+    fn proposal_status(&self, proposal_id: u64) -> Result<ProposalStatus, Self::Error> {
+        let proposal = self
+            .remote
+            .rt
+            .block_on(Gov::new_async(self.remote.channel.clone())._proposal(proposal_id))
+            .map_err(|e| CwEnvError::StdErr(e.to_string()))?;
+        ProposalStatus::try_from(proposal.status)
+            .map_err(|e| CwEnvError::StdErr(format!("proposal {proposal_id}: {e}")))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use cw_orch_daemon::networks::JUNO_1;
+    use speculoos::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn proposal_status_is_queried_on_the_remote_chain() {
+        let chain = CloneTesting::new(JUNO_1).unwrap();
+        let gov: CloneGovQuerier = chain.querier();
+
+        asserting!("past proposals are closed")
+            .that(&gov.proposal_status(1).unwrap())
+            .matches(|status| {
+                matches!(
+                    status,
+                    ProposalStatus::Passed | ProposalStatus::Rejected | ProposalStatus::Failed
+                )
+            });
+        asserting!("unknown proposals are an error")
+            .that(&gov.proposal_status(u64::MAX))
+            .is_err();
+    }
+}
@@ -8,6 +8,7 @@ use cw_orch_core::{
 };
 pub mod bank;
 mod env;
+pub mod gov;
 pub mod node;
 pub mod wasm;
 
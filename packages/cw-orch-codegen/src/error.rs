@@ -0,0 +1,14 @@
+use thiserror::Error;
+
+/// Errors that can happen while generating an interface
+#[derive(Error, Debug)]
+pub enum CodegenError {
+    #[error(transparent)]
+    IOErr(#[from] ::std::io::Error),
+    #[error(transparent)]
+    SerdeJson(#[from] ::serde_json::Error),
+    #[error("Generated code is not valid Rust: {0}")]
+    Syn(#[from] ::syn::Error),
+    #[error("Invalid schema: {0}")]
+    InvalidSchema(String),
+}
@@ -0,0 +1,25 @@
+//! Generates a cw-orch interface crate from a contract schema.
+//!
+//! Usage: `cw-orch-codegen <schema.json> [output dir]`
+//! The output dir defaults to `./<contract_name>-interface`.
+
+use cw_orch_codegen::{generate_interface, CodegenError};
+
+fn main() -> Result<(), CodegenError> {
+    let mut args = std::env::args().skip(1);
+    let Some(schema_path) = args.next() else {
+        eprintln!("Usage: cw-orch-codegen <schema.json> [output dir]");
+        std::process::exit(1);
+    };
+
+    let schema = std::fs::read_to_string(schema_path)?;
+    let interface = generate_interface(&schema)?;
+    let out_dir = args.next().unwrap_or_else(|| interface.crate_name());
+    interface.write_crate(&out_dir)?;
+
+    println!(
+        "Generated interface for {} {} in {}",
+        interface.contract_name, interface.contract_version, out_dir
+    );
+    Ok(())
+}
@@ -0,0 +1,422 @@
+use std::collections::BTreeMap;
+
+use convert_case::{Case, Casing};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use serde_json::Value;
+use syn::ext::IdentExt;
+
+use crate::CodegenError;
+
+/// Types exported by `cosmwasm_std` that are referenced by name in the schemas
+const COSMWASM_STD_TYPES: &[&str] = &[
+    "Addr",
+    "Binary",
+    "Coin",
+    "Decimal",
+    "Decimal256",
+    "Empty",
+    "HexBinary",
+    "Int128",
+    "Int256",
+    "Int512",
+    "Int64",
+    "Timestamp",
+    "Uint128",
+    "Uint256",
+    "Uint512",
+    "Uint64",
+];
+
+const RUST_KEYWORDS: &[&str] = &[
+    "as", "async", "await", "box", "break", "const", "continue", "crate", "dyn", "else", "enum",
+    "extern", "false", "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move",
+    "mut", "pub", "ref", "return", "self", "static", "struct", "super", "trait", "true", "type",
+    "unsafe", "use", "where", "while", "yield",
+];
+
+/// Keywords that can't be raw identifiers, generated with a trailing `_` instead
+const PATH_KEYWORDS: &[&str] = &["crate", "self", "Self", "super"];
+
+/// Kind of message, used to add the cw-orch derives
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) enum MessageKind {
+    Execute,
+    Query,
+    Other,
+}
+
+/// Collects the Rust items generated from the schema definitions.
+/// Definitions are indexed by name so types shared between messages are only generated once.
+#[derive(Default)]
+pub(crate) struct TypeGenerator {
+    items: BTreeMap<String, TokenStream>,
+}
+
+impl TypeGenerator {
+    /// Generated items, sorted by name
+    pub fn items(&self) -> impl Iterator<Item = &TokenStream> {
+        self.items.values()
+    }
+
+    /// Generates the root message described by `schema` and returns the type to use for it.
+    /// `responses` are the query responses, indexed by query variant name.
+    pub fn message(
+        &mut self,
+        schema: &Value,
+        default_name: &str,
+        kind: MessageKind,
+        responses: &Value,
+    ) -> Result<TokenStream, CodegenError> {
+        if schema.is_null() {
+            return Ok(quote!(::cosmwasm_std::Empty));
+        }
+        self.definitions(schema)?;
+        let name = schema["title"].as_str().unwrap_or(default_name);
+        let attrs = match kind {
+            MessageKind::Execute => quote!(#[derive(::cw_orch::ExecuteFns)]),
+            MessageKind::Query => {
+                quote!(#[derive(::cw_orch::QueryFns, ::cosmwasm_schema::QueryResponses)])
+            }
+            MessageKind::Other => quote!(),
+        };
+        let responses = (kind == MessageKind::Query).then_some(responses);
+        self.definition(name, schema, attrs, responses)?;
+        let ident = type_ident(name);
+        Ok(quote!(#ident))
+    }
+
+    /// Generates the type of a query response and returns the type to use for it
+    fn response(&mut self, schema: &Value) -> Result<TokenStream, CodegenError> {
+        self.definitions(schema)?;
+        match schema["title"].as_str() {
+            Some(name) if is_item(schema) => {
+                self.definition(name, schema, quote!(), None)?;
+                let ident = type_ident(name);
+                Ok(quote!(#ident))
+            }
+            _ => rust_type(schema),
+        }
+    }
+
+    /// Registers all the definitions of a root schema
+    fn definitions(&mut self, schema: &Value) -> Result<(), CodegenError> {
+        let Some(definitions) = schema["definitions"].as_object() else {
+            return Ok(());
+        };
+        for (name, definition) in definitions {
+            if COSMWASM_STD_TYPES.contains(&name.as_str()) {
+                continue;
+            }
+            self.definition(name, definition, quote!(), None)?;
+        }
+        Ok(())
+    }
+
+    /// Generates a struct, an enum or a type alias named `name`
+    fn definition(
+        &mut self,
+        name: &str,
+        schema: &Value,
+        attrs: TokenStream,
+        responses: Option<&Value>,
+    ) -> Result<(), CodegenError> {
+        if self.items.contains_key(name) {
+            return Ok(());
+        }
+        let ident = type_ident(name);
+        let doc = doc(schema);
+
+        let item = if let Some(variants) = schema["oneOf"].as_array() {
+            let variants = variants
+                .iter()
+                .map(|variant| self.variants(variant, responses))
+                .collect::<Result<Vec<_>, _>>()?
+                .into_iter()
+                .flatten();
+            quote! {
+                #doc
+                #[::cosmwasm_schema::cw_serde]
+                #attrs
+                pub enum #ident {
+                    #(#variants)*
+                }
+            }
+        } else if schema["enum"].is_array() {
+            let variants = self.variants(schema, responses)?;
+            quote! {
+                #doc
+                #[::cosmwasm_schema::cw_serde]
+                #attrs
+                pub enum #ident {
+                    #(#variants)*
+                }
+            }
+        } else if is_object(schema) {
+            let fields = fields(schema, quote!(pub))?;
+            quote! {
+                #doc
+                #[::cosmwasm_schema::cw_serde]
+                #attrs
+                pub struct #ident {
+                    #(#fields)*
+                }
+            }
+        } else {
+            let ty = rust_type(schema)?;
+            quote! {
+                #doc
+                pub type #ident = #ty;
+            }
+        };
+        self.items.insert(name.to_string(), item);
+        Ok(())
+    }
+
+    /// Generates the enum variants described by one entry of a `oneOf`
+    fn variants(
+        &mut self,
+        schema: &Value,
+        responses: Option<&Value>,
+    ) -> Result<Vec<TokenStream>, CodegenError> {
+        let doc = doc(schema);
+
+        // Unit variants, serialized as strings
+        if let Some(names) = schema["enum"].as_array() {
+            return names
+                .iter()
+                .map(|name| {
+                    let name = name.as_str().ok_or_else(|| {
+                        CodegenError::InvalidSchema(format!("non-string enum value {name}"))
+                    })?;
+                    let (ident, rename) = variant_ident(name);
+                    let returns = self.returns(name, responses)?;
+                    Ok(quote! {
+                        #doc
+                        #rename
+                        #returns
+                        #ident,
+                    })
+                })
+                .collect();
+        }
+
+        // Variants with content, serialized as `{ "name": content }`
+        let properties = schema["properties"].as_object().filter(|p| p.len() == 1);
+        let Some((name, content)) = properties.and_then(|p| p.iter().next()) else {
+            return Err(CodegenError::InvalidSchema(format!(
+                "unsupported enum variant {schema}"
+            )));
+        };
+        let (ident, rename) = variant_ident(name);
+        let returns = self.returns(name, responses)?;
+        let variant = if is_object(content) {
+            let fields = fields(content, quote!())?;
+            quote!(#ident { #(#fields)* },)
+        } else {
+            let ty = rust_type(content)?;
+            quote!(#ident(#ty),)
+        };
+        Ok(vec![quote! {
+            #doc
+            #rename
+            #returns
+            #variant
+        }])
+    }
+
+    /// `#[returns(T)]` attribute of a query variant
+    fn returns(
+        &mut self,
+        variant: &str,
+        responses: Option<&Value>,
+    ) -> Result<TokenStream, CodegenError> {
+        let Some(responses) = responses else {
+            return Ok(quote!());
+        };
+        let response = responses.get(variant).ok_or_else(|| {
+            CodegenError::InvalidSchema(format!("missing response for query {variant}"))
+        })?;
+        let ty = self.response(response)?;
+        Ok(quote!(#[returns(#ty)]))
+    }
+}
+
+/// Fields of an object schema, with the `vis` visibility (none for enum variants)
+fn fields(schema: &Value, vis: TokenStream) -> Result<Vec<TokenStream>, CodegenError> {
+    let required: Vec<&str> = schema["required"]
+        .as_array()
+        .map(|r| r.iter().filter_map(Value::as_str).collect())
+        .unwrap_or_default();
+    let Some(properties) = schema["properties"].as_object() else {
+        return Ok(vec![]);
+    };
+    properties
+        .iter()
+        .map(|(name, field)| {
+            let doc = doc(field);
+            let mut ty = rust_type(field)?;
+            if !required.contains(&name.as_str()) && !is_nullable(field) {
+                ty = quote!(Option<#ty>);
+            }
+            let snake = name.to_case(Case::Snake);
+            let ident = if PATH_KEYWORDS.contains(&snake.as_str()) {
+                format_ident!("{}_", snake)
+            } else if RUST_KEYWORDS.contains(&snake.as_str()) {
+                format_ident!("r#{}", snake)
+            } else {
+                format_ident!("{}", snake)
+            };
+            let rename = (ident.unraw() != name).then(|| quote!(#[serde(rename = #name)]));
+            Ok(quote! {
+                #doc
+                #rename
+                #vis #ident: #ty,
+            })
+        })
+        .collect()
+}
+
+/// Rust type corresponding to a schema
+fn rust_type(schema: &Value) -> Result<TokenStream, CodegenError> {
+    if let Some(reference) = schema["$ref"].as_str() {
+        let name = reference.trim_start_matches("#/definitions/");
+        if COSMWASM_STD_TYPES.contains(&name) {
+            let ident = format_ident!("{}", name);
+            return Ok(quote!(::cosmwasm_std::#ident));
+        }
+        let ident = type_ident(name);
+        return Ok(quote!(#ident));
+    }
+    if let Some([inner]) = schema["allOf"].as_array().map(Vec::as_slice) {
+        return rust_type(inner);
+    }
+    if let Some(alternatives) = schema["anyOf"].as_array() {
+        let non_null: Vec<&Value> = alternatives.iter().filter(|s| !is_null(s)).collect();
+        if let [inner] = non_null.as_slice() {
+            let ty = rust_type(inner)?;
+            return Ok(quote!(Option<#ty>));
+        }
+        return Ok(quote!(::serde_json::Value));
+    }
+    match &schema["type"] {
+        Value::String(ty) => primitive(ty, schema),
+        Value::Array(types) => {
+            let non_null: Vec<&str> = types
+                .iter()
+                .filter_map(Value::as_str)
+                .filter(|t| *t != "null")
+                .collect();
+            match non_null.as_slice() {
+                [ty] if non_null.len() < types.len() => {
+                    let ty = primitive(ty, schema)?;
+                    Ok(quote!(Option<#ty>))
+                }
+                [ty] => primitive(ty, schema),
+                _ => Ok(quote!(::serde_json::Value)),
+            }
+        }
+        _ => Ok(quote!(::serde_json::Value)),
+    }
+}
+
+fn primitive(ty: &str, schema: &Value) -> Result<TokenStream, CodegenError> {
+    let ty = match ty {
+        "string" => quote!(String),
+        "boolean" => quote!(bool),
+        "number" => quote!(f64),
+        "null" => quote!(()),
+        "integer" => match schema["format"].as_str() {
+            Some("uint8") => quote!(u8),
+            Some("uint16") => quote!(u16),
+            Some("uint32") => quote!(u32),
+            Some("uint64") => quote!(u64),
+            Some("uint128") => quote!(u128),
+            Some("int8") => quote!(i8),
+            Some("int16") => quote!(i16),
+            Some("int32") => quote!(i32),
+            Some("int128") => quote!(i128),
+            _ => quote!(i64),
+        },
+        "array" => match &schema["items"] {
+            Value::Array(items) => {
+                let items = items.iter().map(rust_type).collect::<Result<Vec<_>, _>>()?;
+                quote!((#(#items),*))
+            }
+            Value::Null => quote!(Vec<::serde_json::Value>),
+            items => {
+                let item = rust_type(items)?;
+                quote!(Vec<#item>)
+            }
+        },
+        _ => quote!(::serde_json::Value),
+    };
+    Ok(ty)
+}
+
+/// Whether the schema is generated as a named item (struct or enum)
+fn is_item(schema: &Value) -> bool {
+    schema["oneOf"].is_array() || schema["enum"].is_array() || is_object(schema)
+}
+
+fn is_object(schema: &Value) -> bool {
+    schema["type"] == "object" && schema["additionalProperties"] != Value::Bool(true)
+}
+
+fn is_null(schema: &Value) -> bool {
+    schema["type"] == "null"
+}
+
+fn is_nullable(schema: &Value) -> bool {
+    schema["anyOf"]
+        .as_array()
+        .is_some_and(|alternatives| alternatives.iter().any(is_null))
+        || schema["type"]
+            .as_array()
+            .is_some_and(|types| types.iter().any(|t| t == "null"))
+}
+
+fn doc(schema: &Value) -> TokenStream {
+    match schema["description"].as_str() {
+        Some(description) => {
+            let lines = description.lines().map(|l| format!(" {l}"));
+            quote!(#(#[doc = #lines])*)
+        }
+        None => quote!(),
+    }
+}
+
+/// Identifier of a generated type, e.g. `Nullable_Addr` -> `NullableAddr`
+fn type_ident(name: &str) -> proc_macro2::Ident {
+    camel_ident(name)
+}
+
+/// Identifier of an enum variant, with the `serde(rename)` attribute needed if `cw_serde` would serialize it differently
+fn variant_ident(name: &str) -> (proc_macro2::Ident, Option<TokenStream>) {
+    let ident = camel_ident(name);
+    let rename =
+        (serde_snake_case(&ident.to_string()) != name).then(|| quote!(#[serde(rename = #name)]));
+    (ident, rename)
+}
+
+/// Name `#[serde(rename_all = "snake_case")]` serializes the variant `ident` as
+fn serde_snake_case(ident: &str) -> String {
+    let mut snake = String::new();
+    for (i, ch) in ident.char_indices() {
+        if i > 0 && ch.is_uppercase() {
+            snake.push('_');
+        }
+        snake.push(ch.to_ascii_lowercase());
+    }
+    snake
+}
+
+/// UpperCamel identifier of `name`, `Self` is generated as `Self_`
+fn camel_ident(name: &str) -> proc_macro2::Ident {
+    let ident = name.to_case(Case::UpperCamel);
+    if PATH_KEYWORDS.contains(&ident.as_str()) {
+        format_ident!("{}_", ident)
+    } else {
+        format_ident!("{}", ident)
+    }
+}
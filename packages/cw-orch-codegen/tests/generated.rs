@@ -0,0 +1,32 @@
+//! Compiles the interface generated from `schemas/counter-contract.json` against cw-orch.
+//! `generated/counter_contract.rs` is kept in sync with the generator by the `generated_code_matches_compiled_copy` unit test.
+
+#[path = "generated/counter_contract.rs"]
+mod counter_contract;
+
+use counter_contract::{
+    CounterContract, ExecuteMsg, ExecuteMsgFns, GetCountResponse, QueryMsg, QueryMsgFns,
+};
+use cw_orch::prelude::*;
+
+#[test]
+fn generated_interface_compiles() {
+    let contract = CounterContract::new(Mock::new("sender"));
+    assert_eq!(contract.id(), counter_contract::CONTRACT_ID);
+    assert_eq!(
+        cosmwasm_std::to_json_string(&ExecuteMsg::Reset { count: 5 }).unwrap(),
+        r#"{"reset":{"count":5}}"#
+    );
+    assert_eq!(
+        cosmwasm_std::to_json_string(&QueryMsg::GetCount {}).unwrap(),
+        r#"{"get_count":{}}"#
+    );
+}
+
+// Never called, checks the signatures of the generated fns
+#[allow(dead_code)]
+fn generated_fns(contract: &CounterContract<Mock>) -> Result<GetCountResponse, CwOrchError> {
+    contract.increment()?;
+    contract.reset(5)?;
+    contract.get_count()
+}
@@ -0,0 +1,49 @@
+//! cw-orch interface of `counter-contract` 0.11.0, generated from its schema by cw-orch-codegen.
+/// Execute methods for counter
+#[::cosmwasm_schema::cw_serde]
+#[derive(::cw_orch::ExecuteFns)]
+pub enum ExecuteMsg {
+    /// Increment count by one
+    Increment {},
+    /// Reset count
+    Reset {
+        /// Count value after reset
+        count: i32,
+    },
+}
+/// Response from get_count query
+#[::cosmwasm_schema::cw_serde]
+pub struct GetCountResponse {
+    /// Current count in the state
+    pub count: i32,
+}
+/// Instantiate method for counter
+#[::cosmwasm_schema::cw_serde]
+pub struct InstantiateMsg {
+    /// Initial count
+    pub count: i32,
+}
+/// Migrate message for count contract
+#[::cosmwasm_schema::cw_serde]
+pub struct MigrateMsg {
+    /// Your favorite type of tea
+    pub t: String,
+}
+/// Query methods for counter
+#[::cosmwasm_schema::cw_serde]
+#[derive(::cw_orch::QueryFns, ::cosmwasm_schema::QueryResponses)]
+pub enum QueryMsg {
+    /// GetCount returns the current count as a json-encoded number
+    #[returns(GetCountResponse)]
+    GetCount {},
+}
+pub const CONTRACT_ID: &str = "counter-contract";
+#[::cw_orch::interface(InstantiateMsg, ExecuteMsg, QueryMsg, MigrateMsg, id = CONTRACT_ID)]
+pub struct CounterContract;
+impl<Chain> ::cw_orch::prelude::Uploadable for CounterContract<Chain> {
+    fn wasm(_chain: &::cw_orch::prelude::ChainInfoOwned) -> ::cw_orch::prelude::WasmPath {
+        ::cw_orch::prelude::ArtifactsDir::auto(None)
+            .find_wasm_path("counter_contract")
+            .expect("counter_contract.wasm not found in the artifacts directory")
+    }
+}
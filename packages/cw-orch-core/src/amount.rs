@@ -0,0 +1,104 @@
+//! Helpers to display and parse token amounts with decimals, e.g. `1.5` for `1500000` atomics of a 6-decimals token.
+//! The number of decimals comes from the cw20 `TokenInfo` or from the bank denom metadata, see [`crate::environment::TokenHelpers`].
+
+use std::fmt::Display;
+
+use cosmwasm_std::Uint128;
+
+use crate::CwEnvError;
+
+/// Amount of a token, with the decimals and symbol used to display it
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TokenAmount {
+    /// Amount of atomics
+    pub amount: Uint128,
+    /// Number of decimals of the token
+    pub decimals: u32,
+    /// Symbol of the token, or its denom when it has none
+    pub symbol: String,
+}
+
+impl Display for TokenAmount {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} {}",
+            format_amount(self.amount, self.decimals),
+            self.symbol
+        )
+    }
+}
+
+/// Formats an amount of atomics as a decimal number with `decimals` digits after the separator.
+/// Trailing zeros are removed.
+pub fn format_amount(amount: Uint128, decimals: u32) -> String {
+    let digits = amount.to_string();
+    let decimals = decimals as usize;
+    if decimals == 0 {
+        return digits;
+    }
+    let digits = format!("{digits:0>width$}", width = decimals + 1);
+    let (int, frac) = digits.split_at(digits.len() - decimals);
+    let frac = frac.trim_end_matches('0');
+    if frac.is_empty() {
+        int.to_string()
+    } else {
+        format!("{int}.{frac}")
+    }
+}
+
+/// Parses a decimal number into an amount of atomics, given the number of `decimals` of the token.
+/// Errors if the number has more digits after the separator than the token supports.
+pub fn parse_amount(amount: &str, decimals: u32) -> Result<Uint128, CwEnvError> {
+    let invalid = || CwEnvError::StdErr(format!("Invalid amount {amount}"));
+    let (int, frac) = amount.trim().split_once('.').unwrap_or((amount.trim(), ""));
+    if int.is_empty() && frac.is_empty() {
+        return Err(invalid());
+    }
+    if !int.chars().chain(frac.chars()).all(|c| c.is_ascii_digit()) {
+        return Err(invalid());
+    }
+    if frac.len() > decimals as usize {
+        return Err(CwEnvError::StdErr(format!(
+            "Amount {amount} has more than {decimals} decimals"
+        )));
+    }
+    let atomics = format!("{int}{frac:0<width$}", width = decimals as usize);
+    atomics.parse().map_err(|_| invalid())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn format() {
+        assert_eq!(format_amount(Uint128::new(1_500_000), 6), "1.5");
+        assert_eq!(format_amount(Uint128::new(1_000_000), 6), "1");
+        assert_eq!(format_amount(Uint128::new(42), 6), "0.000042");
+        assert_eq!(format_amount(Uint128::zero(), 6), "0");
+        assert_eq!(format_amount(Uint128::new(42), 0), "42");
+    }
+
+    #[test]
+    fn display() {
+        let amount = TokenAmount {
+            amount: Uint128::new(1_500_000),
+            decimals: 6,
+            symbol: "TOK".to_string(),
+        };
+        assert_eq!(amount.to_string(), "1.5 TOK");
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!(parse_amount("1.5", 6).unwrap(), Uint128::new(1_500_000));
+        assert_eq!(parse_amount("1", 6).unwrap(), Uint128::new(1_000_000));
+        assert_eq!(parse_amount(".000042", 6).unwrap(), Uint128::new(42));
+        assert_eq!(parse_amount("42", 0).unwrap(), Uint128::new(42));
+        assert!(parse_amount("0.0000001", 6).is_err());
+        assert!(parse_amount("1.2.3", 6).is_err());
+        assert!(parse_amount("-1", 6).is_err());
+        assert!(parse_amount("", 6).is_err());
+    }
+}
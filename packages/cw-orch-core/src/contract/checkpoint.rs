@@ -0,0 +1,231 @@
+//! Checkpoints of step-by-step deployments, see [`DeployCheckpoint`].
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::environment::EnvironmentInfo;
+use crate::CwEnvError;
+
+/// Step of a deployment recorded in a [`DeployCheckpoint`]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct DeployStep {
+    pub name: String,
+    pub completed: bool,
+    /// Error of the last attempt if the step failed
+    pub error: Option<String>,
+}
+
+/// Progress of a deployment, persisted after every step.
+///
+/// The steps of a deployment are run through [`DeployCheckpoint::step`] in the same order every time.
+/// When an interrupted deployment is resumed, the steps that were already completed are skipped.
+/// Their results, like code ids and contract addresses, are kept in the state of the environment.
+///
+/// ```ignore
+/// fn deploy_in_steps(chain: Chain, data: Empty, checkpoint: &mut DeployCheckpoint) -> Result<Self, CwOrchError> {
+///     let app = Self::load_from(chain)?;
+///     checkpoint.step("upload token", || app.token.upload())?;
+///     checkpoint.step("instantiate token", || app.token.instantiate(&init_msg, None, None))?;
+///     Ok(app)
+/// }
+/// ```
+///
+/// A step interrupted after its transaction was broadcast is run again on resume,
+/// so steps should be safe to retry or check the chain before acting.
+#[derive(Clone, Debug)]
+pub struct DeployCheckpoint {
+    path: PathBuf,
+    steps: Vec<DeployStep>,
+    next: usize,
+}
+
+impl DeployCheckpoint {
+    /// Starts a new checkpoint for the deployment `id` on the environment, replacing any previous one
+    pub fn start(id: &str, env_info: &EnvironmentInfo) -> Result<Self, CwEnvError> {
+        Self::start_at(checkpoint_file(id, env_info))
+    }
+
+    /// Loads the checkpoint of the interrupted deployment `id` on the environment
+    pub fn resume(id: &str, env_info: &EnvironmentInfo) -> Result<Self, CwEnvError> {
+        Self::resume_at(checkpoint_file(id, env_info))
+    }
+
+    /// Whether the deployment `id` on the environment was interrupted and can be resumed
+    pub fn exists(id: &str, env_info: &EnvironmentInfo) -> bool {
+        checkpoint_file(id, env_info).exists()
+    }
+
+    fn start_at(path: PathBuf) -> Result<Self, CwEnvError> {
+        let checkpoint = Self {
+            path,
+            steps: vec![],
+            next: 0,
+        };
+        checkpoint.save()?;
+        Ok(checkpoint)
+    }
+
+    fn resume_at(path: PathBuf) -> Result<Self, CwEnvError> {
+        if !path.exists() {
+            return Err(CwEnvError::StdErr(format!(
+                "No deployment to resume, checkpoint {path:?} not found"
+            )));
+        }
+        let steps = serde_json::from_slice(&fs::read(&path)?)?;
+        Ok(Self {
+            path,
+            steps,
+            next: 0,
+        })
+    }
+
+    /// Runs the step `name`, unless it was completed before the deployment was interrupted.
+    /// Returns `None` if the step was skipped.
+    pub fn step<T, E: From<CwEnvError> + Display>(
+        &mut self,
+        name: &str,
+        f: impl FnOnce() -> Result<T, E>,
+    ) -> Result<Option<T>, E> {
+        let index = self.next;
+        self.next += 1;
+        match self.steps.get(index) {
+            Some(step) if step.name != name => {
+                return Err(CwEnvError::StdErr(format!(
+                    "Deployment step {index} is `{name}` but the checkpoint recorded `{}`, the deployment changed since it was interrupted",
+                    step.name
+                ))
+                .into())
+            }
+            Some(step) if step.completed => {
+                log::info!("Skipping completed deployment step `{name}`");
+                return Ok(None);
+            }
+            Some(_) => {}
+            None => self.steps.push(DeployStep {
+                name: name.to_string(),
+                completed: false,
+                error: None,
+            }),
+        }
+        self.save()?;
+
+        let result = f();
+        let step = &mut self.steps[index];
+        match &result {
+            Ok(_) => {
+                step.completed = true;
+                step.error = None;
+            }
+            Err(e) => step.error = Some(e.to_string()),
+        }
+        self.save()?;
+        result.map(Some)
+    }
+
+    /// Steps run so far, with the ones of the interrupted deployment when resuming
+    pub fn steps(&self) -> &[DeployStep] {
+        &self.steps
+    }
+
+    /// Path of the file the checkpoint is persisted to
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Marks the deployment as done by removing its checkpoint
+    pub fn finish(self) -> Result<(), CwEnvError> {
+        fs::remove_file(&self.path)?;
+        Ok(())
+    }
+
+    fn save(&self) -> Result<(), CwEnvError> {
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(&self.path, serde_json::to_string_pretty(&self.steps)?)?;
+        Ok(())
+    }
+}
+
+fn checkpoint_file(id: &str, env_info: &EnvironmentInfo) -> PathBuf {
+    let sanitize = |s: &str| {
+        s.chars()
+            .map(|c| {
+                if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                    c
+                } else {
+                    '_'
+                }
+            })
+            .collect::<String>()
+    };
+    dirs::home_dir()
+        .unwrap()
+        .join(".cw-orchestrator")
+        .join("checkpoints")
+        .join(sanitize(id))
+        .join(format!(
+            "{}_{}.json",
+            sanitize(&env_info.chain_id),
+            sanitize(&env_info.deployment_id)
+        ))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn temp_checkpoint(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!(
+            "cw-orch-checkpoint-{name}-{}.json",
+            std::process::id()
+        ))
+    }
+
+    #[test]
+    fn resume_skips_completed_steps() -> anyhow::Result<()> {
+        let path = temp_checkpoint("resume");
+        let mut checkpoint = DeployCheckpoint::start_at(path.clone())?;
+        assert_eq!(
+            checkpoint.step("upload", || Ok::<_, CwEnvError>(1))?,
+            Some(1)
+        );
+        checkpoint
+            .step("instantiate", || {
+                Err::<(), _>(CwEnvError::StdErr("rpc error".to_string()))
+            })
+            .unwrap_err();
+
+        let mut resumed = DeployCheckpoint::resume_at(path)?;
+        assert_eq!(resumed.steps()[1].error, Some("rpc error".to_string()));
+        let skipped = resumed.step("upload", || -> Result<u64, CwEnvError> {
+            panic!("completed step run again")
+        })?;
+        assert_eq!(skipped, None);
+        assert_eq!(
+            resumed.step("instantiate", || Ok::<_, CwEnvError>(2))?,
+            Some(2)
+        );
+        assert!(resumed.steps().iter().all(|step| step.completed));
+
+        let path = resumed.path().to_path_buf();
+        resumed.finish()?;
+        assert!(!path.exists());
+        Ok(())
+    }
+
+    #[test]
+    fn changed_steps_are_rejected() -> anyhow::Result<()> {
+        let path = temp_checkpoint("changed");
+        let mut checkpoint = DeployCheckpoint::start_at(path.clone())?;
+        checkpoint.step("upload", || Ok::<_, CwEnvError>(()))?;
+
+        let mut resumed = DeployCheckpoint::resume_at(path)?;
+        resumed
+            .step("instantiate", || Ok::<_, CwEnvError>(()))
+            .unwrap_err();
+        resumed.finish()?;
+        Ok(())
+    }
+}
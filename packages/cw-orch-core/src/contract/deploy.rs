@@ -15,9 +15,11 @@ use std::path::PathBuf;
 use crate::env::CoreEnvVars;
 use crate::environment::CwEnv;
 use crate::environment::Environment;
+use crate::environment::EnvironmentQuerier;
 use crate::environment::QueryHandler;
 use crate::CwEnvError;
 
+use super::checkpoint::DeployCheckpoint;
 use super::interface_traits::ContractInstance;
 
 /// Indicates the ability to deploy an application to a mock chain.
@@ -80,6 +82,44 @@ pub trait Deploy<Chain: CwEnv>: Sized {
         Self::store_on(chain)
     }
 
+    /// Deploys the application in steps recorded in `checkpoint`, so an interrupted deployment can be continued with [`Deploy::resume`].
+    /// See [`DeployCheckpoint`] for how to declare the steps.
+    /// Defaults to [`Deploy::deploy_on`], as a single step-less deployment.
+    #[allow(unused_variables)]
+    fn deploy_in_steps(
+        chain: Chain,
+        data: Self::DeployData,
+        checkpoint: &mut DeployCheckpoint,
+    ) -> Result<Self, Self::Error> {
+        Self::deploy_on(chain, data)
+    }
+
+    /// Deploys the application with [`Deploy::deploy_in_steps`], recording its progress in a new checkpoint.
+    /// Call it from [`Deploy::deploy_on`] to make the deployment resumable.
+    fn deploy_with_checkpoint(chain: Chain, data: Self::DeployData) -> Result<Self, Self::Error> {
+        let mut checkpoint = DeployCheckpoint::start(&Self::checkpoint_id(), &chain.env_info())?;
+        let deployment = Self::deploy_in_steps(chain, data, &mut checkpoint)?;
+        checkpoint.finish()?;
+        Ok(deployment)
+    }
+
+    /// Resumes a deployment on `chain` that was interrupted, skipping the steps that were already completed.
+    /// Errors if there is no deployment to resume.
+    fn resume(chain: Chain, data: Self::DeployData) -> Result<Self, Self::Error> {
+        let mut checkpoint = DeployCheckpoint::resume(&Self::checkpoint_id(), &chain.env_info())?;
+        log::info!("Resuming deployment from {:?}", checkpoint.path());
+        let deployment = Self::deploy_in_steps(chain, data, &mut checkpoint)?;
+        checkpoint.finish()?;
+        Ok(deployment)
+    }
+
+    /// Identifies the checkpoints of the application, together with the chain id and deployment id.
+    /// Defaults to the path of the type, without its generics.
+    fn checkpoint_id() -> String {
+        let type_name = std::any::type_name::<Self>();
+        type_name.split('<').next().unwrap_or(type_name).to_string()
+    }
+
     /// Deploys the applications on all chains indicated in `chains`.
     /// Arguments :
     ///  - `networks`` is a vector of :
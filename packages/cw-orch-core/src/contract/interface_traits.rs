@@ -257,7 +257,8 @@ pub trait Uploadable {
         unimplemented!("no wasm file provided for this contract")
     }
 
-    /// Return the wrapper object for the contract, only works for non-custom mock environments
+    /// Return the wrapper object for the contract, only works for non-custom mock environments.
+    /// Contracts using chain-specific bindings can be wrapped in a `cw_orch::mock::custom::CustomContract`.
     fn wrapper() -> Box<dyn MockContract<Empty, Empty>> {
         unimplemented!("no wrapper function implemented for this contract")
     }
@@ -1,5 +1,5 @@
 pub use artifacts_dir::from_workspace;
-pub use artifacts_dir::ArtifactsDir;
+pub use artifacts_dir::{ArtifactArch, ArtifactsDir};
 pub use wasm_path::WasmPath;
 
 mod wasm_path {
@@ -63,6 +63,7 @@ mod wasm_path {
 
 mod artifacts_dir {
     const ARM_POSTFIX: &str = "-aarch64";
+    const CHECKSUMS_FILE: &str = "checksums.txt";
 
     use super::WasmPath;
     use crate::{
@@ -87,6 +88,10 @@ mod artifacts_dir {
             if ::std::fs::metadata(&artifacts_dir).is_ok() {
                 return Some(dir.clone());
             }
+            // We don't look further than the cargo workspace root
+            if is_workspace_root(dir) {
+                return Some(dir.clone());
+            }
             // First we pop the dir
             if !dir.pop() {
                 return None;
@@ -94,6 +99,12 @@ mod artifacts_dir {
         }
     }
 
+    fn is_workspace_root(dir: &::std::path::Path) -> bool {
+        fs::read_to_string(dir.join("Cargo.toml"))
+            .map(|manifest| manifest.lines().any(|l| l.trim() == "[workspace]"))
+            .unwrap_or(false)
+    }
+
     #[macro_export]
     /// Creates an [`ArtifactsDir`] from the current workspace by searching the file tree for a directory named `artifacts`.
     /// It does this by reading the CARGO_MANIFEST_DIR environment variable and going up the file tree until it finds the `artifacts` directory.
@@ -104,6 +115,25 @@ mod artifacts_dir {
     }
     pub use from_workspace;
 
+    /// Architecture variant of a wasm artifact.
+    /// `cosmwasm/optimizer` appends `-aarch64` to artifacts built on ARM hosts (e.g. Apple Silicon).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum ArtifactArch {
+        /// Artifact without architecture suffix, built on x86_64. These builds are reproducible.
+        Default,
+        /// Artifact with the `-aarch64` suffix
+        Aarch64,
+    }
+
+    impl ArtifactArch {
+        fn suffix(&self) -> &'static str {
+            match self {
+                ArtifactArch::Default => "",
+                ArtifactArch::Aarch64 => ARM_POSTFIX,
+            }
+        }
+    }
+
     /// Points to a directory containing WASM files
     ///
     /// # Example
@@ -118,7 +148,15 @@ mod artifacts_dir {
     /// // Get a path to a WASM file that contains the string "my_contract".
     /// let wasm_path: WasmPath = artifact_dir.find_wasm_path("my_contract").unwrap();
     /// ```
-    pub struct ArtifactsDir(PathBuf);
+    ///
+    /// When both `my_contract.wasm` and `my_contract-aarch64.wasm` are present, the non-ARM artifact is picked by default.
+    /// Set `CW_ORCH_PREFER_ARM_ARTIFACTS=true` or use [`ArtifactsDir::with_arch_preference`] to change that.
+    /// If the directory contains a `checksums.txt` file (as generated by `cosmwasm/optimizer`),
+    /// the checksum of the selected artifact is verified against it.
+    pub struct ArtifactsDir {
+        path: PathBuf,
+        arch_preference: [ArtifactArch; 2],
+    }
 
     impl ArtifactsDir {
         /// Get the artifacts directory from the environment variable `ARTIFACTS_DIR`.
@@ -128,7 +166,8 @@ mod artifacts_dir {
             Self::new(dir)
         }
 
-        /// Creates an artifacts dir by searching for an artifacts directory by going up the file tree from start_path or the current directory
+        /// Creates an artifacts dir by searching for an artifacts directory by going up the file tree from start_path or the current directory.
+        /// The search stops at the root of the cargo workspace.
         pub fn auto(start_path: Option<String>) -> Self {
             // We find the artifacts dir automatically from the place that this function was invoked
             let workspace_dir = find_workspace_dir(start_path).join("artifacts");
@@ -144,12 +183,29 @@ mod artifacts_dir {
                 "provided path {} does not exist",
                 path.display()
             );
-            Self(path)
+            let arch_preference = if CoreEnvVars::prefer_arm_artifacts() {
+                [ArtifactArch::Aarch64, ArtifactArch::Default]
+            } else {
+                [ArtifactArch::Default, ArtifactArch::Aarch64]
+            };
+            Self {
+                path,
+                arch_preference,
+            }
+        }
+
+        /// Set which artifact variant is picked first when both are available
+        pub fn with_arch_preference(mut self, preferred: ArtifactArch) -> Self {
+            self.arch_preference = match preferred {
+                ArtifactArch::Default => [ArtifactArch::Default, ArtifactArch::Aarch64],
+                ArtifactArch::Aarch64 => [ArtifactArch::Aarch64, ArtifactArch::Default],
+            };
+            self
         }
 
         /// Get the path to the artifacts directory
         pub fn path(&self) -> &PathBuf {
-            &self.0
+            &self.path
         }
 
         /// Find a WASM file in the artifacts directory that contains the given name.
@@ -166,82 +222,175 @@ mod artifacts_dir {
             build_postfix: BuildPostfix,
         ) -> Result<WasmPath, CwEnvError> {
             let build_postfix: String = build_postfix.into();
-            // Found artifacts priority respected
 
-            let mut wasm_with_postfix = None;
-            let mut arm_wasm_with_postfix = None;
-            let mut default_wasm = None;
-            let mut arm_default_wasm = None;
-
-            for entry in fs::read_dir(self.path())?.flatten() {
-                let path = entry.path();
+            let file_names: Vec<String> = fs::read_dir(self.path())?
+                .flatten()
+                .map(|entry| entry.path())
                 // Skip if not a wasm file
-                if !path.is_file() || path.extension().unwrap_or_default() != "wasm" {
-                    continue;
-                }
+                .filter(|path| path.is_file() && path.extension().unwrap_or_default() == "wasm")
+                .map(|path| {
+                    path.file_name()
+                        .unwrap_or_default()
+                        .to_string_lossy()
+                        .into_owned()
+                })
+                .collect();
 
-                let file_name = path.file_name().unwrap_or_default().to_string_lossy();
-                // Wasm with build postfix, non-ARM
-                if is_artifact_with_build_postfix(&file_name, name, &build_postfix) {
-                    wasm_with_postfix = Some(file_name.into_owned());
-                    // As it's highest priority we just the loop end here
-                    break;
-                }
+            let file_name =
+                select_artifact(&file_names, name, &build_postfix, &self.arch_preference)
+                    .ok_or_else(|| {
+                        CwEnvError::WasmNotFound(
+                            name.to_owned(),
+                            self.path().to_str().unwrap_or_default().to_owned(),
+                        )
+                    })?;
+            let wasm_path = WasmPath::new(self.path().join(file_name))?;
+            self.verify_checksum(file_name, &wasm_path)?;
+            Ok(wasm_path)
+        }
 
-                // Check other valid filenames
-                if is_arm_artifact_with_build_postfix(&file_name, name, &build_postfix) {
-                    // Wasm with build postfix, ARM
-                    arm_wasm_with_postfix = Some(file_name.into_owned())
-                } else if is_default_artifact(&file_name, name) {
-                    // Wasm without build postfix, non-ARM
-                    default_wasm = Some(file_name.into_owned())
-                } else if is_default_arm_artifact(&file_name, name) {
-                    // Wasm without build postfix, ARM
-                    arm_default_wasm = Some(file_name.into_owned())
-                }
+        /// Compares the checksum of the artifact with the one registered in `checksums.txt`, if any.
+        fn verify_checksum(&self, file_name: &str, wasm_path: &WasmPath) -> Result<(), CwEnvError> {
+            let Ok(checksums) = fs::read_to_string(self.path().join(CHECKSUMS_FILE)) else {
+                return Ok(());
+            };
+            let Some(expected) = find_checksum(&checksums, file_name) else {
+                log::warn!(
+                    target: &local_target(),
+                    "{} not listed in {}, it might not come from the last optimized build",
+                    file_name,
+                    CHECKSUMS_FILE
+                );
+                return Ok(());
+            };
+            let actual = wasm_path.checksum()?.to_hex();
+            if !expected.eq_ignore_ascii_case(&actual) {
+                return Err(CwEnvError::ChecksumMismatch {
+                    file: file_name.to_owned(),
+                    expected: expected.to_owned(),
+                    actual,
+                });
             }
-
-            let path_str = wasm_with_postfix
-                .or(arm_wasm_with_postfix)
-                .or(default_wasm)
-                .or(arm_default_wasm)
-                .ok_or_else(|| {
-                    CwEnvError::WasmNotFound(
-                        name.to_owned(),
-                        self.path().to_str().unwrap_or_default().to_owned(),
-                    )
-                })?;
-            WasmPath::new(self.path().join(path_str))
+            Ok(())
         }
     }
 
-    fn is_artifact(file_name: &str, contract_name: &str) -> bool {
-        file_name.contains(contract_name)
-    }
-
-    fn is_default_artifact(file_name: &str, contract_name: &str) -> bool {
-        file_name.ends_with(format!("{contract_name}.wasm").as_str())
-    }
-
-    fn is_default_arm_artifact(file_name: &str, contract_name: &str) -> bool {
-        file_name.ends_with(format!("{contract_name}{ARM_POSTFIX}.wasm").as_str())
-    }
-
-    fn is_artifact_with_build_postfix(
-        file_name: &str,
+    /// Picks the artifact to use among `file_names`.
+    /// Artifacts with the build post-fix come first, then the architecture preference is applied.
+    fn select_artifact<'a>(
+        file_names: &'a [String],
         contract_name: &str,
         build_postfix: &str,
-    ) -> bool {
-        is_artifact(file_name, contract_name)
-            && file_name.ends_with(format!("{build_postfix}.wasm").as_str())
+        arch_preference: &[ArtifactArch; 2],
+    ) -> Option<&'a str> {
+        let postfixes: &[&str] = if build_postfix.is_empty() {
+            &[""]
+        } else {
+            &[build_postfix, ""]
+        };
+        for postfix in postfixes {
+            for arch in arch_preference {
+                let found = file_names
+                    .iter()
+                    .find(|file_name| is_artifact(file_name, contract_name, postfix, arch));
+                if let Some(file_name) = found {
+                    return Some(file_name);
+                }
+            }
+        }
+        None
     }
 
-    fn is_arm_artifact_with_build_postfix(
+    fn is_artifact(
         file_name: &str,
         contract_name: &str,
         build_postfix: &str,
+        arch: &ArtifactArch,
     ) -> bool {
-        is_artifact(file_name, contract_name)
-            && file_name.ends_with(format!("{build_postfix}{ARM_POSTFIX}.wasm").as_str())
+        let Some(stem) = file_name.strip_suffix(".wasm") else {
+            return false;
+        };
+        let Some(stem) = stem.strip_suffix(arch.suffix()) else {
+            return false;
+        };
+        // A non-ARM lookup should never return an ARM artifact
+        if *arch == ArtifactArch::Default && stem.ends_with(ARM_POSTFIX) {
+            return false;
+        }
+        if build_postfix.is_empty() {
+            stem.ends_with(contract_name)
+        } else {
+            stem.contains(contract_name) && stem.ends_with(build_postfix)
+        }
+    }
+
+    /// Finds the checksum of `file_name` in the content of a `checksums.txt` file (`<sha256>  <file name>` per line)
+    fn find_checksum<'a>(checksums: &'a str, file_name: &str) -> Option<&'a str> {
+        checksums.lines().find_map(|line| {
+            let mut parts = line.split_whitespace();
+            let checksum = parts.next()?;
+            (parts.next()? == file_name).then_some(checksum)
+        })
+    }
+
+    #[cfg(test)]
+    mod test {
+        use super::*;
+
+        const DEFAULT_FIRST: [ArtifactArch; 2] = [ArtifactArch::Default, ArtifactArch::Aarch64];
+        const ARM_FIRST: [ArtifactArch; 2] = [ArtifactArch::Aarch64, ArtifactArch::Default];
+
+        fn files(names: &[&str]) -> Vec<String> {
+            names.iter().map(|n| n.to_string()).collect()
+        }
+
+        #[test]
+        fn arch_preference() {
+            let files = files(&["counter_contract-aarch64.wasm", "counter_contract.wasm"]);
+            assert_eq!(
+                select_artifact(&files, "counter_contract", "", &DEFAULT_FIRST),
+                Some("counter_contract.wasm")
+            );
+            assert_eq!(
+                select_artifact(&files, "counter_contract", "", &ARM_FIRST),
+                Some("counter_contract-aarch64.wasm")
+            );
+        }
+
+        #[test]
+        fn arch_fallback() {
+            let files = files(&["counter_contract-aarch64.wasm"]);
+            assert_eq!(
+                select_artifact(&files, "counter_contract", "", &DEFAULT_FIRST),
+                Some("counter_contract-aarch64.wasm")
+            );
+        }
+
+        #[test]
+        fn build_postfix_first() {
+            let files = files(&[
+                "counter_contract.wasm",
+                "counter_contract-aarch64.wasm",
+                "counter_contract-juno-aarch64.wasm",
+            ]);
+            assert_eq!(
+                select_artifact(&files, "counter_contract", "juno", &DEFAULT_FIRST),
+                Some("counter_contract-juno-aarch64.wasm")
+            );
+            assert_eq!(
+                select_artifact(&files, "counter_contract", "osmosis", &DEFAULT_FIRST),
+                Some("counter_contract.wasm")
+            );
+        }
+
+        #[test]
+        fn checksums() {
+            let checksums = "aaaa  counter_contract.wasm\nbbbb  counter_contract-aarch64.wasm\n";
+            assert_eq!(
+                find_checksum(checksums, "counter_contract-aarch64.wasm"),
+                Some("bbbb")
+            );
+            assert_eq!(find_checksum(checksums, "other.wasm"), None);
+        }
     }
 }
@@ -13,6 +13,8 @@ use cosmwasm_std::StdError;
 pub const ARTIFACTS_DIR_ENV_NAME: &str = "ARTIFACTS_DIR";
 pub const SERIALIZE_ENV_NAME: &str = "CW_ORCH_SERIALIZE_JSON";
 pub const MANUAL_INTERACTION_ENV_NAME: &str = "CW_ORCH_MANUAL_INTERACTION";
+pub const PREFER_ARM_ARTIFACTS_ENV_NAME: &str = "CW_ORCH_PREFER_ARM_ARTIFACTS";
+pub const ADDRESS_BOOK_ENV_NAME: &str = "CW_ORCH_ADDRESS_BOOK";
 
 pub struct CoreEnvVars;
 
@@ -57,6 +59,28 @@ impl CoreEnvVars {
             true
         }
     }
+
+    /// Optional - boolean
+    /// Defaults to "false"
+    /// If equals to true, `-aarch64` artifacts are picked over non-ARM ones when both are present in the artifacts dir
+    pub fn prefer_arm_artifacts() -> bool {
+        if let Ok(str_value) = env::var(PREFER_ARM_ARTIFACTS_ENV_NAME) {
+            parse_with_log(str_value, PREFER_ARM_ARTIFACTS_ENV_NAME)
+        } else {
+            false
+        }
+    }
+
+    /// Optional - Path
+    /// Address book file used by `AddressBook::new`
+    /// Defaults to "~/.cw-orchestrator/address_book.json"
+    pub fn address_book_file() -> Option<PathBuf> {
+        if let Ok(str_value) = env::var(ADDRESS_BOOK_ENV_NAME) {
+            Some(parse_with_log(str_value, ADDRESS_BOOK_ENV_NAME))
+        } else {
+            None
+        }
+    }
 }
 
 fn parse_with_log<F: FromStr<Err = E>, E: std::fmt::Display>(
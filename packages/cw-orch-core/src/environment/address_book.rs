@@ -0,0 +1,135 @@
+//! Address book shared between scripts, libraries and interactive tools, see [`AddressBook`].
+
+use cosmwasm_std::Addr;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+
+use super::{ChainState, EnvironmentInfo, EnvironmentQuerier, StateInterface};
+use crate::{env::CoreEnvVars, CwEnvError};
+
+/// Addresses saved per chain id and deployment id, by `namespace:name`
+type AddressBookEntries = BTreeMap<String, BTreeMap<String, BTreeMap<String, Addr>>>;
+
+/// Named addresses of a chain and deployment, like `addr_of("dex", "factory")`.
+///
+/// The address book looks up the state of the environment first, where contract interfaces save their address under their contract id.
+/// An address named `name` in `namespace` corresponds to the contract id `namespace:name`.
+/// Other addresses, like third-party contracts or accounts, are kept in the address book file,
+/// `~/.cw-orchestrator/address_book.json` by default, shared by all the tools reading the same file.
+/// Addresses inserted through the address book are written to both, so they never disagree.
+///
+/// ```ignore
+/// let mut address_book = AddressBook::new(&chain)?;
+/// address_book.insert("dex", "factory", &factory_addr)?;
+/// let factory = address_book.addr_of("dex", "factory")?;
+/// ```
+#[derive(Clone)]
+pub struct AddressBook<S: StateInterface> {
+    path: PathBuf,
+    env_info: EnvironmentInfo,
+    state: S,
+}
+
+impl<S: StateInterface> AddressBook<S> {
+    /// Address book of the chain and deployment of `chain`, in the default address book file
+    pub fn new<Chain: ChainState<Out = S> + EnvironmentQuerier>(
+        chain: &Chain,
+    ) -> Result<Self, CwEnvError> {
+        Ok(Self::with_path(chain, default_address_book_file()?))
+    }
+
+    /// Address book of the chain and deployment of `chain`, in the address book file at `path`
+    pub fn with_path<Chain: ChainState<Out = S> + EnvironmentQuerier>(
+        chain: &Chain,
+        path: impl Into<PathBuf>,
+    ) -> Self {
+        Self {
+            path: path.into(),
+            env_info: chain.env_info(),
+            state: chain.state(),
+        }
+    }
+
+    /// Address named `name` in `namespace`
+    pub fn addr_of(&self, namespace: &str, name: &str) -> Result<Addr, CwEnvError> {
+        let id = entry_id(namespace, name);
+        if let Ok(addr) = self.state.get_address(&id) {
+            return Ok(addr);
+        }
+        self.entries()?
+            .remove(&id)
+            .ok_or(CwEnvError::AddrNotInStore(id))
+    }
+
+    /// Saves `addr` as `name` in `namespace`, in the state and the address book file
+    pub fn insert(&mut self, namespace: &str, name: &str, addr: &Addr) -> Result<(), CwEnvError> {
+        let id = entry_id(namespace, name);
+        self.state.set_address(&id, addr);
+        self.update(|entries| {
+            entries.insert(id, addr.clone());
+        })
+    }
+
+    /// Removes the address named `name` in `namespace`, from the state and the address book file
+    pub fn remove(&mut self, namespace: &str, name: &str) -> Result<(), CwEnvError> {
+        let id = entry_id(namespace, name);
+        if self.state.get_address(&id).is_ok() {
+            self.state.remove_address(&id);
+        }
+        self.update(|entries| {
+            entries.remove(&id);
+        })
+    }
+
+    /// All the addresses of the chain and deployment by `namespace:name`, the ones of the state taking precedence
+    pub fn all(&self) -> Result<BTreeMap<String, Addr>, CwEnvError> {
+        let mut all = self.entries()?;
+        all.extend(self.state.get_all_addresses()?);
+        Ok(all)
+    }
+
+    /// Addresses saved in the address book file for the chain and deployment
+    fn entries(&self) -> Result<BTreeMap<String, Addr>, CwEnvError> {
+        Ok(self
+            .read()?
+            .remove(&self.env_info.chain_id)
+            .and_then(|mut deployments| deployments.remove(&self.env_info.deployment_id))
+            .unwrap_or_default())
+    }
+
+    fn read(&self) -> Result<AddressBookEntries, CwEnvError> {
+        if !self.path.exists() {
+            return Ok(Default::default());
+        }
+        Ok(serde_json::from_slice(&fs::read(&self.path)?)?)
+    }
+
+    fn update(&self, f: impl FnOnce(&mut BTreeMap<String, Addr>)) -> Result<(), CwEnvError> {
+        let mut all = self.read()?;
+        let entries = all
+            .entry(self.env_info.chain_id.clone())
+            .or_default()
+            .entry(self.env_info.deployment_id.clone())
+            .or_default();
+        f(entries);
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(&self.path, serde_json::to_string_pretty(&all)?)?;
+        Ok(())
+    }
+}
+
+fn entry_id(namespace: &str, name: &str) -> String {
+    format!("{namespace}:{name}")
+}
+
+fn default_address_book_file() -> Result<PathBuf, CwEnvError> {
+    if let Some(path) = CoreEnvVars::address_book_file() {
+        return Ok(path);
+    }
+    dirs::home_dir()
+        .map(|home| home.join(".cw-orchestrator").join("address_book.json"))
+        .ok_or_else(|| CwEnvError::StdErr("Your machine doesn't have a home folder".to_string()))
+}
@@ -0,0 +1,92 @@
+//! Assertions on the state of an environment, for integration tests.
+//!
+//! The checks return a [`CwEnvError::AssertionFailed`] instead of panicking so they can be used with `?` in scripts as well as tests.
+//! They only rely on the default queriers and work the same way against `Mock` and `Daemon`.
+
+use std::time::Duration;
+
+use cosmwasm_std::{Addr, Coin};
+
+use crate::CwEnvError;
+
+use super::{BankQuerier, QueryHandler, WasmQuerier};
+
+/// Assertions on balances and contracts, implemented for every [`QueryHandler`]
+pub trait QueryAssertions: QueryHandler {
+    /// Asserts that `address` holds exactly `expected`
+    fn assert_balance(&self, address: impl Into<String>, expected: Coin) -> Result<(), CwEnvError> {
+        let address = address.into();
+        let balance = self
+            .bank_querier()
+            .balance(&address, Some(expected.denom.clone()))
+            .map_err(Into::into)?;
+        let actual = balance
+            .into_iter()
+            .find(|coin| coin.denom == expected.denom)
+            .map(|coin| coin.amount)
+            .unwrap_or_default();
+        if actual != expected.amount {
+            return Err(CwEnvError::AssertionFailed(format!(
+                "balance of {address} is {actual}{}, expected {expected}",
+                expected.denom
+            )));
+        }
+        Ok(())
+    }
+
+    /// Asserts that the admin of `contract` is `expected`, `None` meaning no admin
+    fn assert_contract_admin(
+        &self,
+        contract: &Addr,
+        expected: Option<&Addr>,
+    ) -> Result<(), CwEnvError> {
+        let info = self
+            .wasm_querier()
+            .contract_info(contract)
+            .map_err(Into::into)?;
+        let expected = expected.map(Addr::to_string);
+        if info.admin != expected {
+            return Err(CwEnvError::AssertionFailed(format!(
+                "admin of {contract} is {:?}, expected {expected:?}",
+                info.admin
+            )));
+        }
+        Ok(())
+    }
+
+    /// Asserts that `contract` runs the code `expected`
+    fn assert_code_id(&self, contract: &Addr, expected: u64) -> Result<(), CwEnvError> {
+        let info = self
+            .wasm_querier()
+            .contract_info(contract)
+            .map_err(Into::into)?;
+        if info.code_id != expected {
+            return Err(CwEnvError::AssertionFailed(format!(
+                "code id of {contract} is {}, expected {expected}",
+                info.code_id
+            )));
+        }
+        Ok(())
+    }
+
+    /// Polls `condition` once per block until it returns `true`.
+    /// `timeout` is measured in block time, so a `Mock` reaches it by producing blocks instead of sleeping.
+    fn wait_for<F>(&self, mut condition: F, timeout: Duration) -> Result<(), CwEnvError>
+    where
+        F: FnMut(&Self) -> Result<bool, CwEnvError>,
+    {
+        let start = self.block_info().map_err(Into::into)?.time;
+        loop {
+            if condition(self)? {
+                return Ok(());
+            }
+            let now = self.block_info().map_err(Into::into)?.time;
+            if now.seconds().saturating_sub(start.seconds()) >= timeout.as_secs() {
+                return Err(CwEnvError::WaitTimeout(timeout));
+            }
+            self.next_block().map_err(Into::into)?;
+        }
+    }
+}
+
+impl<T: QueryHandler> QueryAssertions for T {}
@@ -2,6 +2,8 @@ use std::fmt::Display;
 
 use serde::{Deserialize, Serialize};
 
+use crate::CwEnvError;
+
 pub type ChainInfo = ChainInfoBase<&'static str, &'static [&'static str]>;
 pub type ChainInfoOwned = ChainInfoBase<String, Vec<String>>;
 
@@ -41,6 +43,47 @@ pub struct NetworkInfoBase<StringType: Into<String>> {
     pub pub_address_prefix: StringType,
     /// coin type for key derivation
     pub coin_type: u32,
+    /// type of the account public keys, derived from the coin type if not set, see [`NetworkInfoBase::resolved_pub_key_type`]
+    pub pub_key_type: Option<PubKeyType>,
+}
+
+/// Type of the public keys of the accounts of a chain, which determines how addresses are derived and transactions signed
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum PubKeyType {
+    /// Cosmos `secp256k1` keys, addresses are the RIPEMD160 of the SHA256 of the key
+    #[default]
+    Secp256k1,
+    /// Injective `ethsecp256k1` keys, addresses are the last 20 bytes of the Keccak256 of the key
+    InjectiveEthSecp256k1,
+    /// Ethermint `ethsecp256k1` keys, used by the chains built on Ethermint or Evmos
+    EthermintEthSecp256k1,
+}
+
+impl PubKeyType {
+    /// Whether the keys are Ethereum keys, which sign the Keccak256 of the transactions
+    pub fn is_ethereum(&self) -> bool {
+        matches!(
+            self,
+            PubKeyType::InjectiveEthSecp256k1 | PubKeyType::EthermintEthSecp256k1
+        )
+    }
+}
+
+/// Ethereum coin type, its keys are Injective `ethsecp256k1` keys unless the public key type is set
+const ETHEREUM_COIN_TYPE: u32 = 60;
+
+impl<StringType: Into<String>> NetworkInfoBase<StringType> {
+    /// Type of the account public keys, `pub_key_type` if set.
+    /// Otherwise [`PubKeyType::InjectiveEthSecp256k1`] for the Ethereum coin type 60 and [`PubKeyType::Secp256k1`] for all the others.
+    pub fn resolved_pub_key_type(&self) -> PubKeyType {
+        self.pub_key_type
+            .unwrap_or(if self.coin_type == ETHEREUM_COIN_TYPE {
+                PubKeyType::InjectiveEthSecp256k1
+            } else {
+                PubKeyType::Secp256k1
+            })
+    }
 }
 
 impl<StringType: Into<String> + Default> Default for NetworkInfoBase<StringType> {
@@ -50,6 +93,7 @@ impl<StringType: Into<String> + Default> Default for NetworkInfoBase<StringType>
             pub_address_prefix: StringType::default(),
             // Default cosmos coin
             coin_type: 118,
+            pub_key_type: None,
         }
     }
 }
@@ -91,6 +135,7 @@ impl From<NetworkInfo> for NetworkInfoOwned {
             chain_name: value.chain_name.to_string(),
             pub_address_prefix: value.pub_address_prefix.to_string(),
             coin_type: value.coin_type,
+            pub_key_type: value.pub_key_type,
         }
     }
 }
@@ -159,6 +204,7 @@ impl ChainInfoOwned {
                     chain_name,
                     pub_address_prefix,
                     coin_type,
+                    pub_key_type,
                 },
             kind,
         } = chain_info;
@@ -190,9 +236,199 @@ impl ChainInfoOwned {
         if coin_type != 118 {
             self.network_info.coin_type = coin_type;
         }
+        if let Some(pub_key_type) = pub_key_type {
+            self.network_info.pub_key_type = Some(pub_key_type);
+        }
         if kind != ChainKind::Unspecified {
             self.kind = kind;
         }
         self
     }
 }
+
+/// Fluent builder of a [`ChainInfoOwned`], for custom and private chains.
+///
+/// ```
+/// use cw_orch_core::environment::{ChainInfoBuilder, ChainKind};
+///
+/// let chain = ChainInfoBuilder::new("my-chain-1")
+///     .kind(ChainKind::Testnet)
+///     .prefix("mychain")
+///     .gas_denom("umy")
+///     .gas_price(0.025)
+///     .grpc_url("http://localhost:9090")
+///     .build()
+///     .unwrap();
+/// assert_eq!(chain.network_info.chain_name, "mychain");
+/// ```
+#[derive(Clone, Debug)]
+pub struct ChainInfoBuilder {
+    chain_info: ChainInfoOwned,
+}
+
+impl ChainInfoBuilder {
+    pub fn new(chain_id: impl Into<String>) -> Self {
+        Self {
+            chain_info: ChainInfoOwned::config(chain_id.into()),
+        }
+    }
+
+    /// Chain kind, unspecified by default
+    pub fn kind(mut self, kind: ChainKind) -> Self {
+        self.chain_info.kind = kind;
+        self
+    }
+
+    /// Network identifier, defaults to the address prefix
+    pub fn chain_name(mut self, chain_name: impl Into<String>) -> Self {
+        self.chain_info.network_info.chain_name = chain_name.into();
+        self
+    }
+
+    /// Bech32 prefix of the addresses
+    pub fn prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.chain_info.network_info.pub_address_prefix = prefix.into();
+        self
+    }
+
+    /// Coin type used to derive the keys, 118 by default
+    pub fn coin_type(mut self, coin_type: u32) -> Self {
+        self.chain_info.network_info.coin_type = coin_type;
+        self
+    }
+
+    /// Type of the account public keys, derived from the coin type by default
+    pub fn pub_key_type(mut self, pub_key_type: PubKeyType) -> Self {
+        self.chain_info.network_info.pub_key_type = Some(pub_key_type);
+        self
+    }
+
+    pub fn gas_denom(mut self, gas_denom: impl Into<String>) -> Self {
+        self.chain_info.gas_denom = gas_denom.into();
+        self
+    }
+
+    pub fn gas_price(mut self, gas_price: f64) -> Self {
+        self.chain_info.gas_price = gas_price;
+        self
+    }
+
+    /// Add a gRPC endpoint, the endpoints are tried in order
+    pub fn grpc_url(mut self, url: impl Into<String>) -> Self {
+        self.chain_info.grpc_urls.push(url.into());
+        self
+    }
+
+    /// Add gRPC endpoints, the endpoints are tried in order
+    pub fn grpc_urls(mut self, urls: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.chain_info
+            .grpc_urls
+            .extend(urls.into_iter().map(Into::into));
+        self
+    }
+
+    pub fn lcd_url(mut self, url: impl Into<String>) -> Self {
+        self.chain_info.lcd_url = Some(url.into());
+        self
+    }
+
+    pub fn fcd_url(mut self, url: impl Into<String>) -> Self {
+        self.chain_info.fcd_url = Some(url.into());
+        self
+    }
+
+    /// Checks that the fields needed to connect and send transactions are set
+    pub fn build(self) -> Result<ChainInfoOwned, CwEnvError> {
+        let mut chain_info = self.chain_info;
+        let missing = [
+            ("chain id", chain_info.chain_id.is_empty()),
+            (
+                "address prefix",
+                chain_info.network_info.pub_address_prefix.is_empty(),
+            ),
+            ("gas denom", chain_info.gas_denom.is_empty()),
+            ("gas price", chain_info.gas_price.is_nan()),
+            ("grpc url", chain_info.grpc_urls.is_empty()),
+        ]
+        .into_iter()
+        .filter_map(|(field, missing)| missing.then_some(field))
+        .collect::<Vec<_>>();
+        if !missing.is_empty() {
+            return Err(CwEnvError::StdErr(format!(
+                "chain info of {} is missing: {}",
+                chain_info.chain_id,
+                missing.join(", ")
+            )));
+        }
+        if chain_info.network_info.chain_name.is_empty() {
+            chain_info.network_info.chain_name = chain_info.network_info.pub_address_prefix.clone();
+        }
+        Ok(chain_info)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn builder_reports_missing_fields() {
+        let err = ChainInfoBuilder::new("my-chain-1")
+            .prefix("mychain")
+            .build()
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Generic Error chain info of my-chain-1 is missing: gas denom, gas price, grpc url"
+        );
+
+        let chain = ChainInfoBuilder::new("my-chain-1")
+            .chain_name("my-chain")
+            .prefix("mychain")
+            .coin_type(60)
+            .gas_denom("amy")
+            .gas_price(1.0)
+            .grpc_urls(["http://a:9090", "http://b:9090"])
+            .build()
+            .unwrap();
+        assert_eq!(chain.network_info.chain_name, "my-chain");
+        assert_eq!(chain.network_info.coin_type, 60);
+        assert_eq!(chain.grpc_urls.len(), 2);
+    }
+
+    #[test]
+    fn pub_key_type_defaults_to_coin_type() {
+        let builder = ChainInfoBuilder::new("my-chain-1")
+            .prefix("mychain")
+            .gas_denom("amy")
+            .gas_price(1.0)
+            .grpc_url("http://a:9090");
+        let cosmos = builder.clone().build().unwrap();
+        assert_eq!(
+            cosmos.network_info.resolved_pub_key_type(),
+            PubKeyType::Secp256k1
+        );
+
+        let injective = builder.clone().coin_type(60).build().unwrap();
+        assert_eq!(
+            injective.network_info.resolved_pub_key_type(),
+            PubKeyType::InjectiveEthSecp256k1
+        );
+
+        let ethermint = builder
+            .coin_type(60)
+            .pub_key_type(PubKeyType::EthermintEthSecp256k1)
+            .build()
+            .unwrap();
+        assert_eq!(
+            ethermint.network_info.resolved_pub_key_type(),
+            PubKeyType::EthermintEthSecp256k1
+        );
+
+        let overwritten = cosmos.overwrite_with(ethermint);
+        assert_eq!(
+            overwritten.network_info.pub_key_type,
+            Some(PubKeyType::EthermintEthSecp256k1)
+        );
+    }
+}
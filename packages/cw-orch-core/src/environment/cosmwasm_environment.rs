@@ -13,6 +13,15 @@ impl<T: TxHandler + QueryHandler + Clone> CwEnv for T {}
 /// Response type for actions on an environment
 pub type TxResponse<Chain> = <Chain as TxHandler>::Response;
 
+/// Outcome of a simulated transaction
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TxSimulation {
+    /// Gas used by the transaction, `None` if the environment doesn't meter gas
+    pub gas_used: Option<u64>,
+    /// Fee the transaction would pay at the chain gas price, `None` if the environment doesn't charge fees
+    pub fee: Option<Coin>,
+}
+
 /// Signer trait for chains.
 /// Accesses the sender information from the chain object to perform actions.
 pub trait TxHandler: ChainState + Clone {
@@ -85,6 +94,38 @@ pub trait TxHandler: ChainState + Clone {
         contract_address: &Addr,
     ) -> Result<Self::Response, Self::Error>;
 
+    /// Sends `amount` from the sender to `recipient`.
+    /// Returns [`CwEnvError::Unsupported`] for environments that can't send bank transfers.
+    fn bank_send(&self, _recipient: &Addr, _amount: &[Coin]) -> Result<Self::Response, CwEnvError> {
+        Err(CwEnvError::Unsupported("bank_send".to_string()))
+    }
+
+    /// Simulates sending an ExecMsg to a contract, without changing the environment state.
+    /// Returns an error if the execution would fail.
+    /// Returns [`CwEnvError::Unsupported`] for environments that can't simulate transactions.
+    fn simulate_execute<E: Serialize + Debug>(
+        &self,
+        _exec_msg: &E,
+        _coins: &[Coin],
+        _contract_address: &Addr,
+    ) -> Result<TxSimulation, CwEnvError> {
+        Err(CwEnvError::Unsupported("simulate_execute".to_string()))
+    }
+
+    /// Simulates sending an InstantiateMsg, without changing the environment state.
+    /// Returns an error if the instantiation would fail.
+    /// Returns [`CwEnvError::Unsupported`] for environments that can't simulate transactions.
+    fn simulate_instantiate<I: Serialize + Debug>(
+        &self,
+        _code_id: u64,
+        _init_msg: &I,
+        _label: Option<&str>,
+        _admin: Option<&Addr>,
+        _coins: &[Coin],
+    ) -> Result<TxSimulation, CwEnvError> {
+        Err(CwEnvError::Unsupported("simulate_instantiate".to_string()))
+    }
+
     /// Clones the chain with a different sender.
     /// Usually used to call a contract as a different sender.
     fn call_as(&self, sender: &<Self as TxHandler>::Sender) -> Self {
@@ -219,4 +260,11 @@ mod tests {
         associated_error(MockHandler {})?;
         Ok(())
     }
+
+    #[test]
+    fn simulation_unsupported_by_default() {
+        let simulation =
+            MockHandler {}.simulate_execute(&Empty {}, &[], &Addr::unchecked("contract"));
+        assert!(matches!(simulation, Err(CwEnvError::Unsupported(_))));
+    }
 }
@@ -4,7 +4,7 @@ use cw_multi_test::AppResponse;
 use snailquote::unescape;
 
 const CODE_ID_UPLOAD_EVENT: (&str, &str) = ("store_code", "code_id");
-const ADDRESS_INSTANTIATE_EVENT: (&str, &str) = ("instantiate", "_contract_address");
+const ADDRESS_INSTANTIATE_EVENT: (&str, &str) = ("instantiate", CONTRACT_ADDRESS_ATTRIBUTE);
 
 #[cfg(feature = "eth")]
 const INJECTIVE_CODE_ID_UPLOAD_EVENT: (&str, &str) =
@@ -15,6 +15,55 @@ const INJECTIVE_ADDRESS_INSTANTIATE_EVENT: (&str, &str) = (
     "contract_address",
 );
 
+const CONTRACT_ADDRESS_ATTRIBUTE: &str = "_contract_address";
+
+/// Entry point of a contract
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ContractEntryPoint {
+    Instantiate,
+    Execute,
+    Migrate,
+    Sudo,
+    Reply,
+}
+
+impl ContractEntryPoint {
+    /// Type of the event emitted when the entry point is called
+    pub fn event_type(&self) -> &'static str {
+        match self {
+            ContractEntryPoint::Instantiate => "instantiate",
+            ContractEntryPoint::Execute => "execute",
+            ContractEntryPoint::Migrate => "migrate",
+            ContractEntryPoint::Sudo => "sudo",
+            ContractEntryPoint::Reply => "reply",
+        }
+    }
+
+    fn from_event_type(event_type: &str) -> Option<Self> {
+        match event_type {
+            "instantiate" => Some(ContractEntryPoint::Instantiate),
+            "execute" => Some(ContractEntryPoint::Execute),
+            "migrate" => Some(ContractEntryPoint::Migrate),
+            "sudo" => Some(ContractEntryPoint::Sudo),
+            "reply" => Some(ContractEntryPoint::Reply),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for ContractEntryPoint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.event_type())
+    }
+}
+
+/// Contract entry point call, as reported by the events of a transaction
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ContractCallEvent {
+    pub entry_point: ContractEntryPoint,
+    pub contract: Addr,
+}
+
 /// Index data returned by transactions which are applicable to both AppResponse (mock env) and TxResponse (live env)
 pub trait IndexResponse {
     /// Get all events in the response.
@@ -32,8 +81,44 @@ pub trait IndexResponse {
     /// Get the data field of the response.
     fn data(&self) -> Option<Binary>;
 
+    /// Gas used by the transaction, `None` if the environment doesn't meter gas.
+    fn gas_used(&self) -> Option<u64> {
+        None
+    }
+
+    /// Contract entry points called by the transaction, in execution order, including the ones called by sub-messages.
+    /// Parsed from the `instantiate`, `execute`, `migrate`, `sudo` and `reply` events, which don't tell which contract called which.
+    /// The `Mock` environment records the full call tree in its transaction history.
+    fn contract_calls(&self) -> Vec<ContractCallEvent> {
+        self.events()
+            .iter()
+            .filter_map(|event| {
+                let entry_point = ContractEntryPoint::from_event_type(&event.ty)?;
+                let contract = event
+                    .attributes
+                    .iter()
+                    .find(|attr| attr.key == CONTRACT_ADDRESS_ATTRIBUTE)?;
+                Some(ContractCallEvent {
+                    entry_point,
+                    contract: Addr::unchecked(&contract.value),
+                })
+            })
+            .collect()
+    }
+
     /// Helper to get the contract address of a instantiate response.
     fn instantiated_contract_address(&self) -> StdResult<Addr> {
+        self.instantiated_contract_address_from_events()
+    }
+
+    /// Shortcut to get the code id of a contract of an upload response.
+    fn uploaded_code_id(&self) -> StdResult<u64> {
+        self.uploaded_code_id_from_events()
+    }
+
+    /// Contract address of an instantiate response, parsed from the `instantiate` event.
+    /// Used by [`IndexResponse::instantiated_contract_address`] unless the response provides a typed message response.
+    fn instantiated_contract_address_from_events(&self) -> StdResult<Addr> {
         if let Ok(code_id) = self
             .event_attr_value(ADDRESS_INSTANTIATE_EVENT.0, ADDRESS_INSTANTIATE_EVENT.1)
             .map(Addr::unchecked)
@@ -53,8 +138,9 @@ pub trait IndexResponse {
         }
     }
 
-    /// Shortcut to get the code id of a contract of an upload response.
-    fn uploaded_code_id(&self) -> StdResult<u64> {
+    /// Code id of an upload response, parsed from the `store_code` event.
+    /// Used by [`IndexResponse::uploaded_code_id`] unless the response provides a typed message response.
+    fn uploaded_code_id_from_events(&self) -> StdResult<u64> {
         if let Ok(code_id) = self
             .event_attr_value(CODE_ID_UPLOAD_EVENT.0, CODE_ID_UPLOAD_EVENT.1)
             .map(|s| s.parse().unwrap())
@@ -122,7 +208,7 @@ mod index_response_test {
 
     use speculoos::prelude::*;
 
-    use super::IndexResponse;
+    use super::{ContractEntryPoint, IndexResponse};
 
     const CONTRACT_ADDRESS: &str =
         "cosmos1fd68ah02gr2y8ze7tm9te7m70zlmc7vjyyhs6xlhsdmqqcjud4dql4wpxr";
@@ -184,4 +270,32 @@ mod index_response_test {
             .that(&test_uploaded_code_id(&idxres))
             .is_ok();
     }
+
+    #[test]
+    fn contract_calls() {
+        let idxres = AppResponse {
+            events: vec![
+                Event::new("execute").add_attribute("_contract_address", "router"),
+                Event::new("wasm").add_attribute("_contract_address", "router"),
+                Event::new("instantiate").add_attribute("_contract_address", "pair"),
+                Event::new("reply")
+                    .add_attribute("_contract_address", "router")
+                    .add_attribute("mode", "handle_success"),
+            ],
+            data: None,
+        };
+
+        let calls: Vec<_> = idxres
+            .contract_calls()
+            .into_iter()
+            .map(|call| (call.entry_point, call.contract.to_string()))
+            .collect();
+        asserting!("calls are listed in execution order")
+            .that(&calls)
+            .is_equal_to(vec![
+                (ContractEntryPoint::Execute, "router".to_string()),
+                (ContractEntryPoint::Instantiate, "pair".to_string()),
+                (ContractEntryPoint::Reply, "router".to_string()),
+            ]);
+    }
 }
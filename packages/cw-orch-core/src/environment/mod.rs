@@ -1,22 +1,36 @@
+mod address_book;
+mod assertions;
 mod chain_info;
 mod cosmwasm_environment;
 mod index_response;
 mod mut_env;
 mod queriers;
+mod scenario;
 mod state;
+mod tokens;
 
-pub use chain_info::{ChainInfo, ChainInfoOwned, ChainKind, NetworkInfo, NetworkInfoOwned};
-pub use cosmwasm_environment::{CwEnv, TxHandler, TxResponse};
-pub use index_response::IndexResponse;
-pub use mut_env::{BankSetter, MutCwEnv};
+pub use address_book::AddressBook;
+pub use assertions::QueryAssertions;
+pub use chain_info::{
+    ChainInfo, ChainInfoBuilder, ChainInfoOwned, ChainKind, NetworkInfo, NetworkInfoOwned,
+    PubKeyType,
+};
+pub use cosmwasm_environment::{CwEnv, TxHandler, TxResponse, TxSimulation};
+pub use index_response::{ContractCallEvent, ContractEntryPoint, IndexResponse};
+pub use mut_env::{BankSetter, MutCwEnv, StorageSnapshot};
 pub use queriers::{
     bank::BankQuerier,
+    distribution::DistributionQuerier,
     env::{EnvironmentInfo, EnvironmentQuerier},
+    gov::{GovQuerier, ProposalStatus},
     node::NodeQuerier,
+    staking::StakingQuerier,
     wasm::{AsyncWasmQuerier, WasmQuerier},
-    DefaultQueriers, Querier, QuerierGetter, QueryHandler,
+    DefaultQueriers, ModuleQueriers, Querier, QuerierGetter, QueryHandler,
 };
+pub use scenario::{Scenario, ScenarioReport, StepReport};
 pub use state::{ChainState, StateInterface};
+pub use tokens::TokenHelpers;
 
 /// Describes a structure that contains an underlying execution environment
 pub trait Environment<Chain> {
@@ -5,7 +5,7 @@ use super::{
     queriers::{bank::BankQuerier, QuerierGetter},
     CwEnv, TxHandler,
 };
-use cosmwasm_std::Coin;
+use cosmwasm_std::{Coin, Order, Record, Storage};
 use cw_utils::NativeBalance;
 
 pub trait MutCwEnv: BankSetter + CwEnv {}
@@ -36,3 +36,27 @@ pub trait BankSetter: TxHandler + QuerierGetter<Self::T> {
         Ok(())
     }
 }
+
+/// Copy of the storage of a local test environment, used to revert the changes of a simulated transaction.
+pub struct StorageSnapshot(Vec<Record>);
+
+impl StorageSnapshot {
+    /// Copies all the entries of `storage`
+    pub fn take(storage: &dyn Storage) -> Self {
+        Self(storage.range(None, None, Order::Ascending).collect())
+    }
+
+    /// Replaces all the entries of `storage` with the ones of the snapshot
+    pub fn restore(self, storage: &mut dyn Storage) {
+        let keys: Vec<Vec<u8>> = storage
+            .range(None, None, Order::Ascending)
+            .map(|(key, _)| key)
+            .collect();
+        for key in keys {
+            storage.remove(&key);
+        }
+        for (key, value) in self.0 {
+            storage.set(&key, &value);
+        }
+    }
+}
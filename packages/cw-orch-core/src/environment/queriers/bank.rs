@@ -1,4 +1,4 @@
-use cosmwasm_std::Coin;
+use cosmwasm_std::{Coin, DenomMetadata};
 
 use super::Querier;
 
@@ -16,4 +16,10 @@ pub trait BankQuerier: Querier {
 
     /// Query total supply in the bank for a denom
     fn supply_of(&self, denom: impl Into<String>) -> Result<Coin, Self::Error>;
+
+    /// Query the metadata of a denom
+    fn denom_metadata(&self, denom: impl Into<String>) -> Result<DenomMetadata, Self::Error>;
+
+    /// Query the metadata of all the denoms that have metadata
+    fn denoms_metadata(&self) -> Result<Vec<DenomMetadata>, Self::Error>;
 }
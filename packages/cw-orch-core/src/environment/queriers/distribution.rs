@@ -0,0 +1,20 @@
+use cosmwasm_std::Coin;
+
+use super::Querier;
+
+pub trait DistributionQuerier: Querier {
+    /// Query the rewards accrued by a delegation
+    /// Decimal amounts are rounded down
+    fn delegation_rewards(
+        &self,
+        delegator: impl Into<String>,
+        validator: impl Into<String>,
+    ) -> Result<Vec<Coin>, Self::Error>;
+
+    /// Query the total rewards accrued by all the delegations of a delegator
+    /// Decimal amounts are rounded down
+    fn delegation_total_rewards(
+        &self,
+        delegator: impl Into<String>,
+    ) -> Result<Vec<Coin>, Self::Error>;
+}
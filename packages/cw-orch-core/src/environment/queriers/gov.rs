@@ -0,0 +1,8 @@
+pub use cosmos_sdk_proto::cosmos::gov::v1beta1::ProposalStatus;
+
+use super::Querier;
+
+pub trait GovQuerier: Querier {
+    /// Query the status of a governance proposal
+    fn proposal_status(&self, proposal_id: u64) -> Result<ProposalStatus, Self::Error>;
+}
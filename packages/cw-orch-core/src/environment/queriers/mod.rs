@@ -1,13 +1,19 @@
 use cosmwasm_std::{Addr, BlockInfo, Coin};
 use serde::{de::DeserializeOwned, Serialize};
 
-use self::{bank::BankQuerier, env::EnvironmentQuerier, node::NodeQuerier, wasm::WasmQuerier};
+use self::{
+    bank::BankQuerier, distribution::DistributionQuerier, env::EnvironmentQuerier, gov::GovQuerier,
+    node::NodeQuerier, staking::StakingQuerier, wasm::WasmQuerier,
+};
 use crate::CwEnvError;
 use std::fmt::Debug;
 
 pub mod bank;
+pub mod distribution;
 pub mod env;
+pub mod gov;
 pub mod node;
+pub mod staking;
 pub mod wasm;
 
 /// This trait acts as the high-level trait bound for supported queries on a `CwEnv` environment.
@@ -78,6 +84,28 @@ pub trait DefaultQueriers:
     }
 }
 
+/// Queriers for the Cosmos SDK staking, distribution and gov modules.
+/// Implemented by environments that support these modules so that environment-generic code can query them.
+pub trait ModuleQueriers:
+    QuerierGetter<Self::Staking> + QuerierGetter<Self::Distribution> + QuerierGetter<Self::Gov>
+{
+    type Staking: StakingQuerier;
+    type Distribution: DistributionQuerier;
+    type Gov: GovQuerier;
+
+    fn staking_querier(&self) -> Self::Staking {
+        self.querier()
+    }
+
+    fn distribution_querier(&self) -> Self::Distribution {
+        self.querier()
+    }
+
+    fn gov_querier(&self) -> Self::Gov {
+        self.querier()
+    }
+}
+
 #[cfg(test)]
 pub mod test {
     use cosmwasm_std::{Binary, Coin};
@@ -120,6 +148,17 @@ pub mod test {
         fn supply_of(&self, _denom: impl Into<String>) -> Result<Coin, Self::Error> {
             unimplemented!()
         }
+
+        fn denom_metadata(
+            &self,
+            _denom: impl Into<String>,
+        ) -> Result<cosmwasm_std::DenomMetadata, Self::Error> {
+            unimplemented!()
+        }
+
+        fn denoms_metadata(&self) -> Result<Vec<cosmwasm_std::DenomMetadata>, Self::Error> {
+            unimplemented!()
+        }
     }
     impl WasmQuerier for MockQuerier {
         type Chain = MockHandler;
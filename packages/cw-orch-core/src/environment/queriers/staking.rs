@@ -0,0 +1,27 @@
+use cosmwasm_std::{Delegation, Validator};
+
+use super::Querier;
+
+pub trait StakingQuerier: Querier {
+    /// Query the denom used for staking
+    fn bonded_denom(&self) -> Result<String, Self::Error>;
+
+    /// Query all the bonded validators
+    fn all_validators(&self) -> Result<Vec<Validator>, Self::Error>;
+
+    /// Query a validator by its operator address
+    /// Returns `None` if the validator doesn't exist
+    fn validator(&self, address: impl Into<String>) -> Result<Option<Validator>, Self::Error>;
+
+    /// Query all the delegations of a delegator
+    fn all_delegations(&self, delegator: impl Into<String>)
+        -> Result<Vec<Delegation>, Self::Error>;
+
+    /// Query the delegation of a delegator to a validator
+    /// Returns `None` if there is no such delegation
+    fn delegation(
+        &self,
+        delegator: impl Into<String>,
+        validator: impl Into<String>,
+    ) -> Result<Option<Delegation>, Self::Error>;
+}
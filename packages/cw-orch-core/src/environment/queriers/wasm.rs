@@ -58,6 +58,18 @@ pub trait WasmQuerier: Querier {
         query_msg: &Q,
     ) -> Result<T, Self::Error>;
 
+    /// Smart queries of many contracts, with the result of each query returned in the order of `queries`.
+    /// Environments backed by a node send the queries concurrently.
+    fn query_many<Q: Serialize, T: DeserializeOwned>(
+        &self,
+        queries: Vec<(impl Into<String>, Q)>,
+    ) -> Vec<Result<T, Self::Error>> {
+        queries
+            .into_iter()
+            .map(|(address, query_msg)| self.smart_query(address, &query_msg))
+            .collect()
+    }
+
     /// Query code
     fn code(&self, code_id: u64) -> Result<CodeInfoResponse, Self::Error>;
 
@@ -0,0 +1,207 @@
+//! Declarative multi-step scenarios for integration tests, see [`Scenario`].
+//!
+//! A scenario only relies on the interface traits of the contracts and on [`CwEnv`], so the same scenario runs against `Mock`, `Daemon` or any other environment.
+
+use std::fmt::Debug;
+
+use cosmwasm_std::{Addr, Coin};
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::{
+    contract::interface_traits::{
+        ContractInstance, CwOrchExecute, CwOrchInstantiate, CwOrchQuery, CwOrchUpload,
+    },
+    log::contract_target,
+    CwEnvError,
+};
+
+use super::{CwEnv, IndexResponse, QueryHandler, TxResponse};
+
+/// Action of a step, returning the gas used by its transaction if the environment meters it
+type StepAction<'a, Chain> = Box<dyn Fn(&Chain) -> Result<Option<u64>, CwEnvError> + 'a>;
+
+/// Chain of steps (upload, instantiate, execute, query assertion, block advance, ...) run in order against an environment.
+///
+/// Every step is logged, the gas used by its transaction is captured in the returned [`ScenarioReport`]
+/// and a failing step stops the scenario with a [`CwEnvError::ScenarioStepFailed`] naming the step.
+///
+/// ```rust,ignore
+/// let report = Scenario::new("first message")
+///     .upload(&contract)
+///     .instantiate(&contract, InstantiateMsg {}, None, &[])
+///     .execute(&contract, ExecuteMsg::FirstMessage {}, &[])
+///     .assert_query(&contract, QueryMsg::FirstQuery {}, "first query passed".to_string())
+///     .advance_blocks(2)
+///     .run(&chain)?;
+/// println!("{:?} gas used", report.total_gas_used());
+/// ```
+pub struct Scenario<'a, Chain: CwEnv> {
+    name: String,
+    steps: Vec<(String, StepAction<'a, Chain>)>,
+}
+
+/// Outcome of a step of a [`Scenario`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StepReport {
+    /// Name of the step
+    pub name: String,
+    /// Gas used by the transaction of the step, `None` if the step sent no transaction or the environment doesn't meter gas
+    pub gas_used: Option<u64>,
+}
+
+/// Outcome of a successful [`Scenario`] run
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ScenarioReport {
+    /// Name of the scenario
+    pub name: String,
+    /// Outcome of every step, in execution order
+    pub steps: Vec<StepReport>,
+}
+
+impl ScenarioReport {
+    /// Gas used by all the steps, `None` if no step reported its gas
+    pub fn total_gas_used(&self) -> Option<u64> {
+        self.steps
+            .iter()
+            .filter_map(|step| step.gas_used)
+            .reduce(|total, gas| total + gas)
+    }
+}
+
+impl<'a, Chain: CwEnv> Scenario<'a, Chain> {
+    /// Empty scenario, `name` identifies it in the logs and errors
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            steps: vec![],
+        }
+    }
+
+    /// Adds a custom step, which can't capture the gas of its transactions
+    pub fn step(
+        mut self,
+        name: impl Into<String>,
+        action: impl Fn(&Chain) -> Result<(), CwEnvError> + 'a,
+    ) -> Self {
+        self.steps.push((
+            name.into(),
+            Box::new(move |chain| action(chain).map(|()| None)),
+        ));
+        self
+    }
+
+    /// Adds a custom step sending a transaction, whose gas is captured
+    pub fn tx_step(
+        mut self,
+        name: impl Into<String>,
+        action: impl Fn(&Chain) -> Result<TxResponse<Chain>, CwEnvError> + 'a,
+    ) -> Self {
+        self.steps.push((
+            name.into(),
+            Box::new(move |chain| action(chain).map(|resp| resp.gas_used())),
+        ));
+        self
+    }
+
+    /// Uploads the code of `contract`
+    pub fn upload<T: CwOrchUpload<Chain>>(self, contract: &'a T) -> Self {
+        self.tx_step(format!("upload {}", contract.id()), move |_| {
+            contract.upload()
+        })
+    }
+
+    /// Instantiates `contract` with its latest uploaded code
+    pub fn instantiate<T: CwOrchInstantiate<Chain>>(
+        self,
+        contract: &'a T,
+        msg: T::InstantiateMsg,
+        admin: Option<&Addr>,
+        coins: &[Coin],
+    ) -> Self
+    where
+        T::InstantiateMsg: 'a,
+    {
+        let admin = admin.cloned();
+        let coins = coins.to_vec();
+        self.tx_step(format!("instantiate {}", contract.id()), move |_| {
+            contract.instantiate(&msg, admin.as_ref(), Some(coins.as_slice()))
+        })
+    }
+
+    /// Executes `msg` on `contract`
+    pub fn execute<T: CwOrchExecute<Chain>>(
+        self,
+        contract: &'a T,
+        msg: T::ExecuteMsg,
+        coins: &[Coin],
+    ) -> Self
+    where
+        T::ExecuteMsg: 'a,
+    {
+        let coins = coins.to_vec();
+        self.tx_step(
+            format!("execute {} with {msg:?}", contract.id()),
+            move |_| contract.execute(&msg, Some(coins.as_slice())),
+        )
+    }
+
+    /// Asserts that querying `contract` with `msg` returns `expected`
+    pub fn assert_query<T, R>(self, contract: &'a T, msg: T::QueryMsg, expected: R) -> Self
+    where
+        T: CwOrchQuery<Chain>,
+        T::QueryMsg: 'a,
+        R: Serialize + DeserializeOwned + Debug + PartialEq + 'a,
+    {
+        self.step(format!("query {} with {msg:?}", contract.id()), move |_| {
+            let actual: R = contract.query(&msg)?;
+            if actual != expected {
+                return Err(CwEnvError::AssertionFailed(format!(
+                    "query {msg:?} of {} returned {actual:?}, expected {expected:?}",
+                    contract.id()
+                )));
+            }
+            Ok(())
+        })
+    }
+
+    /// Waits for `amount` blocks, a `Mock` produces them instead
+    pub fn advance_blocks(self, amount: u64) -> Self {
+        self.step(format!("advance {amount} blocks"), move |chain| {
+            chain.wait_blocks(amount).map_err(Into::into)
+        })
+    }
+
+    /// Runs the steps in order against `chain`, stopping at the first failing one
+    pub fn run(&self, chain: &Chain) -> Result<ScenarioReport, CwEnvError> {
+        let mut report = ScenarioReport {
+            name: self.name.clone(),
+            steps: Vec::with_capacity(self.steps.len()),
+        };
+        for (index, (name, action)) in self.steps.iter().enumerate() {
+            let step = index + 1;
+            log::info!(
+                target: &contract_target(),
+                "Scenario {}, step {step}/{}: {name}",
+                self.name,
+                self.steps.len()
+            );
+            let gas_used = action(chain).map_err(|e| {
+                log::error!(target: &contract_target(), "Scenario {} failed at step {step} ({name}): {e}", self.name);
+                CwEnvError::ScenarioStepFailed {
+                    scenario: self.name.clone(),
+                    step,
+                    name: name.clone(),
+                    source: Box::new(e),
+                }
+            })?;
+            if let Some(gas_used) = gas_used {
+                log::debug!(target: &contract_target(), "Step {name} used {gas_used} gas");
+            }
+            report.steps.push(StepReport {
+                name: name.clone(),
+                gas_used,
+            });
+        }
+        Ok(report)
+    }
+}
@@ -0,0 +1,167 @@
+//! Cw20 and bank token operations with decimal amounts and named recipients, see [`TokenHelpers`].
+
+use cosmwasm_std::{Addr, Coin, DenomMetadata};
+use cw20::{AllowanceResponse, BalanceResponse, Cw20ExecuteMsg, Cw20QueryMsg, TokenInfoResponse};
+
+use crate::{
+    amount::{parse_amount, TokenAmount},
+    CwEnvError,
+};
+
+use super::{AddressBook, BankQuerier, CwEnv, TxResponse, WasmQuerier};
+
+/// Routine token operations, implemented for every [`CwEnv`].
+///
+/// Amounts are read and written with the decimals of the token, e.g. `"1.5"` for `1500000` atomics of a 6-decimals token.
+/// Token contracts and recipients are either addresses or `namespace:name` entries of the [`AddressBook`] of the environment.
+///
+/// ```ignore
+/// chain.cw20_transfer("dex:token", "team:treasury", "1.5")?;
+/// println!("{}", chain.cw20_balance("dex:token", "team:treasury")?);
+/// chain.bank_transfer("team:treasury", "10", "ujuno")?;
+/// ```
+pub trait TokenHelpers: CwEnv {
+    /// Resolves `name` to an address, looking up `namespace:name` entries in the [`AddressBook`]
+    fn resolve_address(&self, name: &str) -> Result<Addr, CwEnvError> {
+        match name.split_once(':') {
+            Some((namespace, name)) => AddressBook::new(self)?.addr_of(namespace, name),
+            None => Ok(Addr::unchecked(name)),
+        }
+    }
+
+    /// Cw20 balance of `address`
+    fn cw20_balance(&self, token: &str, address: &str) -> Result<TokenAmount, CwEnvError> {
+        let token = self.resolve_address(token)?;
+        let balance: BalanceResponse = self
+            .wasm_querier()
+            .smart_query(
+                &token,
+                &Cw20QueryMsg::Balance {
+                    address: self.resolve_address(address)?.to_string(),
+                },
+            )
+            .map_err(Into::into)?;
+        cw20_amount(self, &token, balance.balance)
+    }
+
+    /// Cw20 amount `spender` can spend on behalf of `owner`
+    fn cw20_allowance(
+        &self,
+        token: &str,
+        owner: &str,
+        spender: &str,
+    ) -> Result<TokenAmount, CwEnvError> {
+        let token = self.resolve_address(token)?;
+        let allowance: AllowanceResponse = self
+            .wasm_querier()
+            .smart_query(
+                &token,
+                &Cw20QueryMsg::Allowance {
+                    owner: self.resolve_address(owner)?.to_string(),
+                    spender: self.resolve_address(spender)?.to_string(),
+                },
+            )
+            .map_err(Into::into)?;
+        cw20_amount(self, &token, allowance.allowance)
+    }
+
+    /// Transfers the decimal `amount` of cw20 tokens from the sender to `recipient`
+    fn cw20_transfer(
+        &self,
+        token: &str,
+        recipient: &str,
+        amount: &str,
+    ) -> Result<TxResponse<Self>, CwEnvError> {
+        let token = self.resolve_address(token)?;
+        let decimals = cw20_token_info(self, &token)?.decimals;
+        let msg = Cw20ExecuteMsg::Transfer {
+            recipient: self.resolve_address(recipient)?.to_string(),
+            amount: parse_amount(amount, decimals.into())?,
+        };
+        self.execute(&msg, &[], &token).map_err(Into::into)
+    }
+
+    /// Bank balances of `address`, with the decimals of their denom metadata
+    fn bank_balances(&self, address: &str) -> Result<Vec<TokenAmount>, CwEnvError> {
+        let address = self.resolve_address(address)?;
+        let balances = self
+            .bank_querier()
+            .balance(&address, None)
+            .map_err(Into::into)?;
+        Ok(balances
+            .into_iter()
+            .map(|coin| {
+                let (decimals, symbol) = bank_display(self, &coin.denom);
+                TokenAmount {
+                    amount: coin.amount,
+                    decimals,
+                    symbol,
+                }
+            })
+            .collect())
+    }
+
+    /// Sends the decimal `amount` of `denom` from the sender to `recipient`.
+    /// Without metadata for `denom`, `amount` is a number of atomics.
+    fn bank_transfer(
+        &self,
+        recipient: &str,
+        amount: &str,
+        denom: &str,
+    ) -> Result<TxResponse<Self>, CwEnvError> {
+        let (decimals, _) = bank_display(self, denom);
+        let amount = Coin {
+            denom: denom.to_string(),
+            amount: parse_amount(amount, decimals)?,
+        };
+        self.bank_send(&self.resolve_address(recipient)?, &[amount])
+    }
+}
+
+impl<T: CwEnv> TokenHelpers for T {}
+
+fn cw20_token_info<Chain: CwEnv>(
+    chain: &Chain,
+    token: &Addr,
+) -> Result<TokenInfoResponse, CwEnvError> {
+    chain
+        .wasm_querier()
+        .smart_query(token, &Cw20QueryMsg::TokenInfo {})
+        .map_err(Into::into)
+}
+
+fn cw20_amount<Chain: CwEnv>(
+    chain: &Chain,
+    token: &Addr,
+    amount: cosmwasm_std::Uint128,
+) -> Result<TokenAmount, CwEnvError> {
+    let info = cw20_token_info(chain, token)?;
+    Ok(TokenAmount {
+        amount,
+        decimals: info.decimals.into(),
+        symbol: info.symbol,
+    })
+}
+
+/// Decimals and symbol of `denom`, from its metadata.
+/// Denoms without metadata are displayed in atomics.
+fn bank_display<Chain: CwEnv>(chain: &Chain, denom: &str) -> (u32, String) {
+    match chain.bank_querier().denom_metadata(denom) {
+        Ok(metadata) => metadata_display(&metadata, denom),
+        Err(_) => (0, denom.to_string()),
+    }
+}
+
+fn metadata_display(metadata: &DenomMetadata, denom: &str) -> (u32, String) {
+    let decimals = metadata
+        .denom_units
+        .iter()
+        .find(|unit| unit.denom == metadata.display)
+        .map_or(0, |unit| unit.exponent);
+    let symbol = if metadata.symbol.is_empty() {
+        denom.to_string()
+    } else {
+        metadata.symbol.clone()
+    };
+    (decimals, symbol)
+}
@@ -3,12 +3,21 @@
 use std::{
     env,
     num::{ParseFloatError, ParseIntError},
-    str::ParseBoolError,
+    str::{FromStr, ParseBoolError},
 };
 
 use cosmwasm_std::Instantiate2AddressError;
 use thiserror::Error;
 
+/// Suffixes wasmd appends to the error returned by a contract
+const CONTRACT_ERROR_SUFFIXES: &[&str] = &[
+    ": execute wasm contract failed",
+    ": instantiate wasm contract failed",
+    ": migrate wasm contract failed",
+    ": query wasm contract failed",
+];
+const MESSAGE_INDEX_PREFIX: &str = "message index: ";
+
 /// cw-orchestrator error wrapper using thiserror.
 #[derive(Error, Debug)]
 pub enum CwEnvError {
@@ -36,14 +45,33 @@ pub enum CwEnvError {
     NotWasm,
     #[error("Could not find wasm file with name {0} in artifacts:{1} dir")]
     WasmNotFound(String, String),
+    #[error("Checksum of {file} ({actual}) doesn't match the one in checksums.txt ({expected}), re-run the optimizer or remove the stale artifact")]
+    ChecksumMismatch {
+        file: String,
+        expected: String,
+        actual: String,
+    },
     #[error("calling contract with unimplemented action")]
     NotImplemented,
+    #[error("{0} is not supported by this environment")]
+    Unsupported(String),
     #[error(transparent)]
     AnyError(#[from] ::anyhow::Error),
     #[error("Generic Error {0}")]
     StdErr(String),
     #[error("Environment variable not defined {0}")]
     EnvVarNotPresentNamed(String),
+    #[error("Assertion failed: {0}")]
+    AssertionFailed(String),
+    #[error("Condition not met after {0:?}")]
+    WaitTimeout(std::time::Duration),
+    #[error("Scenario {scenario} failed at step {step} ({name}): {source}")]
+    ScenarioStepFailed {
+        scenario: String,
+        step: usize,
+        name: String,
+        source: Box<CwEnvError>,
+    },
 }
 
 impl CwEnvError {
@@ -63,4 +91,63 @@ impl CwEnvError {
             _ => panic!("Unexpected error type"),
         }
     }
+
+    /// Returns the error of type `E` this error was caused by, if any.
+    /// On `Mock`, this is the typed error returned by the contract.
+    pub fn downcast_ref<E>(&self) -> Option<&E>
+    where
+        E: std::fmt::Display + std::fmt::Debug + Send + Sync + 'static,
+    {
+        match self {
+            CwEnvError::AnyError(e) => e.downcast_ref(),
+            _ => None,
+        }
+    }
+
+    /// Whether this error was caused by the contract error `expected`.
+    /// On `Mock` the typed error is compared, on chains its `Display` output is compared to the [`Self::contract_error`] of the failed transaction.
+    /// Allows `assert!(err.is_contract_error(&ContractError::Unauthorized {}))` on every environment.
+    pub fn is_contract_error<E>(&self, expected: &E) -> bool
+    where
+        E: PartialEq + std::fmt::Display + std::fmt::Debug + Send + Sync + 'static,
+    {
+        match self.downcast_ref::<E>() {
+            Some(error) => error == expected,
+            None => self.contract_error() == Some(expected.to_string()),
+        }
+    }
+
+    /// Stringified error returned by the contract, if a transaction or its simulation failed in a contract on chain.
+    /// This is the `Display` output of the contract error, like `Unauthorized` for `ContractError::Unauthorized {}`.
+    /// On `Mock`, use [`Self::downcast_ref`] to get the typed error instead.
+    pub fn contract_error(&self) -> Option<String> {
+        match self {
+            CwEnvError::AnyError(e) => e.chain().find_map(|cause| {
+                Self::contract_error_from_log(&cause.to_string()).map(str::to_string)
+            }),
+            _ => None,
+        }
+    }
+
+    /// The contract error of type `E` a transaction failed with on chain, parsed from its [`Self::contract_error`].
+    /// `E` has to be parsable from its `Display` output; on `Mock`, use [`Self::downcast_ref`] instead.
+    pub fn downcast_contract_error<E: FromStr>(&self) -> Option<E> {
+        self.contract_error()?.parse().ok()
+    }
+
+    /// Extracts the stringified contract error from the log of a transaction or simulation that failed in a contract
+    pub fn contract_error_from_log(log: &str) -> Option<&str> {
+        let end = CONTRACT_ERROR_SUFFIXES
+            .iter()
+            .find_map(|suffix| log.find(suffix))?;
+        let log = &log[..end];
+        // Strip the `failed to execute message; message index: 0: ` prefix
+        match log.rfind(MESSAGE_INDEX_PREFIX) {
+            Some(start) => {
+                let log = &log[start + MESSAGE_INDEX_PREFIX.len()..];
+                Some(log.split_once(": ").map_or(log, |(_, error)| error))
+            }
+            None => Some(log),
+        }
+    }
 }
@@ -0,0 +1,71 @@
+//! ICS-20 denom utilities.
+//!
+//! Tokens transferred over IBC are represented on the receiving chain by `ibc/{hash}`,
+//! where `hash` is the uppercase hex encoded sha256 of the denom trace `{path}/{base_denom}`.
+//! The path is the list of `{port}/{channel}` hops the tokens went through, the last hop first.
+
+use sha2::{Digest, Sha256};
+
+/// Prefix of the denoms of tokens received over IBC
+pub const IBC_DENOM_PREFIX: &str = "ibc/";
+
+/// Path of the tokens after the `(port, channel)` hops, in the order they were received.
+/// ```
+/// use cw_orch_core::ibc::trace_path;
+/// assert_eq!(
+///     trace_path(&[("transfer", "channel-0"), ("transfer", "channel-141")]),
+///     "transfer/channel-141/transfer/channel-0"
+/// );
+/// ```
+pub fn trace_path(hops: &[(&str, &str)]) -> String {
+    hops.iter()
+        .rev()
+        .map(|(port, channel)| format!("{port}/{channel}"))
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// Hash of the denom trace of `base_denom` received through `path`
+pub fn ibc_denom_hash(path: &str, base_denom: &str) -> String {
+    Sha256::digest(format!("{path}/{base_denom}"))
+        .iter()
+        .map(|byte| format!("{byte:02X}"))
+        .collect()
+}
+
+/// Denom of `base_denom` received through `path`. Native denoms (empty path) are returned as is.
+/// ```
+/// use cw_orch_core::ibc::ibc_denom;
+/// assert_eq!(
+///     ibc_denom("transfer/channel-0", "uatom"),
+///     "ibc/27394FB092D2ECCD56123C74F36E4C1F926001CEADA9CA97EA622B25F41E5EB2"
+/// );
+/// ```
+pub fn ibc_denom(path: &str, base_denom: &str) -> String {
+    if path.is_empty() {
+        return base_denom.to_string();
+    }
+    format!("{IBC_DENOM_PREFIX}{}", ibc_denom_hash(path, base_denom))
+}
+
+/// Hash of an `ibc/{hash}` denom, `None` for native denoms
+pub fn ibc_hash(denom: &str) -> Option<&str> {
+    denom.strip_prefix(IBC_DENOM_PREFIX)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn denoms() {
+        let path = trace_path(&[("transfer", "channel-0")]);
+        let denom = ibc_denom(&path, "uatom");
+        assert_eq!(
+            ibc_hash(&denom),
+            Some("27394FB092D2ECCD56123C74F36E4C1F926001CEADA9CA97EA622B25F41E5EB2")
+        );
+        assert_eq!(ibc_denom("", "uatom"), "uatom");
+        assert_eq!(ibc_hash("uatom"), None);
+    }
+}
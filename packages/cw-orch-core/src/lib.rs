@@ -1,3 +1,4 @@
+pub mod amount;
 pub mod contract;
 pub mod env;
 pub use env::CoreEnvVars;
@@ -5,6 +6,7 @@ pub mod environment;
 
 pub mod build;
 mod error;
+pub mod ibc;
 pub mod log;
 pub use error::CwEnvError;
 
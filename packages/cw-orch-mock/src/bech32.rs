@@ -3,7 +3,7 @@ use std::{cell::RefCell, rc::Rc};
 use cosmwasm_std::{Addr, Coin, Uint128};
 use cw_multi_test::{AppBuilder, MockAddressGenerator, MockApiBech32, WasmKeeper};
 use cw_orch_core::{
-    environment::{BankQuerier, BankSetter, DefaultQueriers, StateInterface, TxHandler},
+    environment::{BankQuerier, BankSetter, ChainInfo, DefaultQueriers, StateInterface, TxHandler},
     CwEnvError,
 };
 use cw_utils::NativeBalance;
@@ -25,6 +25,12 @@ impl MockBase<MockApiBech32, MockState> {
 
         chain
     }
+
+    /// Create a mock environment that mimics the given chain.
+    /// Addresses are generated with the chain's bech32 prefix (e.g. `osmo1...`) and blocks use its chain-id.
+    pub fn new_with_chain(chain: &ChainInfo) -> Self {
+        MockBech32::new_with_chain_id(chain.network_info.pub_address_prefix, chain.chain_id)
+    }
 }
 
 impl<S: StateInterface> MockBase<MockApiBech32, S> {
@@ -64,7 +70,14 @@ impl<S: StateInterface> MockBase<MockApiBech32, S> {
         // We create an address internally
         let sender = app.borrow().api().addr_make("sender");
 
-        Self { sender, state, app }
+        Self {
+            sender,
+            state,
+            app,
+            history: Default::default(),
+            calls: Default::default(),
+            proposals: Default::default(),
+        }
     }
 }
 
@@ -115,6 +128,37 @@ impl<S: StateInterface> MockBech32<S> {
             })
     }
 
+    /// Add coins to the balances of many accounts at once, like genesis balances.
+    /// Useful to set up tests with a lot of funded accounts without executing a transaction for each of them.
+    pub fn fund_accounts(
+        &self,
+        funds: &[(&Addr, &[cosmwasm_std::Coin])],
+    ) -> Result<(), CwEnvError> {
+        let mut balances: Vec<(Addr, NativeBalance)> = vec![];
+        for (address, coins) in funds {
+            let addr = (*address).clone();
+            match balances.iter_mut().find(|(a, _)| a == &addr) {
+                Some((_, balance)) => {
+                    *balance = balance.clone() + NativeBalance(coins.to_vec());
+                }
+                None => {
+                    let current = self.query_all_balances(&addr)?;
+                    balances.push((addr, NativeBalance(current) + NativeBalance(coins.to_vec())));
+                }
+            }
+        }
+        self.app
+            .borrow_mut()
+            .init_modules(|router, _, storage| -> Result<(), CwEnvError> {
+                for (addr, balance) in balances {
+                    router
+                        .bank
+                        .init_balance(storage, &addr, balance.into_vec())?;
+                }
+                Ok(())
+            })
+    }
+
     /// Query the (bank) balance of a native token for and address.
     /// Returns the amount of the native token.
     pub fn query_balance(&self, address: &Addr, denom: &str) -> Result<Uint128, CwEnvError> {
@@ -152,7 +196,10 @@ mod test {
     use cosmwasm_std::coins;
 
     use crate::MockBech32;
-    use cw_orch_core::environment::{BankQuerier, DefaultQueriers};
+    use cosmwasm_std::Api;
+    use cw_orch_core::environment::{
+        BankQuerier, ChainInfo, ChainKind, DefaultQueriers, NetworkInfo, QueryHandler, TxHandler,
+    };
     #[test]
     fn addr_make_with_balance() -> anyhow::Result<()> {
         let mock = MockBech32::new("mock");
@@ -165,4 +212,33 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn new_with_chain() -> anyhow::Result<()> {
+        let chain = ChainInfo {
+            kind: ChainKind::Mainnet,
+            chain_id: "osmosis-1",
+            gas_denom: "uosmo",
+            gas_price: 0.025,
+            grpc_urls: &[],
+            network_info: NetworkInfo {
+                chain_name: "osmosis",
+                pub_address_prefix: "osmo",
+                coin_type: 118u32,
+                pub_key_type: None,
+            },
+            lcd_url: None,
+            fcd_url: None,
+        };
+        let mock = MockBech32::new_with_chain(&chain);
+
+        let address = mock.addr_make("sender");
+        assert!(address.as_str().starts_with("osmo1"));
+        assert_eq!(mock.sender_addr(), address);
+        mock.app.borrow().api().addr_validate(address.as_str())?;
+
+        assert_eq!(mock.block_info()?.chain_id, "osmosis-1");
+
+        Ok(())
+    }
 }
@@ -0,0 +1,498 @@
+//! Tree of the contract calls of a [`MockBase`](crate::MockBase) transaction.
+//!
+//! The events of a response only tell in which order the contracts were called.
+//! The contracts uploaded through the mock environment are wrapped so every call to their entry points is recorded,
+//! with the sub-messages it dispatched. The calls are then nested under the call that dispatched them,
+//! so tests of factories and routers can assert on who called whom, and on which replies succeeded.
+//!
+//! Calls to contracts stored directly on the cw-multi-test app aren't recorded, neither are queries and IBC entry points.
+
+use std::{
+    cell::RefCell,
+    collections::VecDeque,
+    fmt::{self, Display},
+    rc::Rc,
+};
+
+use cosmwasm_std::{
+    to_json_binary, Addr, Binary, CosmosMsg, Deps, DepsMut, Empty, Env, IbcBasicResponse,
+    IbcChannelCloseMsg, IbcChannelConnectMsg, IbcChannelOpenMsg, IbcChannelOpenResponse,
+    IbcPacketAckMsg, IbcPacketReceiveMsg, IbcPacketTimeoutMsg, IbcReceiveResponse, MessageInfo,
+    Reply, ReplyOn, Response, WasmMsg,
+};
+use cw_multi_test::Contract;
+use cw_orch_core::environment::ContractEntryPoint;
+
+/// Call of a contract entry point, with the calls made by its sub-messages
+#[derive(Clone, Debug, PartialEq)]
+pub struct ContractCall {
+    pub entry_point: ContractEntryPoint,
+    pub contract: Addr,
+    /// Sender of the message: the transaction sender or the contract that dispatched the sub-message.
+    /// `None` for the entry points without message info: migrate, sudo and reply.
+    pub sender: Option<Addr>,
+    /// Message the entry point was called with, the JSON-encoded [`Reply`] for replies
+    pub msg: Binary,
+    /// Error returned by the entry point
+    pub error: Option<String>,
+    /// Calls made by the sub-messages of the response and the replies to them, in execution order
+    pub children: Vec<ContractCall>,
+}
+
+impl ContractCall {
+    /// Whether the entry point succeeded. The state changes of a successful call can still be reverted by a failing parent.
+    pub fn is_ok(&self) -> bool {
+        self.error.is_none()
+    }
+
+    /// This call and all the nested ones, depth-first in execution order
+    pub fn flatten(&self) -> Vec<&ContractCall> {
+        let mut calls = vec![self];
+        for child in &self.children {
+            calls.extend(child.flatten());
+        }
+        calls
+    }
+
+    /// First call of `entry_point` on `contract` in this tree
+    pub fn find(&self, entry_point: ContractEntryPoint, contract: &Addr) -> Option<&ContractCall> {
+        self.flatten()
+            .into_iter()
+            .find(|call| call.entry_point == entry_point && &call.contract == contract)
+    }
+
+    /// Calls made directly by the sub-messages of this call, without the replies
+    pub fn sub_calls(&self) -> Vec<&ContractCall> {
+        self.children
+            .iter()
+            .filter(|call| call.entry_point != ContractEntryPoint::Reply)
+            .collect()
+    }
+
+    /// Replies received by this call, in execution order
+    pub fn replies(&self) -> Vec<&ContractCall> {
+        self.children
+            .iter()
+            .filter(|call| call.entry_point == ContractEntryPoint::Reply)
+            .collect()
+    }
+
+    fn fmt_indented(&self, f: &mut fmt::Formatter<'_>, depth: usize) -> fmt::Result {
+        write!(
+            f,
+            "{:indent$}{} {}",
+            "",
+            self.entry_point,
+            self.contract,
+            indent = depth * 2
+        )?;
+        if let Some(error) = &self.error {
+            write!(f, " failed: {error}")?;
+        }
+        writeln!(f)?;
+        for child in &self.children {
+            child.fmt_indented(f, depth + 1)?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for ContractCall {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_indented(f, 0)
+    }
+}
+
+/// Wasm sub-message dispatched by a recorded call
+#[derive(Clone, Debug)]
+struct SubCall {
+    entry_point: ContractEntryPoint,
+    /// `None` for instantiations, the address isn't known yet
+    contract: Option<Addr>,
+    reply: bool,
+}
+
+/// Entry point call recorded by a [`RecordingContract`]
+#[derive(Clone, Debug)]
+pub(crate) struct RecordedCall {
+    entry_point: ContractEntryPoint,
+    contract: Addr,
+    sender: Option<Addr>,
+    msg: Binary,
+    result: Result<Vec<SubCall>, String>,
+}
+
+pub(crate) type CallLog = Rc<RefCell<Vec<RecordedCall>>>;
+
+/// Contract recording the calls to its entry points in a shared log
+pub(crate) struct RecordingContract {
+    contract: Box<dyn Contract<Empty, Empty>>,
+    log: CallLog,
+}
+
+impl RecordingContract {
+    pub(crate) fn new(contract: Box<dyn Contract<Empty, Empty>>, log: CallLog) -> Self {
+        Self { contract, log }
+    }
+
+    fn record(
+        &self,
+        entry_point: ContractEntryPoint,
+        env: &Env,
+        sender: Option<&Addr>,
+        msg: Binary,
+        result: &anyhow::Result<Response>,
+    ) {
+        let result = match result {
+            Ok(response) => Ok(response
+                .messages
+                .iter()
+                .filter_map(|sub_msg| {
+                    let (entry_point, contract) = match &sub_msg.msg {
+                        CosmosMsg::Wasm(WasmMsg::Execute { contract_addr, .. }) => {
+                            (ContractEntryPoint::Execute, Some(contract_addr))
+                        }
+                        CosmosMsg::Wasm(WasmMsg::Migrate { contract_addr, .. }) => {
+                            (ContractEntryPoint::Migrate, Some(contract_addr))
+                        }
+                        CosmosMsg::Wasm(
+                            WasmMsg::Instantiate { .. } | WasmMsg::Instantiate2 { .. },
+                        ) => (ContractEntryPoint::Instantiate, None),
+                        _ => return None,
+                    };
+                    Some(SubCall {
+                        entry_point,
+                        contract: contract.map(Addr::unchecked),
+                        reply: sub_msg.reply_on != ReplyOn::Never,
+                    })
+                })
+                .collect()),
+            Err(e) => Err(e.to_string()),
+        };
+        self.log.borrow_mut().push(RecordedCall {
+            entry_point,
+            contract: env.contract.address.clone(),
+            sender: sender.cloned(),
+            msg,
+            result,
+        });
+    }
+}
+
+impl Contract<Empty, Empty> for RecordingContract {
+    fn execute(
+        &self,
+        deps: DepsMut<Empty>,
+        env: Env,
+        info: MessageInfo,
+        msg: Vec<u8>,
+    ) -> anyhow::Result<Response> {
+        let (sender, recorded_env, recorded_msg) = (info.sender.clone(), env.clone(), msg.clone());
+        let result = self.contract.execute(deps, env, info, msg);
+        self.record(
+            ContractEntryPoint::Execute,
+            &recorded_env,
+            Some(&sender),
+            recorded_msg.into(),
+            &result,
+        );
+        result
+    }
+
+    fn instantiate(
+        &self,
+        deps: DepsMut<Empty>,
+        env: Env,
+        info: MessageInfo,
+        msg: Vec<u8>,
+    ) -> anyhow::Result<Response> {
+        let (sender, recorded_env, recorded_msg) = (info.sender.clone(), env.clone(), msg.clone());
+        let result = self.contract.instantiate(deps, env, info, msg);
+        self.record(
+            ContractEntryPoint::Instantiate,
+            &recorded_env,
+            Some(&sender),
+            recorded_msg.into(),
+            &result,
+        );
+        result
+    }
+
+    fn query(&self, deps: Deps<Empty>, env: Env, msg: Vec<u8>) -> anyhow::Result<Binary> {
+        self.contract.query(deps, env, msg)
+    }
+
+    fn sudo(&self, deps: DepsMut<Empty>, env: Env, msg: Vec<u8>) -> anyhow::Result<Response> {
+        let (recorded_env, recorded_msg) = (env.clone(), msg.clone());
+        let result = self.contract.sudo(deps, env, msg);
+        self.record(
+            ContractEntryPoint::Sudo,
+            &recorded_env,
+            None,
+            recorded_msg.into(),
+            &result,
+        );
+        result
+    }
+
+    fn reply(&self, deps: DepsMut<Empty>, env: Env, msg: Reply) -> anyhow::Result<Response> {
+        let (recorded_env, recorded_msg) = (env.clone(), to_json_binary(&msg).unwrap_or_default());
+        let result = self.contract.reply(deps, env, msg);
+        self.record(
+            ContractEntryPoint::Reply,
+            &recorded_env,
+            None,
+            recorded_msg,
+            &result,
+        );
+        result
+    }
+
+    fn migrate(&self, deps: DepsMut<Empty>, env: Env, msg: Vec<u8>) -> anyhow::Result<Response> {
+        let (recorded_env, recorded_msg) = (env.clone(), msg.clone());
+        let result = self.contract.migrate(deps, env, msg);
+        self.record(
+            ContractEntryPoint::Migrate,
+            &recorded_env,
+            None,
+            recorded_msg.into(),
+            &result,
+        );
+        result
+    }
+
+    fn ibc_channel_open(
+        &self,
+        deps: DepsMut<Empty>,
+        env: Env,
+        msg: IbcChannelOpenMsg,
+    ) -> anyhow::Result<IbcChannelOpenResponse> {
+        self.contract.ibc_channel_open(deps, env, msg)
+    }
+
+    fn ibc_channel_connect(
+        &self,
+        deps: DepsMut<Empty>,
+        env: Env,
+        msg: IbcChannelConnectMsg,
+    ) -> anyhow::Result<IbcBasicResponse> {
+        self.contract.ibc_channel_connect(deps, env, msg)
+    }
+
+    fn ibc_channel_close(
+        &self,
+        deps: DepsMut<Empty>,
+        env: Env,
+        msg: IbcChannelCloseMsg,
+    ) -> anyhow::Result<IbcBasicResponse> {
+        self.contract.ibc_channel_close(deps, env, msg)
+    }
+
+    fn ibc_packet_receive(
+        &self,
+        deps: DepsMut<Empty>,
+        env: Env,
+        msg: IbcPacketReceiveMsg,
+    ) -> anyhow::Result<IbcReceiveResponse> {
+        self.contract.ibc_packet_receive(deps, env, msg)
+    }
+
+    fn ibc_packet_acknowledge(
+        &self,
+        deps: DepsMut<Empty>,
+        env: Env,
+        msg: IbcPacketAckMsg,
+    ) -> anyhow::Result<IbcBasicResponse> {
+        self.contract.ibc_packet_acknowledge(deps, env, msg)
+    }
+
+    fn ibc_packet_timeout(
+        &self,
+        deps: DepsMut<Empty>,
+        env: Env,
+        msg: IbcPacketTimeoutMsg,
+    ) -> anyhow::Result<IbcBasicResponse> {
+        self.contract.ibc_packet_timeout(deps, env, msg)
+    }
+}
+
+/// Recorded call whose sub-messages can still be executed
+struct Frame {
+    path: Vec<usize>,
+    contract: Addr,
+    pending: VecDeque<SubCall>,
+    awaited_replies: usize,
+}
+
+/// Nests the calls recorded during a transaction under the call that dispatched them.
+///
+/// Sub-messages are executed depth-first after the entry point returned, so a new call belongs to the innermost call
+/// of its sender that still has a matching sub-message pending, and a reply to the innermost call of its contract awaiting one.
+pub(crate) fn build_call_tree(calls: Vec<RecordedCall>) -> Vec<ContractCall> {
+    let mut roots: Vec<ContractCall> = vec![];
+    let mut stack: Vec<Frame> = vec![];
+
+    for call in calls {
+        let parent = if call.entry_point == ContractEntryPoint::Reply {
+            while stack
+                .last()
+                .is_some_and(|frame| frame.contract != call.contract || frame.awaited_replies == 0)
+            {
+                stack.pop();
+            }
+            stack.last_mut().map(|frame| {
+                frame.awaited_replies -= 1;
+                frame.path.clone()
+            })
+        } else {
+            let mut parent = None;
+            while let Some(frame) = stack.last_mut() {
+                let is_sender = call
+                    .sender
+                    .as_ref()
+                    .map_or(true, |sender| sender == &frame.contract);
+                let position = frame.pending.iter().position(|sub_call| {
+                    sub_call.entry_point == call.entry_point
+                        && sub_call
+                            .contract
+                            .as_ref()
+                            .map_or(true, |contract| contract == &call.contract)
+                });
+                if let (true, Some(position)) = (is_sender, position) {
+                    let sub_call = frame.pending.drain(..=position).last().unwrap();
+                    if sub_call.reply {
+                        frame.awaited_replies += 1;
+                    }
+                    parent = Some(frame.path.clone());
+                    break;
+                }
+                stack.pop();
+            }
+            parent
+        };
+
+        let (error, pending) = match call.result {
+            Ok(sub_calls) => (None, sub_calls),
+            Err(e) => (Some(e), vec![]),
+        };
+        let node = ContractCall {
+            entry_point: call.entry_point,
+            contract: call.contract.clone(),
+            sender: call.sender,
+            msg: call.msg,
+            error,
+            children: vec![],
+        };
+
+        let path = match parent {
+            Some(mut path) => {
+                let siblings = &mut node_at(&mut roots, &path).children;
+                siblings.push(node);
+                path.push(siblings.len() - 1);
+                path
+            }
+            None => {
+                roots.push(node);
+                vec![roots.len() - 1]
+            }
+        };
+        stack.push(Frame {
+            path,
+            contract: call.contract,
+            pending: pending.into(),
+            awaited_replies: 0,
+        });
+    }
+    roots
+}
+
+fn node_at<'a>(roots: &'a mut [ContractCall], path: &[usize]) -> &'a mut ContractCall {
+    let mut node = &mut roots[path[0]];
+    for &index in &path[1..] {
+        node = &mut node.children[index];
+    }
+    node
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn call(
+        entry_point: ContractEntryPoint,
+        contract: &str,
+        sender: Option<&str>,
+        sub_calls: Vec<SubCall>,
+    ) -> RecordedCall {
+        RecordedCall {
+            entry_point,
+            contract: Addr::unchecked(contract),
+            sender: sender.map(Addr::unchecked),
+            msg: Binary::default(),
+            result: Ok(sub_calls),
+        }
+    }
+
+    fn sub_call(entry_point: ContractEntryPoint, contract: Option<&str>, reply: bool) -> SubCall {
+        SubCall {
+            entry_point,
+            contract: contract.map(Addr::unchecked),
+            reply,
+        }
+    }
+
+    #[test]
+    fn nested_calls_and_replies() {
+        use ContractEntryPoint::*;
+        // router executes a swap on pair_a and instantiates a pair, replying to both; pair_a calls the token
+        let calls = vec![
+            call(
+                Execute,
+                "router",
+                Some("user"),
+                vec![
+                    sub_call(Execute, Some("pair_a"), true),
+                    sub_call(Instantiate, None, true),
+                ],
+            ),
+            call(
+                Execute,
+                "pair_a",
+                Some("router"),
+                vec![sub_call(Execute, Some("token"), false)],
+            ),
+            call(Execute, "token", Some("pair_a"), vec![]),
+            call(Reply, "router", None, vec![]),
+            call(Instantiate, "pair_b", Some("router"), vec![]),
+            call(Reply, "router", None, vec![]),
+        ];
+
+        let roots = build_call_tree(calls);
+        assert_eq!(roots.len(), 1);
+        let root = &roots[0];
+        let summary: Vec<_> = root
+            .children
+            .iter()
+            .map(|c| (c.entry_point, c.contract.as_str()))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (Execute, "pair_a"),
+                (Reply, "router"),
+                (Instantiate, "pair_b"),
+                (Reply, "router"),
+            ]
+        );
+        assert_eq!(root.sub_calls().len(), 2);
+        assert_eq!(root.replies().len(), 2);
+        assert_eq!(
+            root.children[0].children[0].contract,
+            Addr::unchecked("token")
+        );
+        assert!(root
+            .find(Execute, &Addr::unchecked("token"))
+            .is_some_and(|c| c.sender == Some(Addr::unchecked("pair_a"))));
+        assert_eq!(root.flatten().len(), 6);
+    }
+}
@@ -2,7 +2,7 @@ use std::{cell::RefCell, fmt::Debug, rc::Rc};
 
 use cosmwasm_std::{
     testing::{MockApi, MockStorage},
-    to_json_binary, Addr, Api, Binary, CosmosMsg, Empty, Event, WasmMsg,
+    to_json_binary, Addr, Api, BankMsg, Binary, CosmosMsg, DenomMetadata, Empty, Event, WasmMsg,
 };
 use cw_multi_test::{
     ibc::IbcSimpleModule, App, AppResponse, BankKeeper, Contract, DistributionKeeper, Executor,
@@ -10,10 +10,17 @@ use cw_multi_test::{
 };
 use serde::Serialize;
 
-use super::state::MockState;
+use super::{
+    call_tree::{CallLog, RecordingContract},
+    history::{MockMsg, TxHistory},
+    queriers::gov::ProposalStatuses,
+    state::MockState,
+};
 use cw_orch_core::{
     contract::interface_traits::Uploadable,
-    environment::{ChainState, IndexResponse, StateInterface, TxHandler},
+    environment::{
+        ChainState, IndexResponse, StateInterface, StorageSnapshot, TxHandler, TxSimulation,
+    },
     CwEnvError,
 };
 
@@ -74,6 +81,12 @@ pub struct MockBase<A: Api = MockApi, S: StateInterface = MockState> {
     pub state: Rc<RefCell<S>>,
     /// Inner mutable cw-multi-test app backend
     pub app: Rc<RefCell<MockApp<A>>>,
+    /// Transactions executed on the app, see [`MockBase::tx_history`]
+    pub(crate) history: Rc<RefCell<TxHistory>>,
+    /// Contract calls of the transaction being executed, recorded by the uploaded contracts
+    pub(crate) calls: CallLog,
+    /// Statuses of the governance proposals, see [`MockBase::set_proposal_status`]
+    pub(crate) proposals: ProposalStatuses,
 }
 
 pub type Mock<S = MockState> = MockBase<MockApi, S>;
@@ -85,6 +98,9 @@ impl<A: Api, S: StateInterface> Clone for MockBase<A, S> {
             sender: self.sender.clone(),
             state: self.state.clone(),
             app: self.app.clone(),
+            history: self.history.clone(),
+            calls: self.calls.clone(),
+            proposals: self.proposals.clone(),
         }
     }
 }
@@ -106,7 +122,49 @@ impl<A: Api, S: StateInterface> MockBase<A, S> {
         contract_id: &str,
         wrapper: Box<dyn Contract<Empty, Empty>>,
     ) -> Result<AppResponse, CwEnvError> {
-        let code_id = self.app.borrow_mut().store_code(wrapper);
+        let code_id = self
+            .app
+            .borrow_mut()
+            .store_code(Box::new(RecordingContract::new(
+                wrapper,
+                self.calls.clone(),
+            )));
+        let resp = self.record_upload(code_id);
+        let code_id = IndexResponse::uploaded_code_id(&resp)?;
+        self.state.borrow_mut().set_code_id(contract_id, code_id);
+        Ok(resp)
+    }
+
+    /// Sets the metadata of `denom`, returned by the bank querier
+    pub fn set_denom_metadata(
+        &self,
+        denom: impl Into<String>,
+        metadata: DenomMetadata,
+    ) -> Result<(), CwEnvError> {
+        self.app
+            .borrow_mut()
+            .init_modules(|router, _, storage| {
+                router
+                    .bank
+                    .set_denom_metadata(storage, denom.into(), metadata)
+            })
+            .map_err(Into::into)
+    }
+
+    /// Runs `action` without recording it in the history and reverts all the changes it made to the app storage
+    fn dry_run<T>(&self, action: impl FnOnce() -> Result<T, CwEnvError>) -> Result<T, CwEnvError> {
+        let snapshot = StorageSnapshot::take(self.app.borrow().storage());
+        let paused = self.history.borrow_mut().pause();
+
+        let result = action();
+
+        self.history.borrow_mut().set_paused(paused);
+        snapshot.restore(self.app.borrow_mut().storage_mut());
+        result
+    }
+
+    /// Records the upload of `code_id`
+    fn record_upload(&self, code_id: u64) -> AppResponse {
         // add contract code_id to events manually
         let mut event = Event::new("store_code");
         event = event.add_attribute("code_id", code_id.to_string());
@@ -114,9 +172,9 @@ impl<A: Api, S: StateInterface> MockBase<A, S> {
             events: vec![event],
             ..Default::default()
         };
-        let code_id = IndexResponse::uploaded_code_id(&resp)?;
-        self.state.borrow_mut().set_code_id(contract_id, code_id);
-        Ok(resp)
+        self.start_tx();
+        self.record_tx(MockMsg::StoreCode { code_id }, &Ok(resp.clone()));
+        resp
     }
 }
 impl<A: Api, S: StateInterface> ChainState for MockBase<A, S> {
@@ -147,15 +205,14 @@ impl<A: Api, S: StateInterface> TxHandler for MockBase<A, S> {
     }
 
     fn upload<T: Uploadable>(&self, _contract: &T) -> Result<Self::Response, CwEnvError> {
-        let code_id = self.app.borrow_mut().store_code(T::wrapper());
-        // add contract code_id to events manually
-        let mut event = Event::new("store_code");
-        event = event.add_attribute("code_id", code_id.to_string());
-        let resp = AppResponse {
-            events: vec![event],
-            ..Default::default()
-        };
-        Ok(resp)
+        let code_id = self
+            .app
+            .borrow_mut()
+            .store_code(Box::new(RecordingContract::new(
+                T::wrapper(),
+                self.calls.clone(),
+            )));
+        Ok(self.record_upload(code_id))
     }
 
     fn execute<E: Serialize + Debug>(
@@ -164,7 +221,9 @@ impl<A: Api, S: StateInterface> TxHandler for MockBase<A, S> {
         coins: &[cosmwasm_std::Coin],
         contract_address: &Addr,
     ) -> Result<Self::Response, CwEnvError> {
-        self.app
+        self.start_tx();
+        let result = self
+            .app
             .borrow_mut()
             .execute_contract(
                 self.sender.clone(),
@@ -172,7 +231,33 @@ impl<A: Api, S: StateInterface> TxHandler for MockBase<A, S> {
                 exec_msg,
                 coins,
             )
-            .map_err(From::from)
+            .map_err(From::from);
+        let msg = WasmMsg::Execute {
+            contract_addr: contract_address.to_string(),
+            msg: to_json_binary(exec_msg)?,
+            funds: coins.to_vec(),
+        };
+        self.record_tx(msg.into(), &result);
+        result
+    }
+
+    fn bank_send(
+        &self,
+        recipient: &Addr,
+        amount: &[cosmwasm_std::Coin],
+    ) -> Result<Self::Response, CwEnvError> {
+        let msg = BankMsg::Send {
+            to_address: recipient.to_string(),
+            amount: amount.to_vec(),
+        };
+        self.start_tx();
+        let result = self
+            .app
+            .borrow_mut()
+            .execute(self.sender.clone(), CosmosMsg::Bank(msg.clone()))
+            .map_err(From::from);
+        self.record_tx(CosmosMsg::Bank(msg), &result);
+        result
     }
 
     fn instantiate<I: Serialize + Debug>(
@@ -190,11 +275,15 @@ impl<A: Api, S: StateInterface> TxHandler for MockBase<A, S> {
             msg: to_json_binary(init_msg)?,
             funds: coins.to_vec(),
         };
-        let app = self
+        self.start_tx();
+        let result = self
             .app
             .borrow_mut()
-            .execute(self.sender.clone(), CosmosMsg::Wasm(msg))?;
+            .execute(self.sender.clone(), CosmosMsg::Wasm(msg.clone()))
+            .map_err(From::from);
+        self.record_tx(CosmosMsg::Wasm(msg), &result);
 
+        let app = result?;
         let resp = AppResponse {
             events: app.events,
             data: app.data,
@@ -220,11 +309,15 @@ impl<A: Api, S: StateInterface> TxHandler for MockBase<A, S> {
             salt,
         };
 
-        let app = self
+        self.start_tx();
+        let result = self
             .app
             .borrow_mut()
-            .execute(self.sender.clone(), CosmosMsg::Wasm(msg))?;
+            .execute(self.sender.clone(), CosmosMsg::Wasm(msg.clone()))
+            .map_err(From::from);
+        self.record_tx(CosmosMsg::Wasm(msg), &result);
 
+        let app = result?;
         let resp = AppResponse {
             events: app.events,
             data: app.data,
@@ -238,7 +331,9 @@ impl<A: Api, S: StateInterface> TxHandler for MockBase<A, S> {
         new_code_id: u64,
         contract_address: &Addr,
     ) -> Result<Self::Response, CwEnvError> {
-        self.app
+        self.start_tx();
+        let result = self
+            .app
             .borrow_mut()
             .migrate_contract(
                 self.sender.clone(),
@@ -246,7 +341,36 @@ impl<A: Api, S: StateInterface> TxHandler for MockBase<A, S> {
                 migrate_msg,
                 new_code_id,
             )
-            .map_err(From::from)
+            .map_err(From::from);
+        let msg = WasmMsg::Migrate {
+            contract_addr: contract_address.to_string(),
+            new_code_id,
+            msg: to_json_binary(migrate_msg)?,
+        };
+        self.record_tx(msg.into(), &result);
+        result
+    }
+
+    fn simulate_execute<E: Serialize + Debug>(
+        &self,
+        exec_msg: &E,
+        coins: &[cosmwasm_std::Coin],
+        contract_address: &Addr,
+    ) -> Result<TxSimulation, CwEnvError> {
+        self.dry_run(|| self.execute(exec_msg, coins, contract_address))?;
+        Ok(TxSimulation::default())
+    }
+
+    fn simulate_instantiate<I: Serialize + Debug>(
+        &self,
+        code_id: u64,
+        init_msg: &I,
+        label: Option<&str>,
+        admin: Option<&Addr>,
+        coins: &[cosmwasm_std::Coin],
+    ) -> Result<TxSimulation, CwEnvError> {
+        self.dry_run(|| self.instantiate(code_id, init_msg, label, admin, coins))?;
+        Ok(TxSimulation::default())
     }
 }
 
@@ -254,12 +378,16 @@ impl<A: Api, S: StateInterface> TxHandler for MockBase<A, S> {
 mod test {
 
     use cosmwasm_std::{
-        coins, to_json_binary, Addr, Binary, Coin, Deps, DepsMut, Env, MessageInfo, Response,
-        StdResult, Uint128,
+        coins, to_json_binary, Addr, Binary, Coin, Deps, DepsMut, Env, MessageInfo, Reply,
+        Response, StdResult, SubMsg, Uint128, WasmMsg,
     };
     use cw_multi_test::ContractWrapper;
-    use cw_orch_core::environment::{BankQuerier, DefaultQueriers, QueryHandler};
+    use cw_orch_core::environment::{
+        AddressBook, BankQuerier, ContractEntryPoint, DefaultQueriers, QueryAssertions,
+        QueryHandler, WasmQuerier,
+    };
     use speculoos::prelude::*;
+    use std::time::Duration;
 
     use crate::core::*;
 
@@ -277,6 +405,7 @@ mod test {
                 .add_attribute("action", "mint")
                 .add_attribute("recipient", recipient)
                 .add_attribute("amount", amount)),
+            cw20::Cw20ExecuteMsg::Burn { .. } => Err(cw20_base::ContractError::Unauthorized {}),
             _ => unimplemented!(),
         }
     }
@@ -293,6 +422,41 @@ mod test {
         }
     }
 
+    fn cw20_init_msg() -> cw20_base::msg::InstantiateMsg {
+        cw20_base::msg::InstantiateMsg {
+            name: String::from("Token"),
+            symbol: String::from("TOK"),
+            decimals: 6u8,
+            initial_balances: vec![],
+            mint: None,
+            marketing: None,
+        }
+    }
+
+    /// Uploads the test cw20 contract, with the `execute` and `query` entry points above
+    fn upload_cw20(chain: &Mock) -> u64 {
+        let contract_source = Box::new(ContractWrapper::new(
+            execute,
+            cw20_base::contract::instantiate,
+            query,
+        ));
+        chain
+            .upload_custom("cw20", contract_source)
+            .unwrap()
+            .uploaded_code_id()
+            .unwrap()
+    }
+
+    /// Uploads and instantiates the test cw20 contract
+    fn cw20(chain: &Mock, admin: Option<&Addr>) -> Addr {
+        let code_id = upload_cw20(chain);
+        chain
+            .instantiate(code_id, &cw20_init_msg(), None, admin, &[])
+            .unwrap()
+            .instantiated_contract_address()
+            .unwrap()
+    }
+
     #[test]
     fn mock() {
         let recipient = BALANCE_ADDR;
@@ -324,14 +488,7 @@ mod test {
             .that(&init_res.events[0].attributes[0].value)
             .is_equal_to(&String::from("1"));
 
-        let init_msg = cw20_base::msg::InstantiateMsg {
-            name: String::from("Token"),
-            symbol: String::from("TOK"),
-            decimals: 6u8,
-            initial_balances: vec![],
-            mint: None,
-            marketing: None,
-        };
+        let init_msg = cw20_init_msg();
         let init_res = chain
             .instantiate(1, &init_msg, None, Some(&Addr::unchecked(sender)), &[])
             .unwrap();
@@ -372,6 +529,285 @@ mod test {
             .is_ok();
     }
 
+    #[test]
+    fn simulation_reverts_changes() {
+        let init_msg = cw20_init_msg();
+        let setup = || {
+            let chain = Mock::new(SENDER);
+            chain
+                .set_balance(SENDER, vec![Coin::new(100, "uosmo")])
+                .unwrap();
+            upload_cw20(&chain);
+            chain
+        };
+
+        let chain = setup();
+        let simulation = chain
+            .simulate_instantiate(1, &init_msg, None, None, &[])
+            .unwrap();
+        asserting("mock doesn't meter gas")
+            .that(&simulation)
+            .is_equal_to(TxSimulation::default());
+
+        // The simulated instantiation didn't take the contract address
+        let contract_address = chain
+            .instantiate(1, &init_msg, None, None, &[])
+            .unwrap()
+            .instantiated_contract_address()
+            .unwrap();
+        let expected_address = setup()
+            .instantiate(1, &init_msg, None, None, &[])
+            .unwrap()
+            .instantiated_contract_address()
+            .unwrap();
+        asserting("contract address is not taken by the simulation")
+            .that(&contract_address)
+            .is_equal_to(&expected_address);
+
+        let mint = cw20::Cw20ExecuteMsg::Mint {
+            recipient: BALANCE_ADDR.to_string(),
+            amount: Uint128::from(100u128),
+        };
+        chain
+            .simulate_execute(&mint, &[Coin::new(50, "uosmo")], &contract_address)
+            .unwrap();
+        asserting("funds sent in the simulation are reverted")
+            .that(&chain.query_balance(SENDER, "uosmo").unwrap().u128())
+            .is_equal_to(100);
+
+        let simulation =
+            chain.simulate_execute(&mint, &[Coin::new(500, "uosmo")], &contract_address);
+        asserting("simulation fails without enough funds")
+            .that(&simulation)
+            .is_err();
+    }
+
+    #[test]
+    fn assertions() {
+        let chain = Mock::new(SENDER);
+        chain
+            .set_balance(BALANCE_ADDR, vec![Coin::new(100, "uosmo")])
+            .unwrap();
+
+        asserting("balance matches")
+            .that(&chain.assert_balance(BALANCE_ADDR, Coin::new(100, "uosmo")))
+            .is_ok();
+        asserting("balance mismatch is reported")
+            .that(&chain.assert_balance(BALANCE_ADDR, Coin::new(10, "uosmo")))
+            .is_err();
+        asserting("missing denom counts as zero")
+            .that(&chain.assert_balance(BALANCE_ADDR, Coin::new(0, "ujuno")))
+            .is_ok();
+
+        let admin = Addr::unchecked(SENDER);
+        let contract_address = cw20(&chain, Some(&admin));
+
+        asserting("admin matches")
+            .that(&chain.assert_contract_admin(&contract_address, Some(&admin)))
+            .is_ok();
+        asserting("admin mismatch is reported")
+            .that(&chain.assert_contract_admin(&contract_address, None))
+            .is_err();
+        asserting("code id matches")
+            .that(&chain.assert_code_id(&contract_address, 1))
+            .is_ok();
+        asserting("code id mismatch is reported")
+            .that(&chain.assert_code_id(&contract_address, 2))
+            .is_err();
+
+        let start = chain.block_info().unwrap().height;
+        chain
+            .wait_for(
+                |chain| Ok(chain.block_info()?.height >= start + 3),
+                Duration::from_secs(60),
+            )
+            .unwrap();
+        asserting("wait_for produced blocks until the condition held")
+            .that(&chain.block_info().unwrap().height)
+            .is_equal_to(start + 3);
+
+        let timeout = chain.wait_for(|_| Ok(false), Duration::from_secs(30));
+        asserting("wait_for times out in block time")
+            .that(&matches!(timeout, Err(CwEnvError::WaitTimeout(_))))
+            .is_true();
+    }
+
+    #[test]
+    fn typed_contract_error() {
+        let chain = Mock::new(SENDER);
+        let contract_address = cw20(&chain, None);
+
+        let err = chain
+            .execute(
+                &cw20::Cw20ExecuteMsg::Burn {
+                    amount: Uint128::one(),
+                },
+                &[],
+                &contract_address,
+            )
+            .unwrap_err();
+
+        asserting("error is the contract error")
+            .that(&err.is_contract_error(&cw20_base::ContractError::Unauthorized {}))
+            .is_true();
+        asserting("error is not another contract error")
+            .that(&err.is_contract_error(&cw20_base::ContractError::NoAllowance {}))
+            .is_false();
+        asserting("error can be borrowed as the contract error")
+            .that(&err.downcast_ref::<cw20_base::ContractError>())
+            .is_equal_to(Some(&cw20_base::ContractError::Unauthorized {}));
+    }
+
+    #[test]
+    fn tx_history() {
+        let chain = Mock::new(SENDER);
+        let contract_address = cw20(&chain, None);
+
+        let mint = cw20::Cw20ExecuteMsg::Mint {
+            recipient: BALANCE_ADDR.to_string(),
+            amount: Uint128::new(100),
+        };
+        chain.execute(&mint, &[], &contract_address).unwrap();
+        chain
+            .simulate_execute(&mint, &[], &contract_address)
+            .unwrap();
+        let burn = cw20::Cw20ExecuteMsg::Burn {
+            amount: Uint128::one(),
+        };
+        chain.execute(&burn, &[], &contract_address).unwrap_err();
+
+        let history = chain.tx_history();
+        asserting("upload, instantiate, mint and failed burn are recorded, not the simulation")
+            .that(&history.len())
+            .is_equal_to(4);
+        asserting("upload is recorded")
+            .that(&history[0].msg)
+            .is_equal_to(MockMsg::StoreCode { code_id: 1 });
+        asserting("sender is recorded")
+            .that(&history[2].sender)
+            .is_equal_to(Addr::unchecked(SENDER));
+        asserting("mint succeeded")
+            .that(&history[2].is_ok())
+            .is_true();
+        asserting("burn failed").that(&history[3].error).is_some();
+        asserting("gas is estimated")
+            .that(&history[2].gas_estimate)
+            .is_greater_than(0);
+
+        let events = chain.events_by_contract(&contract_address);
+        asserting("mint event is found by contract")
+            .that(&events.iter().any(|event| {
+                event.ty == "wasm"
+                    && event
+                        .attributes
+                        .iter()
+                        .any(|attr| attr.key == "action" && attr.value == "mint")
+            }))
+            .is_true();
+
+        chain.set_tx_history_limit(2);
+        asserting("only the last transactions are kept")
+            .that(&chain.tx_history())
+            .is_equal_to(history[2..].to_vec());
+        chain.execute(&mint, &[], &contract_address).unwrap();
+        asserting("the oldest transaction is dropped")
+            .that(&chain.tx_history()[0])
+            .is_equal_to(&history[3]);
+
+        chain.clear_tx_history();
+        asserting("history is cleared")
+            .that(&chain.tx_history())
+            .is_empty();
+
+        chain.set_tx_history_limit(0);
+        chain.execute(&mint, &[], &contract_address).unwrap();
+        asserting("history is disabled")
+            .that(&chain.tx_history())
+            .is_empty();
+    }
+
+    fn router_execute(
+        _deps: DepsMut,
+        _env: Env,
+        _info: MessageInfo,
+        msg: cw20::Cw20ExecuteMsg,
+    ) -> Result<Response, cw20_base::ContractError> {
+        match msg {
+            cw20::Cw20ExecuteMsg::Send {
+                contract, amount, ..
+            } => Ok(Response::default().add_submessage(SubMsg::reply_always(
+                WasmMsg::Execute {
+                    contract_addr: contract,
+                    msg: to_json_binary(&cw20::Cw20ExecuteMsg::Mint {
+                        recipient: BALANCE_ADDR.to_string(),
+                        amount,
+                    })?,
+                    funds: vec![],
+                },
+                1,
+            ))),
+            _ => unimplemented!(),
+        }
+    }
+
+    fn router_reply(_deps: DepsMut, _env: Env, _msg: Reply) -> StdResult<Response> {
+        Ok(Response::default())
+    }
+
+    #[test]
+    fn call_tree() {
+        let chain = Mock::new(SENDER);
+        upload_cw20(&chain);
+        chain
+            .upload_custom(
+                "router",
+                Box::new(
+                    ContractWrapper::new(router_execute, cw20_base::contract::instantiate, query)
+                        .with_reply(router_reply),
+                ),
+            )
+            .unwrap();
+        let init_msg = cw20_init_msg();
+        let token = chain
+            .instantiate(1, &init_msg, None, None, &[])
+            .unwrap()
+            .instantiated_contract_address()
+            .unwrap();
+        let router = chain
+            .instantiate(2, &init_msg, None, None, &[])
+            .unwrap()
+            .instantiated_contract_address()
+            .unwrap();
+
+        let send = cw20::Cw20ExecuteMsg::Send {
+            contract: token.to_string(),
+            amount: Uint128::new(10),
+            msg: Binary::default(),
+        };
+        let resp = chain.execute(&send, &[], &router).unwrap();
+        asserting("events list the calls in execution order")
+            .that(&resp.contract_calls().len())
+            .is_equal_to(3);
+
+        let tx = chain.last_tx().unwrap();
+        let root = tx.root_call().unwrap();
+        asserting("router is called by the sender")
+            .that(&(root.entry_point, &root.sender))
+            .is_equal_to((ContractEntryPoint::Execute, &Some(Addr::unchecked(SENDER))));
+        asserting("router called the token")
+            .that(&root.sub_calls()[0].sender)
+            .is_equal_to(Some(router.clone()));
+        asserting("token is the only contract called by the router")
+            .that(&root.sub_calls().len())
+            .is_equal_to(1);
+        asserting("router received a successful reply")
+            .that(&root.replies()[0].is_ok())
+            .is_true();
+        asserting("mint is found in the tree")
+            .that(&root.find(ContractEntryPoint::Execute, &token))
+            .is_some();
+    }
+
     #[test]
     fn custom_mock_env() {
         let mock_state = MockState::new();
@@ -438,6 +874,29 @@ mod test {
             .contains_all_of(&[&Coin::new(amount, denom_1), &Coin::new(amount, denom_2)])
     }
 
+    #[test]
+    fn fund_accounts() {
+        let chain = Mock::new(SENDER);
+        chain
+            .set_balance(BALANCE_ADDR, coins(100, "uosmo"))
+            .unwrap();
+
+        chain
+            .fund_accounts(&[
+                (BALANCE_ADDR, &[Coin::new(50, "uosmo")]),
+                (SENDER, &[Coin::new(10, "ujuno")]),
+                (BALANCE_ADDR, &[Coin::new(5, "ujuno")]),
+            ])
+            .unwrap();
+
+        asserting("existing balance is topped up")
+            .that(&chain.query_all_balances(BALANCE_ADDR).unwrap())
+            .is_equal_to(vec![Coin::new(5, "ujuno"), Coin::new(150, "uosmo")]);
+        asserting("new account is funded")
+            .that(&chain.query_balance(SENDER, "ujuno").unwrap().u128())
+            .is_equal_to(10);
+    }
+
     #[test]
     fn bank_querier_works() -> Result<(), CwEnvError> {
         let denom = "urandom";
@@ -458,4 +917,137 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn bank_metadata_and_supply() -> Result<(), CwEnvError> {
+        let chain = Mock::new(SENDER);
+        chain.set_balance(SENDER, vec![Coin::new(10, "ujuno"), Coin::new(5, "uosmo")])?;
+        chain.set_balance(BALANCE_ADDR, coins(20, "ujuno"))?;
+        let metadata = cosmwasm_std::DenomMetadata {
+            base: "ujuno".to_string(),
+            display: "juno".to_string(),
+            symbol: "JUNO".to_string(),
+            denom_units: vec![cosmwasm_std::DenomUnit {
+                denom: "juno".to_string(),
+                exponent: 6,
+                aliases: vec![],
+            }],
+            ..Default::default()
+        };
+        chain.set_denom_metadata("ujuno", metadata.clone())?;
+
+        let bank = chain.bank_querier();
+        asserting("total supply sums all the balances")
+            .that(&bank.total_supply()?)
+            .is_equal_to(vec![Coin::new(30, "ujuno"), Coin::new(5, "uosmo")]);
+        asserting("denom metadata is returned")
+            .that(&bank.denom_metadata("ujuno")?)
+            .is_equal_to(&metadata);
+        asserting("all the denoms metadata are returned")
+            .that(&bank.denoms_metadata()?)
+            .is_equal_to(vec![metadata]);
+        Ok(())
+    }
+
+    #[test]
+    fn address_book() -> Result<(), CwEnvError> {
+        let chain = Mock::new(SENDER);
+        let path =
+            std::env::temp_dir().join(format!("cw-orch-address-book-{}.json", std::process::id()));
+        let factory = chain.addr_make("factory");
+
+        let mut address_book = AddressBook::with_path(&chain, &path);
+        address_book.insert("dex", "factory", &factory)?;
+        asserting("address is saved in the state")
+            .that(&chain.state().get_address("dex:factory")?)
+            .is_equal_to(&factory);
+
+        // Another environment on the same chain reads it from the file
+        let other_chain = Mock::new(SENDER);
+        let mut other_book = AddressBook::with_path(&other_chain, &path);
+        asserting("address is shared through the file")
+            .that(&other_book.addr_of("dex", "factory")?)
+            .is_equal_to(&factory);
+
+        other_book.remove("dex", "factory")?;
+        assert!(address_book.addr_of("dex", "router").is_err());
+        assert!(other_book.addr_of("dex", "factory").is_err());
+
+        std::fs::remove_file(path)?;
+        Ok(())
+    }
+
+    #[test]
+    fn query_many() -> Result<(), CwEnvError> {
+        let chain = Mock::new(SENDER);
+        let contract_address = cw20(&chain, None);
+
+        let balance = |address: &str| cw20_base::msg::QueryMsg::Balance {
+            address: address.to_string(),
+        };
+        let results: Vec<Result<Response, CwEnvError>> = chain.wasm_querier().query_many(vec![
+            (contract_address.to_string(), balance("first")),
+            ("unknown".to_string(), balance("second")),
+            (contract_address.to_string(), balance("third")),
+        ]);
+        asserting("a result per query")
+            .that(&results.len())
+            .is_equal_to(3);
+        asserting("results are in order")
+            .that(&results[2].as_ref().unwrap().attributes[0].value)
+            .is_equal_to("third".to_string());
+        asserting("failed query doesn't fail the others")
+            .that(&results[1].is_err())
+            .is_true();
+        Ok(())
+    }
+
+    #[test]
+    fn export_genesis() -> Result<(), CwEnvError> {
+        let chain = Mock::new(SENDER);
+        chain.set_balance(BALANCE_ADDR, coins(100, "uosmo"))?;
+        upload_cw20(&chain);
+        let init_msg = cw20_init_msg();
+        let contract_address = chain
+            .instantiate(1, &init_msg, Some("token"), Some(&chain.sender), &[])?
+            .instantiated_contract_address()?;
+
+        let mut genesis = chain.export_genesis()?;
+        asserting("balance is exported")
+            .that(&genesis.balances.get(&Addr::unchecked(BALANCE_ADDR)))
+            .is_equal_to(Some(&coins(100, "uosmo")));
+        asserting("contract is exported")
+            .that(&genesis.contracts.len())
+            .is_equal_to(1);
+        let contract = &genesis.contracts[0];
+        asserting("contract info is exported")
+            .that(&(&contract.address, contract.code_id, contract.label.as_str()))
+            .is_equal_to((&contract_address, 1, "token"));
+        asserting("contract state is exported")
+            .that(
+                &contract
+                    .state
+                    .iter()
+                    .any(|(key, _)| key.as_slice() == b"token_info"),
+            )
+            .is_true();
+        asserting("instantiate message is exported")
+            .that(&contract.init_msg)
+            .is_equal_to(Some(to_json_binary(&init_msg)?));
+
+        asserting("wasm code is missing")
+            .that(&genesis.missing_wasm())
+            .is_equal_to(vec![1]);
+        genesis.set_wasm(1, b"\0asm".to_vec())?;
+        assert!(genesis.set_wasm(2, vec![]).is_err());
+
+        let app_state = genesis.app_state();
+        asserting("code is exported with its wasm")
+            .that(&app_state["wasm"]["codes"][0]["code_bytes"])
+            .is_equal_to(&serde_json::json!(Binary::from(b"\0asm")));
+        asserting("contract is exported in the wasm genesis")
+            .that(&app_state["wasm"]["contracts"][0]["contract_address"])
+            .is_equal_to(&serde_json::json!(contract_address));
+        Ok(())
+    }
 }
@@ -0,0 +1,376 @@
+//! Contracts using chain-specific bindings, like `NeutronMsg` or `InjectiveMsgWrapper`, in the mock environment.
+//!
+//! The mock app doesn't know the custom messages and queries of any chain.
+//! A [`CustomContract`] wraps a contract built against custom bindings so it can be uploaded to the mock app,
+//! translating the custom messages it returns into standard ones, or dropping them.
+//!
+//! ```
+//! # use cosmwasm_std::{BankMsg, CosmosMsg, CustomMsg, DepsMut, Env, MessageInfo, Response, StdResult, coins};
+//! use cw_orch_mock::{custom::CustomContract, cw_multi_test::ContractWrapper, Mock};
+//!
+//! #[cosmwasm_schema::cw_serde]
+//! pub enum TokenFactoryMsg {
+//!     Mint { amount: u128, recipient: String },
+//! }
+//! impl CustomMsg for TokenFactoryMsg {}
+//!
+//! fn execute(_: DepsMut, _: Env, info: MessageInfo, _: Empty) -> StdResult<Response<TokenFactoryMsg>> {
+//!     Ok(Response::new().add_message(TokenFactoryMsg::Mint { amount: 100, recipient: info.sender.to_string() }))
+//! }
+//! # use cosmwasm_std::{Binary, Deps, Empty};
+//! # fn instantiate(_: DepsMut, _: Env, _: MessageInfo, _: Empty) -> StdResult<Response<TokenFactoryMsg>> { Ok(Response::new()) }
+//! # fn query(_: Deps, _: Env, _: Empty) -> StdResult<Binary> { unimplemented!() }
+//!
+//! let chain: Mock = Mock::new("sender");
+//! // Mints are simulated by bank sends from the contract, which needs to be funded
+//! let contract = CustomContract::new(
+//!     Box::new(ContractWrapper::new(execute, instantiate, query)),
+//!     |msg| match msg {
+//!         TokenFactoryMsg::Mint { amount, recipient } => Ok(Some(CosmosMsg::Bank(BankMsg::Send {
+//!             to_address: recipient,
+//!             amount: coins(amount, "factory/token"),
+//!         }))),
+//!     },
+//! );
+//! chain.upload_custom("token_factory", Box::new(contract)).unwrap();
+//! ```
+//!
+//! Custom queries aren't supported, they fail like on a chain without the bindings.
+
+use cosmwasm_std::{
+    from_json, to_json_vec, Binary, CosmosMsg, CustomMsg, CustomQuery, Deps, DepsMut, Empty, Env,
+    IbcBasicResponse, IbcChannelCloseMsg, IbcChannelConnectMsg, IbcChannelOpenMsg,
+    IbcChannelOpenResponse, IbcPacketAckMsg, IbcPacketReceiveMsg, IbcPacketTimeoutMsg,
+    IbcReceiveResponse, MessageInfo, QuerierWrapper, Reply, Response, SubMsg,
+};
+use cw_multi_test::Contract;
+
+/// Translates a custom message into a standard one, `None` drops the message
+pub type CustomMsgHandler<C> = Box<dyn Fn(C) -> anyhow::Result<Option<CosmosMsg>>>;
+
+/// Contract built against the custom bindings `C` and `Q`, uploadable to the mock environment.
+/// See the [module documentation](self).
+pub struct CustomContract<C: CustomMsg, Q: CustomQuery = Empty> {
+    contract: Box<dyn Contract<C, Q>>,
+    handler: CustomMsgHandler<C>,
+}
+
+impl<C: CustomMsg + 'static, Q: CustomQuery + 'static> CustomContract<C, Q> {
+    /// Wraps `contract`, translating its custom messages with `handler`
+    pub fn new(
+        contract: Box<dyn Contract<C, Q>>,
+        handler: impl Fn(C) -> anyhow::Result<Option<CosmosMsg>> + 'static,
+    ) -> Self {
+        Self {
+            contract,
+            handler: Box::new(handler),
+        }
+    }
+
+    /// Wraps `contract`, dropping its custom messages.
+    /// Sub-messages that are dropped don't trigger a reply.
+    pub fn ignoring_custom_msgs(contract: Box<dyn Contract<C, Q>>) -> Self {
+        Self::new(contract, |_| Ok(None))
+    }
+
+    fn translate_msg(&self, msg: CosmosMsg<C>) -> anyhow::Result<Option<CosmosMsg>> {
+        match msg {
+            CosmosMsg::Custom(custom) => (self.handler)(custom),
+            // The other variants don't depend on the custom message type
+            msg => Ok(Some(from_json(to_json_vec(&msg)?)?)),
+        }
+    }
+
+    fn translate_sub_msgs(&self, sub_msgs: Vec<SubMsg<C>>) -> anyhow::Result<Vec<SubMsg>> {
+        let mut translated = vec![];
+        for sub_msg in sub_msgs {
+            if let Some(msg) = self.translate_msg(sub_msg.msg)? {
+                translated.push(SubMsg {
+                    id: sub_msg.id,
+                    msg,
+                    gas_limit: sub_msg.gas_limit,
+                    reply_on: sub_msg.reply_on,
+                });
+            }
+        }
+        Ok(translated)
+    }
+
+    fn translate_response(&self, response: Response<C>) -> anyhow::Result<Response> {
+        let mut translated = Response::new()
+            .add_submessages(self.translate_sub_msgs(response.messages)?)
+            .add_attributes(response.attributes)
+            .add_events(response.events);
+        if let Some(data) = response.data {
+            translated = translated.set_data(data);
+        }
+        Ok(translated)
+    }
+
+    fn translate_ibc_response(
+        &self,
+        response: IbcBasicResponse<C>,
+    ) -> anyhow::Result<IbcBasicResponse> {
+        Ok(IbcBasicResponse::new()
+            .add_submessages(self.translate_sub_msgs(response.messages)?)
+            .add_attributes(response.attributes)
+            .add_events(response.events))
+    }
+}
+
+/// Dependencies of the wrapped contract, sending its custom queries to the mock app
+fn custom_deps<'a, Q: CustomQuery>(deps: &'a mut DepsMut<Empty>) -> DepsMut<'a, Q> {
+    DepsMut {
+        storage: deps.storage,
+        api: deps.api,
+        querier: QuerierWrapper::new(&*deps.querier),
+    }
+}
+
+impl<C: CustomMsg + 'static, Q: CustomQuery + 'static> Contract<Empty, Empty>
+    for CustomContract<C, Q>
+{
+    fn execute(
+        &self,
+        mut deps: DepsMut<Empty>,
+        env: Env,
+        info: MessageInfo,
+        msg: Vec<u8>,
+    ) -> anyhow::Result<Response> {
+        let response = self
+            .contract
+            .execute(custom_deps(&mut deps), env, info, msg)?;
+        self.translate_response(response)
+    }
+
+    fn instantiate(
+        &self,
+        mut deps: DepsMut<Empty>,
+        env: Env,
+        info: MessageInfo,
+        msg: Vec<u8>,
+    ) -> anyhow::Result<Response> {
+        let response = self
+            .contract
+            .instantiate(custom_deps(&mut deps), env, info, msg)?;
+        self.translate_response(response)
+    }
+
+    fn query(&self, deps: Deps<Empty>, env: Env, msg: Vec<u8>) -> anyhow::Result<Binary> {
+        let custom_deps = Deps {
+            storage: deps.storage,
+            api: deps.api,
+            querier: QuerierWrapper::new(&*deps.querier),
+        };
+        self.contract.query(custom_deps, env, msg)
+    }
+
+    fn sudo(&self, mut deps: DepsMut<Empty>, env: Env, msg: Vec<u8>) -> anyhow::Result<Response> {
+        let response = self.contract.sudo(custom_deps(&mut deps), env, msg)?;
+        self.translate_response(response)
+    }
+
+    fn reply(&self, mut deps: DepsMut<Empty>, env: Env, msg: Reply) -> anyhow::Result<Response> {
+        let response = self.contract.reply(custom_deps(&mut deps), env, msg)?;
+        self.translate_response(response)
+    }
+
+    fn migrate(
+        &self,
+        mut deps: DepsMut<Empty>,
+        env: Env,
+        msg: Vec<u8>,
+    ) -> anyhow::Result<Response> {
+        let response = self.contract.migrate(custom_deps(&mut deps), env, msg)?;
+        self.translate_response(response)
+    }
+
+    fn ibc_channel_open(
+        &self,
+        mut deps: DepsMut<Empty>,
+        env: Env,
+        msg: IbcChannelOpenMsg,
+    ) -> anyhow::Result<IbcChannelOpenResponse> {
+        self.contract
+            .ibc_channel_open(custom_deps(&mut deps), env, msg)
+    }
+
+    fn ibc_channel_connect(
+        &self,
+        mut deps: DepsMut<Empty>,
+        env: Env,
+        msg: IbcChannelConnectMsg,
+    ) -> anyhow::Result<IbcBasicResponse> {
+        let response = self
+            .contract
+            .ibc_channel_connect(custom_deps(&mut deps), env, msg)?;
+        self.translate_ibc_response(response)
+    }
+
+    fn ibc_channel_close(
+        &self,
+        mut deps: DepsMut<Empty>,
+        env: Env,
+        msg: IbcChannelCloseMsg,
+    ) -> anyhow::Result<IbcBasicResponse> {
+        let response = self
+            .contract
+            .ibc_channel_close(custom_deps(&mut deps), env, msg)?;
+        self.translate_ibc_response(response)
+    }
+
+    fn ibc_packet_receive(
+        &self,
+        mut deps: DepsMut<Empty>,
+        env: Env,
+        msg: IbcPacketReceiveMsg,
+    ) -> anyhow::Result<IbcReceiveResponse> {
+        let response = self
+            .contract
+            .ibc_packet_receive(custom_deps(&mut deps), env, msg)?;
+        Ok(IbcReceiveResponse::new()
+            .set_ack(response.acknowledgement)
+            .add_submessages(self.translate_sub_msgs(response.messages)?)
+            .add_attributes(response.attributes)
+            .add_events(response.events))
+    }
+
+    fn ibc_packet_acknowledge(
+        &self,
+        mut deps: DepsMut<Empty>,
+        env: Env,
+        msg: IbcPacketAckMsg,
+    ) -> anyhow::Result<IbcBasicResponse> {
+        let response = self
+            .contract
+            .ibc_packet_acknowledge(custom_deps(&mut deps), env, msg)?;
+        self.translate_ibc_response(response)
+    }
+
+    fn ibc_packet_timeout(
+        &self,
+        mut deps: DepsMut<Empty>,
+        env: Env,
+        msg: IbcPacketTimeoutMsg,
+    ) -> anyhow::Result<IbcBasicResponse> {
+        let response = self
+            .contract
+            .ibc_packet_timeout(custom_deps(&mut deps), env, msg)?;
+        self.translate_ibc_response(response)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use cosmwasm_std::{
+        coins, BankMsg, Binary, CosmosMsg, CustomMsg, Deps, DepsMut, Empty, Env, MessageInfo,
+        Response, StdResult, Uint128,
+    };
+    use cw_multi_test::ContractWrapper;
+    use cw_orch_core::environment::{BankQuerier, DefaultQueriers, IndexResponse, TxHandler};
+
+    use super::CustomContract;
+    use crate::Mock;
+
+    const DENOM: &str = "factory/token";
+
+    #[cosmwasm_schema::cw_serde]
+    enum TokenFactoryMsg {
+        Mint { amount: u128, recipient: String },
+    }
+
+    impl CustomMsg for TokenFactoryMsg {}
+
+    fn execute(
+        _deps: DepsMut,
+        _env: Env,
+        info: MessageInfo,
+        _msg: Empty,
+    ) -> StdResult<Response<TokenFactoryMsg>> {
+        Ok(Response::new()
+            .add_message(TokenFactoryMsg::Mint {
+                amount: 100,
+                recipient: info.sender.to_string(),
+            })
+            .add_attribute("action", "mint"))
+    }
+
+    fn instantiate(
+        _deps: DepsMut,
+        _env: Env,
+        _info: MessageInfo,
+        _msg: Empty,
+    ) -> StdResult<Response<TokenFactoryMsg>> {
+        Ok(Response::new())
+    }
+
+    fn query(_deps: Deps, _env: Env, _msg: Empty) -> StdResult<Binary> {
+        unimplemented!()
+    }
+
+    fn mock() -> Mock {
+        let mut chain = Mock::new("sender");
+        let sender = chain.addr_make("sender");
+        chain.set_sender(sender);
+        chain
+    }
+
+    fn token_factory(
+        chain: &Mock,
+        contract: CustomContract<TokenFactoryMsg>,
+    ) -> cosmwasm_std::Addr {
+        chain
+            .upload_custom("token_factory", Box::new(contract))
+            .unwrap();
+        let address = chain
+            .instantiate(1, &Empty {}, None, None, &[])
+            .unwrap()
+            .instantiated_contract_address()
+            .unwrap();
+        chain.set_balance(&address, coins(1_000, DENOM)).unwrap();
+        address
+    }
+
+    #[test]
+    fn translates_custom_msgs() {
+        let chain = mock();
+        let contract = CustomContract::new(
+            Box::new(ContractWrapper::new(execute, instantiate, query)),
+            |msg| match msg {
+                TokenFactoryMsg::Mint { amount, recipient } => {
+                    Ok(Some(CosmosMsg::Bank(BankMsg::Send {
+                        to_address: recipient,
+                        amount: coins(amount, DENOM),
+                    })))
+                }
+            },
+        );
+        let address = token_factory(&chain, contract);
+
+        chain.execute(&Empty {}, &[], &address).unwrap();
+        let balance = chain
+            .bank_querier()
+            .balance(chain.sender_addr(), Some(DENOM.to_string()))
+            .unwrap();
+        assert_eq!(balance[0].amount, Uint128::new(100));
+    }
+
+    #[test]
+    fn ignores_custom_msgs() {
+        let chain = mock();
+        let contract = CustomContract::ignoring_custom_msgs(Box::new(ContractWrapper::new(
+            execute,
+            instantiate,
+            query,
+        )));
+        let address = token_factory(&chain, contract);
+
+        let resp = chain.execute(&Empty {}, &[], &address).unwrap();
+        assert!(resp.event_attr_value("wasm", "action").is_ok());
+        let balance = chain
+            .bank_querier()
+            .balance(chain.sender_addr(), Some(DENOM.to_string()))
+            .unwrap();
+        assert!(balance.iter().all(|coin| coin.amount.is_zero()));
+    }
+}
@@ -0,0 +1,287 @@
+//! Export of the state of a [`MockBase`] as a wasmd genesis, see [`MockBase::export_genesis`].
+//!
+//! cw-multi-test doesn't expose the accounts and contracts it holds, they are read from its storage:
+//! - balances under the `balances` map of the `bank` namespace,
+//! - contracts under the `contracts` map of the `wasm` namespace,
+//! - contract storage under the `contract_data/<address>` namespace inside the `wasm` namespace.
+
+use std::collections::BTreeMap;
+
+use cosmwasm_std::{Addr, Api, Binary, Coin, HexBinary, Order, Storage};
+use cw_orch_core::{contract::WasmPath, environment::StateInterface, CwEnvError};
+use serde::Deserialize;
+use serde_json::{json, Value};
+use sha2::{Digest, Sha256};
+
+use crate::{call_tree::ContractCall, MockBase};
+use cw_orch_core::environment::ContractEntryPoint;
+
+const NAMESPACE_BANK: &[u8] = b"bank";
+const BALANCES: &[u8] = b"balances";
+const NAMESPACE_WASM: &[u8] = b"wasm";
+const CONTRACTS: &[u8] = b"contracts";
+const CONTRACT_DATA: &[u8] = b"contract_data/";
+
+/// wasmd key of the last code id sequence
+const SEQUENCE_LAST_CODE_ID: &[u8] = b"\x04lastCodeId";
+/// wasmd key of the last contract instance sequence
+const SEQUENCE_LAST_CONTRACT_ID: &[u8] = b"\x04lastContractId";
+
+/// Code stored on a [`MockBase`]
+#[derive(Clone, Debug, PartialEq)]
+pub struct GenesisCode {
+    pub code_id: u64,
+    pub creator: String,
+    /// Checksum reported by cw-multi-test, replaced by the one of the wasm code when it is set
+    pub checksum: HexBinary,
+    /// Wasm code, cw-multi-test only knows the Rust implementation of the contracts
+    pub wasm: Option<Binary>,
+}
+
+/// Contract instantiated on a [`MockBase`]
+#[derive(Clone, Debug, PartialEq)]
+pub struct GenesisContract {
+    pub address: Addr,
+    pub code_id: u64,
+    pub creator: Addr,
+    pub admin: Option<Addr>,
+    pub label: String,
+    /// Height of the block the contract was instantiated in
+    pub created: u64,
+    /// Instantiate message, if the instantiation was recorded in the transaction history
+    pub init_msg: Option<Binary>,
+    /// Raw storage of the contract
+    pub state: Vec<(Binary, Binary)>,
+}
+
+/// Bank balances, codes and contracts of a [`MockBase`], as returned by [`MockBase::export_genesis`]
+#[derive(Clone, Debug, PartialEq)]
+pub struct MockGenesis {
+    pub balances: BTreeMap<Addr, Vec<Coin>>,
+    pub codes: Vec<GenesisCode>,
+    pub contracts: Vec<GenesisContract>,
+}
+
+/// Contract metadata as stored by cw-multi-test
+#[derive(Deserialize)]
+struct ContractData {
+    code_id: u64,
+    creator: Addr,
+    admin: Option<Addr>,
+    label: String,
+    created: u64,
+}
+
+impl MockGenesis {
+    /// Sets the wasm code of `code_id`, needed for every code before booting a chain from the genesis
+    pub fn set_wasm(&mut self, code_id: u64, wasm: Vec<u8>) -> Result<(), CwEnvError> {
+        let code = self
+            .codes
+            .iter_mut()
+            .find(|code| code.code_id == code_id)
+            .ok_or_else(|| CwEnvError::StdErr(format!("code {code_id} not found in the mock")))?;
+        code.checksum = HexBinary::from(Sha256::digest(&wasm).to_vec());
+        code.wasm = Some(wasm.into());
+        Ok(())
+    }
+
+    /// Sets the wasm code of `code_id` from a wasm file, like the one returned by `Uploadable::wasm`
+    pub fn set_wasm_path(&mut self, code_id: u64, path: &WasmPath) -> Result<(), CwEnvError> {
+        self.set_wasm(code_id, std::fs::read(path.path())?)
+    }
+
+    /// Codes whose wasm code is still missing
+    pub fn missing_wasm(&self) -> Vec<u64> {
+        self.codes
+            .iter()
+            .filter(|code| code.wasm.is_none())
+            .map(|code| code.code_id)
+            .collect()
+    }
+
+    /// `app_state` fragment with the `bank` and `wasm` modules, to merge into the genesis of a wasmd chain.
+    /// The bank supply is left empty for the chain to compute it from all the balances.
+    pub fn app_state(&self) -> Value {
+        let coins = |coins: &[Coin]| -> Vec<Value> {
+            coins
+                .iter()
+                .map(|c| json!({ "denom": c.denom, "amount": c.amount.to_string() }))
+                .collect()
+        };
+        let balances: Vec<Value> = self
+            .balances
+            .iter()
+            .map(|(address, balance)| json!({ "address": address, "coins": coins(balance) }))
+            .collect();
+        let everybody = json!({ "permission": "Everybody", "addresses": [] });
+        let codes: Vec<Value> = self
+            .codes
+            .iter()
+            .map(|code| {
+                json!({
+                    "code_id": code.code_id.to_string(),
+                    "code_info": {
+                        "code_hash": Binary::from(code.checksum.to_vec()),
+                        "creator": code.creator,
+                        "instantiate_config": everybody,
+                    },
+                    "code_bytes": code.wasm.clone().unwrap_or_default(),
+                    "pinned": false,
+                })
+            })
+            .collect();
+        let contracts: Vec<Value> = self
+            .contracts
+            .iter()
+            .map(|contract| {
+                let init_msg: Value = contract
+                    .init_msg
+                    .as_ref()
+                    .and_then(|msg| serde_json::from_slice(msg).ok())
+                    .unwrap_or_else(|| json!({}));
+                let created = json!({ "block_height": contract.created.to_string(), "tx_index": "0" });
+                json!({
+                    "contract_address": contract.address,
+                    "contract_info": {
+                        "code_id": contract.code_id.to_string(),
+                        "creator": contract.creator,
+                        "admin": contract.admin.as_ref().map(Addr::to_string).unwrap_or_default(),
+                        "label": contract.label,
+                        "created": created,
+                        "ibc_port_id": "",
+                        "extension": null,
+                    },
+                    "contract_state": contract.state.iter().map(|(key, value)| {
+                        json!({ "key": HexBinary::from(key.to_vec()).to_hex().to_uppercase(), "value": value })
+                    }).collect::<Vec<_>>(),
+                    "contract_code_history": [{
+                        "operation": "CONTRACT_CODE_HISTORY_OPERATION_TYPE_INIT",
+                        "code_id": contract.code_id.to_string(),
+                        "updated": created,
+                        "msg": init_msg,
+                    }],
+                })
+            })
+            .collect();
+        let last_code_id = self.codes.iter().map(|c| c.code_id).max().unwrap_or(0);
+
+        json!({
+            "bank": {
+                "balances": balances,
+                "supply": [],
+            },
+            "wasm": {
+                "params": {
+                    "code_upload_access": everybody,
+                    "instantiate_default_permission": "Everybody",
+                },
+                "codes": codes,
+                "contracts": contracts,
+                "sequences": [
+                    { "id_key": Binary::from(SEQUENCE_LAST_CODE_ID), "value": (last_code_id + 1).to_string() },
+                    { "id_key": Binary::from(SEQUENCE_LAST_CONTRACT_ID), "value": (self.contracts.len() + 1).to_string() },
+                ],
+            },
+        })
+    }
+}
+
+impl<A: Api, S: StateInterface> MockBase<A, S> {
+    /// Exports the bank balances, codes, contracts and contract storage of the mock as a [`MockGenesis`],
+    /// to boot a scenario built in the mock as a real local chain.
+    ///
+    /// Only the Rust implementation of the contracts is known to the mock,
+    /// their wasm code needs to be added with [`MockGenesis::set_wasm`] before booting a chain.
+    /// The addresses need to be valid on the chain, which is the case with [`MockBech32`](crate::MockBech32).
+    pub fn export_genesis(&self) -> Result<MockGenesis, CwEnvError> {
+        let app = self.app.borrow();
+        let storage = app.storage();
+        let records: Vec<(Vec<u8>, Vec<u8>)> =
+            storage.range(None, None, Order::Ascending).collect();
+        let entries = |prefix: Vec<u8>| {
+            records
+                .iter()
+                .filter(move |(key, _)| key.starts_with(&prefix))
+                .map(move |(key, value)| (key[prefix.len()..].to_vec(), value))
+        };
+
+        let balances = bank_balances(storage)?;
+
+        let init_msgs: BTreeMap<Addr, Binary> = self
+            .tx_history()
+            .iter()
+            .flat_map(|tx| tx.call_tree.iter().flat_map(ContractCall::flatten))
+            .filter(|call| call.entry_point == ContractEntryPoint::Instantiate && call.is_ok())
+            .map(|call| (call.contract.clone(), call.msg.clone()))
+            .collect();
+
+        let mut contracts = vec![];
+        for (addr, value) in entries(namespaced(&[NAMESPACE_WASM, CONTRACTS])) {
+            let data: ContractData = cosmwasm_std::from_json(value)?;
+            let address = Addr::unchecked(String::from_utf8_lossy(&addr));
+            let contract_data = [CONTRACT_DATA, address.as_bytes()].concat();
+            contracts.push(GenesisContract {
+                state: entries(namespaced(&[NAMESPACE_WASM, &contract_data]))
+                    .map(|(key, value)| (key.into(), value.clone().into()))
+                    .collect(),
+                init_msg: init_msgs.get(&address).cloned(),
+                address,
+                code_id: data.code_id,
+                creator: data.creator,
+                admin: data.admin,
+                label: data.label,
+                created: data.created,
+            });
+        }
+
+        // Code ids are sequential, starting at 1
+        let mut codes = vec![];
+        for code_id in 1.. {
+            let Ok(info) = app.wrap().query_wasm_code_info(code_id) else {
+                break;
+            };
+            codes.push(GenesisCode {
+                code_id,
+                creator: info.creator.to_string(),
+                checksum: info.checksum,
+                wasm: None,
+            });
+        }
+
+        Ok(MockGenesis {
+            balances,
+            codes,
+            contracts,
+        })
+    }
+}
+
+/// Non-empty bank balances of all the accounts
+pub(crate) fn bank_balances(
+    storage: &dyn Storage,
+) -> Result<BTreeMap<Addr, Vec<Coin>>, CwEnvError> {
+    let prefix = namespaced(&[NAMESPACE_BANK, BALANCES]);
+    let mut balances = BTreeMap::new();
+    for (key, value) in storage.range(Some(&prefix), None, Order::Ascending) {
+        let Some(addr) = key.strip_prefix(prefix.as_slice()) else {
+            break;
+        };
+        let balance: Vec<Coin> = cosmwasm_std::from_json(value)?;
+        if !balance.is_empty() {
+            balances.insert(Addr::unchecked(String::from_utf8_lossy(addr)), balance);
+        }
+    }
+    Ok(balances)
+}
+
+/// Storage prefix of nested cw-multi-test namespaces, each one length-prefixed
+fn namespaced(namespaces: &[&[u8]]) -> Vec<u8> {
+    namespaces
+        .iter()
+        .flat_map(|namespace| {
+            let mut prefixed = (namespace.len() as u16).to_be_bytes().to_vec();
+            prefixed.extend_from_slice(namespace);
+            prefixed
+        })
+        .collect()
+}
@@ -0,0 +1,221 @@
+//! History of the transactions executed on a [`MockBase`].
+//!
+//! Every upload, bank send, execution, instantiation and migration is recorded with the events it emitted, including the ones of the sub-messages,
+//! so tests can assert on interactions that aren't visible in the response of the top-level call.
+//! The calls between contracts are recorded as a [`ContractCall`] tree, see the [`call_tree`](crate::call_tree) module.
+//! Simulations aren't recorded, and only the last [`DEFAULT_TX_HISTORY_LIMIT`] transactions are kept unless another limit is set
+//! with [`MockBase::set_tx_history_limit`].
+
+use std::collections::VecDeque;
+
+use cosmwasm_std::{to_json_vec, Addr, Api, CosmosMsg, Event};
+use cw_multi_test::AppResponse;
+use cw_orch_core::{environment::StateInterface, CwEnvError};
+
+use crate::{
+    call_tree::{build_call_tree, ContractCall},
+    MockBase,
+};
+
+/// Number of transactions kept by the history of a [`MockBase`] by default
+pub const DEFAULT_TX_HISTORY_LIMIT: usize = 1_000;
+
+// Weights of the `MockTx::gas_estimate` heuristic. They are not taken from the gas schedule of any chain.
+const HEURISTIC_TX_GAS: u64 = 75_000;
+const HEURISTIC_CONTRACT_CALL_GAS: u64 = 50_000;
+const HEURISTIC_MSG_BYTE_GAS: u64 = 10;
+
+/// Message of a [`MockTx`]
+#[derive(Clone, Debug, PartialEq)]
+pub enum MockMsg {
+    /// Upload of a contract, which has no [`CosmosMsg`] equivalent
+    StoreCode {
+        /// Code id of the uploaded contract
+        code_id: u64,
+    },
+    /// Message executed on the app
+    Cosmos(CosmosMsg),
+}
+
+impl From<CosmosMsg> for MockMsg {
+    fn from(msg: CosmosMsg) -> Self {
+        MockMsg::Cosmos(msg)
+    }
+}
+
+/// Transaction executed on a [`MockBase`]
+#[derive(Clone, Debug, PartialEq)]
+pub struct MockTx {
+    /// Height of the block the transaction was executed in
+    pub height: u64,
+    pub sender: Addr,
+    pub msg: MockMsg,
+    /// Events emitted by the transaction and its sub-messages, empty if it failed
+    pub events: Vec<Event>,
+    /// Error of the transaction if it failed
+    pub error: Option<String>,
+    /// Contract calls of the transaction, nested under the call that dispatched them.
+    /// Usually a single root, the call of the transaction message, also recorded when the transaction failed.
+    pub call_tree: Vec<ContractCall>,
+    /// Heuristic weight of the transaction, growing with its number of contract calls and the size of its message.
+    /// cw-multi-test doesn't meter gas, so this is not the gas the transaction would use on chain,
+    /// it's only meant to compare the transactions of a test with each other.
+    pub gas_estimate: u64,
+}
+
+impl MockTx {
+    pub(crate) fn new(
+        height: u64,
+        sender: Addr,
+        msg: MockMsg,
+        result: &Result<AppResponse, CwEnvError>,
+        call_tree: Vec<ContractCall>,
+    ) -> Self {
+        let (events, error) = match result {
+            Ok(resp) => (resp.events.clone(), None),
+            Err(e) => (vec![], Some(e.to_string())),
+        };
+        let contract_calls = events
+            .iter()
+            .filter(|e| {
+                matches!(
+                    e.ty.as_str(),
+                    "execute" | "instantiate" | "migrate" | "reply"
+                )
+            })
+            .count() as u64;
+        let msg_bytes = match &msg {
+            MockMsg::Cosmos(msg) => to_json_vec(msg).map_or(0, |bytes| bytes.len()) as u64,
+            MockMsg::StoreCode { .. } => 0,
+        };
+        Self {
+            height,
+            sender,
+            msg,
+            events,
+            error,
+            call_tree,
+            gas_estimate: HEURISTIC_TX_GAS
+                + contract_calls * HEURISTIC_CONTRACT_CALL_GAS
+                + msg_bytes * HEURISTIC_MSG_BYTE_GAS,
+        }
+    }
+
+    /// Whether the transaction succeeded
+    pub fn is_ok(&self) -> bool {
+        self.error.is_none()
+    }
+
+    /// Call of the transaction message, with all the calls made by its sub-messages
+    pub fn root_call(&self) -> Option<&ContractCall> {
+        self.call_tree.first()
+    }
+}
+
+/// Last transactions executed on a [`MockBase`], shared by its clones
+#[derive(Debug)]
+pub(crate) struct TxHistory {
+    txs: VecDeque<MockTx>,
+    limit: usize,
+    paused: bool,
+}
+
+impl Default for TxHistory {
+    fn default() -> Self {
+        Self {
+            txs: VecDeque::new(),
+            limit: DEFAULT_TX_HISTORY_LIMIT,
+            paused: false,
+        }
+    }
+}
+
+impl TxHistory {
+    fn push(&mut self, tx: MockTx) {
+        if self.paused || self.limit == 0 {
+            return;
+        }
+        if self.txs.len() == self.limit {
+            self.txs.pop_front();
+        }
+        self.txs.push_back(tx);
+    }
+
+    fn set_limit(&mut self, limit: usize) {
+        self.limit = limit;
+        let excess = self.txs.len().saturating_sub(limit);
+        self.txs.drain(..excess);
+    }
+
+    /// Stops recording transactions, returning whether it was already stopped
+    pub(crate) fn pause(&mut self) -> bool {
+        std::mem::replace(&mut self.paused, true)
+    }
+
+    pub(crate) fn set_paused(&mut self, paused: bool) {
+        self.paused = paused;
+    }
+}
+
+impl<A: Api, S: StateInterface> MockBase<A, S> {
+    /// Transactions executed on this environment, oldest first
+    pub fn tx_history(&self) -> Vec<MockTx> {
+        self.history.borrow().txs.iter().cloned().collect()
+    }
+
+    /// Last transaction executed on this environment
+    pub fn last_tx(&self) -> Option<MockTx> {
+        self.history.borrow().txs.back().cloned()
+    }
+
+    /// Keeps the last `limit` transactions in the history, `0` disables it.
+    /// The history keeps the last [`DEFAULT_TX_HISTORY_LIMIT`] transactions by default.
+    pub fn set_tx_history_limit(&self, limit: usize) {
+        self.history.borrow_mut().set_limit(limit);
+    }
+
+    /// Events emitted by `contract` in all the successful transactions, including the ones emitted in sub-messages
+    pub fn events_by_contract(&self, contract: &Addr) -> Vec<Event> {
+        self.history
+            .borrow()
+            .txs
+            .iter()
+            .flat_map(|tx| tx.events.iter())
+            .filter(|event| {
+                event
+                    .attributes
+                    .iter()
+                    .any(|attr| attr.key == "_contract_address" && attr.value == contract.as_str())
+            })
+            .cloned()
+            .collect()
+    }
+
+    /// Forget the transactions executed until now
+    pub fn clear_tx_history(&self) {
+        self.history.borrow_mut().txs.clear();
+    }
+
+    /// Forgets the contract calls made outside of a recorded transaction, for instance by using the app directly
+    pub(crate) fn start_tx(&self) {
+        self.calls.borrow_mut().clear();
+    }
+
+    /// Records the result of executing `msg`
+    pub(crate) fn record_tx(
+        &self,
+        msg: impl Into<MockMsg>,
+        result: &Result<AppResponse, CwEnvError>,
+    ) {
+        let height = self.app.borrow().block_info().height;
+        let calls = std::mem::take(&mut *self.calls.borrow_mut());
+        let tx = MockTx::new(
+            height,
+            self.sender.clone(),
+            msg.into(),
+            result,
+            build_call_tree(calls),
+        );
+        self.history.borrow_mut().push(tx);
+    }
+}
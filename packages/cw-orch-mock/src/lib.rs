@@ -5,12 +5,18 @@
 pub extern crate cw_multi_test;
 
 mod bech32;
+pub mod call_tree;
 mod core;
+pub mod custom;
+pub mod genesis;
+pub mod history;
 pub mod queriers;
 mod simple;
 mod state;
 
 pub use self::core::{Mock, MockBase, MockBech32};
+pub use call_tree::ContractCall;
+pub use history::{MockMsg, MockTx, DEFAULT_TX_HISTORY_LIMIT};
 
 pub type MockApp = self::core::MockApp<MockApi>;
 pub type MockAppBech32 = self::core::MockApp<MockApiBech32>;
@@ -1,6 +1,6 @@
-use std::{cell::RefCell, rc::Rc};
+use std::{cell::RefCell, collections::BTreeMap, rc::Rc};
 
-use cosmwasm_std::{Api, Coin};
+use cosmwasm_std::{Api, Coin, DenomMetadata, PageRequest, Uint128};
 use cw_orch_core::{
     environment::{
         QuerierGetter, StateInterface, {BankQuerier, Querier},
@@ -8,7 +8,7 @@ use cw_orch_core::{
     CwEnvError,
 };
 
-use crate::{core::MockApp, MockBase};
+use crate::{core::MockApp, genesis::bank_balances, MockBase};
 
 pub struct MockBankQuerier<A> {
     app: Rc<RefCell<MockApp<A>>>,
@@ -56,7 +56,43 @@ impl<A: Api> BankQuerier for MockBankQuerier<A> {
         Ok(self.app.borrow().wrap().query_supply(denom)?)
     }
 
+    /// Sum of the balances of all the accounts, multi-test doesn't track the supply of all the denoms
     fn total_supply(&self) -> Result<Vec<cosmwasm_std::Coin>, Self::Error> {
-        unimplemented!()
+        let mut supply: BTreeMap<String, Uint128> = BTreeMap::new();
+        for coin in bank_balances(self.app.borrow().storage())?
+            .into_values()
+            .flatten()
+        {
+            *supply.entry(coin.denom).or_default() += coin.amount;
+        }
+        Ok(supply
+            .into_iter()
+            .map(|(denom, amount)| Coin { amount, denom })
+            .collect())
+    }
+
+    fn denom_metadata(&self, denom: impl Into<String>) -> Result<DenomMetadata, Self::Error> {
+        Ok(self.app.borrow().wrap().query_denom_metadata(denom)?)
+    }
+
+    fn denoms_metadata(&self) -> Result<Vec<DenomMetadata>, Self::Error> {
+        let mut metadata = vec![];
+        let mut key = None;
+        loop {
+            let page = self
+                .app
+                .borrow()
+                .wrap()
+                .query_all_denom_metadata(PageRequest {
+                    key,
+                    limit: 100,
+                    reverse: false,
+                })?;
+            metadata.extend(page.metadata);
+            match page.next_key {
+                Some(next_key) => key = Some(next_key),
+                None => return Ok(metadata),
+            }
+        }
     }
 }
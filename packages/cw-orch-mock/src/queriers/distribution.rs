@@ -0,0 +1,66 @@
+use std::{cell::RefCell, rc::Rc};
+
+use cosmwasm_std::{Api, Coin};
+use cw_orch_core::{
+    environment::{DistributionQuerier, Querier, QuerierGetter, StateInterface},
+    CwEnvError,
+};
+use cw_utils::NativeBalance;
+
+use crate::{core::MockApp, MockBase};
+
+/// Rewards are computed by the cw-multi-test staking module and exposed through its delegation queries.
+pub struct MockDistributionQuerier<A> {
+    app: Rc<RefCell<MockApp<A>>>,
+}
+
+impl<A: Api> MockDistributionQuerier<A> {
+    fn new<S: StateInterface>(mock: &MockBase<A, S>) -> Self {
+        Self {
+            app: mock.app.clone(),
+        }
+    }
+}
+
+impl<A: Api, S: StateInterface> QuerierGetter<MockDistributionQuerier<A>> for MockBase<A, S> {
+    fn querier(&self) -> MockDistributionQuerier<A> {
+        MockDistributionQuerier::new(self)
+    }
+}
+
+impl<A: Api> Querier for MockDistributionQuerier<A> {
+    type Error = CwEnvError;
+}
+
+impl<A: Api> DistributionQuerier for MockDistributionQuerier<A> {
+    fn delegation_rewards(
+        &self,
+        delegator: impl Into<String>,
+        validator: impl Into<String>,
+    ) -> Result<Vec<Coin>, Self::Error> {
+        let delegation = self
+            .app
+            .borrow()
+            .wrap()
+            .query_delegation(delegator, validator)?;
+        Ok(delegation
+            .map(|d| d.accumulated_rewards)
+            .unwrap_or_default())
+    }
+
+    fn delegation_total_rewards(
+        &self,
+        delegator: impl Into<String>,
+    ) -> Result<Vec<Coin>, Self::Error> {
+        let delegator = delegator.into();
+        let delegations = self.app.borrow().wrap().query_all_delegations(&delegator)?;
+
+        let mut total = NativeBalance::default();
+        for delegation in delegations {
+            total =
+                total + NativeBalance(self.delegation_rewards(&delegator, delegation.validator)?);
+        }
+        total.normalize();
+        Ok(total.into_vec())
+    }
+}
@@ -0,0 +1,77 @@
+use std::{cell::RefCell, collections::BTreeMap, marker::PhantomData, rc::Rc};
+
+use cosmwasm_std::Api;
+use cw_orch_core::{
+    environment::{GovQuerier, ProposalStatus, Querier, QuerierGetter, StateInterface},
+    CwEnvError,
+};
+
+use crate::MockBase;
+
+/// Statuses of the governance proposals of a [`MockBase`], by proposal id
+pub(crate) type ProposalStatuses = Rc<RefCell<BTreeMap<u64, ProposalStatus>>>;
+
+/// The cw-multi-test gov module doesn't keep any state, the proposal statuses are set with [`MockBase::set_proposal_status`].
+pub struct MockGovQuerier<A> {
+    proposals: ProposalStatuses,
+    _api: PhantomData<A>,
+}
+
+impl<A: Api, S: StateInterface> QuerierGetter<MockGovQuerier<A>> for MockBase<A, S> {
+    fn querier(&self) -> MockGovQuerier<A> {
+        MockGovQuerier {
+            proposals: self.proposals.clone(),
+            _api: PhantomData,
+        }
+    }
+}
+
+impl<A: Api, S: StateInterface> MockBase<A, S> {
+    /// Set the status of the governance proposal `proposal_id`, as returned by [`GovQuerier::proposal_status`]
+    pub fn set_proposal_status(&self, proposal_id: u64, status: ProposalStatus) {
+        self.proposals.borrow_mut().insert(proposal_id, status);
+    }
+}
+
+impl<A: Api> Querier for MockGovQuerier<A> {
+    type Error = CwEnvError;
+}
+
+impl<A: Api> GovQuerier for MockGovQuerier<A> {
+    fn proposal_status(&self, proposal_id: u64) -> Result<ProposalStatus, Self::Error> {
+        self.proposals
+            .borrow()
+            .get(&proposal_id)
+            .copied()
+            .ok_or_else(|| {
+                CwEnvError::StdErr(format!(
+                    "Proposal {proposal_id} not found, set its status with `set_proposal_status`"
+                ))
+            })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use cw_orch_core::environment::ModuleQueriers;
+
+    use super::*;
+    use crate::Mock;
+
+    #[test]
+    fn proposal_status() {
+        let chain = Mock::new("sender");
+        assert!(chain.gov_querier().proposal_status(1).is_err());
+
+        chain.set_proposal_status(1, ProposalStatus::VotingPeriod);
+        // The status is shared by the clones of the environment
+        chain.clone().set_proposal_status(2, ProposalStatus::Passed);
+
+        let gov = chain.gov_querier();
+        assert_eq!(
+            gov.proposal_status(1).unwrap(),
+            ProposalStatus::VotingPeriod
+        );
+        assert_eq!(gov.proposal_status(2).unwrap(), ProposalStatus::Passed);
+    }
+}
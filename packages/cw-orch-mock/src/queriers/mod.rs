@@ -3,13 +3,16 @@ use crate::MockBase;
 use cosmwasm_std::Api;
 use cw_multi_test::next_block;
 use cw_orch_core::{
-    environment::{DefaultQueriers, QueryHandler, StateInterface},
+    environment::{DefaultQueriers, ModuleQueriers, QueryHandler, StateInterface},
     CwEnvError,
 };
 
 pub mod bank;
+pub mod distribution;
 mod env;
+pub mod gov;
 pub mod node;
+pub mod staking;
 pub mod wasm;
 
 impl<A: Api, S: StateInterface> QueryHandler for MockBase<A, S> {
@@ -42,3 +45,9 @@ impl<A: Api, S: StateInterface> DefaultQueriers for MockBase<A, S> {
     type Wasm = wasm::MockWasmQuerier<A, S>;
     type Node = node::MockNodeQuerier<A>;
 }
+
+impl<A: Api, S: StateInterface> ModuleQueriers for MockBase<A, S> {
+    type Staking = staking::MockStakingQuerier<A>;
+    type Distribution = distribution::MockDistributionQuerier<A>;
+    type Gov = gov::MockGovQuerier<A>;
+}
@@ -0,0 +1,69 @@
+use std::{cell::RefCell, rc::Rc};
+
+use cosmwasm_std::{Api, Delegation, Validator};
+use cw_orch_core::{
+    environment::{Querier, QuerierGetter, StakingQuerier, StateInterface},
+    CwEnvError,
+};
+
+use crate::{core::MockApp, MockBase};
+
+pub struct MockStakingQuerier<A> {
+    app: Rc<RefCell<MockApp<A>>>,
+}
+
+impl<A: Api> MockStakingQuerier<A> {
+    fn new<S: StateInterface>(mock: &MockBase<A, S>) -> Self {
+        Self {
+            app: mock.app.clone(),
+        }
+    }
+}
+
+impl<A: Api, S: StateInterface> QuerierGetter<MockStakingQuerier<A>> for MockBase<A, S> {
+    fn querier(&self) -> MockStakingQuerier<A> {
+        MockStakingQuerier::new(self)
+    }
+}
+
+impl<A: Api> Querier for MockStakingQuerier<A> {
+    type Error = CwEnvError;
+}
+
+impl<A: Api> StakingQuerier for MockStakingQuerier<A> {
+    fn bonded_denom(&self) -> Result<String, Self::Error> {
+        Ok(self.app.borrow().wrap().query_bonded_denom()?)
+    }
+
+    fn all_validators(&self) -> Result<Vec<Validator>, Self::Error> {
+        Ok(self.app.borrow().wrap().query_all_validators()?)
+    }
+
+    fn validator(&self, address: impl Into<String>) -> Result<Option<Validator>, Self::Error> {
+        Ok(self.app.borrow().wrap().query_validator(address)?)
+    }
+
+    fn all_delegations(
+        &self,
+        delegator: impl Into<String>,
+    ) -> Result<Vec<Delegation>, Self::Error> {
+        Ok(self.app.borrow().wrap().query_all_delegations(delegator)?)
+    }
+
+    fn delegation(
+        &self,
+        delegator: impl Into<String>,
+        validator: impl Into<String>,
+    ) -> Result<Option<Delegation>, Self::Error> {
+        let delegation = self
+            .app
+            .borrow()
+            .wrap()
+            .query_delegation(delegator, validator)?;
+        Ok(delegation.map(|d| Delegation {
+            delegator: d.delegator,
+            validator: d.validator,
+            amount: d.amount,
+        }))
+    }
+}
@@ -69,6 +69,37 @@ impl<S: StateInterface> Mock<S> {
             })
     }
 
+    /// Add coins to the balances of many accounts at once, like genesis balances.
+    /// Useful to set up tests with a lot of funded accounts without executing a transaction for each of them.
+    pub fn fund_accounts(
+        &self,
+        funds: &[(impl Into<String> + Clone, &[cosmwasm_std::Coin])],
+    ) -> Result<(), CwEnvError> {
+        let mut balances: Vec<(Addr, NativeBalance)> = vec![];
+        for (address, coins) in funds {
+            let addr = Addr::unchecked(address.clone());
+            match balances.iter_mut().find(|(a, _)| a == &addr) {
+                Some((_, balance)) => {
+                    *balance = balance.clone() + NativeBalance(coins.to_vec());
+                }
+                None => {
+                    let current = self.query_all_balances(addr.clone())?;
+                    balances.push((addr, NativeBalance(current) + NativeBalance(coins.to_vec())));
+                }
+            }
+        }
+        self.app
+            .borrow_mut()
+            .init_modules(|router, _, storage| -> Result<(), CwEnvError> {
+                for (addr, balance) in balances {
+                    router
+                        .bank
+                        .init_balance(storage, &addr, balance.into_vec())?;
+                }
+                Ok(())
+            })
+    }
+
     /// Query the (bank) balance of a native token for and address.
     /// Returns the amount of the native token.
     pub fn query_balance(
@@ -91,6 +122,24 @@ impl<S: StateInterface> Mock<S> {
     ) -> Result<Vec<cosmwasm_std::Coin>, CwEnvError> {
         self.bank_querier().balance(address, None)
     }
+
+    /// Generate a valid bech32 address (with the `cosmwasm` prefix) from an account name.
+    /// Use [`MockBech32`](crate::MockBech32) to generate addresses with a chain-specific prefix.
+    pub fn addr_make(&self, account_name: impl Into<String>) -> Addr {
+        self.app.borrow().api().addr_make(&account_name.into())
+    }
+
+    /// Generate a valid bech32 address from an account name and set its bank balance.
+    pub fn addr_make_with_balance(
+        &self,
+        account_name: impl Into<String>,
+        balance: Vec<Coin>,
+    ) -> Result<Addr, CwEnvError> {
+        let addr = self.addr_make(account_name);
+        self.set_balance(&addr, balance)?;
+
+        Ok(addr)
+    }
 }
 
 impl Mock {
@@ -120,6 +169,9 @@ impl<S: StateInterface> Mock<S> {
             sender: Addr::unchecked(sender),
             state,
             app,
+            history: Default::default(),
+            calls: Default::default(),
+            proposals: Default::default(),
         }
     }
 }
@@ -0,0 +1,102 @@
+use cosmwasm_std::Addr;
+use cw_orch_core::{
+    contract::interface_traits::ContractInstance,
+    environment::{QueryHandler, Scenario},
+    CwEnvError,
+};
+use cw_orch_mock::Mock;
+use mock_contract::{ExecuteMsg, InstantiateMsg, MockContract, QueryMsg};
+
+use speculoos::prelude::*;
+
+#[test]
+fn runs_steps_in_order() {
+    let sender = "sender";
+    let chain = Mock::new(sender);
+    let contract = MockContract::new("test:mock_contract", chain.clone());
+    let start = chain.block_info().unwrap().height;
+
+    let report = Scenario::new("first message")
+        .upload(&contract)
+        .instantiate(
+            &contract,
+            InstantiateMsg {},
+            Some(&Addr::unchecked(sender)),
+            &[],
+        )
+        .execute(&contract, ExecuteMsg::FirstMessage {}, &[])
+        .assert_query(
+            &contract,
+            QueryMsg::FirstQuery {},
+            "first query passed".to_string(),
+        )
+        .advance_blocks(2)
+        .run(&chain)
+        .unwrap();
+
+    asserting!("all the steps are reported")
+        .that(&report.steps.len())
+        .is_equal_to(5);
+    asserting!("contract is instantiated")
+        .that(&contract.address())
+        .is_ok();
+    asserting!("blocks are advanced")
+        .that(&chain.block_info().unwrap().height)
+        .is_equal_to(start + 2);
+    asserting!("mock doesn't meter gas")
+        .that(&report.total_gas_used())
+        .is_none();
+}
+
+#[test]
+fn reports_failing_step() {
+    let chain = Mock::new("sender");
+    let contract = MockContract::new("test:mock_contract", chain.clone());
+
+    let result = Scenario::new("second message")
+        .upload(&contract)
+        .instantiate(&contract, InstantiateMsg {}, None, &[])
+        .execute(
+            &contract,
+            ExecuteMsg::SecondMessage {
+                t: "fails".to_string(),
+            },
+            &[],
+        )
+        .advance_blocks(1)
+        .run(&chain);
+
+    match result {
+        Err(CwEnvError::ScenarioStepFailed {
+            scenario,
+            step,
+            name,
+            ..
+        }) => {
+            assert_eq!(scenario, "second message");
+            assert_eq!(step, 3);
+            assert!(name.starts_with("execute test:mock_contract"));
+        }
+        other => panic!("expected the scenario to fail, got {other:?}"),
+    }
+}
+
+#[test]
+fn reports_failed_query_assertion() {
+    let chain = Mock::new("sender");
+    let contract = MockContract::new("test:mock_contract", chain.clone());
+
+    let result = Scenario::new("query")
+        .upload(&contract)
+        .instantiate(&contract, InstantiateMsg {}, None, &[])
+        .assert_query(&contract, QueryMsg::FourthQuery(1, "a".to_string()), 5u64)
+        .run(&chain);
+
+    match result {
+        Err(CwEnvError::ScenarioStepFailed { step, source, .. }) => {
+            assert_eq!(step, 3);
+            assert!(matches!(*source, CwEnvError::AssertionFailed(_)));
+        }
+        other => panic!("expected the query assertion to fail, got {other:?}"),
+    }
+}
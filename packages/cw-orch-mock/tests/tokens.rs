@@ -0,0 +1,114 @@
+use cosmwasm_std::{Addr, Coin, DenomMetadata, DenomUnit, Uint128};
+use cw20::Cw20Coin;
+use cw_multi_test::ContractWrapper;
+use cw_orch_core::environment::{
+    BankQuerier, ChainState, DefaultQueriers, IndexResponse, StateInterface, TokenHelpers,
+    TxHandler,
+};
+use cw_orch_mock::Mock;
+
+use speculoos::prelude::*;
+
+const SENDER: &str = "sender";
+const RECIPIENT: &str = "recipient";
+
+fn cw20(chain: &Mock) -> Addr {
+    let code = Box::new(ContractWrapper::new(
+        cw20_base::contract::execute,
+        cw20_base::contract::instantiate,
+        cw20_base::contract::query,
+    ));
+    let code_id = chain
+        .upload_custom("cw20", code)
+        .unwrap()
+        .uploaded_code_id()
+        .unwrap();
+    let init_msg = cw20_base::msg::InstantiateMsg {
+        name: String::from("Token"),
+        symbol: String::from("TOK"),
+        decimals: 6u8,
+        initial_balances: vec![Cw20Coin {
+            address: SENDER.to_string(),
+            amount: Uint128::new(10_000_000),
+        }],
+        mint: None,
+        marketing: None,
+    };
+    chain
+        .instantiate(code_id, &init_msg, None, None, &[])
+        .unwrap()
+        .instantiated_contract_address()
+        .unwrap()
+}
+
+#[test]
+fn cw20_transfer_with_decimals() {
+    let chain = Mock::new(SENDER);
+    let token = cw20(&chain);
+    chain.state().set_address("tokens:tok", &token);
+
+    chain.cw20_transfer("tokens:tok", RECIPIENT, "1.5").unwrap();
+
+    let balance = chain.cw20_balance("tokens:tok", RECIPIENT).unwrap();
+    asserting!("recipient received 1.5 tokens")
+        .that(&balance.amount)
+        .is_equal_to(Uint128::new(1_500_000));
+    asserting!("balance is displayed with decimals")
+        .that(&balance.to_string())
+        .is_equal_to("1.5 TOK".to_string());
+    asserting!("amounts with too many decimals are rejected")
+        .that(&chain.cw20_transfer(token.as_str(), RECIPIENT, "0.0000001"))
+        .is_err();
+    asserting!("unknown address book entries are rejected")
+        .that(&chain.cw20_balance("tokens:unknown", RECIPIENT))
+        .is_err();
+    asserting!("no allowance by default")
+        .that(
+            &chain
+                .cw20_allowance(token.as_str(), SENDER, RECIPIENT)
+                .unwrap()
+                .amount,
+        )
+        .is_equal_to(Uint128::zero());
+}
+
+#[test]
+fn bank_transfer_with_decimals() {
+    let chain = Mock::new(SENDER);
+    chain
+        .set_balance(SENDER, vec![Coin::new(10_000_000, "ujuno")])
+        .unwrap();
+    chain
+        .set_denom_metadata(
+            "ujuno",
+            DenomMetadata {
+                base: "ujuno".to_string(),
+                display: "juno".to_string(),
+                symbol: "JUNO".to_string(),
+                denom_units: vec![
+                    DenomUnit {
+                        denom: "ujuno".to_string(),
+                        exponent: 0,
+                        aliases: vec![],
+                    },
+                    DenomUnit {
+                        denom: "juno".to_string(),
+                        exponent: 6,
+                        aliases: vec![],
+                    },
+                ],
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+    chain.bank_transfer(RECIPIENT, "2.5", "ujuno").unwrap();
+
+    asserting!("recipient received 2.5 juno")
+        .that(&chain.bank_querier().balance(RECIPIENT, None).unwrap())
+        .is_equal_to(vec![Coin::new(2_500_000, "ujuno")]);
+    let balances = chain.bank_balances(RECIPIENT).unwrap();
+    asserting!("balances are displayed with decimals")
+        .that(&balances[0].to_string())
+        .is_equal_to("2.5 JUNO".to_string());
+}
@@ -5,6 +5,7 @@ pub const ARCHWAY_NETWORK: NetworkInfo = NetworkInfo {
     chain_name: "archway",
     pub_address_prefix: "archway",
     coin_type: 118u32,
+    pub_key_type: None,
 };
 
 /// Archway Docs: <https://docs.archway.io/resources/networks>
@@ -7,6 +7,7 @@ pub const DORAVOTA_NETWORK: NetworkInfo = NetworkInfo {
     chain_name: "doravota",
     pub_address_prefix: "dora",
     coin_type: 118u32,
+    pub_key_type: None,
 };
 
 pub const VOTA_ASH: ChainInfo = ChainInfo {
@@ -0,0 +1,25 @@
+use crate::networks::{ChainInfo, ChainKind, NetworkInfo};
+
+// ANCHOR: dymension
+pub const DYMENSION_NETWORK: NetworkInfo = NetworkInfo {
+    chain_name: "dymension",
+    pub_address_prefix: "dym",
+    coin_type: 60u32,
+    pub_key_type: None,
+};
+
+/// Dymension hub, which doesn't run CosmWasm. Its ethermint accounts can only be used for queries,
+/// cw-orch only signs transactions with Injective's ethsecp256k1 keys.
+/// For that reason, it's not part of the [`SUPPORTED_NETWORKS`](crate::networks::SUPPORTED_NETWORKS).
+/// <https://github.com/cosmos/chain-registry/blob/master/dymension/chain.json>
+pub const DYMENSION_1100_1: ChainInfo = ChainInfo {
+    kind: ChainKind::Mainnet,
+    chain_id: "dymension_1100-1",
+    gas_denom: "adym",
+    gas_price: 20_000_000_000.0,
+    grpc_urls: &["https://grpc.dymension.nodestake.org:443"],
+    network_info: DYMENSION_NETWORK,
+    lcd_url: None,
+    fcd_url: None,
+};
+// ANCHOR_END: dymension
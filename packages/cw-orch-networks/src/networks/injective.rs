@@ -5,6 +5,7 @@ pub const INJECTIVE_NETWORK: NetworkInfo = NetworkInfo {
     chain_name: "injective",
     pub_address_prefix: "inj",
     coin_type: 60u32,
+    pub_key_type: None,
 };
 
 /// <https://docs.injective.network/develop/public-endpoints/#mainnet>
@@ -7,6 +7,7 @@ pub const JUNO_NETWORK: NetworkInfo = NetworkInfo {
     chain_name: "juno",
     pub_address_prefix: "juno",
     coin_type: 118u32,
+    pub_key_type: None,
 };
 
 pub const UNI_6: ChainInfo = ChainInfo {
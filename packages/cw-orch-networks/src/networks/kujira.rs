@@ -5,6 +5,7 @@ pub const KUJIRA_NETWORK: NetworkInfo = NetworkInfo {
     chain_name: "kujira",
     pub_address_prefix: "kujira",
     coin_type: 118u32,
+    pub_key_type: None,
 };
 
 pub const HARPOON_4: ChainInfo = ChainInfo {
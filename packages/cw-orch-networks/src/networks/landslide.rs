@@ -5,6 +5,7 @@ pub const LANDSLIDE_NETWORK: NetworkInfo = NetworkInfo {
     chain_name: "landslide",
     pub_address_prefix: "wasm",
     coin_type: 118u32,
+    pub_key_type: None,
 };
 
 pub const LOCAL_LANDSLIDE: ChainInfo = ChainInfo {
@@ -5,6 +5,7 @@ pub const MIGALOO_NETWORK: NetworkInfo = NetworkInfo {
     chain_name: "migaloo-1",
     pub_address_prefix: "migaloo",
     coin_type: 118u32,
+    pub_key_type: None,
 };
 
 pub const LOCAL_MIGALOO: ChainInfo = ChainInfo {
@@ -4,6 +4,7 @@
 //! See [parse_network] to easily retrieve this static network information
 pub mod archway;
 pub mod doravota;
+pub mod dymension;
 pub mod injective;
 pub mod juno;
 pub mod kujira;
@@ -15,11 +16,13 @@ pub mod osmosis;
 pub mod rollkit;
 pub mod sei;
 pub mod terra;
+pub mod wasmd;
 pub mod xion;
 
 pub use archway::{ARCHWAY_1, CONSTANTINE_3};
 pub use cw_orch_core::environment::{ChainInfo, ChainKind, NetworkInfo};
 pub use doravota::{VOTA_ASH, VOTA_TESTNET};
+pub use dymension::DYMENSION_1100_1;
 pub use injective::{INJECTIVE_1, INJECTIVE_888};
 pub use juno::{JUNO_1, LOCAL_JUNO, UNI_6};
 pub use kujira::HARPOON_4;
@@ -31,7 +34,8 @@ pub use osmosis::{LOCAL_OSMO, OSMOSIS_1, OSMO_5};
 pub use rollkit::{LOCAL_ROLLKIT, ROLLKIT_TESTNET};
 pub use sei::{ATLANTIC_2, LOCAL_SEI, PACIFIC_1, SEI_DEVNET_3};
 pub use terra::{LOCAL_TERRA, PHOENIX_1, PISCO_1};
-pub use xion::XION_TESTNET_1;
+pub use wasmd::LOCAL_WASMD;
+pub use xion::{XION_MAINNET_1, XION_TESTNET_1, XION_TESTNET_2};
 /// A helper function to retrieve a [`ChainInfo`] struct for a given chain-id.
 ///
 /// ## Example
@@ -76,6 +80,8 @@ pub const SUPPORTED_NETWORKS: &[ChainInfo] = &[
     ATLANTIC_2,
     PACIFIC_1,
     XION_TESTNET_1,
+    XION_TESTNET_2,
+    XION_MAINNET_1,
     ROLLKIT_TESTNET,
     LOCAL_LANDSLIDE,
 ];
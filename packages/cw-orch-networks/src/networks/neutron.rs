@@ -5,6 +5,7 @@ pub const NEUTRON_NETWORK: NetworkInfo = NetworkInfo {
     chain_name: "neutron",
     pub_address_prefix: "neutron",
     coin_type: 118u32,
+    pub_key_type: None,
 };
 
 /// <https://github.com/cosmos/chain-registry/blob/master/testnets/neutrontestnet/chain.json>
@@ -5,6 +5,7 @@ pub const NIBIRU_NETWORK: NetworkInfo = NetworkInfo {
     chain_name: "nibiru",
     pub_address_prefix: "nibi",
     coin_type: 118u32,
+    pub_key_type: None,
 };
 
 pub const NIBIRU_ITN_2: ChainInfo = ChainInfo {
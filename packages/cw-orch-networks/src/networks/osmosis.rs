@@ -5,6 +5,7 @@ pub const OSMO_NETWORK: NetworkInfo = NetworkInfo {
     chain_name: "osmosis",
     pub_address_prefix: "osmo",
     coin_type: 118u32,
+    pub_key_type: None,
 };
 
 pub const OSMOSIS_1: ChainInfo = ChainInfo {
@@ -5,6 +5,7 @@ pub const ROLLKIT_NETWORK: NetworkInfo = NetworkInfo {
     chain_name: "rollkit",
     pub_address_prefix: "wasm",
     coin_type: 118u32,
+    pub_key_type: None,
 };
 
 pub const LOCAL_ROLLKIT: ChainInfo = ChainInfo {
@@ -5,6 +5,7 @@ pub const SEI_NETWORK: NetworkInfo = NetworkInfo {
     chain_name: "sei",
     pub_address_prefix: "sei",
     coin_type: 118u32,
+    pub_key_type: None,
 };
 
 pub const LOCAL_SEI: ChainInfo = ChainInfo {
@@ -5,6 +5,7 @@ pub const TERRA_NETWORK: NetworkInfo = NetworkInfo {
     chain_name: "terra2",
     pub_address_prefix: "terra",
     coin_type: 330u32,
+    pub_key_type: None,
 };
 
 /// Terra testnet network.
@@ -0,0 +1,23 @@
+use crate::networks::{ChainInfo, ChainKind, NetworkInfo};
+
+// ANCHOR: wasmd
+pub const WASMD_NETWORK: NetworkInfo = NetworkInfo {
+    chain_name: "wasmd",
+    pub_address_prefix: "wasm",
+    coin_type: 118u32,
+    pub_key_type: None,
+};
+
+/// Chain started by the `cosmwasm/wasmd` docker image.
+/// Not part of [`SUPPORTED_NETWORKS`](super::SUPPORTED_NETWORKS) as its chain-id is the same as [`LOCAL_JUNO`](super::LOCAL_JUNO).
+pub const LOCAL_WASMD: ChainInfo = ChainInfo {
+    kind: ChainKind::Local,
+    chain_id: "testing",
+    gas_denom: "ucosm",
+    gas_price: 0.0,
+    grpc_urls: &["http://localhost:9090"],
+    network_info: WASMD_NETWORK,
+    lcd_url: None,
+    fcd_url: None,
+};
+// ANCHOR_END: wasmd
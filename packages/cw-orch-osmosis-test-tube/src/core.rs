@@ -1,4 +1,4 @@
-use cosmwasm_std::{coin, Addr, Coins};
+use cosmwasm_std::{coin, to_json_vec, Addr, Coins};
 
 use cw_orch_core::contract::interface_traits::Uploadable;
 use cw_orch_core::contract::WasmPath;
@@ -15,7 +15,11 @@ use osmosis_test_tube::{
 // This should be the way to import stuff.
 // But apparently osmosis-test-tube doesn't have the same dependencies as the test-tube package
 use osmosis_test_tube::osmosis_std::{
-    cosmwasm_to_proto_coins, types::cosmos::bank::v1beta1::MsgSend,
+    cosmwasm_to_proto_coins,
+    types::{
+        cosmos::bank::v1beta1::MsgSend,
+        cosmwasm::wasm::v1::{MsgExecuteContract, MsgInstantiateContract},
+    },
 };
 
 use osmosis_test_tube::OsmosisTestApp;
@@ -25,7 +29,7 @@ use serde::Serialize;
 
 use cw_orch_core::{
     environment::TxHandler,
-    environment::{ChainState, StateInterface},
+    environment::{ChainState, StateInterface, TxSimulation},
 };
 
 use cw_orch_mock::MockState;
@@ -46,6 +50,7 @@ pub const MOCK_CHAIN_INFO: ChainInfo = ChainInfo {
         chain_name: "osmosis",
         pub_address_prefix: "osmo",
         coin_type: 118u32,
+        pub_key_type: None,
     },
     kind: cw_orch_core::environment::ChainKind::Local,
 };
@@ -235,6 +240,14 @@ impl<S: StateInterface> TxHandler for OsmosisTestTube<S> {
         })
     }
 
+    fn bank_send(
+        &self,
+        recipient: &Addr,
+        amount: &[cosmwasm_std::Coin],
+    ) -> Result<Self::Response, CwEnvError> {
+        OsmosisTestTube::<S>::bank_send(self, recipient.to_string(), amount.to_vec())
+    }
+
     fn execute<E: Serialize + Debug>(
         &self,
         exec_msg: &E,
@@ -296,6 +309,57 @@ impl<S: StateInterface> TxHandler for OsmosisTestTube<S> {
     ) -> Result<Self::Response, Self::Error> {
         unimplemented!("Osmosis Test Tube doesn't support Instantiate 2 directly");
     }
+
+    fn simulate_execute<E: Serialize + Debug>(
+        &self,
+        exec_msg: &E,
+        coins: &[cosmwasm_std::Coin],
+        contract_address: &Addr,
+    ) -> Result<TxSimulation, CwEnvError> {
+        let msg = MsgExecuteContract {
+            sender: self.sender.address(),
+            contract: contract_address.to_string(),
+            msg: to_json_vec(exec_msg)?,
+            funds: cosmwasm_to_proto_coins(coins.iter().cloned()),
+        }
+        .to_any();
+        self.simulate_any(msg.type_url, msg.value)
+    }
+
+    fn simulate_instantiate<I: Serialize + Debug>(
+        &self,
+        code_id: u64,
+        init_msg: &I,
+        label: Option<&str>,
+        admin: Option<&Addr>,
+        coins: &[cosmwasm_std::Coin],
+    ) -> Result<TxSimulation, CwEnvError> {
+        let msg = MsgInstantiateContract {
+            sender: self.sender.address(),
+            admin: admin.map(ToString::to_string).unwrap_or_default(),
+            code_id,
+            label: label.unwrap_or_default().to_string(),
+            msg: to_json_vec(init_msg)?,
+            funds: cosmwasm_to_proto_coins(coins.iter().cloned()),
+        }
+        .to_any();
+        self.simulate_any(msg.type_url, msg.value)
+    }
+}
+
+impl<S: StateInterface> OsmosisTestTube<S> {
+    /// Estimates the gas used by a single message, without committing it
+    fn simulate_any(&self, type_url: String, value: Vec<u8>) -> Result<TxSimulation, CwEnvError> {
+        let gas_info = self
+            .app
+            .borrow()
+            .simulate_tx(vec![prost_types::Any { type_url, value }], &self.sender)
+            .map_err(map_err)?;
+        Ok(TxSimulation {
+            gas_used: Some(gas_info.gas_used),
+            fee: None,
+        })
+    }
 }
 
 /// Gas Fee token for OmosisTestTube, used in BankSetter
@@ -356,9 +420,16 @@ impl Stargate for OsmosisTestTube {
 pub mod tests {
     use cosmwasm_std::{coin, coins, ContractInfoResponse};
 
+    use cw_orch_core::environment::{GovQuerier, ProposalStatus};
+    use cw_orch_traits::Stargate;
+    use osmosis_test_tube::osmosis_std::{
+        shim::Any,
+        types::cosmos::gov::v1beta1::{MsgSubmitProposal, MsgSubmitProposalResponse, TextProposal},
+    };
     use osmosis_test_tube::Account;
+    use prost::Message;
 
-    use crate::{GAS_TOKEN, MOCK_CHAIN_INFO};
+    use crate::{OsmosisTestTubeGovQuerier, GAS_TOKEN, MOCK_CHAIN_INFO};
 
     use super::OsmosisTestTube;
     use counter_contract::{msg::InstantiateMsg, CounterContract};
@@ -437,4 +508,35 @@ pub mod tests {
         );
         Ok(())
     }
+
+    #[test]
+    fn gov_querier_works() -> cw_orch::anyhow::Result<()> {
+        let app = OsmosisTestTube::new(coins(100_000_000_000_000, "uosmo"));
+        let gov: OsmosisTestTubeGovQuerier = app.querier();
+        assert!(gov.proposal_status(1).is_err());
+
+        let content = TextProposal {
+            title: "Title".to_string(),
+            description: "Description".to_string(),
+        };
+        app.commit_any::<MsgSubmitProposalResponse>(
+            vec![prost_types::Any {
+                type_url: MsgSubmitProposal::TYPE_URL.to_string(),
+                value: MsgSubmitProposal {
+                    content: Some(Any {
+                        type_url: TextProposal::TYPE_URL.to_string(),
+                        value: content.encode_to_vec(),
+                    }),
+                    initial_deposit: vec![],
+                    proposer: app.sender.address(),
+                }
+                .encode_to_vec(),
+            }],
+            None,
+        )?;
+
+        // Without deposit, the proposal waits for one
+        assert_eq!(gov.proposal_status(1)?, ProposalStatus::DepositPeriod);
+        Ok(())
+    }
 }
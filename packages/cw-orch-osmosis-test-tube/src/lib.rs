@@ -4,3 +4,4 @@ mod core;
 
 mod queriers;
 pub use self::core::*;
+pub use queriers::gov::OsmosisTestTubeGovQuerier;
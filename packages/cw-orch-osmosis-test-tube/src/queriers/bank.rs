@@ -1,12 +1,15 @@
 use std::{cell::RefCell, rc::Rc};
 
-use cosmwasm_std::coin;
+use cosmwasm_std::{coin, DenomMetadata, DenomUnit};
 use cw_orch_core::environment::{BankQuerier, Querier, QuerierGetter, StateInterface};
 use cw_orch_core::CwEnvError;
 use osmosis_test_tube::osmosis_std::try_proto_to_cosmwasm_coins;
 use osmosis_test_tube::osmosis_std::types::cosmos::bank::v1beta1::{
-    QuerySupplyOfRequest, QuerySupplyOfResponse,
+    Metadata, QueryDenomMetadataRequest, QueryDenomMetadataResponse, QueryDenomsMetadataRequest,
+    QueryDenomsMetadataResponse, QuerySupplyOfRequest, QuerySupplyOfResponse,
+    QueryTotalSupplyRequest, QueryTotalSupplyResponse,
 };
+use osmosis_test_tube::osmosis_std::types::cosmos::base::query::v1beta1::PageRequest;
 use osmosis_test_tube::{Bank, Module, OsmosisTestApp, Runner};
 
 use crate::{map_err, OsmosisTestTube};
@@ -96,6 +99,94 @@ impl BankQuerier for OsmosisTestTubeBankQuerier {
     }
 
     fn total_supply(&self) -> Result<Vec<cosmwasm_std::Coin>, Self::Error> {
-        unimplemented!()
+        let mut supply = vec![];
+        let mut key = vec![];
+        loop {
+            let page: QueryTotalSupplyResponse = self
+                .app
+                .borrow()
+                .query(
+                    "/cosmos.bank.v1beta1.Query/TotalSupply",
+                    &QueryTotalSupplyRequest {
+                        pagination: Some(page_request(key)),
+                    },
+                )
+                .map_err(map_err)?;
+            supply.extend(try_proto_to_cosmwasm_coins(page.supply)?);
+            match page.pagination.filter(|p| !p.next_key.is_empty()) {
+                Some(pagination) => key = pagination.next_key,
+                None => return Ok(supply),
+            }
+        }
+    }
+
+    fn denom_metadata(&self, denom: impl Into<String>) -> Result<DenomMetadata, Self::Error> {
+        let response: QueryDenomMetadataResponse = self
+            .app
+            .borrow()
+            .query(
+                "/cosmos.bank.v1beta1.Query/DenomMetadata",
+                &QueryDenomMetadataRequest {
+                    denom: denom.into(),
+                },
+            )
+            .map_err(map_err)?;
+        Ok(response
+            .metadata
+            .map(proto_to_cosmwasm_metadata)
+            .unwrap_or_default())
+    }
+
+    fn denoms_metadata(&self) -> Result<Vec<DenomMetadata>, Self::Error> {
+        let mut metadata = vec![];
+        let mut key = vec![];
+        loop {
+            let page: QueryDenomsMetadataResponse = self
+                .app
+                .borrow()
+                .query(
+                    "/cosmos.bank.v1beta1.Query/DenomsMetadata",
+                    &QueryDenomsMetadataRequest {
+                        pagination: Some(page_request(key)),
+                    },
+                )
+                .map_err(map_err)?;
+            metadata.extend(page.metadatas.into_iter().map(proto_to_cosmwasm_metadata));
+            match page.pagination.filter(|p| !p.next_key.is_empty()) {
+                Some(pagination) => key = pagination.next_key,
+                None => return Ok(metadata),
+            }
+        }
+    }
+}
+
+fn page_request(key: Vec<u8>) -> PageRequest {
+    PageRequest {
+        key,
+        offset: 0,
+        limit: 100,
+        count_total: false,
+        reverse: false,
+    }
+}
+
+fn proto_to_cosmwasm_metadata(metadata: Metadata) -> DenomMetadata {
+    DenomMetadata {
+        description: metadata.description,
+        denom_units: metadata
+            .denom_units
+            .into_iter()
+            .map(|unit| DenomUnit {
+                denom: unit.denom,
+                exponent: unit.exponent,
+                aliases: unit.aliases,
+            })
+            .collect(),
+        base: metadata.base,
+        display: metadata.display,
+        name: metadata.name,
+        symbol: metadata.symbol,
+        uri: metadata.uri,
+        uri_hash: metadata.uri_hash,
     }
 }
@@ -0,0 +1,52 @@
+use std::{cell::RefCell, rc::Rc};
+
+use cw_orch_core::environment::{
+    GovQuerier, ProposalStatus, Querier, QuerierGetter, StateInterface,
+};
+use cw_orch_core::CwEnvError;
+use osmosis_test_tube::osmosis_std::types::cosmos::gov::v1beta1::{
+    QueryProposalRequest, QueryProposalResponse,
+};
+use osmosis_test_tube::{OsmosisTestApp, Runner};
+
+use crate::{map_err, OsmosisTestTube};
+
+pub struct OsmosisTestTubeGovQuerier {
+    app: Rc<RefCell<OsmosisTestApp>>,
+}
+
+impl OsmosisTestTubeGovQuerier {
+    fn new<S: StateInterface>(mock: &OsmosisTestTube<S>) -> Self {
+        Self {
+            app: mock.app.clone(),
+        }
+    }
+}
+
+impl Querier for OsmosisTestTubeGovQuerier {
+    type Error = CwEnvError;
+}
+
+impl<S: StateInterface> QuerierGetter<OsmosisTestTubeGovQuerier> for OsmosisTestTube<S> {
+    fn querier(&self) -> OsmosisTestTubeGovQuerier {
+        OsmosisTestTubeGovQuerier::new(self)
+    }
+}
+
+impl GovQuerier for OsmosisTestTubeGovQuerier {
+    fn proposal_status(&self, proposal_id: u64) -> Result<ProposalStatus, Self::Error> {
+        let response: QueryProposalResponse = self
+            .app
+            .borrow()
+            .query(
+                "/cosmos.gov.v1beta1.Query/Proposal",
+                &QueryProposalRequest { proposal_id },
+            )
+            .map_err(map_err)?;
+        let proposal = response
+            .proposal
+            .ok_or_else(|| CwEnvError::StdErr(format!("proposal {proposal_id} not found")))?;
+        ProposalStatus::try_from(proposal.status)
+            .map_err(|e| CwEnvError::StdErr(format!("proposal {proposal_id}: {e}")))
+    }
+}
@@ -7,6 +7,7 @@ use super::OsmosisTestTube;
 
 pub mod bank;
 mod env;
+pub mod gov;
 pub mod node;
 pub mod wasm;
 
@@ -2,7 +2,7 @@ use cw_orch_core::environment::CwEnv;
 
 pub mod stargate;
 
-pub use stargate::Stargate;
+pub use stargate::{proto_any, stargate_any, Stargate};
 
 pub trait FullNode: CwEnv + Stargate {}
 
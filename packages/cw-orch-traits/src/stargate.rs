@@ -1,5 +1,6 @@
-use cw_orch_core::environment::TxHandler;
-use prost::Message;
+use cosmwasm_std::CosmosMsg;
+use cw_orch_core::{environment::TxHandler, CwEnvError};
+use prost::{Message, Name};
 use prost_types::Any;
 
 /// Alows the execution of stargate like messages on cw-orch environments
@@ -13,4 +14,103 @@ pub trait Stargate: TxHandler {
         msgs: Vec<Any>,
         memo: Option<&str>,
     ) -> Result<<Self as TxHandler>::Response, <Self as TxHandler>::Error>;
+
+    /// Execute messages that convert to a `CosmosMsg::Stargate`,
+    /// like the ones of `osmosis-std`, `injective-std` or `neutron-std`, without having to encode them
+    /// ```ignore
+    /// chain.commit_stargate::<MsgCreateDenomResponse>(
+    ///     vec![MsgCreateDenom { sender, subdenom }],
+    ///     None,
+    /// )?;
+    /// ```
+    fn commit_stargate<R: Message + Default>(
+        &self,
+        msgs: Vec<impl Into<CosmosMsg>>,
+        memo: Option<&str>,
+    ) -> Result<<Self as TxHandler>::Response, CwEnvError> {
+        let msgs = msgs
+            .into_iter()
+            .map(stargate_any)
+            .collect::<Result<Vec<_>, _>>()?;
+        self.commit_any::<R>(msgs, memo).map_err(Into::into)
+    }
+
+    /// Execute protobuf messages that know their type url, like the ones of `cosmos-sdk-proto` or `ibc-proto`
+    fn commit_proto<R: Message + Default>(
+        &self,
+        msgs: Vec<impl Name>,
+        memo: Option<&str>,
+    ) -> Result<<Self as TxHandler>::Response, <Self as TxHandler>::Error> {
+        self.commit_any::<R>(msgs.iter().map(proto_any).collect(), memo)
+    }
+}
+
+/// Encodes a message that converts to a `CosmosMsg::Stargate` as protobuf any.
+/// Errors if it converts to another kind of message.
+pub fn stargate_any(msg: impl Into<CosmosMsg>) -> Result<Any, CwEnvError> {
+    match msg.into() {
+        CosmosMsg::Stargate { type_url, value } => Ok(Any {
+            type_url,
+            value: value.to_vec(),
+        }),
+        msg => Err(CwEnvError::StdErr(format!(
+            "Expected a stargate message, got {msg:?}"
+        ))),
+    }
+}
+
+/// Encodes a protobuf message as protobuf any, with its type url
+pub fn proto_any<M: Name>(msg: &M) -> Any {
+    Any {
+        type_url: M::type_url(),
+        value: msg.encode_to_vec(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use cosmwasm_std::{Binary, CosmosMsg, Empty};
+
+    use super::*;
+
+    #[derive(Clone, PartialEq, Message)]
+    struct MsgCreateDenom {
+        #[prost(string, tag = "1")]
+        sender: String,
+        #[prost(string, tag = "2")]
+        subdenom: String,
+    }
+
+    impl Name for MsgCreateDenom {
+        const NAME: &'static str = "MsgCreateDenom";
+        const PACKAGE: &'static str = "osmosis.tokenfactory.v1beta1";
+    }
+
+    // Conversion generated by the `osmosis-std` derive macros
+    impl From<MsgCreateDenom> for CosmosMsg {
+        fn from(msg: MsgCreateDenom) -> Self {
+            CosmosMsg::Stargate {
+                type_url: MsgCreateDenom::type_url(),
+                value: Binary(msg.encode_to_vec()),
+            }
+        }
+    }
+
+    #[test]
+    fn encodes_stargate_and_proto_msgs() {
+        let msg = MsgCreateDenom {
+            sender: "osmo1sender".to_string(),
+            subdenom: "token".to_string(),
+        };
+
+        let any = stargate_any(msg.clone()).unwrap();
+        assert_eq!(any, proto_any(&msg));
+        assert_eq!(any.type_url, "/osmosis.tokenfactory.v1beta1.MsgCreateDenom");
+        assert_eq!(MsgCreateDenom::decode(any.value.as_slice()).unwrap(), msg);
+
+        stargate_any(CosmosMsg::<Empty>::Bank(cosmwasm_std::BankMsg::Burn {
+            amount: vec![],
+        }))
+        .unwrap_err();
+    }
 }
@@ -56,6 +56,14 @@ pub enum InterchainError {
     #[error("No packets were found while following packets")]
     NoPacketsFound {},
 
+    #[error("IBC packet n°{sequence} sent on {port}/{channel} stalled on {chain_id}, no acknowledgement was written for it")]
+    PacketStalled {
+        chain_id: String,
+        port: String,
+        channel: String,
+        sequence: u64,
+    },
+
     #[error("Failure acknowledgment received: {0:?}")]
     FailedAckReceived(String),
 }
@@ -0,0 +1,168 @@
+//! Multi-hop transfers through the [packet-forward-middleware](https://github.com/cosmos/ibc-apps/tree/main/middleware/packet-forward-middleware).
+//!
+//! The forwarding instructions are set in the memo of an ICS20 transfer:
+//! ```json
+//! {"forward": {"receiver": "osmo1...", "port": "transfer", "channel": "channel-1", "next": {"forward": {...}}}}
+//! ```
+//! The intermediate chain receives the packet and sends it out again on the indicated channel.
+//! Its acknowledgement is only written once the forwarded packet is acknowledged, so the whole path needs to succeed for the first hop to be acknowledged.
+
+use cosmwasm_std::Binary;
+use cw_orch_core::environment::{CwEnv, IndexResponse};
+use serde_json::Value;
+
+use crate::types::{FullIbcPacketAnalysis, IbcPacketOutcome, NetworkId};
+
+/// Forwarding instruction of a packet-forward-middleware memo
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ForwardHop {
+    /// Receiver on the chain the packet is forwarded to
+    pub receiver: String,
+    /// Port the packet is forwarded on
+    pub port: String,
+    /// Channel the packet is forwarded on
+    pub channel: String,
+}
+
+/// Forwarding instructions of an ICS20 transfer memo, in the order they are executed.
+/// Empty if the memo doesn't forward the transfer.
+pub fn forward_hops_from_memo(memo: &str) -> Vec<ForwardHop> {
+    let mut hops = vec![];
+    let mut next = serde_json::from_str::<Value>(memo).ok();
+    while let Some(forward) = next.as_ref().and_then(|memo| memo.get("forward")) {
+        let field = |key: &str| forward.get(key).and_then(Value::as_str).map(str::to_string);
+        let (Some(receiver), Some(port), Some(channel)) =
+            (field("receiver"), field("port"), field("channel"))
+        else {
+            break;
+        };
+        hops.push(ForwardHop {
+            receiver,
+            port,
+            channel,
+        });
+        // The next memo can either be an object or a string containing its json
+        next = match forward.get("next") {
+            Some(Value::String(next)) => serde_json::from_str(next).ok(),
+            next => next.cloned(),
+        };
+    }
+    hops
+}
+
+/// Forwarding instructions of an ICS20 packet, from the json packet data
+pub fn forward_hops(packet_data: &str) -> Vec<ForwardHop> {
+    serde_json::from_str::<Value>(packet_data)
+        .ok()
+        .and_then(|data| data.get("memo").and_then(Value::as_str).map(str::to_string))
+        .map(|memo| forward_hops_from_memo(&memo))
+        .unwrap_or_default()
+}
+
+/// Step of a packet on its path to its final destination
+#[derive(Debug, Clone, PartialEq)]
+pub struct PacketHop {
+    /// Chain that sent the packet
+    pub src_chain: NetworkId,
+    /// Chain that received the packet, unknown if the packet timed out
+    pub dst_chain: Option<NetworkId>,
+    /// Outcome of the packet on this hop
+    pub outcome: HopOutcome,
+}
+
+/// Outcome of a [`PacketHop`]
+#[derive(Debug, Clone, PartialEq)]
+pub enum HopOutcome {
+    /// The packet was received and acknowledged with `ack`
+    Acknowledged {
+        /// Raw acknowledgement of the hop
+        ack: Binary,
+    },
+    /// The packet timed out
+    Timeout,
+}
+
+impl<Chain: CwEnv> FullIbcPacketAnalysis<Chain> {
+    /// Path of the packet, followed across the chains it was forwarded through by the packet-forward-middleware.
+    /// The first hop is this packet, a single hop is returned if it wasn't forwarded.
+    pub fn hops(&self) -> Vec<PacketHop> {
+        let mut hops = vec![];
+        let mut packet = Some(self);
+        while let Some(current) = packet {
+            let src_chain = current
+                .send_tx
+                .as_ref()
+                .map(|tx| tx.chain_id.clone())
+                .unwrap_or_default();
+            packet = None;
+            match &current.outcome {
+                IbcPacketOutcome::Timeout { .. } => hops.push(PacketHop {
+                    src_chain,
+                    dst_chain: None,
+                    outcome: HopOutcome::Timeout,
+                }),
+                IbcPacketOutcome::Success {
+                    receive_tx, ack, ..
+                } => {
+                    hops.push(PacketHop {
+                        src_chain,
+                        dst_chain: Some(receive_tx.tx_id.chain_id.clone()),
+                        outcome: HopOutcome::Acknowledged { ack: ack.clone() },
+                    });
+                    // The forwarded packet is sent in the transaction receiving the packet
+                    let forwarded = receive_tx
+                        .tx_id
+                        .response
+                        .event_attr_values("recv_packet", "packet_data")
+                        .iter()
+                        .any(|data| !forward_hops(data).is_empty());
+                    if forwarded {
+                        packet = receive_tx.packets.first();
+                    }
+                }
+            }
+        }
+        hops
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parses_nested_forwards() {
+        let memo = r#"{"forward": {"receiver": "osmo1receiver", "port": "transfer", "channel": "channel-1", "next": "{\"forward\": {\"receiver\": \"juno1receiver\", \"port\": \"transfer\", \"channel\": \"channel-2\"}}"}}"#;
+        let packet_data = serde_json::json!({
+            "amount": "100",
+            "denom": "uatom",
+            "receiver": "pfm",
+            "sender": "cosmos1sender",
+            "memo": memo,
+        })
+        .to_string();
+
+        assert_eq!(
+            forward_hops(&packet_data),
+            vec![
+                ForwardHop {
+                    receiver: "osmo1receiver".to_string(),
+                    port: "transfer".to_string(),
+                    channel: "channel-1".to_string(),
+                },
+                ForwardHop {
+                    receiver: "juno1receiver".to_string(),
+                    port: "transfer".to_string(),
+                    channel: "channel-2".to_string(),
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn ignores_other_memos() {
+        assert!(forward_hops_from_memo("").is_empty());
+        assert!(forward_hops_from_memo(r#"{"wasm": {"contract": "juno1contract"}}"#).is_empty());
+        assert!(forward_hops(r#"{"amount": "100", "denom": "uatom"}"#).is_empty());
+    }
+}
@@ -8,6 +8,9 @@ pub mod channel;
 /// Contains definitions of the main trait exposed by this crate
 pub mod env;
 
+/// Contains helpers for packets forwarded across multiple chains
+pub mod forward;
+
 /// Contains default acknowledgment parsers
 mod ack_parser;
 mod error;
@@ -1,5 +1,7 @@
 #![allow(missing_docs)]
 
+use std::time::Duration;
+
 use cosmwasm_std::StdError;
 use cw_orch_interchain_core::{channel::InterchainChannel, types::NetworkId, InterchainError};
 use thiserror::Error;
@@ -45,6 +47,15 @@ pub enum InterchainDaemonError {
 
     #[error("Configuration already registered for chain {0}")]
     AlreadyRegistered(String),
+
+    #[error("Relayer error: {0}")]
+    Relayer(String),
+
+    #[error("query of chain {chain_id} timed out after {timeout:?}")]
+    QueryTimeout {
+        chain_id: NetworkId,
+        timeout: Duration,
+    },
 }
 
 impl From<InterchainDaemonError> for InterchainError {
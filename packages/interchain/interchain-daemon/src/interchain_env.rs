@@ -12,6 +12,7 @@ use tonic::transport::Channel;
 
 use crate::channel_creator::{ChannelCreationValidator, ChannelCreator};
 use crate::interchain_log::InterchainLog;
+use crate::interchain_query::InterchainQuery;
 use crate::packet_inspector::PacketInspector;
 use ibc_relayer_types::core::ics24_host::identifier::{ChannelId, PortId};
 
@@ -126,6 +127,12 @@ impl<C: ChannelCreator> DaemonInterchainEnv<C> {
         Ok(())
     }
 
+    /// Runs queries against all the chains of the environment concurrently, see [`InterchainQuery`]
+    pub fn interchain_query(&self) -> InterchainQuery {
+        InterchainQuery::new(self.daemons.values().cloned())
+            .expect("the daemons of the environment are indexed by chain id")
+    }
+
     /// Enables logging on multiple files to separate chains from each other
     pub fn with_log(&mut self) {
         let log = InterchainLog::default();
@@ -0,0 +1,323 @@
+//! Concurrent queries of many chains, see [`InterchainQuery`].
+
+use std::{collections::HashMap, future::Future, time::Duration};
+
+use cosmwasm_std::{from_json, to_json_vec, Coin, Validator};
+use cw_orch_daemon::{
+    queriers::{Bank, CosmWasm, Staking, StakingBondStatus},
+    DaemonBase, DaemonError, QuerySender, RUNTIME,
+};
+use cw_orch_interchain_core::types::NetworkId;
+use futures::future::join_all;
+use serde::{de::DeserializeOwned, Serialize};
+use tokio::runtime::Handle;
+use tonic::transport::Channel;
+
+use crate::{IcDaemonResult, InterchainDaemonError};
+
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Runs the same query against many chains concurrently, for instance to build cross-chain dashboards.
+///
+/// Every chain gets [`InterchainQuery::timeout`] to answer. A chain that fails or doesn't answer in time
+/// doesn't fail the other ones, its error is reported in the [`InterchainQueryResult`].
+///
+/// ```no_run
+/// use cw_orch_daemon::{networks, queriers::Bank, Daemon};
+/// use cw_orch_interchain_daemon::interchain_query::InterchainQuery;
+///
+/// let juno = Daemon::builder(networks::JUNO_1).build_sender(()).unwrap();
+/// let osmosis = Daemon::builder(networks::OSMOSIS_1).build_sender(()).unwrap();
+/// let query = InterchainQuery::new([juno, osmosis]).unwrap();
+///
+/// let supplies = query.query(|_chain_id, channel| async move {
+///     Bank::new_async(channel)._total_supply().await
+/// });
+/// for chain_id in supplies.failed_chains() {
+///     println!("{chain_id} is unavailable");
+/// }
+/// let (supplies, _failures) = supplies.partition();
+/// ```
+#[derive(Clone)]
+pub struct InterchainQuery {
+    channels: HashMap<NetworkId, Channel>,
+    timeout: Duration,
+    rt_handle: Handle,
+}
+
+/// Per-chain results of an [`InterchainQuery`]
+#[derive(Debug)]
+pub struct InterchainQueryResult<T> {
+    /// Result of every queried chain, indexed by chain id
+    pub results: HashMap<NetworkId, IcDaemonResult<T>>,
+}
+
+impl<T> InterchainQueryResult<T> {
+    /// Splits the responses of the chains that answered from the errors of the ones that failed
+    pub fn partition(
+        self,
+    ) -> (
+        HashMap<NetworkId, T>,
+        HashMap<NetworkId, InterchainDaemonError>,
+    ) {
+        let mut responses = HashMap::new();
+        let mut failures = HashMap::new();
+        for (chain_id, result) in self.results {
+            match result {
+                Ok(response) => {
+                    responses.insert(chain_id, response);
+                }
+                Err(e) => {
+                    failures.insert(chain_id, e);
+                }
+            }
+        }
+        (responses, failures)
+    }
+
+    /// Chain ids of the chains that failed, sorted
+    pub fn failed_chains(&self) -> Vec<&NetworkId> {
+        let mut failed: Vec<_> = self
+            .results
+            .iter()
+            .filter_map(|(chain_id, result)| result.is_err().then_some(chain_id))
+            .collect();
+        failed.sort();
+        failed
+    }
+
+    /// The responses of all the chains, or the error of the first failed chain by chain id
+    pub fn into_result(self) -> IcDaemonResult<HashMap<NetworkId, T>> {
+        let (responses, failures) = self.partition();
+        match failures.into_iter().min_by(|(a, _), (b, _)| a.cmp(b)) {
+            Some((_, e)) => Err(e),
+            None => Ok(responses),
+        }
+    }
+}
+
+impl InterchainQuery {
+    /// Query the chains of `daemons`, identified by their chain id.
+    /// Errors with [`InterchainDaemonError::AlreadyRegistered`] if two daemons are connected to the same chain.
+    pub fn new<Sender: QuerySender>(
+        daemons: impl IntoIterator<Item = DaemonBase<Sender>>,
+    ) -> IcDaemonResult<Self> {
+        let mut channels = HashMap::new();
+        let mut rt_handle = None;
+        for daemon in daemons {
+            let chain_id = daemon.chain_info().chain_id.clone();
+            if channels
+                .insert(chain_id.clone(), daemon.channel())
+                .is_some()
+            {
+                return Err(InterchainDaemonError::AlreadyRegistered(chain_id));
+            }
+            rt_handle.get_or_insert_with(|| daemon.rt_handle.clone());
+        }
+        Ok(Self {
+            channels,
+            timeout: DEFAULT_TIMEOUT,
+            rt_handle: rt_handle.unwrap_or_else(|| RUNTIME.handle().clone()),
+        })
+    }
+
+    /// Set how long every chain has to answer, 30 seconds by default
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Chain ids of the queried chains
+    pub fn chain_ids(&self) -> Vec<&NetworkId> {
+        self.channels.keys().collect()
+    }
+
+    /// Runs `query` against all the chains concurrently, with the gRPC channel of every chain
+    pub async fn _query<T, F, Fut>(&self, query: F) -> InterchainQueryResult<T>
+    where
+        F: Fn(NetworkId, Channel) -> Fut,
+        Fut: Future<Output = Result<T, DaemonError>>,
+    {
+        let inputs = self
+            .channels
+            .keys()
+            .map(|chain_id| (chain_id.clone(), ()))
+            .collect();
+        self.fan_out(inputs, |chain_id, channel, ()| query(chain_id, channel))
+            .await
+    }
+
+    /// Runs `query` against all the chains concurrently, see [`InterchainQuery::_query`]
+    pub fn query<T, F, Fut>(&self, query: F) -> InterchainQueryResult<T>
+    where
+        F: Fn(NetworkId, Channel) -> Fut,
+        Fut: Future<Output = Result<T, DaemonError>>,
+    {
+        self.rt_handle.block_on(self._query(query))
+    }
+
+    /// Smart query of a contract on every chain of `contracts`, which maps chain ids to contract addresses
+    pub fn smart<Q: Serialize, T: DeserializeOwned>(
+        &self,
+        contracts: &HashMap<NetworkId, String>,
+        query_msg: &Q,
+    ) -> InterchainQueryResult<T> {
+        let inputs = contracts
+            .iter()
+            .map(|(chain_id, address)| (chain_id.clone(), address.clone()))
+            .collect();
+        self.rt_handle
+            .block_on(self.fan_out(inputs, |_, channel, address| async move {
+                let response = CosmWasm::new_async(channel)
+                    ._contract_state(address, to_json_vec(query_msg)?)
+                    .await?;
+                Ok(from_json(response)?)
+            }))
+    }
+
+    /// Bank balances of an address on every chain of `addresses`, which maps chain ids to addresses.
+    /// If denom is None, returns all balances
+    pub fn balance(
+        &self,
+        addresses: &HashMap<NetworkId, String>,
+        denom: Option<String>,
+    ) -> InterchainQueryResult<Vec<Coin>> {
+        let inputs = addresses
+            .iter()
+            .map(|(chain_id, address)| (chain_id.clone(), address.clone()))
+            .collect();
+        self.rt_handle
+            .block_on(self.fan_out(inputs, |_, channel, address| {
+                let denom = denom.clone();
+                async move { Bank::new_async(channel)._balance(address, denom).await }
+            }))
+    }
+
+    /// Validators matching `status` on every chain
+    pub fn validators(&self, status: StakingBondStatus) -> InterchainQueryResult<Vec<Validator>> {
+        self.query(
+            |_, channel| async move { Staking::new_async(channel)._validators(status).await },
+        )
+    }
+
+    /// Runs `query` concurrently with the input of every chain of `inputs`
+    async fn fan_out<I, T, F, Fut>(
+        &self,
+        inputs: Vec<(NetworkId, I)>,
+        query: F,
+    ) -> InterchainQueryResult<T>
+    where
+        F: Fn(NetworkId, Channel, I) -> Fut,
+        Fut: Future<Output = Result<T, DaemonError>>,
+    {
+        let queries = inputs.into_iter().map(|(chain_id, input)| {
+            let channel = self.channels.get(&chain_id).cloned();
+            let query = channel.map(|channel| query(chain_id.clone(), channel, input));
+            async move {
+                let result = match query {
+                    None => Err(InterchainDaemonError::DaemonNotFound(chain_id.clone())),
+                    Some(query) => match tokio::time::timeout(self.timeout, query).await {
+                        Ok(result) => result.map_err(Into::into),
+                        Err(_) => Err(InterchainDaemonError::QueryTimeout {
+                            chain_id: chain_id.clone(),
+                            timeout: self.timeout,
+                        }),
+                    },
+                };
+                if let Err(e) = &result {
+                    log::warn!("Query of chain {chain_id} failed: {e}");
+                }
+                (chain_id, result)
+            }
+        });
+        InterchainQueryResult {
+            results: join_all(queries).await.into_iter().collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn interchain_query(chain_ids: &[&str], timeout: Duration) -> InterchainQuery {
+        // The channels are never connected, the queries of the tests don't use them
+        let channel = Channel::from_static("http://localhost:9090").connect_lazy();
+        InterchainQuery {
+            channels: chain_ids
+                .iter()
+                .map(|chain_id| (chain_id.to_string(), channel.clone()))
+                .collect(),
+            timeout,
+            rt_handle: Handle::current(),
+        }
+    }
+
+    async fn query_height(chain_id: NetworkId) -> Result<u64, DaemonError> {
+        match chain_id.as_str() {
+            "juno-1" => Ok(1),
+            "osmosis-1" => Ok(2),
+            "slow-1" => {
+                tokio::time::sleep(Duration::from_secs(60)).await;
+                Ok(3)
+            }
+            _ => Err(DaemonError::StdErr(format!("{chain_id} is down"))),
+        }
+    }
+
+    #[tokio::test]
+    async fn failed_chains_are_reported() {
+        let query = interchain_query(
+            &["osmosis-1", "neutron-1", "juno-1", "cosmoshub-4"],
+            DEFAULT_TIMEOUT,
+        );
+        let result = query._query(|chain_id, _| query_height(chain_id)).await;
+
+        assert_eq!(result.failed_chains(), vec!["cosmoshub-4", "neutron-1"]);
+        match result.into_result() {
+            Err(InterchainDaemonError::Daemon(DaemonError::StdErr(e))) => {
+                assert_eq!(e, "cosmoshub-4 is down")
+            }
+            other => panic!("expected the error of cosmoshub-4, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn partition_splits_responses_and_failures() {
+        let query = interchain_query(&["juno-1", "osmosis-1", "neutron-1"], DEFAULT_TIMEOUT);
+        let (responses, failures) = query
+            ._query(|chain_id, _| query_height(chain_id))
+            .await
+            .partition();
+
+        assert_eq!(
+            responses,
+            HashMap::from([("juno-1".to_string(), 1), ("osmosis-1".to_string(), 2)])
+        );
+        assert_eq!(failures.len(), 1);
+        assert!(failures.contains_key("neutron-1"));
+
+        let query = interchain_query(&["juno-1", "osmosis-1"], DEFAULT_TIMEOUT);
+        let responses = query
+            ._query(|chain_id, _| query_height(chain_id))
+            .await
+            .into_result()
+            .unwrap();
+        assert_eq!(responses.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn slow_chains_time_out() {
+        let timeout = Duration::from_millis(50);
+        let query = interchain_query(&["juno-1", "slow-1"], timeout);
+        let result = query._query(|chain_id, _| query_height(chain_id)).await;
+
+        assert_eq!(result.failed_chains(), vec!["slow-1"]);
+        assert!(matches!(
+            &result.results["slow-1"],
+            Err(InterchainDaemonError::QueryTimeout { chain_id, timeout: t })
+                if chain_id == "slow-1" && *t == timeout
+        ));
+        assert!(matches!(result.results["juno-1"], Ok(1)));
+    }
+}
@@ -5,7 +5,9 @@
 mod channel_creator;
 pub mod error;
 mod interchain_env;
+pub mod interchain_query;
 pub mod packet_inspector;
+mod relayer;
 // Tracking IBC state
 pub mod ibc_tracker;
 pub mod interchain_log;
@@ -19,3 +21,5 @@ pub type IcDaemonResult<R> = Result<R, InterchainDaemonError>;
 pub use channel_creator::{ChannelCreationValidator, ChannelCreator};
 
 pub use interchain_env::DaemonInterchainEnv;
+pub use interchain_query::{InterchainQuery, InterchainQueryResult};
+pub use relayer::{DockerRelayer, DockerRelayerBuilder, RelayerKind};
@@ -8,7 +8,8 @@ use cw_orch_daemon::TxResultBlockEvent;
 use cw_orch_daemon::{CosmTxResponse, Daemon, DaemonError};
 use cw_orch_interchain_core::channel::{IbcPort, InterchainChannel};
 use cw_orch_interchain_core::env::ChainId;
-use futures_util::future::select_all;
+use cw_orch_interchain_core::InterchainError;
+use futures_util::future::select_ok;
 use futures_util::FutureExt;
 use ibc_relayer_types::core::ics04_channel::channel::State;
 
@@ -183,7 +184,10 @@ impl PacketInspector {
             Ok(dst_grpc_channel.clone())
         } else {
             // If no custom channel was registered, we try to get it from the registry
-            let chain_data: ChainInfoOwned = parse_network(chain_id).unwrap().into(); // TODO, no unwrap here ?
+            // Packets can be forwarded through chains that are not registered
+            let chain_data: ChainInfoOwned = parse_network(chain_id)
+                .map_err(|_| InterchainError::ChainNotFound(chain_id.to_string()))?
+                .into();
             Ok(GrpcChannel::connect(&chain_data.grpc_urls, chain_id).await?)
         }
     }
@@ -235,16 +239,17 @@ impl PacketInspector {
         // 1. The transfer succeeds, this is covered by the `InterchainChannel::follow_packet_cycle` method
         // 2. The transfer errors and the packet times out. This is covered by the `InterchainChannel::follow_packet_timeout` method
         // If either of those functions succeeds, the other one will never succeeds. That's why we are racing those 2 functions here.
+        // If both fail, the error of the packet cycle is returned as it finishes last, it tells where a forwarded packet stalled.
 
-        let (result, _, _) = select_all(vec![
+        let (result, _) = select_ok(vec![
             self.follow_packet_cycle(src_chain, &ibc_channel, sequence)
-                .boxed(),
+                .boxed_local(),
             self.follow_packet_timeout(src_chain, &ibc_channel, sequence)
-                .boxed(),
+                .boxed_local(),
         ])
-        .await;
+        .await?;
 
-        result
+        Ok(result)
     }
 
     /// This functions follows an IBC packet on the remote chain and back on its origin chain. It returns all encountered tx hashes
@@ -282,6 +287,7 @@ impl PacketInspector {
         if received_tx.code != 0 {
             return Err(DaemonError::TxFailed {
                 code: received_tx.code,
+                codespace: received_tx.codespace.clone(),
                 reason: format!(
                     "Raw log on {} : {}",
                     dst_port.chain_id,
@@ -292,20 +298,25 @@ impl PacketInspector {
         }
 
         // 2. We get the events related to the acknowledgements sent back on the remote chain
-
-        let all_recv_events = received_tx.get_events("write_acknowledgement");
-        let recv_event = all_recv_events
-            .iter()
-            .filter(|e| {
-                e.get_first_attribute_value("packet_sequence").unwrap() == sequence.to_string()
-            })
-            .collect::<Vec<_>>()[0];
-
-        let recv_packet_sequence = recv_event
-            .get_first_attribute_value("packet_sequence")
-            .unwrap();
-        let recv_packet_data = recv_event.get_first_attribute_value("packet_data").unwrap();
-        let acknowledgment = recv_event.get_first_attribute_value("packet_ack").unwrap();
+        // When the packet is forwarded to another chain, e.g. by the packet-forward-middleware,
+        // the acknowledgement is written later, once the forwarded packet is acknowledged
+
+        let (recv_packet_data, acknowledgment) =
+            match written_ack(&received_tx, &dst_port.channel, sequence) {
+                Some(written) => written,
+                None => {
+                    let ack_tx = self
+                        .get_async_ack_tx(from, ibc_channel, sequence, &received_tx)
+                        .await?;
+                    written_ack(&ack_tx, &dst_port.channel, sequence).ok_or_else(|| {
+                        DaemonError::ibc_err(format!(
+                            "No acknowledgement found for packet n°{sequence} in tx {}",
+                            ack_tx.txhash
+                        ))
+                    })?
+                }
+            };
+        let recv_packet_sequence = sequence;
 
         // We try to unpack the acknowledgement if possible, when it's following the standard format (is not enforced so it's not always possible)
         let decoded_ack_string =
@@ -329,6 +340,7 @@ impl PacketInspector {
         if ack_tx.code != 0 {
             return Err(DaemonError::TxFailed {
                 code: ack_tx.code,
+                codespace: ack_tx.codespace.clone(),
                 reason: format!(
                     "Raw log on {} : {}",
                     src_port.chain_id.clone(),
@@ -384,6 +396,7 @@ impl PacketInspector {
         if timeout_tx.code != 0 {
             return Err(DaemonError::TxFailed {
                 code: timeout_tx.code,
+                codespace: timeout_tx.codespace.clone(),
                 reason: format!(
                     "Raw log on {} : {}",
                     src_port.chain_id,
@@ -486,6 +499,69 @@ impl PacketInspector {
         Self::get_tx_by_events_and_assert_one(dst_port.chain, receive_events_string).await
     }
 
+    /// Waits for the acknowledgement of a packet that was forwarded to another chain when it was received.
+    /// If it never comes, the packets sent when receiving it are followed to report on which chain the transfer stalled.
+    async fn get_async_ack_tx<'a>(
+        &self,
+        from: ChainId<'a>,
+        ibc_channel: &'a InterchainChannel<Channel>,
+        packet_sequence: Sequence,
+        received_tx: &CosmTxResponse,
+    ) -> Result<CosmTxResponse, InterchainDaemonError> {
+        let (src_port, dst_port) = ibc_channel.get_ordered_ports_from(from)?;
+        let dst_channel = dst_port
+            .channel
+            .clone()
+            .ok_or(DaemonError::ibc_err(format!(
+                "No channel registered between {:?} and {:?}",
+                src_port, dst_port
+            )))?;
+
+        log::info!(
+            target: &dst_port.chain_id,
+            "IBC packet n°{} received on {} on tx {} was forwarded, waiting for its acknowledgment",
+            packet_sequence,
+            dst_port.chain_id,
+            received_tx.txhash
+        );
+
+        let ack_events_string = vec![
+            format!("write_acknowledgement.packet_dst_port='{}'", dst_port.port),
+            format!("write_acknowledgement.packet_dst_channel='{}'", dst_channel),
+            format!(
+                "write_acknowledgement.packet_sequence='{}'",
+                packet_sequence
+            ),
+        ];
+
+        match Self::get_tx_by_events_and_assert_one(dst_port.chain.clone(), ack_events_string).await
+        {
+            Ok(ack_tx) => Ok(ack_tx),
+            Err(e) => {
+                log::debug!(
+                    target: &dst_port.chain_id,
+                    "Acknowledgment of IBC packet n°{} not found: {}",
+                    packet_sequence,
+                    e
+                );
+                // Following the forwarded packets errors on the chain where they stalled
+                self.wait_ibc(dst_port.chain_id.clone(), received_tx.clone())
+                    .await?;
+                Err(InterchainError::PacketStalled {
+                    chain_id: dst_port.chain_id.clone(),
+                    port: src_port.port.to_string(),
+                    channel: src_port
+                        .channel
+                        .as_ref()
+                        .map(ToString::to_string)
+                        .unwrap_or_default(),
+                    sequence: packet_sequence.into(),
+                }
+                .into())
+            }
+        }
+    }
+
     // on is the chain on which the packet will be received
     pub async fn get_packet_timeout_tx<'a>(
         &self,
@@ -541,6 +617,29 @@ impl PacketInspector {
     }
 }
 
+/// Packet data and acknowledgement written for the packet `sequence` received on `dst_channel` in `tx`
+fn written_ack(
+    tx: &CosmTxResponse,
+    dst_channel: &Option<ChannelId>,
+    sequence: Sequence,
+) -> Option<(String, String)> {
+    tx.get_events("write_acknowledgement")
+        .into_iter()
+        .find(|e| {
+            e.get_first_attribute_value("packet_sequence") == Some(sequence.to_string())
+                && dst_channel.as_ref().map_or(true, |channel| {
+                    e.get_first_attribute_value("packet_dst_channel")
+                        .map_or(true, |c| c == channel.to_string())
+                })
+        })
+        .and_then(|e| {
+            Some((
+                e.get_first_attribute_value("packet_data")?,
+                e.get_first_attribute_value("packet_ack")?,
+            ))
+        })
+}
+
 fn get_events(events: &[TxResultBlockEvent], attr_name: &str) -> Vec<String> {
     events
         .iter()
@@ -0,0 +1,748 @@
+//! Hermes or rly relayer running in Docker, configured from the daemons of the interchain environment.
+//!
+//! ```no_run
+//! # use cw_orch_daemon::Daemon;
+//! # use cw_orch_interchain_daemon::{ChannelCreator, DockerRelayer};
+//! # let (juno, juno_rpc): (Daemon, String) = todo!();
+//! # let (osmosis, osmosis_rpc): (Daemon, String) = todo!();
+//! let relayer = DockerRelayer::hermes()
+//!     .chain(&juno, juno_rpc)
+//!     .chain(&osmosis, osmosis_rpc)
+//!     .mnemonic("relayer mnemonic ...")
+//!     .start()
+//!     .unwrap();
+//! let interchain = relayer.interchain_env();
+//! ```
+
+use std::{
+    collections::HashSet,
+    fmt::Write,
+    path::PathBuf,
+    process::Command,
+    sync::{Arc, Mutex},
+};
+
+use cosmwasm_std::{coins, IbcOrder};
+use cw_orch_core::environment::ChainInfoOwned;
+use cw_orch_daemon::{
+    docker::{remove_container, run},
+    keys::private::PrivateKey,
+    Daemon,
+};
+use cw_orch_interchain_core::env::ChainId;
+use ibc_relayer_types::core::ics24_host::identifier::PortId;
+use serde_json::{json, Value};
+
+use crate::{
+    channel_creator::ChannelCreator, interchain_env::DaemonInterchainEnv, IcDaemonResult,
+    InterchainDaemonError,
+};
+
+const HERMES_IMAGE: &str = "ghcr.io/informalsystems/hermes:1.8.2";
+const RLY_IMAGE: &str = "ghcr.io/cosmos/relayer:v2.5.2";
+const DEFAULT_CONTAINER_NAME: &str = "cw-orch-relayer";
+const KEY_NAME: &str = "relayer";
+/// Amount of gas tokens sent to the relayer key on every chain
+const DEFAULT_FUNDS: u128 = 10_000_000;
+
+/// Relayer implementation run by a [`DockerRelayer`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RelayerKind {
+    /// [Hermes](https://github.com/informalsystems/hermes)
+    Hermes,
+    /// [rly](https://github.com/cosmos/relayer), the Go relayer
+    Rly,
+}
+
+impl RelayerKind {
+    fn default_image(&self) -> &'static str {
+        match self {
+            RelayerKind::Hermes => HERMES_IMAGE,
+            RelayerKind::Rly => RLY_IMAGE,
+        }
+    }
+
+    /// Home directory of the relayer in the container, the generated configuration is mounted there
+    fn home(&self) -> &'static str {
+        match self {
+            RelayerKind::Hermes => "/root/.hermes",
+            RelayerKind::Rly => "/root/.relayer",
+        }
+    }
+
+    fn binary(&self) -> &'static str {
+        match self {
+            RelayerKind::Hermes => "hermes",
+            RelayerKind::Rly => "rly",
+        }
+    }
+}
+
+/// Chain relayed by the relayer
+#[derive(Clone)]
+struct RelayedChain {
+    daemon: Daemon,
+    rpc_url: String,
+}
+
+/// Create a [`DockerRelayer`] through [`DockerRelayerBuilder`]
+#[derive(Clone)]
+pub struct DockerRelayerBuilder {
+    kind: RelayerKind,
+    image: String,
+    container_name: String,
+    mnemonic: Option<String>,
+    funds: u128,
+    chains: Vec<RelayedChain>,
+}
+
+impl DockerRelayerBuilder {
+    /// Builder of a relayer of `kind`, running its default image
+    pub fn new(kind: RelayerKind) -> Self {
+        Self {
+            kind,
+            image: kind.default_image().to_string(),
+            container_name: DEFAULT_CONTAINER_NAME.to_string(),
+            mnemonic: None,
+            funds: DEFAULT_FUNDS,
+            chains: vec![],
+        }
+    }
+
+    /// Relay packets of the chain of `daemon`, whose Tendermint RPC endpoint is `rpc_url`.
+    /// The relayer key is funded by the sender of `daemon`.
+    pub fn chain(&mut self, daemon: &Daemon, rpc_url: impl Into<String>) -> &mut Self {
+        self.chains.push(RelayedChain {
+            daemon: daemon.clone(),
+            rpc_url: rpc_url.into(),
+        });
+        self
+    }
+
+    /// Set the mnemonic of the relayer key, used on all the chains
+    pub fn mnemonic(&mut self, mnemonic: impl Into<String>) -> &mut Self {
+        self.mnemonic = Some(mnemonic.into());
+        self
+    }
+
+    /// Set the amount of gas tokens sent to the relayer key on every chain
+    pub fn funds(&mut self, amount: u128) -> &mut Self {
+        self.funds = amount;
+        self
+    }
+
+    /// Set the docker image to run, it needs to match the [`RelayerKind`] of the builder
+    pub fn image(&mut self, image: impl Into<String>) -> &mut Self {
+        self.image = image.into();
+        self
+    }
+
+    /// Set the name of the container. An existing container with the same name is replaced.
+    pub fn container_name(&mut self, name: impl Into<String>) -> &mut Self {
+        self.container_name = name.into();
+        self
+    }
+
+    /// Generate the relayer config, start the relayer container, fund its keys and check that it is healthy
+    pub fn start(&self) -> IcDaemonResult<DockerRelayer> {
+        let mnemonic = self
+            .mnemonic
+            .clone()
+            .ok_or_else(|| InterchainDaemonError::Relayer("missing relayer mnemonic".into()))?;
+        if self.chains.is_empty() {
+            return Err(InterchainDaemonError::Relayer("no chain to relay".into()));
+        }
+
+        let config_dir = std::env::temp_dir().join(&self.container_name);
+        std::fs::create_dir_all(&config_dir)?;
+        match self.kind {
+            RelayerKind::Hermes => {
+                std::fs::write(config_dir.join("config.toml"), self.hermes_config()?)?;
+                std::fs::write(config_dir.join("mnemonic.txt"), &mnemonic)?;
+            }
+            RelayerKind::Rly => {
+                let chains_dir = config_dir.join("chains");
+                std::fs::create_dir_all(&chains_dir)?;
+                for chain in &self.chains {
+                    let chain_info = chain.daemon.chain_info();
+                    std::fs::write(
+                        chains_dir.join(format!("{}.json", chain_info.chain_id)),
+                        rly_chain_config(chain_info, &chain.rpc_url).to_string(),
+                    )?;
+                }
+            }
+        }
+
+        // Replace any leftover container of a previous run
+        remove_container(&self.container_name);
+        run(Command::new("docker")
+            .args(["run", "-d", "--network", "host", "--user", "root"])
+            .args(["--name", &self.container_name])
+            .arg("-v")
+            .arg(format!("{}:{}", config_dir.display(), self.kind.home()))
+            .args(["--entrypoint", "sleep", &self.image, "infinity"]))?;
+        let container = Arc::new(RelayerContainer {
+            name: self.container_name.clone(),
+            kind: self.kind,
+            config_dir,
+        });
+
+        if self.kind == RelayerKind::Rly {
+            container.exec(&["config", "init"])?;
+        }
+        for chain in &self.chains {
+            let chain_info = chain.daemon.chain_info();
+            container.add_key(chain_info, &mnemonic)?;
+
+            // The relayer key is derived the same way as a daemon sender
+            let relayer_address =
+                PrivateKey::mnemonic_address(&mnemonic, &chain_info.network_info)?;
+            log::info!(
+                "Funding relayer key {relayer_address} on {}",
+                chain_info.chain_id
+            );
+            chain
+                .daemon
+                .rt_handle
+                .block_on(chain.daemon.sender().bank_send(
+                    &relayer_address,
+                    coins(self.funds, chain_info.gas_denom.clone()),
+                ))?;
+        }
+
+        let chain_ids: Vec<String> = self
+            .chains
+            .iter()
+            .map(|chain| chain.daemon.chain_info().chain_id.clone())
+            .collect();
+        match self.kind {
+            RelayerKind::Hermes => container.exec_detached(&["start"])?,
+            // rly relays the paths it is started with, which are started once they're linked
+            RelayerKind::Rly => {
+                for (src, dst) in chain_pairs(&chain_ids) {
+                    container.exec(&["paths", "new", src, dst, &rly_path(src, dst)])?;
+                }
+            }
+        }
+        let relayer = DockerRelayer {
+            container,
+            daemons: self.chains.iter().map(|c| c.daemon.clone()).collect(),
+            chain_ids,
+            started_paths: Default::default(),
+        };
+        relayer.health_check()?;
+        Ok(relayer)
+    }
+
+    /// Hermes configuration relaying between all the registered chains
+    pub fn hermes_config(&self) -> IcDaemonResult<String> {
+        let mut config = String::from(HERMES_GLOBAL_CONFIG);
+        for chain in &self.chains {
+            config.push_str(&hermes_chain_config(
+                chain.daemon.chain_info(),
+                &chain.rpc_url,
+            )?);
+        }
+        Ok(config)
+    }
+}
+
+const HERMES_GLOBAL_CONFIG: &str = r#"[global]
+log_level = 'info'
+
+[mode.clients]
+enabled = true
+refresh = true
+misbehaviour = false
+
+[mode.connections]
+enabled = true
+
+[mode.channels]
+enabled = true
+
+[mode.packets]
+enabled = true
+clear_interval = 100
+clear_on_start = true
+tx_confirmation = true
+
+[rest]
+enabled = false
+host = '127.0.0.1'
+port = 3000
+
+[telemetry]
+enabled = false
+host = '127.0.0.1'
+port = 3001
+"#;
+
+/// `[[chains]]` entry of the Hermes configuration
+fn hermes_chain_config(chain_info: &ChainInfoOwned, rpc_url: &str) -> IcDaemonResult<String> {
+    let grpc_url = chain_info.grpc_urls.first().ok_or_else(|| {
+        InterchainDaemonError::Relayer(format!(
+            "no gRPC URL configured for {}, Hermes needs one",
+            chain_info.chain_id
+        ))
+    })?;
+    let websocket_url = format!(
+        "{}/websocket",
+        rpc_url.trim_end_matches('/').replacen("http", "ws", 1)
+    );
+    let mut config = String::new();
+    // Writing to a String can't fail
+    let _ = write!(
+        config,
+        r#"
+[[chains]]
+id = '{chain_id}'
+type = 'CosmosSdk'
+rpc_addr = '{rpc_url}'
+grpc_addr = '{grpc_url}'
+event_source = {{ mode = 'push', url = '{websocket_url}', batch_delay = '500ms' }}
+rpc_timeout = '10s'
+account_prefix = '{prefix}'
+key_name = '{KEY_NAME}'
+store_prefix = 'ibc'
+default_gas = 100000
+max_gas = 3000000
+gas_price = {{ price = {gas_price}, denom = '{gas_denom}' }}
+gas_multiplier = 1.5
+max_msg_num = 30
+max_tx_size = 2097152
+clock_drift = '5s'
+max_block_time = '30s'
+trusting_period = '14days'
+trust_threshold = {{ numerator = '1', denominator = '3' }}
+address_type = {{ derivation = 'cosmos' }}
+"#,
+        chain_id = chain_info.chain_id,
+        prefix = chain_info.network_info.pub_address_prefix,
+        gas_price = chain_info.gas_price,
+        gas_denom = chain_info.gas_denom,
+    );
+    Ok(config)
+}
+
+/// Chain file imported with `rly chains add --file`
+fn rly_chain_config(chain_info: &ChainInfoOwned, rpc_url: &str) -> Value {
+    json!({
+        "type": "cosmos",
+        "value": {
+            "key": KEY_NAME,
+            "chain-id": chain_info.chain_id,
+            "rpc-addr": rpc_url,
+            "account-prefix": chain_info.network_info.pub_address_prefix,
+            "keyring-backend": "test",
+            "gas-adjustment": 1.5,
+            "gas-prices": format!("{}{}", chain_info.gas_price, chain_info.gas_denom),
+            "coin-type": chain_info.network_info.coin_type,
+            "debug": false,
+            "timeout": "20s",
+            "output-format": "json",
+            "sign-mode": "direct"
+        }
+    })
+}
+
+/// Every unordered pair of chains, the rly paths are created between them
+fn chain_pairs(chain_ids: &[String]) -> impl Iterator<Item = (&str, &str)> {
+    chain_ids.iter().enumerate().flat_map(move |(i, src)| {
+        chain_ids[i + 1..]
+            .iter()
+            .map(move |dst| (src.as_str(), dst.as_str()))
+    })
+}
+
+fn rly_path(src_chain: &str, dst_chain: &str) -> String {
+    format!("{src_chain}_{dst_chain}")
+}
+
+/// Channel end `(channel_id, connection_id)` on `port` towards `counterparty_port` in the output of `rly query channels`.
+/// The output has a JSON channel per line, the newest channel is the last matching open one.
+fn find_rly_channel(output: &str, port: &str, counterparty_port: &str) -> Option<(String, String)> {
+    output
+        .lines()
+        .filter_map(|line| serde_json::from_str::<Value>(line).ok())
+        .filter(|channel| {
+            channel["state"] == "STATE_OPEN"
+                && channel["port_id"] == port
+                && channel["counterparty"]["port_id"] == counterparty_port
+        })
+        .last()
+        .and_then(|channel| {
+            Some((
+                channel["channel_id"].as_str()?.to_string(),
+                channel["connection_hops"][0].as_str()?.to_string(),
+            ))
+        })
+}
+
+/// Hermes or rly relayer running in a Docker container, created with [`DockerRelayer::hermes`] or [`DockerRelayer::rly`].
+/// Use [`ChannelCreator::interchain_env`] to get an interchain environment relayed by it.
+/// The container is removed when the last clone of the relayer is dropped.
+#[derive(Clone)]
+pub struct DockerRelayer {
+    container: Arc<RelayerContainer>,
+    daemons: Vec<Daemon>,
+    chain_ids: Vec<String>,
+    /// rly paths already relayed by a `rly start` process
+    started_paths: Arc<Mutex<HashSet<String>>>,
+}
+
+impl DockerRelayer {
+    /// Builder of a Hermes relayer
+    pub fn hermes() -> DockerRelayerBuilder {
+        DockerRelayerBuilder::new(RelayerKind::Hermes)
+    }
+
+    /// Builder of a rly relayer
+    pub fn rly() -> DockerRelayerBuilder {
+        DockerRelayerBuilder::new(RelayerKind::Rly)
+    }
+
+    /// Relayer implementation running in the container
+    pub fn kind(&self) -> RelayerKind {
+        self.container.kind
+    }
+
+    /// Name of the container running the relayer
+    pub fn container_name(&self) -> &str {
+        &self.container.name
+    }
+
+    /// Checks that the relayer can reach all the chains
+    pub fn health_check(&self) -> IcDaemonResult<()> {
+        match self.kind() {
+            RelayerKind::Hermes => {
+                self.container.hermes(&["health-check"])?;
+            }
+            RelayerKind::Rly => {
+                for chain_id in &self.chain_ids {
+                    self.container.exec(&["query", "balance", chain_id])?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks that the channel end `channel_id` on `port` of `chain` is open
+    pub fn check_channel_open(
+        &self,
+        chain: ChainId,
+        port: &PortId,
+        channel_id: &str,
+    ) -> IcDaemonResult<()> {
+        let state = match self.kind() {
+            RelayerKind::Hermes => self.container.hermes(&[
+                "query",
+                "channel",
+                "end",
+                "--chain",
+                chain,
+                "--port",
+                port.as_str(),
+                "--channel",
+                channel_id,
+            ])?["state"]
+                .clone(),
+            RelayerKind::Rly => {
+                let output =
+                    self.container
+                        .exec(&["query", "channel", chain, channel_id, port.as_str()])?;
+                let output: Value = serde_json::from_str(&output).map_err(|_| {
+                    InterchainDaemonError::Relayer(format!("unexpected rly output: {output}"))
+                })?;
+                output["channel"]["state"].clone()
+            }
+        };
+        match state.as_str() {
+            Some("Open" | "STATE_OPEN") => Ok(()),
+            state => Err(InterchainDaemonError::Relayer(format!(
+                "channel {channel_id} on {chain} is not open: {state:?}"
+            ))),
+        }
+    }
+
+    /// Creates the channel with Hermes, returns the channel and connection ids on `src_chain`
+    fn hermes_create_channel(
+        &self,
+        src_chain: ChainId,
+        dst_chain: ChainId,
+        src_port: &PortId,
+        dst_port: &PortId,
+        version: &str,
+        order: &str,
+    ) -> IcDaemonResult<(String, String)> {
+        let result = self.container.hermes(&[
+            "create",
+            "channel",
+            "--yes",
+            "--new-client-connection",
+            "--a-chain",
+            src_chain,
+            "--b-chain",
+            dst_chain,
+            "--a-port",
+            src_port.as_str(),
+            "--b-port",
+            dst_port.as_str(),
+            "--channel-version",
+            version,
+            "--order",
+            order,
+        ])?;
+        let a_side = &result["a_side"];
+        match (
+            a_side["channel_id"].as_str(),
+            a_side["connection_id"].as_str(),
+        ) {
+            (Some(channel_id), Some(connection_id)) => {
+                Ok((channel_id.to_string(), connection_id.to_string()))
+            }
+            _ => Err(InterchainDaemonError::Relayer(format!(
+                "unexpected channel creation result: {result}"
+            ))),
+        }
+    }
+
+    /// Links the path between the chains with rly and relays it, returns the channel and connection ids on `src_chain`
+    fn rly_create_channel(
+        &self,
+        src_chain: ChainId,
+        dst_chain: ChainId,
+        src_port: &PortId,
+        dst_port: &PortId,
+        version: &str,
+        order: &str,
+    ) -> IcDaemonResult<(String, String)> {
+        let (path, path_src_port, path_dst_port) = chain_pairs(&self.chain_ids)
+            .find_map(|pair| {
+                if pair == (src_chain, dst_chain) {
+                    Some((rly_path(src_chain, dst_chain), src_port, dst_port))
+                } else if pair == (dst_chain, src_chain) {
+                    Some((rly_path(dst_chain, src_chain), dst_port, src_port))
+                } else {
+                    None
+                }
+            })
+            .ok_or_else(|| {
+                InterchainDaemonError::Relayer(format!(
+                    "no rly path between {src_chain} and {dst_chain}"
+                ))
+            })?;
+        self.container.exec(&[
+            "transact",
+            "link",
+            &path,
+            "--src-port",
+            path_src_port.as_str(),
+            "--dst-port",
+            path_dst_port.as_str(),
+            "--version",
+            version,
+            "--order",
+            order,
+        ])?;
+        if self.started_paths.lock().unwrap().insert(path.clone()) {
+            self.container.exec_detached(&["start", &path])?;
+        }
+
+        let channels = self.container.exec(&["query", "channels", src_chain])?;
+        find_rly_channel(&channels, src_port.as_str(), dst_port.as_str()).ok_or_else(|| {
+            InterchainDaemonError::Relayer(format!(
+                "no open channel on {src_chain} port {src_port} after linking {path}"
+            ))
+        })
+    }
+}
+
+impl ChannelCreator for DockerRelayer {
+    fn create_ibc_channel(
+        &self,
+        src_chain: ChainId,
+        dst_chain: ChainId,
+        src_port: &PortId,
+        dst_port: &PortId,
+        version: &str,
+        order: Option<IbcOrder>,
+    ) -> Result<String, InterchainDaemonError> {
+        let order = match order {
+            Some(IbcOrder::Ordered) => "ordered",
+            _ => "unordered",
+        };
+        let (channel_id, connection_id) = match self.kind() {
+            RelayerKind::Hermes => self
+                .hermes_create_channel(src_chain, dst_chain, src_port, dst_port, version, order)?,
+            RelayerKind::Rly => {
+                self.rly_create_channel(src_chain, dst_chain, src_port, dst_port, version, order)?
+            }
+        };
+        self.check_channel_open(src_chain, src_port, &channel_id)?;
+        log::info!("Channel {channel_id} was created by the relayer on {src_chain}");
+
+        Ok(connection_id)
+    }
+
+    fn interchain_env(&self) -> DaemonInterchainEnv<Self> {
+        DaemonInterchainEnv::from_daemons(self.daemons.clone(), self)
+    }
+}
+
+/// Relayer container, removed on drop
+struct RelayerContainer {
+    name: String,
+    kind: RelayerKind,
+    config_dir: PathBuf,
+}
+
+impl RelayerContainer {
+    fn command(&self, detached: bool, args: &[&str]) -> Command {
+        let mut command = Command::new("docker");
+        command.arg("exec");
+        if detached {
+            command.arg("-d");
+        }
+        command.args([&self.name, self.kind.binary()]);
+        if self.kind == RelayerKind::Rly {
+            command.args(["--home", self.kind.home()]);
+        }
+        command.args(args);
+        command
+    }
+
+    /// Runs a relayer command in the container and returns its output
+    fn exec(&self, args: &[&str]) -> IcDaemonResult<String> {
+        Ok(run(&mut self.command(false, args))?)
+    }
+
+    /// Starts a relayer command in the background
+    fn exec_detached(&self, args: &[&str]) -> IcDaemonResult<()> {
+        run(&mut self.command(true, args))?;
+        Ok(())
+    }
+
+    /// Runs a hermes command in the container and returns its JSON result
+    fn hermes(&self, args: &[&str]) -> IcDaemonResult<Value> {
+        let args = [&["--json"][..], args].concat();
+        let stdout = self.exec(&args)?;
+        // The result is the last line, previous lines are logs
+        let last_line = stdout.lines().last().unwrap_or_default();
+        let output: Value = serde_json::from_str(last_line).map_err(|_| {
+            InterchainDaemonError::Relayer(format!("unexpected hermes output: {stdout}"))
+        })?;
+        if output["status"] != "success" {
+            return Err(InterchainDaemonError::Relayer(format!(
+                "hermes {} failed: {}",
+                args.join(" "),
+                output["result"]
+            )));
+        }
+        Ok(output["result"].clone())
+    }
+
+    /// Imports the relayer key of `chain_info`, derived like a daemon sender
+    fn add_key(&self, chain_info: &ChainInfoOwned, mnemonic: &str) -> IcDaemonResult<()> {
+        let coin_type = chain_info.network_info.coin_type.to_string();
+        match self.kind {
+            RelayerKind::Hermes => {
+                self.hermes(&[
+                    "keys",
+                    "add",
+                    "--overwrite",
+                    "--key-name",
+                    KEY_NAME,
+                    "--chain",
+                    &chain_info.chain_id,
+                    "--mnemonic-file",
+                    &format!("{}/mnemonic.txt", self.kind.home()),
+                    "--hd-path",
+                    &format!("m/44'/{coin_type}'/0'/0/0"),
+                ])?;
+            }
+            RelayerKind::Rly => {
+                let chain_file =
+                    format!("{}/chains/{}.json", self.kind.home(), chain_info.chain_id);
+                self.exec(&["chains", "add", "--file", &chain_file, &chain_info.chain_id])?;
+                self.exec(&[
+                    "keys",
+                    "restore",
+                    &chain_info.chain_id,
+                    KEY_NAME,
+                    mnemonic,
+                    "--coin-type",
+                    &coin_type,
+                ])?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Drop for RelayerContainer {
+    fn drop(&mut self) {
+        log::info!("Removing relayer container {}", self.name);
+        remove_container(&self.name);
+        let _ = std::fs::remove_dir_all(&self.config_dir);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use cw_orch_daemon::networks::LOCAL_JUNO;
+
+    use super::*;
+
+    #[test]
+    fn hermes_chain_config_needs_grpc_url() -> anyhow::Result<()> {
+        let mut chain_info: ChainInfoOwned = LOCAL_JUNO.into();
+        let config = hermes_chain_config(&chain_info, "http://localhost:26657/")?;
+        assert!(config.contains("id = 'testing'"));
+        assert!(config.contains("grpc_addr = 'http://localhost:9090'"));
+        assert!(config.contains("url = 'ws://localhost:26657/websocket'"));
+        assert!(config.contains("account_prefix = 'juno'"));
+
+        chain_info.grpc_urls = vec![];
+        assert!(matches!(
+            hermes_chain_config(&chain_info, "http://localhost:26657"),
+            Err(InterchainDaemonError::Relayer(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn rly_chain_config() {
+        let chain_info: ChainInfoOwned = LOCAL_JUNO.into();
+        let config = super::rly_chain_config(&chain_info, "http://localhost:26657");
+        assert_eq!(config["value"]["chain-id"], "testing");
+        assert_eq!(config["value"]["rpc-addr"], "http://localhost:26657");
+        assert_eq!(config["value"]["gas-prices"], "0ujunox");
+        assert_eq!(config["value"]["coin-type"], 118);
+    }
+
+    #[test]
+    fn rly_paths_between_every_chain() {
+        let chain_ids = ["a", "b", "c"].map(String::from);
+        let pairs: Vec<_> = chain_pairs(&chain_ids).collect();
+        assert_eq!(pairs, vec![("a", "b"), ("a", "c"), ("b", "c")]);
+        assert_eq!(rly_path("a", "b"), "a_b");
+    }
+
+    #[test]
+    fn rly_channel_is_found_in_the_query_output() {
+        let output = [
+            r#"{"state":"STATE_OPEN","port_id":"transfer","channel_id":"channel-0","counterparty":{"port_id":"transfer","channel_id":"channel-0"},"connection_hops":["connection-0"]}"#,
+            r#"{"state":"STATE_INIT","port_id":"wasm.juno1","channel_id":"channel-1","counterparty":{"port_id":"wasm.osmo1","channel_id":""},"connection_hops":["connection-0"]}"#,
+            "not json",
+            r#"{"state":"STATE_OPEN","port_id":"wasm.juno1","channel_id":"channel-2","counterparty":{"port_id":"wasm.osmo1","channel_id":"channel-5"},"connection_hops":["connection-1"]}"#,
+        ]
+        .join("\n");
+
+        assert_eq!(
+            find_rly_channel(&output, "wasm.juno1", "wasm.osmo1"),
+            Some(("channel-2".to_string(), "connection-1".to_string()))
+        );
+        assert_eq!(find_rly_channel(&output, "transfer", "wasm.osmo1"), None);
+    }
+}
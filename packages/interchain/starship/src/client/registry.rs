@@ -1,5 +1,15 @@
 //! Defines a structure to interact with an online chain registry
+//!
+//! The chain data of the registry can be cached on disk with [`Registry::new_with_cache`], so that creating a [`Registry`] repeatedly,
+//! for instance in every test, doesn't query the registry every time.
+//! IBC paths and test keys change every time Starship restarts and are always fetched from the registry.
 
+use std::{
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime},
+};
+
+use cw_orch_daemon::env::default_state_folder;
 use ibc_chain_registry::{chain::ChainData, paths::IBCPath};
 use serde::Deserialize;
 use serde_json::Value;
@@ -10,31 +20,142 @@ use super::{error::StarshipClientError, StarshipClientResult};
 /// Alias for URL
 pub type URL = String;
 
+/// Default duration the cached chain data is used for
+pub const DEFAULT_CACHE_TTL: Duration = Duration::from_secs(60 * 60);
+
+const CHAINS_CACHE_KEY: &str = "chains";
+
+/// On-disk cache of the chain data of a registry
+#[derive(Debug, Clone)]
+pub struct RegistryCache {
+    dir: PathBuf,
+    ttl: Duration,
+}
+
+impl RegistryCache {
+    /// Cache responses in `dir`, using them for `ttl`
+    pub fn new(dir: impl Into<PathBuf>, ttl: Duration) -> Self {
+        Self {
+            dir: dir.into(),
+            ttl,
+        }
+    }
+
+    /// Cache of the registry at `url` in the cw-orchestrator folder, with the [`DEFAULT_CACHE_TTL`].
+    /// Registries are identified by their host and port only, use [`RegistryCache::new`] to separate the caches of clusters sharing them
+    pub fn default_for(url: &Url) -> Option<Self> {
+        let registry_id = format!(
+            "{}_{}",
+            url.host_str().unwrap_or("registry"),
+            url.port_or_known_default().unwrap_or_default()
+        );
+        let dir = default_state_folder()
+            .ok()?
+            .join("registry")
+            .join(registry_id);
+        Some(Self::new(dir, DEFAULT_CACHE_TTL))
+    }
+
+    /// Directory the responses are stored in
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Remove the cached chain data
+    pub fn clear(&self) -> std::io::Result<()> {
+        match std::fs::remove_dir_all(&self.dir) {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(e),
+            _ => Ok(()),
+        }
+    }
+
+    fn path(&self, key: &str) -> PathBuf {
+        self.dir.join(format!("{key}.json"))
+    }
+
+    /// Cached response for `key`, if it didn't expire
+    fn read(&self, key: &str) -> Option<Value> {
+        let path = self.path(key);
+        let age = std::fs::metadata(&path)
+            .and_then(|metadata| metadata.modified())
+            .ok()
+            .and_then(|modified| SystemTime::now().duration_since(modified).ok())?;
+        if age > self.ttl {
+            return None;
+        }
+        let content = std::fs::read_to_string(path).ok()?;
+        serde_json::from_str(&content).ok()
+    }
+
+    fn write(&self, key: &str, value: &Value) {
+        let written = std::fs::create_dir_all(&self.dir)
+            .and_then(|_| std::fs::write(self.path(key), value.to_string()));
+        if let Err(e) = written {
+            log::warn!("Could not cache registry response {key}: {e}");
+        }
+    }
+}
+
 /// Structure that is able to query information about the chains inside the starship registry
 #[derive(Debug, Clone)]
-pub struct Registry(url::Url);
+pub struct Registry {
+    url: Url,
+    cache: Option<RegistryCache>,
+}
 
 impl Registry {
     /// Construct the registry url from the config
     pub async fn new(url: Url) -> Self {
-        let registry = Self(url);
-
-        let registry_url = registry.chains_data_url();
+        Self::new_with_cache(url, None).await
+    }
 
-        // Assert that the registry is reachable
-        let client = reqwest::Client::new();
-        client
-            .get(registry_url)
-            .send()
-            .await
-            .map_err(|e| StarshipClientError::RegistryError(e.to_string()))
-            .unwrap();
+    /// Construct the registry url from the config, caching the chain data in `cache` if provided.
+    /// The cache is only valid as long as the Starship cluster isn't restarted, [`Registry::refresh`] it otherwise.
+    pub async fn new_with_cache(url: Url, cache: Option<RegistryCache>) -> Self {
+        let registry = Self { url, cache };
+
+        let cached = registry
+            .cache
+            .as_ref()
+            .and_then(|cache| cache.read(CHAINS_CACHE_KEY));
+        if cached.is_none() {
+            let registry_url = registry.chains_data_url();
+
+            // Assert that the registry is reachable
+            let client = reqwest::Client::new();
+            client
+                .get(registry_url)
+                .send()
+                .await
+                .map_err(|e| StarshipClientError::RegistryError(e.to_string()))
+                .unwrap();
+        }
 
         registry
     }
 
+    /// Cache used for the responses of the registry
+    pub fn cache(&self) -> Option<&RegistryCache> {
+        self.cache.as_ref()
+    }
+
+    /// Fetch the chain data again, replacing the cached one
+    pub async fn refresh(&self) -> StarshipClientResult<Vec<ChainData>> {
+        if let Some(cache) = &self.cache {
+            cache
+                .clear()
+                .map_err(|e| StarshipClientError::RegistryError(e.to_string()))?;
+        }
+        self.chain_data().await
+    }
+
+    /// Get the JSON at `url`
+    async fn get_json(&self, url: &str) -> StarshipClientResult<Value> {
+        Ok(reqwest::get(url).await?.error_for_status()?.json().await?)
+    }
+
     fn get_url(&self, subpath: &str) -> String {
-        self.0.join(subpath).unwrap().to_string()
+        self.url.join(subpath).unwrap().to_string()
     }
 
     fn chains_data_url(&self) -> String {
@@ -54,15 +175,28 @@ impl Registry {
         let ibc_path_url = format!("{}/{}/{}", self.ibc_data_url(), chain_id_a, chain_id_b);
         eprintln!("ibc_paths_url: {:?}", ibc_path_url);
 
-        let response = reqwest::get(&ibc_path_url).await?;
-        let path: IBCPath = response.json().await?;
+        let value = self.get_json(&ibc_path_url).await?;
+        let path: IBCPath = serde_json::from_value(value)
+            .map_err(|e| StarshipClientError::RegistryError(e.to_string()))?;
         Ok(path)
     }
 
     /// Get all the chain data for this registry.
     pub async fn chain_data(&self) -> StarshipClientResult<Vec<ChainData>> {
-        let response = reqwest::get(&self.chains_data_url()).await?;
-        let value: Value = response.json().await?;
+        let cached = self
+            .cache
+            .as_ref()
+            .and_then(|cache| cache.read(CHAINS_CACHE_KEY));
+        let value = match cached {
+            Some(value) => value,
+            None => {
+                let value = self.get_json(&self.chains_data_url()).await?;
+                if let Some(cache) = &self.cache {
+                    cache.write(CHAINS_CACHE_KEY, &value);
+                }
+                value
+            }
+        };
 
         let chains: Vec<ChainData> = serde_json::from_value(value["chains"].clone()).unwrap();
         Ok(chains)
@@ -71,8 +205,9 @@ impl Registry {
     /// Get the first test account mnemonic from the chain registry.
     pub async fn test_mnemonic(&self, chain_id: &str) -> Result<String, StarshipClientError> {
         let url = self.get_url(&format!("chains/{}/keys", chain_id));
-        let response = reqwest::get(&url).await?;
-        let data: Mnemonics = response.json().await?;
+        let value = self.get_json(&url).await?;
+        let data: Mnemonics = serde_json::from_value(value)
+            .map_err(|e| StarshipClientError::RegistryError(e.to_string()))?;
         let first_test_account_mnemonic = data
             .genesis
             .first()
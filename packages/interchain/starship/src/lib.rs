@@ -86,6 +86,7 @@ fn chain_data_conversion(chain: ChainData) -> ChainInfoOwned {
             chain_name: chain.chain_name,
             pub_address_prefix: chain.bech32_prefix,
             coin_type: chain.slip44,
+            pub_key_type: None,
         },
         kind: chain.network_type.into(),
     }
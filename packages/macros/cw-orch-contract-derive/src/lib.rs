@@ -1,12 +1,14 @@
 #![recursion_limit = "128"]
 
+use syn::{
+    __private::TokenStream2, parse_macro_input, Fields, GenericArgument, GenericParam, Item, Path,
+};
 use syn::{Expr, Token};
-use syn::{__private::TokenStream2, parse_macro_input, Fields, GenericArgument, Item, Path};
 extern crate proc_macro;
 
 use proc_macro::TokenStream;
 
-use quote::quote;
+use quote::{format_ident, quote};
 
 use syn::{punctuated::Punctuated, token::Comma};
 
@@ -14,49 +16,136 @@ use syn::parse::{Parse, ParseStream};
 
 mod kw {
     syn::custom_keyword!(id);
+    syn::custom_keyword!(sylvia);
+    syn::custom_keyword!(entry_points);
+    syn::custom_keyword!(wasm);
+    syn::custom_keyword!(artifacts);
+    syn::custom_keyword!(build_postfix);
+    syn::custom_keyword!(chain_name);
+    syn::custom_keyword!(chain_id);
 }
+
+// Optional entry points that can be wired in the generated mock wrapper
+const OPTIONAL_ENTRY_POINTS: [&str; 3] = ["migrate", "sudo", "reply"];
+
+// Module containing the contract entry points, followed by the optional entry points it exposes
+// e.g. `entry_points(crate::contract::entry_points, migrate)`
+struct EntryPoints {
+    module: Path,
+    optional: Vec<syn::Ident>,
+}
+
+impl Parse for EntryPoints {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let _: kw::entry_points = input.parse()?;
+        let content;
+        syn::parenthesized!(content in input);
+        let module: Path = content.parse()?;
+        let mut optional = vec![];
+        while content.parse::<Option<Token![,]>>()?.is_some() && !content.is_empty() {
+            let entry_point: syn::Ident = content.parse()?;
+            if !OPTIONAL_ENTRY_POINTS.contains(&entry_point.to_string().as_str()) {
+                return Err(syn::Error::new(
+                    entry_point.span(),
+                    format!("Expected one of {OPTIONAL_ENTRY_POINTS:?}"),
+                ));
+            }
+            optional.push(entry_point);
+        }
+        Ok(Self { module, optional })
+    }
+}
+
+// Build post-fix of the wasm artifact, e.g. `build_postfix = chain_name` or `build_postfix = "custom"`
+enum BuildPostfix {
+    ChainName,
+    ChainId,
+    Custom(Expr),
+}
+
+impl Parse for BuildPostfix {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.peek(kw::chain_name) {
+            let _: kw::chain_name = input.parse()?;
+            Ok(Self::ChainName)
+        } else if input.peek(kw::chain_id) {
+            let _: kw::chain_id = input.parse()?;
+            Ok(Self::ChainId)
+        } else {
+            Ok(Self::Custom(input.parse()?))
+        }
+    }
+}
+
 // This is used to parse the types into a list of types separated by Commas
-// and default contract id if provided by "id = $expr"
+// and the options of the interface:
+// - default contract id if provided by "id = $expr"
+// - sylvia contract to take the message types from if provided by "sylvia = $path"
+// - entry points to use for the mock wrapper if provided by "entry_points($path, ...)"
+// - wasm artifact name, artifacts directory and build post-fix if provided by "wasm = $expr", "artifacts = $expr" and "build_postfix = ..."
 struct InterfaceInput {
     expressions: Punctuated<Path, Comma>,
-    _kw_id: Option<kw::id>,
-    _eq_token: Option<Token![=]>,
     default_id: Option<Expr>,
+    sylvia: Option<Path>,
+    entry_points: Option<EntryPoints>,
+    wasm: Option<Expr>,
+    artifacts: Option<Expr>,
+    build_postfix: Option<BuildPostfix>,
 }
 
 // Implement the `Parse` trait for your input struct
 impl Parse for InterfaceInput {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         let mut expressions: Punctuated<Path, Comma> = Punctuated::new();
-
-        while let Ok(path) = input.parse() {
-            expressions.push(path);
-            let _: Option<Token![,]> = input.parse().ok();
-
-            // If we found id = break
+        let mut default_id = None;
+        let mut sylvia = None;
+        let mut entry_points = None;
+        let mut wasm = None;
+        let mut artifacts = None;
+        let mut build_postfix = None;
+
+        while !input.is_empty() {
             if input.peek(kw::id) {
-                break;
+                let _: kw::id = input.parse()?;
+                let _: Token![=] = input.parse().map_err(|_| {
+                    syn::Error::new(
+                        input.span(),
+                        "The id argument of the macro should be of the format `id=my_contract_id`",
+                    )
+                })?;
+                default_id = Some(input.parse()?);
+            } else if input.peek(kw::sylvia) {
+                let _: kw::sylvia = input.parse()?;
+                let _: Token![=] = input.parse()?;
+                sylvia = Some(input.parse()?);
+            } else if input.peek(kw::entry_points) {
+                entry_points = Some(input.parse()?);
+            } else if input.peek(kw::wasm) {
+                let _: kw::wasm = input.parse()?;
+                let _: Token![=] = input.parse()?;
+                wasm = Some(input.parse()?);
+            } else if input.peek(kw::artifacts) {
+                let _: kw::artifacts = input.parse()?;
+                let _: Token![=] = input.parse()?;
+                artifacts = Some(input.parse()?);
+            } else if input.peek(kw::build_postfix) {
+                let _: kw::build_postfix = input.parse()?;
+                let _: Token![=] = input.parse()?;
+                build_postfix = Some(input.parse()?);
+            } else {
+                expressions.push(input.parse()?);
             }
+            let _: Option<Token![,]> = input.parse()?;
         }
-        // Parse if there is any
-        let kw_id: Option<kw::id> = input.parse().map_err(|_| {
-            syn::Error::new(
-                input.span(),
-                "The 5th argument of the macro should be of the format `id=my_contract_id`",
-            )
-        })?;
-        let eq_token: Option<Token![=]> = input.parse().map_err(|_| {
-            syn::Error::new(
-                input.span(),
-                "The 5th argument of the macro should be of the format `id=my_contract_id`",
-            )
-        })?;
-        let default_id: Option<Expr> = input.parse().ok();
+
         Ok(Self {
             expressions,
-            _kw_id: kw_id,
-            _eq_token: eq_token,
             default_id,
+            sylvia,
+            entry_points,
+            wasm,
+            artifacts,
+            build_postfix,
         })
     }
 }
@@ -76,6 +165,112 @@ fn get_generics_from_path(p: &Path) -> Punctuated<GenericArgument, Comma> {
     generics
 }
 
+// Generic parameters of the interface besides `Chain`, in the different positions they are used in
+struct InterfaceGenerics {
+    // Parameters of the struct definition, with their bounds and defaults
+    struct_params: Vec<TokenStream2>,
+    // Parameters of the impl blocks, with their bounds
+    impl_params: Vec<TokenStream2>,
+    // Arguments of the interface type
+    type_args: Vec<TokenStream2>,
+    // Predicates of the struct where clause
+    predicates: Vec<TokenStream2>,
+    // Whether the parameters were declared on the struct rather than taken from the message types
+    declared: bool,
+}
+
+impl InterfaceGenerics {
+    // Where clause of the struct and of the impl blocks that don't involve the messages
+    fn where_clause(&self) -> TokenStream2 {
+        let predicates = &self.predicates;
+        if predicates.is_empty() {
+            quote!()
+        } else {
+            quote!(where #(#predicates,)*)
+        }
+    }
+
+    // Where clause of the impl block of an entry point trait, the message types need to be `Debug + Serialize`
+    fn entry_point_where_clause(&self, msg: &TokenStream2) -> TokenStream2 {
+        let predicates = &self.predicates;
+        if self.declared {
+            quote!(where #(#predicates,)* #msg: ::std::fmt::Debug + ::serde::Serialize)
+        } else if self.type_args.is_empty() {
+            quote!()
+        } else {
+            let bounds = self
+                .type_args
+                .iter()
+                .map(|t| quote!(#t: ::std::fmt::Debug + ::serde::Serialize));
+            quote!(where #(#bounds,)*)
+        }
+    }
+}
+
+// The generic parameters declared on the struct take precedence, e.g. `pub struct Cw20<Chain, M = Empty>;`
+// Otherwise every generic argument of the message types becomes a parameter of the interface
+fn interface_generics(
+    generics: &syn::Generics,
+    message_types: impl Iterator<Item = Path>,
+) -> InterfaceGenerics {
+    let declared: Vec<syn::TypeParam> = generics
+        .params
+        .iter()
+        .filter_map(|param| match param {
+            GenericParam::Type(t) if t.ident == "Chain" => None,
+            GenericParam::Type(t) => Some(t.clone()),
+            _ => panic!("Only type parameters are supported on the interface struct"),
+        })
+        .collect();
+
+    if declared.is_empty() {
+        let mut args: Vec<TokenStream2> = vec![];
+        for arg in message_types.flat_map(|p| get_generics_from_path(&p)) {
+            let arg = quote!(#arg);
+            // The same parameter can be used by multiple message types
+            if !args.iter().any(|a| a.to_string() == arg.to_string()) {
+                args.push(arg);
+            }
+        }
+        return InterfaceGenerics {
+            struct_params: args.clone(),
+            impl_params: args.clone(),
+            type_args: args,
+            predicates: vec![],
+            declared: false,
+        };
+    }
+
+    InterfaceGenerics {
+        struct_params: declared.iter().map(|t| quote!(#t)).collect(),
+        impl_params: declared
+            .iter()
+            .map(|t| {
+                let (ident, bounds) = (&t.ident, &t.bounds);
+                if bounds.is_empty() {
+                    quote!(#ident)
+                } else {
+                    quote!(#ident: #bounds)
+                }
+            })
+            .collect(),
+        type_args: declared
+            .iter()
+            .map(|t| {
+                let ident = &t.ident;
+                quote!(#ident)
+            })
+            .collect(),
+        predicates: generics
+            .where_clause
+            .iter()
+            .flat_map(|w| w.predicates.iter())
+            .map(|p| quote!(#p))
+            .collect(),
+        declared: true,
+    }
+}
+
 /**
 Procedural macro to generate a cw-orchestrator interface
 
@@ -116,6 +311,18 @@ impl <Chain> ::cw_orch::core::contract::interface_traits::ExecutableContract for
 // ... other entry point & upload traits
 ```
 
+## Generic message types
+
+Generic arguments of the message types become generic parameters of the interface, after `Chain`.
+They can also be declared on the struct, with bounds and defaults, so the same interface serves the vanilla and the chain-specialized builds of a contract:
+
+```ignore
+#[interface(InstantiateMsg, ExecuteMsg<M>, QueryMsg<Q>, Empty)]
+pub struct Vault<Chain, M = Empty, Q = Empty>;
+
+// `Vault<Daemon>` uses the vanilla messages, `Vault<Daemon, NeutronMsg, NeutronQuery>` the Neutron ones
+```
+
 ## Linking the interface to its source code
 
 The interface can be linked to its source code by implementing the `Uploadable` trait for the interface.
@@ -139,6 +346,28 @@ impl <Chain> Uploadable for Cw20<Chain> {
         WasmPath::new("path/to/cw20.wasm").unwrap()
     }
 }
+```
+
+## Sylvia contracts
+
+The message types of a Sylvia contract are taken from its `ContractApi` with `sylvia = ContractType`, and `entry_points(module, ...)` generates the `Uploadable::wrapper` from the entry points generated by Sylvia.
+The `ExecuteFns` and `QueryFns` are derived on the Sylvia messages with `sv::msg_attr`:
+
+```ignore
+#[entry_points]
+#[contract]
+#[sv::msg_attr(exec, derive(cw_orch::ExecuteFns))]
+#[sv::msg_attr(query, derive(cw_orch::QueryFns))]
+impl CounterContract {
+    // ...
+}
+
+#[interface(sylvia = CounterContract, entry_points(crate::contract::entry_points, migrate))]
+pub struct SylviaCounter;
+
+// `increment` is generated from the `ExecMsg::Increment` variant
+counter.increment()?;
+```
 */
 #[proc_macro_attribute]
 pub fn interface(attrs: TokenStream, input: TokenStream) -> TokenStream {
@@ -147,12 +376,32 @@ pub fn interface(attrs: TokenStream, input: TokenStream) -> TokenStream {
     // Try to parse the attributes to a
     let attributes = parse_macro_input!(attrs as InterfaceInput);
 
-    let types_in_order = attributes.expressions;
     let default_id = attributes.default_id;
 
-    if types_in_order.len() != 4 {
-        panic!("Expected four endpoint types (InstantiateMsg, ExecuteMsg, QueryMsg, MigrateMsg). Use cosmwasm_std::Empty if not implemented.")
-    }
+    let types_in_order: Vec<TokenStream2> = match &attributes.sylvia {
+        Some(contract) => {
+            if !attributes.expressions.is_empty() {
+                panic!("Endpoint types can't be specified together with a sylvia contract, they are taken from the contract directly.")
+            }
+            let contract_api = quote!(<#contract as ::sylvia::types::ContractApi>);
+            vec![
+                quote!(#contract_api::Instantiate),
+                quote!(#contract_api::Exec),
+                quote!(#contract_api::Query),
+                quote!(#contract_api::Migrate),
+            ]
+        }
+        None => {
+            if attributes.expressions.len() != 4 {
+                panic!("Expected four endpoint types (InstantiateMsg, ExecuteMsg, QueryMsg, MigrateMsg). Use cosmwasm_std::Empty if not implemented.")
+            }
+            attributes
+                .expressions
+                .iter()
+                .map(|path| quote!(#path))
+                .collect()
+        }
+    };
 
     let Item::Struct(cw_orch_struct) = &mut item else {
         panic!("Only works on structs");
@@ -166,13 +415,24 @@ pub fn interface(attrs: TokenStream, input: TokenStream) -> TokenStream {
     let query = types_in_order[2].clone();
     let migrate = types_in_order[3].clone();
 
-    // We create all generics for all types
-    let all_generics: Punctuated<GenericArgument, Comma> = types_in_order
-        .iter()
-        .flat_map(get_generics_from_path)
-        .collect();
+    let generics = interface_generics(
+        &cw_orch_struct.generics,
+        attributes
+            .expressions
+            .iter()
+            .chain(attributes.sylvia.iter())
+            .cloned(),
+    );
+    let InterfaceGenerics {
+        struct_params,
+        impl_params,
+        type_args,
+        ..
+    } = &generics;
+    let where_clause = generics.where_clause();
+
     // We create all phantom markers because else types are unused
-    let all_phantom_markers: Vec<TokenStream2> = all_generics
+    let all_phantom_markers: Vec<TokenStream2> = type_args
         .iter()
         .map(|t| {
             quote!(
@@ -181,30 +441,20 @@ pub fn interface(attrs: TokenStream, input: TokenStream) -> TokenStream {
         })
         .collect();
 
-    let all_phantom_marker_values: Vec<TokenStream2> = all_generics
+    let all_phantom_marker_values: Vec<TokenStream2> = type_args
         .iter()
         .map(|_| quote!(::std::marker::PhantomData::default()))
         .collect();
 
-    // We create necessary Debug + Serialize traits
-    let all_debug_serialize: Vec<TokenStream2> = all_generics
-        .iter()
-        .map(|t| {
-            quote!(
-                #t: ::std::fmt::Debug + ::serde::Serialize
-            )
-        })
-        .collect();
-    let all_debug_serialize = if !all_debug_serialize.is_empty() {
-        quote!(where #(#all_debug_serialize,)*)
-    } else {
-        quote!()
-    };
+    let init_where_clause = generics.entry_point_where_clause(&init);
+    let exec_where_clause = generics.entry_point_where_clause(&exec);
+    let query_where_clause = generics.entry_point_where_clause(&query);
+    let migrate_where_clause = generics.entry_point_where_clause(&migrate);
 
     let name = cw_orch_struct.ident.clone();
     let default_num = if let Some(id_expr) = default_id {
         quote!(
-            impl <Chain, #all_generics> #name<Chain, #all_generics> {
+            impl <Chain, #(#impl_params,)*> #name<Chain, #(#type_args,)*> #where_clause {
                 pub fn new(chain: Chain) -> Self {
                     Self(
                         ::cw_orch::core::contract::Contract::new(#id_expr, chain)
@@ -214,7 +464,7 @@ pub fn interface(attrs: TokenStream, input: TokenStream) -> TokenStream {
         )
     } else {
         quote!(
-            impl <Chain, #all_generics> #name<Chain, #all_generics> {
+            impl <Chain, #(#impl_params,)*> #name<Chain, #(#type_args,)*> #where_clause {
                 pub fn new(contract_id: impl ToString, chain: Chain) -> Self {
                     Self(
                         ::cw_orch::core::contract::Contract::new(contract_id, chain)
@@ -223,12 +473,80 @@ pub fn interface(attrs: TokenStream, input: TokenStream) -> TokenStream {
             }
         )
     };
+    let wrapper = attributes.entry_points.map(|EntryPoints { module, optional }| {
+        let optional = optional.iter().map(|entry_point| {
+            let with_entry_point = format_ident!("with_{}", entry_point);
+            quote!(.#with_entry_point(#module::#entry_point))
+        });
+        quote!(
+            fn wrapper() -> Box<dyn ::cw_orch::mock::cw_multi_test::Contract<::cosmwasm_std::Empty, ::cosmwasm_std::Empty>> {
+                Box::new(
+                    ::cw_orch::mock::cw_multi_test::ContractWrapper::new_with_empty(
+                        #module::execute,
+                        #module::instantiate,
+                        #module::query,
+                    )
+                    #(#optional)*
+                )
+            }
+        )
+    });
+
+    if attributes.wasm.is_none()
+        && (attributes.artifacts.is_some() || attributes.build_postfix.is_some())
+    {
+        panic!("The `artifacts` and `build_postfix` options require the wasm artifact name to be specified with `wasm = \"my_contract\"`");
+    }
+    let wasm = attributes.wasm.map(|wasm_name| {
+        // Relative artifacts directories are resolved from the crate the interface is defined in
+        let artifacts_dir = match attributes.artifacts {
+            Some(artifacts) => quote!(
+                ::cw_orch::contract::ArtifactsDir::new(
+                    ::std::path::Path::new(::std::env!("CARGO_MANIFEST_DIR")).join(#artifacts)
+                )
+            ),
+            None => quote!(
+                ::cw_orch::contract::ArtifactsDir::auto(Some(::std::env!("CARGO_MANIFEST_DIR").to_string()))
+            ),
+        };
+        let build_postfix = match attributes.build_postfix {
+            None => quote!(::cw_orch::build::BuildPostfix::None),
+            Some(BuildPostfix::ChainName) => quote!(::cw_orch::build::BuildPostfix::ChainName(chain)),
+            Some(BuildPostfix::ChainId) => quote!(::cw_orch::build::BuildPostfix::ChainID(chain)),
+            Some(BuildPostfix::Custom(postfix)) => quote!(::cw_orch::build::BuildPostfix::Custom(#postfix.to_string())),
+        };
+        quote!(
+            #[allow(unused_variables)]
+            fn wasm(chain: &::cw_orch::environment::ChainInfoOwned) -> ::cw_orch::contract::WasmPath {
+                #artifacts_dir
+                    .find_wasm_path_with_build_postfix(#wasm_name, #build_postfix)
+                    .expect(::std::concat!(
+                        "Wasm artifact of the ",
+                        ::std::stringify!(#name),
+                        " interface not found, check the `wasm`, `artifacts` and `build_postfix` options of `#[interface]`"
+                    ))
+            }
+        )
+    });
+
+    let uploadable = if wrapper.is_some() || wasm.is_some() {
+        quote!(
+            #[cfg(not(target_arch = "wasm32"))]
+            impl<Chain, #(#impl_params,)*> ::cw_orch::core::contract::interface_traits::Uploadable for #name<Chain, #(#type_args,)*> #where_clause {
+                #wasm
+                #wrapper
+            }
+        )
+    } else {
+        quote!()
+    };
+
     let struct_def = quote!(
         #[cfg(not(target_arch = "wasm32"))]
         #[derive(
             ::std::clone::Clone,
         )]
-        pub struct #name<Chain, #all_generics>(::cw_orch::core::contract::Contract<Chain>, #(#all_phantom_markers,)*);
+        pub struct #name<Chain, #(#struct_params,)*>(::cw_orch::core::contract::Contract<Chain>, #(#all_phantom_markers,)*) #where_clause;
 
         #[cfg(target_arch = "wasm32")]
         #[derive(
@@ -240,7 +558,7 @@ pub fn interface(attrs: TokenStream, input: TokenStream) -> TokenStream {
         #default_num
 
         #[cfg(not(target_arch = "wasm32"))]
-        impl<Chain: ::cw_orch::core::environment::ChainState, #all_generics> ::cw_orch::core::contract::interface_traits::ContractInstance<Chain> for #name<Chain, #all_generics> {
+        impl<Chain: ::cw_orch::core::environment::ChainState, #(#impl_params,)*> ::cw_orch::core::contract::interface_traits::ContractInstance<Chain> for #name<Chain, #(#type_args,)*> #where_clause {
             fn as_instance(&self) -> &::cw_orch::core::contract::Contract<Chain> {
                 &self.0
             }
@@ -250,24 +568,26 @@ pub fn interface(attrs: TokenStream, input: TokenStream) -> TokenStream {
         }
 
         #[cfg(not(target_arch = "wasm32"))]
-        impl<Chain, #all_generics> ::cw_orch::core::contract::interface_traits::InstantiableContract for #name<Chain, #all_generics> #all_debug_serialize {
+        impl<Chain, #(#impl_params,)*> ::cw_orch::core::contract::interface_traits::InstantiableContract for #name<Chain, #(#type_args,)*> #init_where_clause {
             type InstantiateMsg = #init;
         }
 
         #[cfg(not(target_arch = "wasm32"))]
-        impl<Chain, #all_generics> ::cw_orch::core::contract::interface_traits::ExecutableContract for #name<Chain, #all_generics> #all_debug_serialize {
+        impl<Chain, #(#impl_params,)*> ::cw_orch::core::contract::interface_traits::ExecutableContract for #name<Chain, #(#type_args,)*> #exec_where_clause {
             type ExecuteMsg = #exec;
         }
 
         #[cfg(not(target_arch = "wasm32"))]
-        impl<Chain, #all_generics> ::cw_orch::core::contract::interface_traits::QueryableContract for #name<Chain, #all_generics> #all_debug_serialize {
+        impl<Chain, #(#impl_params,)*> ::cw_orch::core::contract::interface_traits::QueryableContract for #name<Chain, #(#type_args,)*> #query_where_clause {
             type QueryMsg = #query;
         }
 
         #[cfg(not(target_arch = "wasm32"))]
-        impl<Chain, #all_generics> ::cw_orch::core::contract::interface_traits::MigratableContract for #name<Chain, #all_generics> #all_debug_serialize {
+        impl<Chain, #(#impl_params,)*> ::cw_orch::core::contract::interface_traits::MigratableContract for #name<Chain, #(#type_args,)*> #migrate_where_clause {
             type MigrateMsg = #migrate;
         }
+
+        #uploadable
     );
     struct_def.into()
 }